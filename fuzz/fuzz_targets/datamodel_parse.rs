@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Schema parsing and validation run on arbitrary user input (editor
+// integrations, CLI). Malformed schemas must come back as diagnostics,
+// never as a panic.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = datamodel::parse_configuration(input);
+        let _ = datamodel::parse_datamodel(input);
+    }
+});
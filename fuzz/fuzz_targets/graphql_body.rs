@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use request_handlers::GraphQlBody;
+
+// The HTTP server deserializes request bodies straight into `GraphQlBody`
+// and converts them into query documents. A panic anywhere on that path
+// takes down the whole engine process, so errors must stay errors.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(body) = serde_json::from_slice::<GraphQlBody>(data) {
+        let _ = body.into_doc();
+    }
+});
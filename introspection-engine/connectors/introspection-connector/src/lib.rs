@@ -20,6 +20,14 @@ pub trait IntrospectionConnector: Send + Sync + 'static {
 
     async fn get_database_version(&self) -> ConnectorResult<String>;
 
+    /// Check which privileges the connected database user has. Introspection
+    /// only needs read access: any write privilege reported here means the
+    /// connection is more powerful than introspection requires. The default
+    /// implementation reports nothing.
+    async fn get_database_privileges(&self) -> ConnectorResult<DatabasePrivileges> {
+        Ok(DatabasePrivileges::default())
+    }
+
     async fn introspect(
         &self,
         existing_data_model: &Datamodel,
@@ -33,6 +41,25 @@ pub struct DatabaseMetadata {
     pub size_in_bytes: usize,
 }
 
+/// A report on the privileges of the connected database user, as far as the
+/// connector can determine them. Each field is `Some(true)` or `Some(false)`
+/// when the privilege could be verified, and `None` when it could not be
+/// determined.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabasePrivileges {
+    /// Can the connected user create tables?
+    pub create_tables: Option<bool>,
+    /// Can the connected user alter existing tables?
+    pub alter_tables: Option<bool>,
+    /// Can the connected user drop tables?
+    pub drop_tables: Option<bool>,
+    /// Can the connected user create foreign keys referencing other tables?
+    pub references: Option<bool>,
+    /// Can the connected user create databases?
+    pub create_databases: Option<bool>,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum Version {
     NonPrisma,
@@ -49,6 +76,8 @@ pub struct IntrospectionResult {
     pub warnings: Vec<Warning>,
     /// version
     pub version: Version,
+    /// user customizations that had to be overwritten
+    pub conflicts: Vec<Conflict>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -58,6 +87,23 @@ pub struct Warning {
     pub affected: Value,
 }
 
+/// A user customization from the previous data model that re-introspection had
+/// to overwrite because the database changed underneath it.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Conflict {
+    /// The model the conflict happened on.
+    pub model: String,
+    /// The field the conflict happened on, if it is field-level.
+    pub field: Option<String>,
+    /// The definition in the previous data model.
+    pub previous: String,
+    /// The definition after re-introspection.
+    pub new: String,
+    /// Why the previous definition could not be kept.
+    pub reason: String,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct IntrospectionResultOutput {
     /// Datamodel
@@ -66,16 +112,19 @@ pub struct IntrospectionResultOutput {
     pub warnings: Vec<Warning>,
     /// version
     pub version: Version,
+    /// user customizations that had to be overwritten
+    pub conflicts: Vec<Conflict>,
 }
 
 impl fmt::Display for IntrospectionResultOutput {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{{\"datamodel\": \"{}\", \"warnings\": {}, \"version\": \"{}\"}}",
+            "{{\"datamodel\": \"{}\", \"warnings\": {}, \"version\": \"{}\", \"conflicts\": {}}}",
             self.datamodel,
             serde_json::to_string(&self.warnings).unwrap(),
             serde_json::to_string(&self.version).unwrap(),
+            serde_json::to_string(&self.conflicts).unwrap(),
         )
     }
 }
@@ -84,6 +133,8 @@ pub struct IntrospectionContext {
     pub source: Datasource,
     pub composite_type_depth: CompositeTypeDepth,
     pub preview_features: BitFlags<PreviewFeature>,
+    pub table_filter: TableFilter,
+    pub naming_strategy: NamingStrategy,
 }
 
 impl IntrospectionContext {
@@ -92,6 +143,84 @@ impl IntrospectionContext {
     }
 }
 
+/// Opt-in renames applied to the introspected data model. The database names
+/// are preserved through `@map`/`@@map`, so the renames never require schema
+/// changes on the database side.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NamingStrategy {
+    /// Rename snake_case table names to PascalCase model names.
+    pub pascal_case_models: bool,
+    /// Rename snake_case column names to camelCase field names.
+    pub camel_case_fields: bool,
+    /// Singularize plural model names (`users` -> `user`).
+    pub singularize_models: bool,
+}
+
+impl NamingStrategy {
+    /// True when no renames were requested.
+    pub fn is_default(&self) -> bool {
+        !self.pascal_case_models && !self.camel_case_fields && !self.singularize_models
+    }
+}
+
+/// Filtering of the tables to introspect, based on glob patterns where `*`
+/// matches any sequence of characters. Matching is case sensitive.
+#[derive(Debug, Default, Clone)]
+pub struct TableFilter {
+    /// When non-empty, only tables matching at least one of these patterns are
+    /// introspected.
+    pub include: Vec<String>,
+    /// Tables matching at least one of these patterns are left out of the
+    /// introspected data model. Takes precedence over `include`.
+    pub exclude: Vec<String>,
+}
+
+impl TableFilter {
+    pub fn new(include: Vec<String>, exclude: Vec<String>) -> Self {
+        TableFilter { include, exclude }
+    }
+
+    /// True when no pattern was provided, i.e. all tables are introspected.
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Should the table with the given name be part of the introspected data model?
+    pub fn includes_table(&self, table_name: &str) -> bool {
+        if self.exclude.iter().any(|pattern| matches_glob(pattern, table_name)) {
+            return false;
+        }
+
+        self.include.is_empty() || self.include.iter().any(|pattern| matches_glob(pattern, table_name))
+    }
+}
+
+fn matches_glob(pattern: &str, name: &str) -> bool {
+    if !pattern.contains('*') {
+        return pattern == name;
+    }
+
+    let segments: Vec<&str> = pattern.split('*').collect();
+    let first = segments.first().unwrap();
+    let last = segments.last().unwrap();
+
+    if name.len() < first.len() + last.len() || !name.starts_with(first) || !name.ends_with(last) {
+        return false;
+    }
+
+    let mut haystack = &name[first.len()..name.len() - last.len()];
+
+    for segment in &segments[1..segments.len() - 1] {
+        match haystack.find(segment) {
+            Some(idx) => haystack = &haystack[idx + segment.len()..],
+            None => return false,
+        }
+    }
+
+    true
+}
+
 /// Control type for composite type traversal.
 #[derive(Debug, Clone, Copy)]
 pub enum CompositeTypeDepth {
@@ -135,3 +264,32 @@ impl CompositeTypeDepth {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_filter_glob_matching() {
+        assert!(matches_glob("User", "User"));
+        assert!(!matches_glob("User", "Users"));
+        assert!(matches_glob("*", "anything"));
+        assert!(matches_glob("etl_*", "etl_orders"));
+        assert!(!matches_glob("etl_*", "orders_etl"));
+        assert!(matches_glob("*_audit", "orders_audit"));
+        assert!(matches_glob("legacy_*_v2", "legacy_orders_v2"));
+        assert!(!matches_glob("legacy_*_v2", "legacy_orders_v3"));
+        assert!(!matches_glob("a*a", "a"));
+    }
+
+    #[test]
+    fn table_filter_exclude_takes_precedence() {
+        let filter = TableFilter::new(vec!["app_*".into()], vec!["app_secrets".into()]);
+
+        assert!(filter.includes_table("app_users"));
+        assert!(!filter.includes_table("app_secrets"));
+        assert!(!filter.includes_table("etl_orders"));
+
+        assert!(TableFilter::default().includes_table("anything"));
+    }
+}
@@ -53,5 +53,6 @@ pub(super) async fn sample(
         data_model,
         warnings,
         version: Version::NonPrisma,
+        conflicts: Vec::new(),
     })
 }
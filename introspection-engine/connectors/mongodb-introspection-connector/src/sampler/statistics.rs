@@ -146,6 +146,9 @@ impl<'a> Statistics<'a> {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        is_encrypted: false,
+                        is_readonly: false,
+                        computed_sql: None,
                     }));
                 }
                 Name::CompositeType(type_name) => {
@@ -443,6 +446,7 @@ fn add_indices_to_models(models: &mut BTreeMap<String, Model>, indices: &mut BTr
                 db_name: Some(index.name().to_string()),
                 name: None,
                 algorithm: None,
+                nulls_not_distinct: false,
             });
         }
     }
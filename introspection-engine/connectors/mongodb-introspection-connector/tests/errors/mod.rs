@@ -48,6 +48,8 @@ fn using_without_preview_feature_enabled() {
                 source: config.subject.datasources.pop().unwrap(),
                 composite_type_depth: Default::default(),
                 preview_features: Default::default(),
+                table_filter: Default::default(),
+                naming_strategy: Default::default(),
             };
 
             let connector = MongoDbIntrospectionConnector::new(&*CONN_STR).await?;
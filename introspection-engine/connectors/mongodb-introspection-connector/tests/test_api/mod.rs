@@ -92,6 +92,8 @@ where
         source: config.subject.datasources.pop().unwrap(),
         composite_type_depth,
         preview_features,
+        table_filter: Default::default(),
+        naming_strategy: Default::default(),
     };
 
     RT.block_on(async move {
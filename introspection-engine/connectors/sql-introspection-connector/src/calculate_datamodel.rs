@@ -1,4 +1,6 @@
+use crate::conflict_report::conflict_report;
 use crate::introspection_helpers::*;
+use crate::naming_strategies::{apply_naming_strategy, renaming_leads_to_duplicate_names};
 use crate::prisma_1_defaults::*;
 use crate::re_introspection::enrich;
 use crate::sanitize_datamodel_names::{sanitization_leads_to_duplicate_names, sanitize_datamodel_names};
@@ -29,13 +31,22 @@ pub fn calculate_datamodel(
         sanitize_datamodel_names(&mut data_model, &ctx);
     }
 
+    // user-requested casing and singularization, on top of sanitization
+    if !ctx.naming_strategy.is_default() && !renaming_leads_to_duplicate_names(&data_model, &ctx.naming_strategy) {
+        apply_naming_strategy(&mut data_model, &ctx.naming_strategy);
+    }
+
     // deduplicating relation field names
     deduplicate_relation_field_names(&mut data_model);
 
     let mut warnings = vec![];
+    let mut conflicts = vec![];
     if !previous_data_model.is_empty() {
         enrich(previous_data_model, &mut data_model, &ctx, &mut warnings);
         tracing::debug!("Enriching datamodel is done: {:?}", data_model);
+
+        // definitions from the previous data model that could not be kept
+        conflicts = conflict_report(previous_data_model, &data_model);
     }
 
     // commenting out models, fields, enums, enum values
@@ -53,5 +64,6 @@ pub fn calculate_datamodel(
         data_model,
         warnings,
         version,
+        conflicts,
     })
 }
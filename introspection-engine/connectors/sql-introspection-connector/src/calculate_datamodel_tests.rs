@@ -28,12 +28,17 @@ mod tests {
             shadow_database_url: None,
             provider: "postgresql".to_string(),
             referential_integrity: None,
+            migrations_table_name: None,
+            online_schema_change_tool: None,
+            autoincrement_strategy: None,
         };
 
         IntrospectionContext {
             source,
             composite_type_depth: Default::default(),
             preview_features: BitFlags::empty(),
+            table_filter: Default::default(),
+            naming_strategy: Default::default(),
         }
     }
 
@@ -62,6 +67,9 @@ mod tests {
                                     is_updated_at: false,
                                     is_commented_out: false,
                                     is_ignored: false,
+                                    is_encrypted: false,
+                                    is_readonly: false,
+                                    computed_sql: None,
                                 },
                             ),
                             ScalarField(
@@ -82,6 +90,9 @@ mod tests {
                                     is_updated_at: false,
                                     is_commented_out: false,
                                     is_ignored: false,
+                                    is_encrypted: false,
+                                    is_readonly: false,
+                                    computed_sql: None,
                                 },
                             ),
                             ScalarField(
@@ -100,6 +111,9 @@ mod tests {
                                     is_updated_at: false,
                                     is_commented_out: false,
                                     is_ignored: false,
+                                    is_encrypted: false,
+                                    is_readonly: false,
+                                    computed_sql: None,
                                 },
                             ),
                         ],
@@ -123,6 +137,9 @@ mod tests {
                         is_generated: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        is_strict: false,
+                        is_without_rowid: false,
+                        datasource: None,
                     },
                 ],
                 composite_types: [],
@@ -138,18 +155,21 @@ mod tests {
                     tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
                 Column {
                     name: "required".to_string(),
                     tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                     default: None,
                     auto_increment: true,
+                    identity: None,
                 },
                 Column {
                     name: "list".to_string(),
                     tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List),
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
             ],
             indices: vec![],
@@ -159,6 +179,7 @@ mod tests {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            sqlite_options: Default::default(),
         }];
         let introspection_result =
             calculate_datamodel(&schema, &Datamodel::new(), postgres_context()).expect("calculate data model");
@@ -178,6 +199,9 @@ mod tests {
                     documentation: None,
                     is_commented_out: false,
                     is_ignored: false,
+                    is_strict: false,
+                    is_without_rowid: false,
+                    datasource: None,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -197,6 +221,9 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        is_encrypted: false,
+                        is_readonly: false,
+                        computed_sql: None,
                     })],
                     is_generated: false,
                     indices: vec![],
@@ -214,6 +241,9 @@ mod tests {
                     documentation: None,
                     is_commented_out: false,
                     is_ignored: false,
+                    is_strict: false,
+                    is_without_rowid: false,
+                    datasource: None,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -233,6 +263,9 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        is_encrypted: false,
+                        is_readonly: false,
+                        computed_sql: None,
                     })],
                     is_generated: false,
                     indices: vec![],
@@ -250,6 +283,9 @@ mod tests {
                     documentation: None,
                     is_commented_out: false,
                     is_ignored: false,
+                    is_strict: false,
+                    is_without_rowid: false,
+                    datasource: None,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -269,6 +305,9 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        is_encrypted: false,
+                        is_readonly: false,
+                        computed_sql: None,
                     })],
                     is_generated: false,
                     indices: vec![],
@@ -297,6 +336,7 @@ mod tests {
                     },
                     default: None,
                     auto_increment: true,
+                    identity: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -305,6 +345,7 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                sqlite_options: Default::default(),
             },
             Table {
                 name: "Table2".to_string(),
@@ -318,6 +359,7 @@ mod tests {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -326,6 +368,7 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                sqlite_options: Default::default(),
             },
             Table {
                 name: "Table3".to_string(),
@@ -339,6 +382,7 @@ mod tests {
                     },
                     default: None,
                     auto_increment: true,
+                    identity: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -349,6 +393,7 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                sqlite_options: Default::default(),
             },
         ];
         let introspection_result =
@@ -367,6 +412,9 @@ mod tests {
                 documentation: None,
                 is_commented_out: false,
                 is_ignored: false,
+                is_strict: false,
+                is_without_rowid: false,
+                datasource: None,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
                         "non_unique",
@@ -384,6 +432,9 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        is_encrypted: false,
+                        is_readonly: false,
+                        computed_sql: None,
                     }),
                 ],
                 is_generated: false,
@@ -394,6 +445,7 @@ mod tests {
                     tpe: dml::IndexType::Unique,
                     defined_on_field: true,
                     algorithm: None,
+                    nulls_not_distinct: false,
                 }],
                 primary_key: None,
             }],
@@ -409,12 +461,14 @@ mod tests {
                     tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
                 Column {
                     name: "unique".to_string(),
                     tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
             ],
             indices: vec![Index {
@@ -425,6 +479,7 @@ mod tests {
             }],
             primary_key: None,
             foreign_keys: vec![],
+            sqlite_options: Default::default(),
         }];
         let introspection_result =
             calculate_datamodel(&schema, &Datamodel::new(), postgres_context()).expect("calculate data model");
@@ -443,6 +498,9 @@ mod tests {
                     documentation: None,
                     is_commented_out: false,
                     is_ignored: false,
+                    is_strict: false,
+                    is_without_rowid: false,
+                    datasource: None,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -463,6 +521,9 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            is_encrypted: false,
+                            is_readonly: false,
+                            computed_sql: None,
                         }),
                         Field::ScalarField(ScalarField::new(
                             "name",
@@ -507,6 +568,9 @@ mod tests {
                     documentation: None,
                     is_commented_out: false,
                     is_ignored: false,
+                    is_strict: false,
+                    is_without_rowid: false,
+                    datasource: None,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -527,6 +591,9 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            is_encrypted: false,
+                            is_readonly: false,
+                            computed_sql: None,
                         }),
                         Field::ScalarField(ScalarField {
                             name: "city_id".to_string(),
@@ -547,6 +614,9 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            is_encrypted: false,
+                            is_readonly: false,
+                            computed_sql: None,
                         }),
                         Field::ScalarField(ScalarField {
                             name: "city_name".to_string(),
@@ -567,6 +637,9 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            is_encrypted: false,
+                            is_readonly: false,
+                            computed_sql: None,
                         }),
                         Field::RelationField(RelationField {
                             name: "City".into(),
@@ -617,6 +690,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        identity: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -628,6 +702,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        identity: None,
                     },
                 ],
                 indices: vec![],
@@ -637,6 +712,7 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                sqlite_options: Default::default(),
             },
             Table {
                 name: "User".to_string(),
@@ -651,6 +727,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        identity: None,
                     },
                     Column {
                         name: "city-id".to_string(),
@@ -662,6 +739,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        identity: None,
                     },
                     Column {
                         name: "city-name".to_string(),
@@ -673,6 +751,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        identity: None,
                     },
                 ],
                 indices: vec![],
@@ -684,12 +763,14 @@ mod tests {
                 foreign_keys: vec![ForeignKey {
                     // what does this mean? the from columns are not targeting a specific to column?
                     constraint_name: None,
+                    referenced_constraint_name: None,
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
                     on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
+                sqlite_options: Default::default(),
             },
         ];
         let introspection_result =
@@ -708,6 +789,9 @@ mod tests {
                 documentation: None,
                 is_commented_out: false,
                 is_ignored: false,
+                is_strict: false,
+                is_without_rowid: false,
+                datasource: None,
                 fields: vec![
                     Field::ScalarField(ScalarField {
                         name: "id".to_string(),
@@ -728,6 +812,9 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        is_encrypted: false,
+                        is_readonly: false,
+                        computed_sql: None,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -764,6 +851,7 @@ mod tests {
                     tpe: datamodel::dml::IndexType::Unique,
                     defined_on_field: false,
                     algorithm: None,
+                    nulls_not_distinct: false,
                 }],
                 primary_key: Some(PrimaryKeyDefinition {
                     name: None,
@@ -789,6 +877,7 @@ mod tests {
                     },
                     default: None,
                     auto_increment: true,
+                    identity: None,
                 },
                 Column {
                     name: "name".to_string(),
@@ -800,6 +889,7 @@ mod tests {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
                 Column {
                     name: "lastname".to_string(),
@@ -811,6 +901,7 @@ mod tests {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
             ],
             indices: vec![Index {
@@ -825,6 +916,7 @@ mod tests {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            sqlite_options: Default::default(),
         }];
         let introspection_result =
             calculate_datamodel(&schema, &Datamodel::new(), postgres_context()).expect("calculate data model");
@@ -843,6 +935,9 @@ mod tests {
                     documentation: None,
                     is_commented_out: false,
                     is_ignored: false,
+                    is_strict: false,
+                    is_without_rowid: false,
+                    datasource: None,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -863,6 +958,9 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            is_encrypted: false,
+                            is_readonly: false,
+                            computed_sql: None,
                         }),
                         Field::ScalarField(ScalarField::new(
                             "name",
@@ -907,6 +1005,9 @@ mod tests {
                     documentation: None,
                     is_commented_out: false,
                     is_ignored: false,
+                    is_strict: false,
+                    is_without_rowid: false,
+                    datasource: None,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -927,6 +1028,9 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            is_encrypted: false,
+                            is_readonly: false,
+                            computed_sql: None,
                         }),
                         Field::ScalarField(ScalarField::new(
                             "city_id",
@@ -990,6 +1094,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        identity: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -1001,6 +1106,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        identity: None,
                     },
                 ],
                 indices: vec![],
@@ -1010,6 +1116,7 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                sqlite_options: Default::default(),
             },
             Table {
                 name: "User".to_string(),
@@ -1024,6 +1131,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        identity: None,
                     },
                     Column {
                         name: "city_id".to_string(),
@@ -1035,6 +1143,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        identity: None,
                     },
                 ],
                 indices: vec![],
@@ -1045,12 +1154,14 @@ mod tests {
                 }),
                 foreign_keys: vec![ForeignKey {
                     constraint_name: None,
+                    referenced_constraint_name: None,
                     columns: vec!["city_id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
                     on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
+                sqlite_options: Default::default(),
             },
         ];
         let introspection_result =
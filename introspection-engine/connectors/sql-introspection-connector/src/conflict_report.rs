@@ -0,0 +1,91 @@
+use datamodel::{Datamodel, FieldArity, FieldType, ScalarField};
+use introspection_connector::Conflict;
+
+static REMOVED: &str = "(not present anymore)";
+
+/// Compare the previous data model with the enriched re-introspected one and
+/// report every definition that could not be carried over, so the CLI can show
+/// the user exactly what got overwritten instead of silently replacing it.
+pub(crate) fn conflict_report(old_data_model: &Datamodel, new_data_model: &Datamodel) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+
+    for old_model in old_data_model.models() {
+        let new_model = match new_data_model.find_model(&old_model.name) {
+            Some(new_model) => new_model,
+            None => {
+                conflicts.push(Conflict {
+                    model: old_model.name.clone(),
+                    field: None,
+                    previous: format!("model {}", old_model.name),
+                    new: REMOVED.to_owned(),
+                    reason: "The underlying table does not exist in the database anymore.".to_owned(),
+                });
+
+                continue;
+            }
+        };
+
+        for old_field in old_model.scalar_fields() {
+            let new_field = match new_model.find_scalar_field(&old_field.name) {
+                Some(new_field) => new_field,
+                None => {
+                    conflicts.push(Conflict {
+                        model: old_model.name.clone(),
+                        field: Some(old_field.name.clone()),
+                        previous: field_signature(old_field),
+                        new: REMOVED.to_owned(),
+                        reason: "The underlying column does not exist in the database anymore.".to_owned(),
+                    });
+
+                    continue;
+                }
+            };
+
+            if field_type_name(&old_field.field_type) != field_type_name(&new_field.field_type) {
+                conflicts.push(Conflict {
+                    model: old_model.name.clone(),
+                    field: Some(old_field.name.clone()),
+                    previous: field_signature(old_field),
+                    new: field_signature(new_field),
+                    reason: "The type of the underlying column changed in the database.".to_owned(),
+                });
+            } else if old_field.arity != new_field.arity {
+                conflicts.push(Conflict {
+                    model: old_model.name.clone(),
+                    field: Some(old_field.name.clone()),
+                    previous: field_signature(old_field),
+                    new: field_signature(new_field),
+                    reason: "The nullability of the underlying column changed in the database.".to_owned(),
+                });
+            }
+        }
+    }
+
+    conflicts
+}
+
+fn field_signature(field: &ScalarField) -> String {
+    format!(
+        "{} {}{}",
+        field.name,
+        field_type_name(&field.field_type),
+        arity_suffix(field.arity)
+    )
+}
+
+fn field_type_name(field_type: &FieldType) -> String {
+    match field_type {
+        FieldType::Scalar(scalar_type, _, _) => scalar_type.to_string(),
+        FieldType::Enum(name) | FieldType::CompositeType(name) => name.clone(),
+        FieldType::Unsupported(description) => format!("Unsupported(\"{}\")", description),
+        FieldType::Relation(info) => info.to.clone(),
+    }
+}
+
+fn arity_suffix(arity: FieldArity) -> &'static str {
+    match arity {
+        FieldArity::Required => "",
+        FieldArity::Optional => "?",
+        FieldArity::List => "[]",
+    }
+}
@@ -41,6 +41,11 @@ pub fn introspect(
         debug!("Calculating model: {}", table.name);
         let mut model = Model::new(table.name.clone(), None);
 
+        if ctx.preview_features.contains(PreviewFeature::StrictTables) {
+            model.is_strict = table.sqlite_options.strict;
+            model.is_without_rowid = table.sqlite_options.without_rowid;
+        }
+
         for column in &table.columns {
             version_check.check_column_for_type_and_default_value(column);
             let field = calculate_scalar_field(table, column, ctx);
@@ -7,6 +7,7 @@ use datamodel::{
     ScalarField, ScalarType, SortOrder, ValueGenerator as VG,
 };
 use introspection_connector::IntrospectionContext;
+use prisma_value::PrismaValue;
 use sql_schema_describer::SQLIndexAlgorithm;
 use sql_schema_describer::{
     Column, ColumnArity, ColumnTypeFamily, ForeignKey, Index, IndexType, SQLSortOrder, SqlSchema, Table,
@@ -14,6 +15,21 @@ use sql_schema_describer::{
 use sql_schema_describer::{DefaultKind, ForeignKeyAction};
 use tracing::debug;
 
+/// Drop the tables excluded by the filter from the schema, together with all
+/// foreign keys referencing them, so the filtered tables leave no trace in the
+/// rendered data model.
+pub(crate) fn apply_table_filter(schema: &mut SqlSchema, filter: &introspection_connector::TableFilter) {
+    schema.tables.retain(|table| filter.includes_table(&table.name));
+
+    let remaining_tables: Vec<String> = schema.tables.iter().map(|table| table.name.clone()).collect();
+
+    for table in &mut schema.tables {
+        table
+            .foreign_keys
+            .retain(|fk| remaining_tables.contains(&fk.referenced_table));
+    }
+}
+
 //checks
 pub fn is_old_migration_table(table: &Table) -> bool {
     table.name == "_Migration"
@@ -177,6 +193,7 @@ pub(crate) fn calculate_index(index: &Index, ctx: &IntrospectionContext) -> Inde
         tpe,
         defined_on_field: index.columns.len() == 1,
         algorithm: using,
+        nulls_not_distinct: index.nulls_not_distinct,
     }
 }
 
@@ -206,7 +223,70 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column, ctx: &Intro
         is_updated_at: false,
         is_commented_out: false,
         is_ignored: false,
+        is_encrypted: false,
+        is_readonly: false,
+        computed_sql: None,
+    }
+}
+
+/// The unique index on the referenced table a foreign key resolves to, if any.
+/// The constraint name reported by the database takes precedence; when it is
+/// missing (SQLite), resolution falls back to the unique index covering exactly
+/// the referenced column set. `None` when the foreign key references the
+/// primary key or no unique index matches.
+fn referenced_unique_index<'a>(schema: &'a SqlSchema, foreign_key: &ForeignKey) -> Option<&'a Index> {
+    let table = schema.table(&foreign_key.referenced_table).ok()?;
+
+    if let Some(name) = &foreign_key.referenced_constraint_name {
+        if let Some(index) = table.indices.iter().find(|i| i.is_unique() && i.name == *name) {
+            return Some(index);
+        }
     }
+
+    table.indices.iter().find(|index| {
+        index.is_unique()
+            && index.columns.len() == foreign_key.referenced_columns.len()
+            && index
+                .columns
+                .iter()
+                .all(|c| foreign_key.referenced_columns.contains(&c.name))
+    })
+}
+
+/// Databases do not necessarily report the referenced columns of a compound
+/// foreign key in the order of the unique index backing it, which previously
+/// attributed the relation to the wrong index shape and churned the rendered
+/// `references` argument between introspections. Returns the `fields` /
+/// `references` pair reordered to follow the referenced unique index, or the
+/// foreign key's own order when no unique index resolves (e.g. references to
+/// the primary key).
+fn align_with_referenced_index(schema: &SqlSchema, foreign_key: &ForeignKey) -> (Vec<String>, Vec<String>) {
+    let unaligned = || (foreign_key.columns.clone(), foreign_key.referenced_columns.clone());
+
+    let index = match referenced_unique_index(schema, foreign_key) {
+        Some(index) if index.columns.len() == foreign_key.referenced_columns.len() => index,
+        _ => return unaligned(),
+    };
+
+    let mut fields = Vec::with_capacity(foreign_key.columns.len());
+    let mut references = Vec::with_capacity(foreign_key.referenced_columns.len());
+
+    for index_column in &index.columns {
+        match foreign_key
+            .referenced_columns
+            .iter()
+            .position(|c| *c == index_column.name)
+        {
+            Some(position) => {
+                fields.push(foreign_key.columns[position].clone());
+                references.push(foreign_key.referenced_columns[position].clone());
+            }
+            // The index does not cover the referenced columns after all.
+            None => return unaligned(),
+        }
+    }
+
+    (fields, references)
 }
 
 pub(crate) fn calculate_relation_field(
@@ -225,12 +305,14 @@ pub(crate) fn calculate_relation_field(
         ForeignKeyAction::SetDefault => ReferentialAction::SetDefault,
     };
 
+    let (fields, references) = align_with_referenced_index(schema, foreign_key);
+
     let relation_info = RelationInfo {
         name: calculate_relation_name(schema, foreign_key, table, m2m_table_names)?,
         fk_name: foreign_key.constraint_name.clone(),
-        fields: foreign_key.columns.clone(),
+        fields,
         to: foreign_key.referenced_table.clone(),
-        references: foreign_key.referenced_columns.clone(),
+        references,
         on_delete: Some(map_action(foreign_key.on_delete_action)),
         on_update: Some(map_action(foreign_key.on_update_action)),
     };
@@ -315,6 +397,12 @@ pub(crate) fn calculate_backrelation_field(
 
 pub(crate) fn calculate_default(table: &Table, column: &Column, arity: &FieldArity) -> Option<DMLDef> {
     match (column.default.as_ref().map(|d| d.kind()), &column.tpe.family) {
+        // Enum arrays are the only list columns with introspectable defaults.
+        (Some(DefaultKind::Value(val @ PrismaValue::List(_))), ColumnTypeFamily::Enum(_))
+            if *arity == FieldArity::List =>
+        {
+            Some(set_default(DMLDef::new_single(val.clone()), column))
+        }
         (_, _) if *arity == FieldArity::List => None,
         (_, ColumnTypeFamily::Int) if column.auto_increment => Some(DMLDef::new_expression(VG::new_autoincrement())),
         (_, ColumnTypeFamily::BigInt) if column.auto_increment => Some(DMLDef::new_expression(VG::new_autoincrement())),
@@ -3,9 +3,11 @@
 pub mod calculate_datamodel; // only exported to be able to unit test it
 mod calculate_datamodel_tests;
 mod commenting_out_guardrails;
+mod conflict_report;
 mod error;
 mod introspection;
 mod introspection_helpers;
+mod naming_strategies;
 mod prisma_1_defaults;
 mod re_introspection;
 mod sanitize_datamodel_names;
@@ -18,10 +20,10 @@ use datamodel::Datamodel;
 use enumflags2::BitFlags;
 pub use error::*;
 use introspection_connector::{
-    ConnectorError, ConnectorResult, DatabaseMetadata, ErrorKind, IntrospectionConnector, IntrospectionContext,
-    IntrospectionResult,
+    ConnectorError, ConnectorResult, DatabaseMetadata, DatabasePrivileges, ErrorKind, IntrospectionConnector,
+    IntrospectionContext, IntrospectionResult,
 };
-use quaint::prelude::SqlFamily;
+use quaint::prelude::{Queryable, SqlFamily};
 use quaint::{prelude::ConnectionInfo, single::Quaint};
 use schema_describer_loading::load_describer;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
@@ -112,6 +114,130 @@ impl SqlIntrospectionConnector {
             .await?)
     }
 
+    async fn get_database_privileges_internal(&self) -> SqlIntrospectionResult<DatabasePrivileges> {
+        match self.connection.connection_info().sql_family() {
+            SqlFamily::Mysql => {
+                let result_set = self.connection.query_raw("SHOW GRANTS", &[]).await?;
+
+                let mut privileges = DatabasePrivileges {
+                    create_tables: Some(false),
+                    alter_tables: Some(false),
+                    drop_tables: Some(false),
+                    references: Some(false),
+                    create_databases: Some(false),
+                };
+
+                for row in result_set {
+                    let grant = match row.at(0).and_then(|value| value.to_string()) {
+                        Some(grant) => grant.to_uppercase(),
+                        None => continue,
+                    };
+
+                    // Each row has the shape `GRANT <privilege list> ON <scope> TO <grantee>`.
+                    let (granted, scope) = match grant.strip_prefix("GRANT ").and_then(|rest| rest.split_once(" ON ")) {
+                        Some((granted, rest)) => (
+                            granted.split(", ").collect::<Vec<_>>(),
+                            rest.split(" TO ").next().unwrap_or("").trim().to_owned(),
+                        ),
+                        None => continue,
+                    };
+
+                    let has = |privilege: &str| granted.contains(&"ALL PRIVILEGES") || granted.contains(&privilege);
+
+                    privileges.create_tables = privileges.create_tables.map(|p| p || has("CREATE"));
+                    privileges.alter_tables = privileges.alter_tables.map(|p| p || has("ALTER"));
+                    privileges.drop_tables = privileges.drop_tables.map(|p| p || has("DROP"));
+                    privileges.references = privileges.references.map(|p| p || has("REFERENCES"));
+
+                    // Creating databases needs a global CREATE grant.
+                    if scope == "*.*" {
+                        privileges.create_databases = privileges.create_databases.map(|p| p || has("CREATE"));
+                    }
+                }
+
+                Ok(privileges)
+            }
+            SqlFamily::Postgres => {
+                let result_set = self
+                    .connection
+                    .query_raw(
+                        "SELECT has_schema_privilege(current_schema(), 'CREATE'), (SELECT rolcreatedb OR rolsuper FROM pg_roles WHERE rolname = current_user)",
+                        &[],
+                    )
+                    .await?;
+
+                let row = result_set.into_single().ok();
+                let create_tables = row.as_ref().and_then(|row| row.at(0).and_then(|value| value.as_bool()));
+                let create_databases = row.as_ref().and_then(|row| row.at(1).and_then(|value| value.as_bool()));
+
+                // Altering and dropping a table, as well as referencing it
+                // from a foreign key, is tied to table ownership on
+                // PostgreSQL, so it cannot be answered for the connection as
+                // a whole.
+                Ok(DatabasePrivileges {
+                    create_tables,
+                    alter_tables: None,
+                    drop_tables: None,
+                    references: None,
+                    create_databases,
+                })
+            }
+            SqlFamily::Mssql => {
+                let result_set = self
+                    .connection
+                    .query_raw(
+                        r#"
+                        SELECT
+                            HAS_PERMS_BY_NAME(DB_NAME(), 'DATABASE', 'CREATE TABLE'),
+                            HAS_PERMS_BY_NAME(DB_NAME(), 'DATABASE', 'ALTER'),
+                            HAS_PERMS_BY_NAME(DB_NAME(), 'DATABASE', 'REFERENCES'),
+                            HAS_PERMS_BY_NAME(NULL, NULL, 'CREATE ANY DATABASE')
+                        "#,
+                        &[],
+                    )
+                    .await?;
+
+                let row = result_set.into_single().ok();
+                let flag = |index: usize| {
+                    row.as_ref()
+                        .and_then(|row| row.at(index))
+                        .and_then(|value| value.as_i64().map(|i| i != 0).or_else(|| value.as_bool()))
+                };
+
+                let alter_tables = flag(1);
+
+                Ok(DatabasePrivileges {
+                    create_tables: flag(0),
+                    // Database-level ALTER implies ALTER on all contained
+                    // schemas and their objects, which includes dropping
+                    // tables.
+                    drop_tables: alter_tables,
+                    alter_tables,
+                    references: flag(2),
+                    create_databases: flag(3),
+                })
+            }
+            SqlFamily::Sqlite => {
+                // SQLite has no user accounts: every schema change is
+                // possible as long as the database file is writable.
+                let writable = match self.connection.connection_info() {
+                    ConnectionInfo::Sqlite { file_path, .. } => std::fs::metadata(file_path)
+                        .ok()
+                        .map(|metadata| !metadata.permissions().readonly()),
+                    _ => Some(true),
+                };
+
+                Ok(DatabasePrivileges {
+                    create_tables: writable,
+                    alter_tables: writable,
+                    drop_tables: writable,
+                    references: writable,
+                    create_databases: Some(true),
+                })
+            }
+        }
+    }
+
     async fn version(&self) -> SqlIntrospectionResult<String> {
         Ok(self
             .describer()
@@ -146,14 +272,24 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         Ok(description)
     }
 
+    async fn get_database_privileges(&self) -> ConnectorResult<DatabasePrivileges> {
+        let privileges = self.catch(self.get_database_privileges_internal()).await?;
+        tracing::debug!("Fetched database privileges: {:?}", privileges);
+        Ok(privileges)
+    }
+
     async fn introspect(
         &self,
         previous_data_model: &Datamodel,
         ctx: IntrospectionContext,
     ) -> ConnectorResult<IntrospectionResult> {
-        let sql_schema = self.catch(self.describe()).await?;
+        let mut sql_schema = self.catch(self.describe()).await?;
         tracing::debug!("SQL Schema Describer is done: {:?}", sql_schema);
 
+        if !ctx.table_filter.is_empty() {
+            introspection_helpers::apply_table_filter(&mut sql_schema, &ctx.table_filter);
+        }
+
         let introspection_result = calculate_datamodel::calculate_datamodel(&sql_schema, previous_data_model, ctx)
             .map_err(|sql_introspection_error| {
                 sql_introspection_error.into_connector_error(self.connection.connection_info())
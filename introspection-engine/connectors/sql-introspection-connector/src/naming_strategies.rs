@@ -0,0 +1,236 @@
+use datamodel::{Datamodel, Field, WithDatabaseName, WithName};
+use introspection_connector::NamingStrategy;
+use std::collections::HashMap;
+
+/// Apply the requested naming strategy on top of the sanitized data model. The
+/// database names are kept through `@map`/`@@map`, mirroring what
+/// `sanitize_datamodel_names` does for invalid identifiers.
+pub(crate) fn apply_naming_strategy(datamodel: &mut Datamodel, strategy: &NamingStrategy) {
+    let model_renames = rename_models(datamodel, strategy);
+
+    if strategy.camel_case_fields {
+        rename_fields(datamodel, &model_renames);
+    } else {
+        fix_relation_info_to(datamodel, &model_renames);
+    }
+}
+
+// if the renames collapse distinct names, e.g. tables `users` and `Users`
+// with pascal casing, we leave the data model as-is rather than producing
+// a duplicate model name the validator would reject
+pub(crate) fn renaming_leads_to_duplicate_names(datamodel: &Datamodel, strategy: &NamingStrategy) -> bool {
+    for model in datamodel.models() {
+        let renamed = rename_model_string(model.name(), strategy);
+
+        let dups = datamodel
+            .models()
+            .filter(|m| rename_model_string(m.name(), strategy) == renamed)
+            .count();
+
+        if dups > 1 {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn rename_models(datamodel: &mut Datamodel, strategy: &NamingStrategy) -> HashMap<String, String> {
+    let mut renames = HashMap::new();
+
+    for model in datamodel.models_mut() {
+        let renamed = rename_model_string(model.name(), strategy);
+
+        if &renamed != model.name() {
+            if model.database_name().is_none() {
+                model.set_database_name(Some(model.name().clone()));
+            }
+
+            renames.insert(model.name().clone(), renamed.clone());
+            model.set_name(&renamed);
+        }
+    }
+
+    renames
+}
+
+fn rename_fields(datamodel: &mut Datamodel, model_renames: &HashMap<String, String>) {
+    // Per model, the scalar field renames. Needed in a second pass to fix the
+    // `fields` and `references` arguments of relation fields.
+    let mut field_renames: HashMap<String, HashMap<String, String>> = HashMap::new();
+
+    for model in datamodel.models_mut() {
+        let mut renames = HashMap::new();
+
+        for field in model.fields() {
+            let renamed = camel_case(field.name());
+
+            if renamed != field.name() && model.find_field(&renamed).is_none() && !renames.contains_key(&renamed) {
+                renames.insert(renamed, field.name().to_owned());
+            }
+        }
+
+        // Invert to old name -> new name now that collisions are resolved.
+        let renames: HashMap<String, String> = renames.into_iter().map(|(new, old)| (old, new)).collect();
+
+        for field in model.fields_mut() {
+            let renamed = match renames.get(field.name()) {
+                Some(renamed) => renamed.clone(),
+                None => continue,
+            };
+
+            // Relation fields are virtual, they have no column to map to.
+            if matches!(field, Field::ScalarField(_)) && field.database_name().is_none() {
+                let old_name = field.name().to_owned();
+                field.set_database_name(Some(old_name));
+            }
+
+            field.set_name(&renamed);
+        }
+
+        if let Some(pk) = &mut model.primary_key {
+            for pk_field in &mut pk.fields {
+                if let Some(renamed) = renames.get(&pk_field.name) {
+                    pk_field.name = renamed.clone();
+                }
+            }
+        }
+
+        for index in &mut model.indices {
+            for index_field in &mut index.fields {
+                if let Some(renamed) = renames.get(&index_field.name) {
+                    index_field.name = renamed.clone();
+                }
+            }
+        }
+
+        field_renames.insert(model.name.clone(), renames);
+    }
+
+    // Fix relation info in a second pass: `references` points at fields of the
+    // related model, which may live anywhere in the data model.
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+
+        for relation_field in model.relation_fields_mut() {
+            let info = &mut relation_field.relation_info;
+
+            if let Some(renamed) = model_renames.get(&info.to) {
+                info.to = renamed.clone();
+            }
+
+            if let Some(renames) = field_renames.get(&model_name) {
+                for field in &mut info.fields {
+                    if let Some(renamed) = renames.get(field) {
+                        *field = renamed.clone();
+                    }
+                }
+            }
+
+            if let Some(renames) = field_renames.get(&info.to) {
+                for reference in &mut info.references {
+                    if let Some(renamed) = renames.get(reference) {
+                        *reference = renamed.clone();
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn fix_relation_info_to(datamodel: &mut Datamodel, model_renames: &HashMap<String, String>) {
+    for model in datamodel.models_mut() {
+        for relation_field in model.relation_fields_mut() {
+            if let Some(renamed) = model_renames.get(&relation_field.relation_info.to) {
+                relation_field.relation_info.to = renamed.clone();
+            }
+        }
+    }
+}
+
+fn rename_model_string(name: &str, strategy: &NamingStrategy) -> String {
+    let mut name = if strategy.pascal_case_models {
+        pascal_case(name)
+    } else {
+        name.to_owned()
+    };
+
+    if strategy.singularize_models {
+        name = singularize(&name);
+    }
+
+    name
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(capitalize)
+        .collect()
+}
+
+fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => pascal,
+    }
+}
+
+fn capitalize(part: &str) -> String {
+    let mut chars = part.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Conservative English singularization: only the common regular plural forms
+/// are rewritten, anything else is left alone.
+fn singularize(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        if !stem.is_empty() {
+            return format!("{}y", stem);
+        }
+    }
+
+    for suffix in ["ses", "xes", "zes", "ches", "shes"] {
+        if let Some(stem) = name.strip_suffix(suffix) {
+            return format!("{}{}", stem, &suffix[..suffix.len() - 2]);
+        }
+    }
+
+    if name.len() > 1 && name.ends_with('s') && !name.ends_with("ss") && !name.ends_with("us") && !name.ends_with("is")
+    {
+        return name[..name.len() - 1].to_owned();
+    }
+
+    name.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn casing_helpers() {
+        assert_eq!(pascal_case("user_accounts"), "UserAccounts");
+        assert_eq!(pascal_case("User"), "User");
+        assert_eq!(camel_case("created_at"), "createdAt");
+        assert_eq!(camel_case("ID"), "iD");
+    }
+
+    #[test]
+    fn singularization_is_conservative() {
+        assert_eq!(singularize("Users"), "User");
+        assert_eq!(singularize("Categories"), "Category");
+        assert_eq!(singularize("Boxes"), "Box");
+        assert_eq!(singularize("Addresses"), "Address");
+        assert_eq!(singularize("Status"), "Status");
+        assert_eq!(singularize("Analysis"), "Analysis");
+        assert_eq!(singularize("Person"), "Person");
+    }
+}
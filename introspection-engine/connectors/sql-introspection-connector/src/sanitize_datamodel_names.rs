@@ -116,6 +116,38 @@ fn sanitize_models(datamodel: &mut Datamodel, ctx: &IntrospectionContext) -> Has
 
                             sf.default_value.replace(new_default);
                         };
+
+                        // Same for enum array defaults: every value in the list is an enum value.
+                        if let Some(DefaultKind::Single(PrismaValue::List(values))) =
+                            sf.default_value.as_mut().map(|dv| dv.mut_kind())
+                        {
+                            let sanitized_values: Option<Vec<PrismaValue>> = values
+                                .iter()
+                                .map(|value| match value {
+                                    PrismaValue::Enum(value) if value.is_empty() => {
+                                        Some(PrismaValue::Enum(EMPTY_ENUM_PLACEHOLDER.to_string()))
+                                    }
+                                    PrismaValue::Enum(value) => {
+                                        let sanitized_value = sanitize_string(value);
+
+                                        (!sanitized_value.is_empty()).then(|| PrismaValue::Enum(sanitized_value))
+                                    }
+                                    _ => None,
+                                })
+                                .collect();
+
+                            match sanitized_values {
+                                Some(values) => {
+                                    sf.default_value
+                                        .replace(DefaultValue::new_single(PrismaValue::List(values)));
+                                }
+                                // A value that sanitizes away entirely cannot be expressed in the
+                                // schema, so the default is dropped.
+                                None => {
+                                    sf.default_value = None;
+                                }
+                            }
+                        };
                     }
                 }
                 Field::CompositeField(_) => todo!(),
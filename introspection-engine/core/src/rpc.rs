@@ -1,8 +1,8 @@
 use crate::error::Error;
 use datamodel::{Configuration, Datamodel};
 use introspection_connector::{
-    CompositeTypeDepth, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionContext,
-    IntrospectionResultOutput,
+    CompositeTypeDepth, ConnectorResult, DatabaseMetadata, DatabasePrivileges, IntrospectionConnector,
+    IntrospectionContext, IntrospectionResultOutput, NamingStrategy, TableFilter,
 };
 use jsonrpc_core::BoxFuture;
 use jsonrpc_derive::rpc;
@@ -28,9 +28,15 @@ pub trait Rpc {
     #[rpc(name = "getDatabaseVersion")]
     fn get_database_version(&self, input: IntrospectionInput) -> RpcFutureResult<String>;
 
+    #[rpc(name = "getDatabasePrivileges")]
+    fn get_database_privileges(&self, input: IntrospectionInput) -> RpcFutureResult<DatabasePrivileges>;
+
     #[rpc(name = "introspect")]
     fn introspect(&self, input: IntrospectionInput) -> RpcFutureResult<IntrospectionResultOutput>;
 
+    #[rpc(name = "introspectSql")]
+    fn introspect_sql(&self, input: IntrospectSqlInput) -> RpcFutureResult<IntrospectionResultOutput>;
+
     #[rpc(name = "debugPanic")]
     fn debug_panic(&self) -> RpcFutureResult<()>;
 }
@@ -54,14 +60,24 @@ impl Rpc for RpcImpl {
         Box::pin(Self::get_database_version_internal(input.schema))
     }
 
+    fn get_database_privileges(&self, input: IntrospectionInput) -> RpcFutureResult<DatabasePrivileges> {
+        Box::pin(Self::get_database_privileges_internal(input.schema))
+    }
+
     fn introspect(&self, input: IntrospectionInput) -> RpcFutureResult<IntrospectionResultOutput> {
         Box::pin(Self::introspect_internal(
             input.schema,
             input.force,
             CompositeTypeDepth::from(input.composite_type_depth.unwrap_or(0)),
+            TableFilter::new(input.include_tables, input.exclude_tables),
+            input.naming_strategy,
         ))
     }
 
+    fn introspect_sql(&self, input: IntrospectSqlInput) -> RpcFutureResult<IntrospectionResultOutput> {
+        Box::pin(Self::introspect_sql_internal(input.schema, input.dump))
+    }
+
     fn debug_panic(&self) -> RpcFutureResult<()> {
         Box::pin(Self::debug_panic())
     }
@@ -102,6 +118,8 @@ impl RpcImpl {
         schema: String,
         force: bool,
         composite_type_depth: CompositeTypeDepth,
+        table_filter: TableFilter,
+        naming_strategy: NamingStrategy,
     ) -> RpcResult<IntrospectionResultOutput> {
         let (config, url, connector) = RpcImpl::load_connector(&schema).await?;
 
@@ -117,6 +135,8 @@ impl RpcImpl {
             preview_features: config2.preview_features(),
             source: config2.datasources.into_iter().next().unwrap(),
             composite_type_depth,
+            table_filter,
+            naming_strategy,
         };
 
         let result = match connector.introspect(&input_data_model, ctx).await {
@@ -131,6 +151,7 @@ impl RpcImpl {
                         ),
                         warnings: introspection_result.warnings,
                         version: introspection_result.version,
+                        conflicts: introspection_result.conflicts,
                     })
                 }
             }
@@ -140,6 +161,54 @@ impl RpcImpl {
         result.map_err(RpcError::from)
     }
 
+    /// Introspects a schema-only SQL dump (`pg_dump`, `mysqldump`) instead of
+    /// a live database. The datasource in the schema only determines the
+    /// connector flavor and preview features; its URL is never loaded and no
+    /// connection is made, so this also works in air-gapped environments.
+    pub async fn introspect_sql_internal(schema: String, dump: String) -> RpcResult<IntrospectionResultOutput> {
+        let config = datamodel::parse_configuration(&schema)
+            .map_err(|diagnostics| Error::DatamodelError(diagnostics.to_pretty_string("schema.prisma", &schema)))?;
+
+        let preview_features = config.subject.preview_features();
+        let source = config
+            .subject
+            .datasources
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Generic("There is no datasource in the schema.".into()))?;
+
+        let sql_schema = sql_schema_describer::sql_dump::parse_sql_dump(&dump)
+            .map_err(|err| Error::Generic(format!("Error parsing the SQL dump: {}", err)))?;
+
+        let ctx = IntrospectionContext {
+            preview_features,
+            source,
+            composite_type_depth: CompositeTypeDepth::None,
+            table_filter: TableFilter::default(),
+            naming_strategy: NamingStrategy::default(),
+        };
+
+        let result =
+            sql_introspection_connector::calculate_datamodel::calculate_datamodel(&sql_schema, &Datamodel::new(), ctx)
+                .map_err(|err| Error::Generic(format!("Error calculating the data model: {}", err)))?;
+
+        if result.data_model.is_empty() {
+            return Err(RpcError::from(Error::IntrospectionResultEmpty(
+                "the provided SQL dump".into(),
+            )));
+        }
+
+        let config = datamodel::parse_configuration(&schema)
+            .map_err(|diagnostics| Error::DatamodelError(diagnostics.to_pretty_string("schema.prisma", &schema)))?;
+
+        Ok(IntrospectionResultOutput {
+            datamodel: datamodel::render_datamodel_and_config_to_string(&result.data_model, &config.subject),
+            warnings: result.warnings,
+            version: result.version,
+            conflicts: result.conflicts,
+        })
+    }
+
     /// This function parses the provided schema and returns the contained Datamodel.
     pub fn parse_datamodel(schema: &str) -> RpcResult<Datamodel> {
         let final_dm = datamodel::parse_datamodel(schema)
@@ -169,6 +238,11 @@ impl RpcImpl {
         RpcImpl::catch(connector.get_metadata()).await
     }
 
+    pub async fn get_database_privileges_internal(schema: String) -> RpcResult<DatabasePrivileges> {
+        let (_, _, connector) = RpcImpl::load_connector(&schema).await?;
+        RpcImpl::catch(connector.get_database_privileges()).await
+    }
+
     pub async fn debug_panic() -> RpcResult<()> {
         panic!("This is the debugPanic artificial panic")
     }
@@ -182,6 +256,27 @@ pub struct IntrospectionInput {
     pub(crate) force: bool,
     #[serde(default)]
     pub(crate) composite_type_depth: Option<isize>,
+    /// Glob patterns for the tables to introspect. When empty, all tables are
+    /// introspected.
+    #[serde(default)]
+    pub(crate) include_tables: Vec<String>,
+    /// Glob patterns for tables to leave out of introspection.
+    #[serde(default)]
+    pub(crate) exclude_tables: Vec<String>,
+    /// Opt-in renames for introspected models and fields.
+    #[serde(default)]
+    pub(crate) naming_strategy: NamingStrategy,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectSqlInput {
+    /// A Prisma schema with the datasource to introspect for. Only the
+    /// provider and preview features are used; the URL is never loaded.
+    pub(crate) schema: String,
+    /// A schema-only SQL dump to introspect, as produced by `pg_dump
+    /// --schema-only` or `mysqldump --no-data`.
+    pub(crate) dump: String,
 }
 
 fn default_false() -> bool {
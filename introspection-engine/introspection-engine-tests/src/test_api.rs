@@ -129,6 +129,8 @@ impl TestApi {
             preview_features: self.preview_features(),
             source: config.datasources.into_iter().next().unwrap(),
             composite_type_depth: CompositeTypeDepth::Infinite,
+            table_filter: Default::default(),
+            naming_strategy: Default::default(),
         };
 
         self.api
@@ -70,9 +70,15 @@ async fn bad_connection_string_in_datamodel_returns_nice_error() {
     }
     "#;
 
-    let error = RpcImpl::introspect_internal(schema.into(), false, Default::default())
-        .await
-        .unwrap_err();
+    let error = RpcImpl::introspect_internal(
+        schema.into(),
+        false,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .await
+    .unwrap_err();
 
     let json_error = serde_json::to_value(error).unwrap();
 
@@ -28,7 +28,7 @@ async fn introspecting_custom_fk_names_does_not_return_them(api: &TestApi) -> Te
 
     let expected = expect![[r#"
         model Post {
-          id      Int  @id @default(autoincrement())
+          id      Int  @id
           user_id Int
           User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
 
@@ -36,7 +36,7 @@ async fn introspecting_custom_fk_names_does_not_return_them(api: &TestApi) -> Te
         }
 
         model User {
-          id   Int    @id @default(autoincrement())
+          id   Int    @id
           Post Post[]
         }
     "#]];
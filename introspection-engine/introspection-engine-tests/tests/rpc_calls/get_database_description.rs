@@ -35,7 +35,8 @@ async fn database_description_for_mysql_should_work(api: &TestApi) -> TestResult
                     "native_type": "Int"
                   },
                   "default": null,
-                  "auto_increment": true
+                  "auto_increment": true,
+                  "identity": null
                 },
                 {
                   "name": "string",
@@ -46,7 +47,8 @@ async fn database_description_for_mysql_should_work(api: &TestApi) -> TestResult
                     "native_type": "Text"
                   },
                   "default": null,
-                  "auto_increment": false
+                  "auto_increment": false,
+                  "identity": null
                 }
               ],
               "indices": [],
@@ -95,7 +97,8 @@ async fn database_description_for_mysql_8_should_work(api: &TestApi) -> TestResu
                     "native_type": "Int"
                   },
                   "default": null,
-                  "auto_increment": true
+                  "auto_increment": true,
+                  "identity": null
                 },
                 {
                   "name": "string",
@@ -106,7 +109,8 @@ async fn database_description_for_mysql_8_should_work(api: &TestApi) -> TestResu
                     "native_type": "Text"
                   },
                   "default": null,
-                  "auto_increment": false
+                  "auto_increment": false,
+                  "identity": null
                 }
               ],
               "indices": [],
@@ -160,7 +164,8 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> TestRes
                     },
                     "constraint_name": null
                   },
-                  "auto_increment": true
+                  "auto_increment": true,
+                  "identity": null
                 },
                 {
                   "name": "string",
@@ -171,7 +176,8 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> TestRes
                     "native_type": "Text"
                   },
                   "default": null,
-                  "auto_increment": false
+                  "auto_increment": false,
+                  "identity": null
                 }
               ],
               "indices": [],
@@ -226,7 +232,8 @@ async fn database_description_for_sqlite_should_work(api: &TestApi) -> TestResul
                     "native_type": null
                   },
                   "default": null,
-                  "auto_increment": true
+                  "auto_increment": true,
+                  "identity": null
                 },
                 {
                   "name": "string",
@@ -237,7 +244,8 @@ async fn database_description_for_sqlite_should_work(api: &TestApi) -> TestResul
                     "native_type": null
                   },
                   "default": null,
-                  "auto_increment": false
+                  "auto_increment": false,
+                  "identity": null
                 }
               ],
               "indices": [],
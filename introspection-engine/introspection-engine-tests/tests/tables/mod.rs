@@ -933,7 +933,7 @@ async fn unique_and_id_on_same_field_works_sqlite(api: &TestApi) -> TestResult {
 
     let expected = expect![[r#"
         model users {
-          id Int @id @unique(map: "sqlite_autoindex_users_1") @default(autoincrement())
+          id Int @id @unique(map: "sqlite_autoindex_users_1")
         }
     "#]];
 
@@ -17,7 +17,7 @@ async fn a_table_with_descending_unique(api: &TestApi) -> TestResult {
 
     let expectation = expect![[r#"
         model A {
-          id Int @id @default(autoincrement())
+          id Int @id
           a  Int @unique(sort: Desc)
         }
     "#]];
@@ -44,7 +44,7 @@ async fn a_table_with_descending_compound_unique(api: &TestApi) -> TestResult {
 
     let expectation = expect![[r#"
         model A {
-          id Int @id @default(autoincrement())
+          id Int @id
           a  Int
           b  Int
 
@@ -74,7 +74,7 @@ async fn a_table_with_descending_index(api: &TestApi) -> TestResult {
 
     let expectation = expect![[r#"
         model A {
-          id Int @id @default(autoincrement())
+          id Int @id
           a  Int
           b  Int
 
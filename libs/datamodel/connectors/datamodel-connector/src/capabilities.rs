@@ -58,13 +58,19 @@ capabilities!(
     NamedPrimaryKeys,
     NamedForeignKeys,
     ReferenceCycleDetection,
+    // The database accepts a self-referential foreign key whose `ON UPDATE` action cascades.
+    // MySQL (InnoDB) rejects such constraints at DDL time with an opaque errno 150.
+    CascadingSelfReferentialActions,
     NamedDefaultValues,
     IndexColumnLengthPrefixing,
+    MultipleNullsInUniqueIndex, // Unique indexes on optional columns allow more than one row with a NULL value.
     PrimaryKeySortOrderDefinition,
     UsingHashIndex,
+    UniqueNullsNotDistinct, // `UNIQUE NULLS NOT DISTINCT` constraints (PostgreSQL 15+).
     FullTextIndex,
     SortOrderInFullTextIndex,
     MultipleFullTextAttributesPerModel,
+    SqliteTableOptions, // STRICT and WITHOUT ROWID tables.
     // Start of query-engine-only Capabilities
     InsensitiveFilters,
     CreateMany,
@@ -82,6 +88,8 @@ capabilities!(
     FullTextSearchWithoutIndex,
     FullTextSearchWithIndex,
     AdvancedJsonNullability, // Database distinguishes between their null type and JSON null.
+    LargeOffsets,            // `skip` and `take` values beyond the 32-bit integer range.
+    OrderByNullsFirstLast,   // Explicit `nulls: first | last` placement in orderBy.
 );
 
 /// Contains all capabilities that the connector is able to serve.
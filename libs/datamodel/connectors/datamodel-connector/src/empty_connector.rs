@@ -19,6 +19,7 @@ impl Connector for EmptyDatamodelConnector {
             ConnectorCapability::CompoundIds,
             ConnectorCapability::Enums,
             ConnectorCapability::Json,
+            ConnectorCapability::MultipleNullsInUniqueIndex,
         ]
     }
 
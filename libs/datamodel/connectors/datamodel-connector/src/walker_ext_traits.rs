@@ -52,12 +52,36 @@ impl<'ast> DefaultValueExt<'ast> for DefaultValueWalker<'ast, '_> {
             ast::Expression::Function(funcname, _args, _) if funcname == "autoincrement" => {
                 DefaultKind::Expression(ValueGenerator::new_autoincrement())
             }
-            ast::Expression::Function(funcname, _args, _) if funcname == "uuid" => {
-                DefaultKind::Expression(ValueGenerator::new_uuid())
+            ast::Expression::Function(funcname, args, _) if funcname == "uuid" => {
+                match args.arguments.get(0).and_then(|arg| arg.value.as_numeric_value()) {
+                    Some(("7", _)) => DefaultKind::Expression(ValueGenerator::new_uuid_v7()),
+                    _ => DefaultKind::Expression(ValueGenerator::new_uuid()),
+                }
             }
             ast::Expression::Function(funcname, _args, _) if funcname == "cuid" => {
                 DefaultKind::Expression(ValueGenerator::new_cuid())
             }
+            ast::Expression::Function(funcname, _args, _) if funcname == "cuid2" => {
+                DefaultKind::Expression(ValueGenerator::new_cuid2())
+            }
+            ast::Expression::Function(funcname, args, _) if funcname == "custom" => {
+                let strategy = args
+                    .arguments
+                    .get(0)
+                    .and_then(|arg| arg.value.as_string_value())
+                    .map(|(val, _)| val.to_owned());
+
+                DefaultKind::Expression(ValueGenerator::new_custom(strategy))
+            }
+            ast::Expression::Function(funcname, args, _) if funcname == "nanoid" => {
+                let length = args
+                    .arguments
+                    .get(0)
+                    .and_then(|arg| arg.value.as_numeric_value())
+                    .map(|(length, _)| length.parse().unwrap());
+
+                DefaultKind::Expression(ValueGenerator::new_nanoid(length))
+            }
             ast::Expression::Function(funcname, _args, _) if funcname == "now" => {
                 DefaultKind::Expression(ValueGenerator::new_now())
             }
@@ -73,6 +97,18 @@ impl<'ast> DefaultValueExt<'ast> for DefaultValueWalker<'ast, '_> {
                 None => DefaultKind::Single(PrismaValue::Enum(v.to_owned())),
                 other => unreachable!("{:?}", other),
             },
+            ast::Expression::Array(values, _) => {
+                // Only enum lists can have array defaults, so the elements are enum values.
+                let values = values
+                    .iter()
+                    .map(|value| match value {
+                        ast::Expression::ConstantValue(value, _) => PrismaValue::Enum(value.to_owned()),
+                        other => unreachable!("{:?}", other),
+                    })
+                    .collect();
+
+                DefaultKind::Single(PrismaValue::List(values))
+            }
             ast::Expression::StringValue(v, _) => match self.field().scalar_type() {
                 Some(ScalarType::DateTime) => DefaultKind::Single(PrismaValue::DateTime(v.parse().unwrap())),
                 Some(ScalarType::String) => DefaultKind::Single(PrismaValue::String(v.parse().unwrap())),
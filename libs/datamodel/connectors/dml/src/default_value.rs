@@ -29,11 +29,26 @@ impl DefaultKind {
         matches!(self, DefaultKind::Expression(generator) if generator.name == "cuid")
     }
 
+    /// Does this match @default(cuid2(_))?
+    pub fn is_cuid2(&self) -> bool {
+        matches!(self, DefaultKind::Expression(generator) if generator.name == "cuid2")
+    }
+
+    /// Does this match @default(custom(_))?
+    pub fn is_custom(&self) -> bool {
+        matches!(self, DefaultKind::Expression(generator) if generator.name == "custom")
+    }
+
     /// Does this match @default(dbgenerated(_))?
     pub fn is_dbgenerated(&self) -> bool {
         matches!(self, DefaultKind::Expression(generator) if generator.name == "dbgenerated")
     }
 
+    /// Does this match @default(nanoid(_))?
+    pub fn is_nanoid(&self) -> bool {
+        matches!(self, DefaultKind::Expression(generator) if generator.name == "nanoid")
+    }
+
     /// Does this match @default(now())?
     pub fn is_now(&self) -> bool {
         matches!(self, DefaultKind::Expression(generator) if generator.name == "now")
@@ -92,11 +107,26 @@ impl DefaultValue {
         self.kind.is_cuid()
     }
 
+    /// Does this match @default(cuid2(_))?
+    pub fn is_cuid2(&self) -> bool {
+        self.kind.is_cuid2()
+    }
+
+    /// Does this match @default(custom(_))?
+    pub fn is_custom(&self) -> bool {
+        self.kind.is_custom()
+    }
+
     /// Does this match @default(dbgenerated(_))?
     pub fn is_dbgenerated(&self) -> bool {
         self.kind.is_dbgenerated()
     }
 
+    /// Does this match @default(nanoid(_))?
+    pub fn is_nanoid(&self) -> bool {
+        self.kind.is_nanoid()
+    }
+
     /// Does this match @default(now())?
     pub fn is_now(&self) -> bool {
         self.kind.is_now()
@@ -174,10 +204,33 @@ impl ValueGenerator {
         ValueGenerator::new("cuid".to_owned(), vec![]).unwrap()
     }
 
+    pub fn new_cuid2() -> Self {
+        ValueGenerator::new("cuid2".to_owned(), vec![]).unwrap()
+    }
+
+    pub fn new_custom(strategy: Option<String>) -> Self {
+        let args = strategy.map(PrismaValue::String).into_iter().collect();
+
+        ValueGenerator::new("custom".to_owned(), args).unwrap()
+    }
+
+    pub fn new_nanoid(length: Option<u8>) -> Self {
+        let args = length
+            .map(|length| PrismaValue::Int(length as i64))
+            .into_iter()
+            .collect();
+
+        ValueGenerator::new("nanoid".to_owned(), args).unwrap()
+    }
+
     pub fn new_uuid() -> Self {
         ValueGenerator::new("uuid".to_owned(), vec![]).unwrap()
     }
 
+    pub fn new_uuid_v7() -> Self {
+        ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(7)]).unwrap()
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -200,7 +253,7 @@ impl ValueGenerator {
 
     #[cfg(feature = "default_generators")]
     pub fn generate(&self) -> Option<PrismaValue> {
-        self.generator.invoke()
+        self.generator.invoke(self.args())
     }
 
     pub fn check_compatibility_with_scalar_type(&self, scalar_type: ScalarType) -> Result<(), String> {
@@ -228,15 +281,44 @@ impl ValueGenerator {
 pub enum ValueGeneratorFn {
     Uuid,
     Cuid,
+    Cuid2,
+    Custom,
+    Nanoid,
     Now,
     Autoincrement,
     DbGenerated,
 }
 
+/// Hook backing `@default(custom())`. Receives the arguments given in the schema (e.g. the
+/// strategy name in `@default(custom("ksuid"))`) and returns the generated id value.
+#[cfg(feature = "default_generators")]
+pub type CustomGenerator = dyn Fn(&[PrismaValue]) -> PrismaValue + Send + Sync;
+
+#[cfg(feature = "default_generators")]
+static CUSTOM_GENERATOR: once_cell::sync::OnceCell<Box<CustomGenerator>> = once_cell::sync::OnceCell::new();
+
+/// Registers the process-wide generator backing `@default(custom())` fields. Embedders call
+/// this once at startup to plug in their own id formats (sharding-aware ids, KSUIDs, ...).
+/// While no generator is registered, `custom()` defaults produce no value - like
+/// `autoincrement()` - and the field has to be filled by the caller or the database.
+#[cfg(feature = "default_generators")]
+pub fn register_custom_generator(generator: Box<CustomGenerator>) -> Result<(), String> {
+    CUSTOM_GENERATOR
+        .set(generator)
+        .map_err(|_| "A custom default value generator is already registered.".to_owned())
+}
+
+/// The alphabet used by `nanoid()`, as defined by the reference implementation.
+#[cfg(feature = "default_generators")]
+const NANOID_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789_-";
+
 impl ValueGeneratorFn {
     fn new(name: &str) -> std::result::Result<Self, String> {
         match name {
             "cuid" => Ok(Self::Cuid),
+            "cuid2" => Ok(Self::Cuid2),
+            "custom" => Ok(Self::Custom),
+            "nanoid" => Ok(Self::Nanoid),
             "uuid" => Ok(Self::Uuid),
             "now" => Ok(Self::Now),
             "autoincrement" => Ok(Self::Autoincrement),
@@ -246,10 +328,13 @@ impl ValueGeneratorFn {
     }
 
     #[cfg(feature = "default_generators")]
-    fn invoke(&self) -> Option<PrismaValue> {
+    fn invoke(&self, args: &[PrismaValue]) -> Option<PrismaValue> {
         match self {
-            Self::Uuid => Some(Self::generate_uuid()),
+            Self::Uuid => Some(Self::generate_uuid(args)),
             Self::Cuid => Some(Self::generate_cuid()),
+            Self::Cuid2 => Some(Self::generate_cuid2()),
+            Self::Custom => Self::generate_custom(args),
+            Self::Nanoid => Some(Self::generate_nanoid(args)),
             Self::Now => Some(Self::generate_now()),
             Self::Autoincrement => None,
             Self::DbGenerated => None,
@@ -261,6 +346,9 @@ impl ValueGeneratorFn {
         match (self, scalar_type) {
             (Self::Uuid, ScalarType::String) => true,
             (Self::Cuid, ScalarType::String) => true,
+            (Self::Cuid2, ScalarType::String) => true,
+            (Self::Custom, ScalarType::String) => true,
+            (Self::Nanoid, ScalarType::String) => true,
             (Self::Now, ScalarType::DateTime) => true,
             (Self::Autoincrement, ScalarType::Int) => true,
             (Self::Autoincrement, ScalarType::BigInt) => true,
@@ -274,15 +362,86 @@ impl ValueGeneratorFn {
         PrismaValue::String(cuid::cuid().unwrap())
     }
 
+    /// The cuid crate does not expose a cuid2 generator yet, so we assemble one ourselves: a
+    /// random lowercase letter followed by 23 base36 characters.
     #[cfg(feature = "default_generators")]
-    fn generate_uuid() -> PrismaValue {
-        PrismaValue::Uuid(uuid::Uuid::new_v4())
+    fn generate_cuid2() -> PrismaValue {
+        let random = Self::random_bytes(24);
+        let mut id = String::with_capacity(24);
+
+        id.push((b'a' + (random[0] % 26)) as char);
+
+        for byte in &random[1..] {
+            id.push(std::char::from_digit((byte % 36) as u32, 36).unwrap());
+        }
+
+        PrismaValue::String(id)
+    }
+
+    #[cfg(feature = "default_generators")]
+    fn generate_custom(args: &[PrismaValue]) -> Option<PrismaValue> {
+        CUSTOM_GENERATOR.get().map(|generate| generate(args))
+    }
+
+    #[cfg(feature = "default_generators")]
+    fn generate_nanoid(args: &[PrismaValue]) -> PrismaValue {
+        let length = match args.first() {
+            Some(PrismaValue::Int(length)) => *length as usize,
+            _ => 21,
+        };
+
+        let id = Self::random_bytes(length)
+            .into_iter()
+            .map(|byte| NANOID_ALPHABET[(byte & 0x3f) as usize] as char)
+            .collect();
+
+        PrismaValue::String(id)
+    }
+
+    #[cfg(feature = "default_generators")]
+    fn generate_uuid(args: &[PrismaValue]) -> PrismaValue {
+        match args.first() {
+            Some(PrismaValue::Int(7)) => PrismaValue::Uuid(Self::generate_uuid_v7()),
+            _ => PrismaValue::Uuid(uuid::Uuid::new_v4()),
+        }
+    }
+
+    /// The uuid crate has no UUIDv7 support yet, so we splice the millisecond timestamp and the
+    /// version and variant bits into random bytes ourselves (RFC 4122 layout).
+    #[cfg(feature = "default_generators")]
+    fn generate_uuid_v7() -> uuid::Uuid {
+        let mut bytes = *uuid::Uuid::new_v4().as_bytes();
+        let millis = chrono::Utc::now().timestamp_millis() as u64;
+
+        bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+        bytes[6] = (bytes[6] & 0x0f) | 0x70;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        uuid::Uuid::from_bytes(bytes)
     }
 
     #[cfg(feature = "default_generators")]
     fn generate_now() -> PrismaValue {
         PrismaValue::DateTime(chrono::Utc::now().into())
     }
+
+    /// Random bytes for the id generators that need more entropy than a single UUID contains.
+    #[cfg(feature = "default_generators")]
+    fn random_bytes(len: usize) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(len + 16);
+
+        while bytes.len() < len {
+            let block = *uuid::Uuid::new_v4().as_bytes();
+
+            // Skip the version and variant bytes, they contain fixed bits.
+            bytes.extend_from_slice(&block[..6]);
+            bytes.push(block[7]);
+            bytes.extend_from_slice(&block[9..]);
+        }
+
+        bytes.truncate(len);
+        bytes
+    }
 }
 
 impl PartialEq for ValueGenerator {
@@ -335,6 +494,30 @@ mod tests {
         assert!(!cuid_default.is_now());
     }
 
+    #[test]
+    fn default_value_is_cuid2() {
+        let cuid2_default = DefaultValue::new_expression(ValueGenerator::new_cuid2());
+
+        assert!(cuid2_default.is_cuid2());
+        assert!(!cuid2_default.is_cuid());
+    }
+
+    #[test]
+    fn default_value_is_nanoid() {
+        let nanoid_default = DefaultValue::new_expression(ValueGenerator::new_nanoid(Some(12)));
+
+        assert!(nanoid_default.is_nanoid());
+        assert!(!nanoid_default.is_cuid());
+    }
+
+    #[test]
+    fn default_value_is_custom() {
+        let custom_default = DefaultValue::new_expression(ValueGenerator::new_custom(Some("ksuid".to_owned())));
+
+        assert!(custom_default.is_custom());
+        assert!(!custom_default.is_cuid());
+    }
+
     #[test]
     fn default_value_is_dbgenerated() {
         let db_generated_default = DefaultValue::new_expression(ValueGenerator::new_dbgenerated("test".to_string()));
@@ -198,6 +198,30 @@ impl Field {
             Field::CompositeField(_) => false,
         }
     }
+
+    pub fn is_encrypted(&self) -> bool {
+        match &self {
+            Field::ScalarField(sf) => sf.is_encrypted,
+            Field::RelationField(_) => false,
+            Field::CompositeField(_) => false,
+        }
+    }
+
+    pub fn is_readonly(&self) -> bool {
+        match &self {
+            Field::ScalarField(sf) => sf.is_readonly,
+            Field::RelationField(_) => false,
+            Field::CompositeField(_) => false,
+        }
+    }
+
+    pub fn computed_sql(&self) -> Option<&str> {
+        match &self {
+            Field::ScalarField(sf) => sf.computed_sql.as_deref(),
+            Field::RelationField(_) => None,
+            Field::CompositeField(_) => None,
+        }
+    }
 }
 
 impl WithName for Field {
@@ -448,6 +472,15 @@ pub struct ScalarField {
 
     /// Indicates if this field is ignored by the Client.
     pub is_ignored: bool,
+
+    /// Indicates if values of this field are encrypted at rest (`@encrypted`).
+    pub is_encrypted: bool,
+
+    /// Indicates that the field may not be written through the client (`@readonly`).
+    pub is_readonly: bool,
+
+    /// The SQL expression computing this read-only field (`@computedSql`).
+    pub computed_sql: Option<String>,
 }
 
 impl ScalarField {
@@ -464,6 +497,9 @@ impl ScalarField {
             is_updated_at: false,
             is_commented_out: false,
             is_ignored: false,
+            is_encrypted: false,
+            is_readonly: false,
+            computed_sql: None,
         }
     }
 
@@ -26,6 +26,13 @@ pub struct Model {
     pub is_commented_out: bool,
     /// Indicates if this model has to be ignored by the Client.
     pub is_ignored: bool,
+    /// Indicates if this model maps to a SQLite `STRICT` table.
+    pub is_strict: bool,
+    /// Indicates if this model maps to a SQLite `WITHOUT ROWID` table.
+    pub is_without_rowid: bool,
+    /// The name of the datasource block this model is routed to (`@@datasource`), if any.
+    /// `None` means the model lives on the default (first) datasource.
+    pub datasource: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -49,6 +56,8 @@ pub struct IndexDefinition {
     pub tpe: IndexType,
     pub algorithm: Option<IndexAlgorithm>,
     pub defined_on_field: bool,
+    /// Whether a unique index treats nulls as not distinct (`UNIQUE NULLS NOT DISTINCT`).
+    pub nulls_not_distinct: bool,
 }
 
 impl IndexDefinition {
@@ -179,6 +188,9 @@ impl Model {
             is_generated: false,
             is_commented_out: false,
             is_ignored: false,
+            is_strict: false,
+            is_without_rowid: false,
+            datasource: None,
         }
     }
 
@@ -69,6 +69,7 @@ const CAPABILITIES: &[ConnectorCapability] = &[
     ConnectorCapability::AutoIncrementAllowedOnNonId,
     ConnectorCapability::AutoIncrementMultipleAllowed,
     ConnectorCapability::AutoIncrementNonIndexedAllowed,
+    ConnectorCapability::CascadingSelfReferentialActions,
     ConnectorCapability::CompoundIds,
     ConnectorCapability::CreateMany,
     ConnectorCapability::CreateManyWriteableAutoIncId,
@@ -77,8 +78,11 @@ const CAPABILITIES: &[ConnectorCapability] = &[
     ConnectorCapability::InsensitiveFilters,
     ConnectorCapability::Json,
     ConnectorCapability::JsonFilteringArrayPath,
+    ConnectorCapability::LargeOffsets,
+    ConnectorCapability::MultipleNullsInUniqueIndex,
     ConnectorCapability::NamedPrimaryKeys,
     ConnectorCapability::NamedForeignKeys,
+    ConnectorCapability::OrderByNullsFirstLast,
     ConnectorCapability::QueryRaw,
     ConnectorCapability::RelationFieldsInArbitraryOrder,
     ConnectorCapability::ScalarLists,
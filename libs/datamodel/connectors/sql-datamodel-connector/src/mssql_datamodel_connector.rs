@@ -88,6 +88,10 @@ const CAPABILITIES: &[ConnectorCapability] = &[
     ConnectorCapability::AutoIncrementNonIndexedAllowed,
     ConnectorCapability::CompoundIds,
     ConnectorCapability::CreateMany,
+    ConnectorCapability::LargeOffsets,
+    // Emulated by the migration engine: uniques over nullable columns are rendered as filtered
+    // unique indexes (`WHERE <column> IS NOT NULL`).
+    ConnectorCapability::MultipleNullsInUniqueIndex,
     ConnectorCapability::NamedDefaultValues,
     ConnectorCapability::NamedForeignKeys,
     ConnectorCapability::NamedPrimaryKeys,
@@ -106,6 +106,7 @@ const CAPABILITIES: &[ConnectorCapability] = &[
     ConnectorCapability::AutoIncrement,
     ConnectorCapability::CompoundIds,
     ConnectorCapability::AnyId,
+    ConnectorCapability::MultipleNullsInUniqueIndex,
     ConnectorCapability::QueryRaw,
     ConnectorCapability::NamedForeignKeys,
     ConnectorCapability::AdvancedJsonNullability,
@@ -113,6 +114,8 @@ const CAPABILITIES: &[ConnectorCapability] = &[
     ConnectorCapability::FullTextIndex,
     ConnectorCapability::FullTextSearchWithIndex,
     ConnectorCapability::MultipleFullTextAttributesPerModel,
+    ConnectorCapability::LargeOffsets,
+    ConnectorCapability::OrderByNullsFirstLast,
 ];
 
 const CONSTRAINT_SCOPES: &[ConstraintScope] = &[ConstraintScope::GlobalForeignKey, ConstraintScope::ModelKeyIndex];
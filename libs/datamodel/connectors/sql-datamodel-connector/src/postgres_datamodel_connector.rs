@@ -1,6 +1,6 @@
 use datamodel_connector::{
     connector_error::ConnectorError,
-    helper::{arg_vec_from_opt, args_vec_from_opt, parse_one_opt_u32, parse_two_opt_u32},
+    helper::{arg_vec_from_opt, args_vec_from_opt, parse_one_opt_u32, parse_two_opt_u32, wrap_error_from_result},
     parser_database::walkers::ModelWalker,
     walker_ext_traits::*,
     Connector, ConnectorCapability, ConstraintScope, Diagnostics, NativeTypeConstructor, ReferentialAction,
@@ -29,11 +29,14 @@ const TIMESTAMP_TZ_TYPE_NAME: &str = "Timestamptz";
 const DATE_TYPE_NAME: &str = "Date";
 const TIME_TYPE_NAME: &str = "Time";
 const TIME_TZ_TYPE_NAME: &str = "Timetz";
+const INTERVAL_TYPE_NAME: &str = "Interval";
 const BOOLEAN_TYPE_NAME: &str = "Boolean";
 const BIT_TYPE_NAME: &str = "Bit";
 const VAR_BIT_TYPE_NAME: &str = "VarBit";
 const UUID_TYPE_NAME: &str = "Uuid";
 const XML_TYPE_NAME: &str = "Xml";
+const GEOMETRY_TYPE_NAME: &str = "Geometry";
+const GEOGRAPHY_TYPE_NAME: &str = "Geography";
 const JSON_TYPE_NAME: &str = "Json";
 const JSON_B_TYPE_NAME: &str = "JsonB";
 
@@ -57,11 +60,14 @@ const NATIVE_TYPE_CONSTRUCTORS: &[NativeTypeConstructor] = &[
     NativeTypeConstructor::without_args(DATE_TYPE_NAME, &[ScalarType::DateTime]),
     NativeTypeConstructor::with_optional_args(TIME_TYPE_NAME, 1, &[ScalarType::DateTime]),
     NativeTypeConstructor::with_optional_args(TIME_TZ_TYPE_NAME, 1, &[ScalarType::DateTime]),
+    NativeTypeConstructor::with_optional_args(INTERVAL_TYPE_NAME, 1, &[ScalarType::String]),
     NativeTypeConstructor::without_args(BOOLEAN_TYPE_NAME, &[ScalarType::Boolean]),
     NativeTypeConstructor::with_optional_args(BIT_TYPE_NAME, 1, &[ScalarType::String]),
     NativeTypeConstructor::with_optional_args(VAR_BIT_TYPE_NAME, 1, &[ScalarType::String]),
     NativeTypeConstructor::without_args(UUID_TYPE_NAME, &[ScalarType::String]),
     NativeTypeConstructor::without_args(XML_TYPE_NAME, &[ScalarType::String]),
+    NativeTypeConstructor::with_optional_args(GEOMETRY_TYPE_NAME, 2, &[ScalarType::String]),
+    NativeTypeConstructor::with_optional_args(GEOGRAPHY_TYPE_NAME, 2, &[ScalarType::String]),
     NativeTypeConstructor::without_args(JSON_TYPE_NAME, &[ScalarType::Json]),
     NativeTypeConstructor::without_args(JSON_B_TYPE_NAME, &[ScalarType::Json]),
 ];
@@ -78,6 +84,7 @@ const CAPABILITIES: &[ConnectorCapability] = &[
     ConnectorCapability::AutoIncrementAllowedOnNonId,
     ConnectorCapability::AutoIncrementMultipleAllowed,
     ConnectorCapability::AutoIncrementNonIndexedAllowed,
+    ConnectorCapability::CascadingSelfReferentialActions,
     ConnectorCapability::CompoundIds,
     ConnectorCapability::CreateMany,
     ConnectorCapability::CreateManyWriteableAutoIncId,
@@ -89,8 +96,11 @@ const CAPABILITIES: &[ConnectorCapability] = &[
     ConnectorCapability::Json,
     ConnectorCapability::JsonFilteringArrayPath,
     ConnectorCapability::JsonFilteringAlphanumeric,
+    ConnectorCapability::LargeOffsets,
+    ConnectorCapability::MultipleNullsInUniqueIndex,
     ConnectorCapability::NamedForeignKeys,
     ConnectorCapability::NamedPrimaryKeys,
+    ConnectorCapability::OrderByNullsFirstLast,
     ConnectorCapability::QueryRaw,
     ConnectorCapability::RelationFieldsInArbitraryOrder,
     ConnectorCapability::ScalarLists,
@@ -98,6 +108,7 @@ const CAPABILITIES: &[ConnectorCapability] = &[
     ConnectorCapability::UpdateableId,
     ConnectorCapability::WritableAutoincField,
     ConnectorCapability::UsingHashIndex,
+    ConnectorCapability::UniqueNullsNotDistinct,
 ];
 
 pub struct PostgresDatamodelConnector;
@@ -150,6 +161,10 @@ impl Connector for PostgresDatamodelConnector {
             Xml => ScalarType::String,
             Inet => ScalarType::String,
             Citext => ScalarType::String,
+            // ISO-8601 duration strings.
+            Interval(_) => ScalarType::String,
+            // Well-known text (WKT) strings.
+            Geometry(_) | Geography(_) => ScalarType::String,
             //Boolean
             Boolean => ScalarType::Boolean,
             //Int
@@ -216,7 +231,9 @@ impl Connector for PostgresDatamodelConnector {
             Bit(Some(0)) | VarBit(Some(0)) => {
                 errors.push(error.new_argument_m_out_of_range_error("M must be a positive integer."))
             }
-            Timestamp(Some(p)) | Timestamptz(Some(p)) | Time(Some(p)) | Timetz(Some(p)) if p > 6 => {
+            Timestamp(Some(p)) | Timestamptz(Some(p)) | Time(Some(p)) | Timetz(Some(p)) | Interval(Some(p))
+                if p > 6 =>
+            {
                 errors.push(error.new_argument_m_out_of_range_error("M can range from 0 to 6."))
             }
             _ => (),
@@ -282,11 +299,14 @@ impl Connector for PostgresDatamodelConnector {
             DATE_TYPE_NAME => Date,
             TIME_TYPE_NAME => Time(parse_one_opt_u32(args, TIME_TYPE_NAME)?),
             TIME_TZ_TYPE_NAME => Timetz(parse_one_opt_u32(args, TIME_TZ_TYPE_NAME)?),
+            INTERVAL_TYPE_NAME => Interval(parse_one_opt_u32(args, INTERVAL_TYPE_NAME)?),
             BOOLEAN_TYPE_NAME => Boolean,
             BIT_TYPE_NAME => Bit(parse_one_opt_u32(args, BIT_TYPE_NAME)?),
             VAR_BIT_TYPE_NAME => VarBit(parse_one_opt_u32(args, VAR_BIT_TYPE_NAME)?),
             UUID_TYPE_NAME => Uuid,
             XML_TYPE_NAME => Xml,
+            GEOMETRY_TYPE_NAME => Geometry(parse_geometry_modifier(args, GEOMETRY_TYPE_NAME)?),
+            GEOGRAPHY_TYPE_NAME => Geography(parse_geometry_modifier(args, GEOGRAPHY_TYPE_NAME)?),
             JSON_TYPE_NAME => Json,
             JSON_B_TYPE_NAME => JsonB,
             _ => return Err(ConnectorError::new_native_type_parser_error(name)),
@@ -313,11 +333,14 @@ impl Connector for PostgresDatamodelConnector {
             Date => (DATE_TYPE_NAME, vec![]),
             Time(x) => (TIME_TYPE_NAME, arg_vec_from_opt(x)),
             Timetz(x) => (TIME_TZ_TYPE_NAME, arg_vec_from_opt(x)),
+            Interval(x) => (INTERVAL_TYPE_NAME, arg_vec_from_opt(x)),
             Boolean => (BOOLEAN_TYPE_NAME, vec![]),
             Bit(x) => (BIT_TYPE_NAME, arg_vec_from_opt(x)),
             VarBit(x) => (VAR_BIT_TYPE_NAME, arg_vec_from_opt(x)),
             Uuid => (UUID_TYPE_NAME, vec![]),
             Xml => (XML_TYPE_NAME, vec![]),
+            Geometry(ref x) => (GEOMETRY_TYPE_NAME, geometry_args_from_opt(x)),
+            Geography(ref x) => (GEOGRAPHY_TYPE_NAME, geometry_args_from_opt(x)),
             Json => (JSON_TYPE_NAME, vec![]),
             JsonB => (JSON_B_TYPE_NAME, vec![]),
             Money => (MONEY_TYPE_NAME, vec![]),
@@ -341,3 +364,31 @@ impl Connector for PostgresDatamodelConnector {
         Ok(())
     }
 }
+
+/// Parses the optional `(type, srid)` modifier of the PostGIS `Geometry` and `Geography` types.
+/// When only the geometry type is given, the SRID defaults to 0, mirroring PostGIS.
+fn parse_geometry_modifier(args: Vec<String>, type_name: &str) -> Result<Option<(String, u32)>, ConnectorError> {
+    let number_of_args = args.len();
+
+    match args.as_slice() {
+        [] => Ok(None),
+        [r#type] => Ok(Some((r#type.clone(), 0))),
+        [r#type, srid] => {
+            let srid = wrap_error_from_result(srid.parse::<u32>(), "numeric", srid)?;
+
+            Ok(Some((r#type.clone(), srid)))
+        }
+        _ => Err(ConnectorError::new_argument_count_mismatch_error(
+            type_name,
+            2,
+            number_of_args,
+        )),
+    }
+}
+
+fn geometry_args_from_opt(input: &Option<(String, u32)>) -> Vec<String> {
+    match input {
+        Some((r#type, srid)) => vec![r#type.clone(), srid.to_string()],
+        None => vec![],
+    }
+}
@@ -12,9 +12,13 @@ const CONSTRAINT_SCOPES: &[ConstraintScope] = &[ConstraintScope::GlobalKeyIndex]
 const CAPABILITIES: &[ConnectorCapability] = &[
     ConnectorCapability::AnyId,
     ConnectorCapability::AutoIncrement,
+    ConnectorCapability::CascadingSelfReferentialActions,
     ConnectorCapability::CompoundIds,
+    ConnectorCapability::MultipleNullsInUniqueIndex,
+    ConnectorCapability::OrderByNullsFirstLast,
     ConnectorCapability::QueryRaw,
     ConnectorCapability::RelationFieldsInArbitraryOrder,
+    ConnectorCapability::SqliteTableOptions,
     ConnectorCapability::UpdateableId,
 ];
 
@@ -64,6 +64,9 @@ features!(
     DataProxy,
     ExtendedIndexes,
     Cockroachdb,
+    FieldReference,
+    Interval,
+    StrictTables,
 );
 
 // Mapping of which active, deprecated and hidden
@@ -81,6 +84,9 @@ pub static GENERATOR: Lazy<FeatureMap> = Lazy::new(|| {
             FullTextIndex,
             DataProxy,
             ExtendedIndexes,
+            FieldReference,
+            Interval,
+            StrictTables,
         ])
         .with_hidden(vec![Cockroachdb])
         .with_deprecated(vec![
@@ -29,6 +29,13 @@ impl Configuration {
         self.datasources.first().map(|source| source.referential_integrity())
     }
 
+    pub fn uses_identity_autoincrement(&self) -> bool {
+        self.datasources
+            .first()
+            .map(|source| source.uses_identity_autoincrement())
+            .unwrap_or(false)
+    }
+
     pub fn max_identifier_length(&self) -> usize {
         self.datasources
             .first()
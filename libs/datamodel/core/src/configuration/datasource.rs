@@ -22,6 +22,15 @@ pub struct Datasource {
     pub shadow_database_url: Option<(StringFromEnvVar, Span)>,
     /// In which layer referential actions are handled.
     pub referential_integrity: Option<ReferentialIntegrity>,
+    /// An optional user-defined name for the table storing applied migrations. Defaults to
+    /// `_prisma_migrations`.
+    pub migrations_table_name: Option<String>,
+    /// An optional online schema change tool (`gh-ost` or `pt-osc`) to render
+    /// `ALTER TABLE` migration steps with. Only supported on MySQL.
+    pub online_schema_change_tool: Option<String>,
+    /// How `@default(autoincrement())` columns are implemented (`"serial"` or
+    /// `"identity"`). Only supported on PostgreSQL, defaults to `"serial"`.
+    pub autoincrement_strategy: Option<String>,
 }
 
 impl std::fmt::Debug for Datasource {
@@ -35,6 +44,9 @@ impl std::fmt::Debug for Datasource {
             .field("active_connector", &&"...")
             .field("shadow_database_url", &self.shadow_database_url)
             .field("referential_integrity", &self.referential_integrity)
+            .field("migrations_table_name", &self.migrations_table_name)
+            .field("online_schema_change_tool", &self.online_schema_change_tool)
+            .field("autoincrement_strategy", &self.autoincrement_strategy)
             .finish()
     }
 }
@@ -52,6 +64,12 @@ impl Datasource {
             .unwrap_or(self.active_connector.default_referential_integrity())
     }
 
+    /// Whether `@default(autoincrement())` columns are implemented as identity columns
+    /// (`GENERATED BY DEFAULT AS IDENTITY`) instead of `SERIAL` types. PostgreSQL only.
+    pub fn uses_identity_autoincrement(&self) -> bool {
+        self.autoincrement_strategy.as_deref() == Some("identity")
+    }
+
     /// Load the database URL, validating it and resolving env vars in the
     /// process. Also see `load_url_with_config_dir()`.
     pub fn load_url<F>(&self, env: F) -> Result<String, Diagnostics>
@@ -117,6 +117,9 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated()),
         is_updated_at: Some(field.is_updated_at()),
+        is_encrypted: field.is_encrypted().then(|| true),
+        is_readonly: field.is_readonly().then(|| true),
+        computed_sql: field.computed_sql().map(ToOwned::to_owned),
         documentation: field.documentation().map(|v| v.to_owned()),
     }
 }
@@ -15,8 +15,14 @@ use crate::{
 };
 use datamodel_connector::ReferentialIntegrity;
 use enumflags2::BitFlags;
-use std::{collections::HashMap, convert::TryFrom};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+};
 
+const AUTOINCREMENT_STRATEGY_KEY: &str = "autoincrementStrategy";
+const MIGRATIONS_TABLE_NAME_KEY: &str = "migrationsTableName";
+const ONLINE_SCHEMA_CHANGE_TOOL_KEY: &str = "onlineSchemaChangeTool";
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
 const SHADOW_DATABASE_URL_KEY: &str = "shadowDatabaseUrl";
 const URL_KEY: &str = "url";
@@ -42,10 +48,18 @@ impl DatasourceLoader {
             }
         }
 
-        if sources.len() > 1 {
-            for src in ast_schema.sources() {
+        // Multiple datasources are allowed. The first one is the default; models opt into
+        // the others with the `@@datasource` attribute. Names still have to be unique so
+        // that `@@datasource` references are unambiguous.
+        let mut seen_names = HashSet::new();
+
+        for src in ast_schema.sources() {
+            if !seen_names.insert(src.name.name.as_str()) {
                 diagnostics.push_error(DatamodelError::new_source_validation_error(
-                    &"You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.".to_string(),
+                    &format!(
+                        "You defined more than one datasource named `{}`. Datasource names must be unique.",
+                        &src.name.name
+                    ),
                     &src.name.name,
                     src.span,
                 ));
@@ -149,6 +163,110 @@ impl DatasourceLoader {
                 None
             };
 
+        let migrations_table_name = match args.get(MIGRATIONS_TABLE_NAME_KEY) {
+            Some((_, value)) => match value.as_string_literal() {
+                Some(("", _)) => {
+                    diagnostics.push_error(DatamodelError::new_source_validation_error(
+                        "The migrationsTableName argument in a datasource must not be empty",
+                        source_name,
+                        value.span(),
+                    ));
+                    None
+                }
+                Some((name, _)) => Some(name.to_owned()),
+                None => {
+                    diagnostics.push_error(DatamodelError::new_source_validation_error(
+                        "The migrationsTableName argument in a datasource must be a string literal",
+                        source_name,
+                        value.span(),
+                    ));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let online_schema_change_tool = match args.get(ONLINE_SCHEMA_CHANGE_TOOL_KEY) {
+            Some((_, value)) => match value.as_string_literal() {
+                Some((tool @ "gh-ost", _)) | Some((tool @ "pt-osc", _)) => Some(tool.to_owned()),
+                Some((tool, _)) => {
+                    diagnostics.push_error(DatamodelError::new_source_validation_error(
+                        &format!(
+                            "Invalid onlineSchemaChangeTool setting: \"{}\". Supported values: \"gh-ost\", \"pt-osc\"",
+                            tool
+                        ),
+                        source_name,
+                        value.span(),
+                    ));
+                    None
+                }
+                None => {
+                    diagnostics.push_error(DatamodelError::new_source_validation_error(
+                        "The onlineSchemaChangeTool argument in a datasource must be a string literal",
+                        source_name,
+                        value.span(),
+                    ));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let autoincrement_strategy = match args.get(AUTOINCREMENT_STRATEGY_KEY) {
+            Some((_, value)) => match value.as_string_literal() {
+                Some((strategy @ "serial", _)) | Some((strategy @ "identity", _)) => Some(strategy.to_owned()),
+                Some((strategy, _)) => {
+                    diagnostics.push_error(DatamodelError::new_source_validation_error(
+                        &format!(
+                            "Invalid autoincrementStrategy setting: \"{}\". Supported values: \"serial\", \"identity\"",
+                            strategy
+                        ),
+                        source_name,
+                        value.span(),
+                    ));
+                    None
+                }
+                None => {
+                    diagnostics.push_error(DatamodelError::new_source_validation_error(
+                        "The autoincrementStrategy argument in a datasource must be a string literal",
+                        source_name,
+                        value.span(),
+                    ));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        if online_schema_change_tool.is_some() && provider != MYSQL_SOURCE_NAME {
+            let span = args
+                .get(ONLINE_SCHEMA_CHANGE_TOOL_KEY)
+                .map(|(_, v)| v.span())
+                .unwrap_or_else(Span::empty);
+
+            diagnostics.push_error(DatamodelError::new_source_validation_error(
+                "The onlineSchemaChangeTool argument is only supported on the mysql provider",
+                source_name,
+                span,
+            ));
+        }
+
+        if autoincrement_strategy.is_some()
+            && provider != POSTGRES_SOURCE_NAME
+            && provider != POSTGRES_SOURCE_NAME_HEROKU
+        {
+            let span = args
+                .get(AUTOINCREMENT_STRATEGY_KEY)
+                .map(|(_, v)| v.span())
+                .unwrap_or_else(Span::empty);
+
+            diagnostics.push_error(DatamodelError::new_source_validation_error(
+                "The autoincrementStrategy argument is only supported on the postgresql provider",
+                source_name,
+                span,
+            ));
+        }
+
         preview_features_guardrail(&args, diagnostics);
 
         let documentation = ast_source.documentation.as_ref().map(|comment| comment.text.clone());
@@ -213,6 +331,9 @@ impl DatasourceLoader {
             active_connector,
             shadow_database_url,
             referential_integrity,
+            migrations_table_name,
+            online_schema_change_tool,
+            autoincrement_strategy,
         })
     }
 }
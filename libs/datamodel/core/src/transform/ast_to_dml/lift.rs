@@ -274,6 +274,9 @@ impl<'a> LiftAstToDml<'a> {
         model.documentation = ast_model.documentation.clone().map(|comment| comment.text);
         model.database_name = walker.mapped_name().map(String::from);
         model.is_ignored = walker.is_ignored();
+        model.is_strict = walker.is_strict();
+        model.is_without_rowid = walker.is_without_rowid();
+        model.datasource = walker.datasource_name().map(String::from);
 
         model.primary_key = walker.primary_key().map(|pk| dml::PrimaryKeyDefinition {
             name: pk.name().map(String::from),
@@ -326,6 +329,7 @@ impl<'a> LiftAstToDml<'a> {
                     tpe,
                     algorithm,
                     defined_on_field: idx.is_defined_on_field(),
+                    nulls_not_distinct: idx.nulls_not_distinct(),
                 }
             })
             .collect();
@@ -353,6 +357,9 @@ impl<'a> LiftAstToDml<'a> {
             field.documentation = ast_field.documentation.clone().map(|comment| comment.text);
             field.is_ignored = scalar_field.is_ignored();
             field.is_updated_at = scalar_field.is_updated_at();
+            field.is_encrypted = scalar_field.is_encrypted();
+            field.is_readonly = scalar_field.is_readonly();
+            field.computed_sql = scalar_field.computed_sql().map(String::from);
             field.database_name = scalar_field.mapped_name().map(String::from);
             field.default_value = scalar_field.default_value().map(|d| dml::DefaultValue {
                 kind: d.dml_default_kind(),
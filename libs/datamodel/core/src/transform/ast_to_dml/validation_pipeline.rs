@@ -51,6 +51,7 @@ pub(crate) fn validate<'ast>(
     let mut context = context::Context {
         db: &output.db,
         datasource: source,
+        datasources: sources,
         preview_features,
         connector,
         referential_integrity,
@@ -9,6 +9,9 @@ use enumflags2::BitFlags;
 pub(crate) struct Context<'a> {
     pub(super) db: &'a ParserDatabase<'a>,
     pub(super) datasource: Option<&'a Datasource>,
+    /// All datasources defined in the schema. The first one is the default, models opt
+    /// into the others with `@@datasource`.
+    pub(super) datasources: &'a [Datasource],
     pub(super) preview_features: BitFlags<PreviewFeature>,
     pub(super) connector: &'static dyn Connector,
     /// Referential integrity is a pure function of the datasource, but since there are defaults,
@@ -34,7 +34,10 @@ pub(super) fn validate(ctx: &mut Context<'_>, relation_transformation_enabled: b
         models::primary_key_length_prefix_supported(model, ctx);
         models::primary_key_sort_order_supported(model, ctx);
         models::only_one_fulltext_attribute_allowed(model, ctx);
+        models::sqlite_table_options_preview_feature_enabled(model, ctx);
+        models::sqlite_table_options_supported(model, ctx);
         models::connector_specific(model, ctx);
+        models::datasource_is_defined(model, ctx);
         autoincrement::validate_auto_increment(model, ctx);
 
         if let Some(pk) = model.primary_key() {
@@ -83,6 +86,8 @@ pub(super) fn validate(ctx: &mut Context<'_>, relation_transformation_enabled: b
             indexes::fulltext_columns_should_not_define_length(index, ctx);
             indexes::fulltext_column_sort_is_supported(index, ctx);
             indexes::fulltext_text_columns_should_be_bundled_together(index, ctx);
+            indexes::nulls_not_distinct_supported(index, ctx);
+            indexes::unique_with_optional_fields_supported(index, ctx);
             indexes::has_valid_mapped_name(index, ctx);
 
             for field_attribute in index.scalar_field_attributes() {
@@ -116,8 +121,10 @@ pub(super) fn validate(ctx: &mut Context<'_>, relation_transformation_enabled: b
                 if let Some(relation) = relation.as_complete() {
                     relations::cycles(relation, ctx);
                     relations::multiple_cascading_paths(relation, ctx);
+                    relations::self_relation_on_update_actions(relation, ctx);
                 }
 
+                relations::models_on_same_datasource(relation, ctx);
                 relations::references_unique_fields(relation, ctx);
                 relations::same_length_in_referencing_and_referenced(relation, ctx);
                 relations::referencing_fields_in_correct_order(relation, ctx);
@@ -148,6 +155,7 @@ pub(super) fn validate(ctx: &mut Context<'_>, relation_transformation_enabled: b
             }
             RefinedRelationWalker::ImplicitManyToMany(relation) => {
                 relations::many_to_many::validate_singular_id(relation, ctx);
+                relations::many_to_many::models_on_same_datasource(relation, ctx);
             }
         }
     }
@@ -5,6 +5,7 @@ use super::{
 };
 use crate::{
     ast,
+    common::preview_features::PreviewFeature,
     diagnostics::DatamodelError,
     transform::ast_to_dml::{
         db::{
@@ -161,6 +162,14 @@ pub(super) fn validate_native_type_arguments(field: ScalarFieldWalker<'_, '_>, c
         return;
     };
 
+    // The Postgres `Interval` native type is only available with the `interval` preview feature.
+    if type_name == "Interval" && !ctx.preview_features.contains(PreviewFeature::Interval) {
+        let message = "You must enable `interval` preview feature to use the `Interval` native type.";
+
+        ctx.push_error(DatamodelError::new_connector_error(message, span));
+        return;
+    }
+
     let number_of_args = args.len();
 
     if number_of_args < constructor._number_of_args
@@ -351,6 +351,63 @@ pub(crate) fn hash_index_must_not_use_sort_param(index: IndexWalker<'_, '_>, ctx
     }
 }
 
+/// `nullsNotDistinct` requires database support for `UNIQUE NULLS NOT DISTINCT`.
+pub(crate) fn nulls_not_distinct_supported(index: IndexWalker<'_, '_>, ctx: &mut Context<'_>) {
+    if !index.nulls_not_distinct() {
+        return;
+    }
+
+    if ctx
+        .connector
+        .has_capability(ConnectorCapability::UniqueNullsNotDistinct)
+    {
+        return;
+    }
+
+    let message =
+        "The `nullsNotDistinct` argument is not supported with the current connector. It requires PostgreSQL 15 or later.";
+    let span = index
+        .ast_attribute()
+        .and_then(|i| i.span_for_argument("nullsNotDistinct"))
+        .unwrap_or_else(Span::empty);
+
+    ctx.push_error(DatamodelError::new_attribute_validation_error(
+        message,
+        index.attribute_name(),
+        span,
+    ));
+}
+
+/// Connectors that treat NULLs as equal in unique indexes reject the second row with a missing
+/// value at runtime. Fail at validation time instead of behaving differently per database.
+pub(crate) fn unique_with_optional_fields_supported(index: IndexWalker<'_, '_>, ctx: &mut Context<'_>) {
+    if ctx
+        .connector
+        .has_capability(ConnectorCapability::MultipleNullsInUniqueIndex)
+    {
+        return;
+    }
+
+    if !index.is_unique() {
+        return;
+    }
+
+    if index.fields().any(|f| f.is_optional()) {
+        let message = "The current connector does not allow more than one row with a NULL value in a unique constraint. Please make the fields required or remove the unique constraint.";
+
+        let span = index
+            .ast_attribute()
+            .map(|i| i.span)
+            .unwrap_or_else(|| index.model().ast_model().span);
+
+        ctx.push_error(DatamodelError::new_attribute_validation_error(
+            message,
+            index.attribute_name(),
+            span,
+        ));
+    }
+}
+
 pub(super) fn has_valid_mapped_name(index: IndexWalker<'_, '_>, ctx: &mut Context<'_>) {
     if let Some(ast_attribute) = index.ast_attribute() {
         validate_db_name(
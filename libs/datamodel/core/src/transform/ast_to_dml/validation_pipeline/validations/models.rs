@@ -227,6 +227,80 @@ pub(crate) fn only_one_fulltext_attribute_allowed(model: ModelWalker<'_, '_>, ct
     }
 }
 
+/// `@@strict` and `@@withoutRowid` are not available without the `strictTables` preview feature.
+pub(crate) fn sqlite_table_options_preview_feature_enabled(model: ModelWalker<'_, '_>, ctx: &mut Context<'_>) {
+    if ctx.preview_features.contains(PreviewFeature::StrictTables) {
+        return;
+    }
+
+    for (used, attribute) in [
+        (model.is_strict(), "strict"),
+        (model.is_without_rowid(), "withoutRowid"),
+    ] {
+        if used {
+            let message = format!(
+                "You must enable the `strictTables` preview feature to be able to use the `@@{}` attribute.",
+                attribute
+            );
+
+            ctx.push_error(DatamodelError::new_attribute_validation_error(
+                &message,
+                attribute,
+                model.ast_model().span,
+            ));
+        }
+    }
+}
+
+/// `@@strict` and `@@withoutRowid` only make sense where the database supports these table options.
+pub(crate) fn sqlite_table_options_supported(model: ModelWalker<'_, '_>, ctx: &mut Context<'_>) {
+    if ctx.connector.has_capability(ConnectorCapability::SqliteTableOptions) {
+        return;
+    }
+
+    for (used, attribute) in [
+        (model.is_strict(), "strict"),
+        (model.is_without_rowid(), "withoutRowid"),
+    ] {
+        if used {
+            let message = format!(
+                "Defining `@@{}` tables is not supported with the current connector.",
+                attribute
+            );
+
+            ctx.push_error(DatamodelError::new_attribute_validation_error(
+                &message,
+                attribute,
+                model.ast_model().span,
+            ));
+        }
+    }
+}
+
+/// `@@datasource` must reference a datasource block defined in the schema.
+pub(crate) fn datasource_is_defined(model: ModelWalker<'_, '_>, ctx: &mut Context<'_>) {
+    let name = match model.datasource_name() {
+        Some(name) => name,
+        None => return,
+    };
+
+    if ctx.datasources.iter().any(|datasource| datasource.name == name) {
+        return;
+    }
+
+    let message = format!(
+        "Model `{}` is routed to the datasource `{}`, but no datasource with that name is defined in the schema.",
+        model.name(),
+        name
+    );
+
+    ctx.push_error(DatamodelError::new_attribute_validation_error(
+        &message,
+        "datasource",
+        model.ast_model().span,
+    ));
+}
+
 /// Does the connector support named and compound primary keys at all?
 pub(crate) fn primary_key_connector_specific(model: ModelWalker<'_, '_>, ctx: &mut Context<'_>) {
     let primary_key = if let Some(pk) = model.primary_key() {
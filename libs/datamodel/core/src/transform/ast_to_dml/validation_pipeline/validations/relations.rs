@@ -5,6 +5,7 @@ pub(super) mod one_to_one;
 mod visited_relation;
 
 use crate::{
+    ast,
     common::provider_names::MONGODB_SOURCE_NAME,
     diagnostics::DatamodelError,
     transform::ast_to_dml::{
@@ -68,6 +69,34 @@ pub(super) fn has_a_unique_constraint_name(
     }
 }
 
+/// A relation can only connect models routed to the same datasource: the engine can
+/// neither join nor enforce referential integrity across databases.
+pub(super) fn models_on_same_datasource(relation: InlineRelationWalker<'_, '_>, ctx: &mut Context<'_>) {
+    let default = ctx.datasources.first().map(|datasource| datasource.name.as_str());
+    let referencing = relation.referencing_model().datasource_name().or(default);
+    let referenced = relation.referenced_model().datasource_name().or(default);
+
+    if referencing == referenced {
+        return;
+    }
+
+    let span = relation
+        .forward_relation_field()
+        .map(|rf| rf.ast_field().span)
+        .unwrap_or_else(|| relation.referencing_model().ast_model().span);
+
+    ctx.push_error(DatamodelError::new_validation_error(
+        format!(
+            "The relation between `{}` and `{}` crosses datasources (`{}` and `{}`). Relations can only connect models routed to the same datasource.",
+            relation.referencing_model().name(),
+            relation.referenced_model().name(),
+            referencing.unwrap_or("<none>"),
+            referenced.unwrap_or("<none>"),
+        ),
+        span,
+    ));
+}
+
 /// Required relational fields should point to required scalar fields.
 pub(super) fn field_arity(relation: InlineRelationWalker<'_, '_>, ctx: &mut Context<'_>) {
     let forward_relation_field = if let Some(f) = relation.forward_relation_field() {
@@ -265,6 +294,7 @@ pub(super) fn cycles<'ast, 'db>(relation: CompleteInlineRelationWalker<'ast, 'db
                     ctx.connector,
                     ctx.referential_integrity,
                     msg,
+                    relation.referencing_field().ast_field().span,
                 ));
 
                 return;
@@ -281,6 +311,7 @@ pub(super) fn cycles<'ast, 'db>(relation: CompleteInlineRelationWalker<'ast, 'db
                     ctx.connector,
                     ctx.referential_integrity,
                     &msg,
+                    relation.referencing_field().ast_field().span,
                 ));
                 return;
             }
@@ -296,6 +327,62 @@ pub(super) fn cycles<'ast, 'db>(relation: CompleteInlineRelationWalker<'ast, 'db
     }
 }
 
+/// Rejects self-relations whose `onUpdate` action cascades on connectors
+/// without the [`CascadingSelfReferentialActions`] capability. MySQL (InnoDB)
+/// refuses such foreign keys at DDL time with an opaque errno 150, long after
+/// the schema validated — catching it here points at the `@relation` attribute
+/// and suggests the fix instead. Since `onUpdate` defaults to `Cascade`, this
+/// also fires for self-relations that spell out no action at all. Connectors
+/// with full reference cycle detection (SQL Server) report self-relation
+/// cycles in [`cycles`] already.
+///
+/// [`CascadingSelfReferentialActions`]: ConnectorCapability::CascadingSelfReferentialActions
+pub(super) fn self_relation_on_update_actions(relation: CompleteInlineRelationWalker<'_, '_>, ctx: &mut Context<'_>) {
+    if ctx
+        .connector
+        .has_capability(ConnectorCapability::CascadingSelfReferentialActions)
+        || ctx
+            .connector
+            .has_capability(ConnectorCapability::ReferenceCycleDetection)
+    {
+        return;
+    }
+
+    // Without foreign keys, the constraint the database would reject is never created.
+    if !ctx
+        .datasource
+        .map(|ds| ds.referential_integrity().uses_foreign_keys())
+        .unwrap_or(false)
+    {
+        return;
+    }
+
+    if relation.referencing_model() != relation.referenced_model() {
+        return;
+    }
+
+    if !relation.on_update().triggers_modification() {
+        return;
+    }
+
+    let referencing_field = relation.referencing_field();
+
+    let span = referencing_field
+        .relation_attribute()
+        .map(|attribute| attribute.span)
+        .unwrap_or_else(|| referencing_field.ast_field().span);
+
+    let msg = "The `onUpdate` referential action of a self-relation must not modify the updated record on this database. Set `onUpdate: NoAction` in the @relation attribute.";
+
+    ctx.push_error(cascade_error_with_default_values(
+        relation,
+        ctx.connector,
+        ctx.referential_integrity,
+        msg,
+        span,
+    ));
+}
+
 /// From the given relation, checks if any other relation fits the criteria:
 ///
 /// - Triggers a cascading action (anything else but NoAction)
@@ -436,6 +523,7 @@ pub(super) fn multiple_cascading_paths(relation: CompleteInlineRelationWalker<'_
             ctx.connector,
             ctx.referential_integrity,
             &msg,
+            relation.referencing_field().ast_field().span,
         ));
     } else if reachable.len() > 1 {
         let msg = format!(
@@ -449,6 +537,7 @@ pub(super) fn multiple_cascading_paths(relation: CompleteInlineRelationWalker<'_
             ctx.connector,
             ctx.referential_integrity,
             &msg,
+            relation.referencing_field().ast_field().span,
         ));
     }
 }
@@ -458,6 +547,7 @@ fn cascade_error_with_default_values(
     connector: &dyn Connector,
     referential_integrity: ReferentialIntegrity,
     msg: &str,
+    span: ast::Span,
 ) -> DatamodelError {
     let on_delete = match relation.referencing_field().explicit_on_delete() {
         None if relation
@@ -494,7 +584,7 @@ fn cascade_error_with_default_values(
 
     msg.push_str(" Read more at https://pris.ly/d/cyclic-referential-actions");
 
-    DatamodelError::new_validation_error(msg, relation.referencing_field().ast_field().span)
+    DatamodelError::new_validation_error(msg, span)
 }
 
 /// The types of the referencing and referenced scalar fields in a relation must be compatible.
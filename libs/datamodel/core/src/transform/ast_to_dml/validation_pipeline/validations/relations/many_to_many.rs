@@ -37,3 +37,27 @@ pub(crate) fn validate_singular_id(relation: ImplicitManyToManyRelationWalker<'_
         }
     }
 }
+
+/// A relation can only connect models routed to the same datasource: the engine can
+/// neither join nor enforce referential integrity across databases. The implicit join
+/// table of a many-to-many relation could not live on either side otherwise.
+pub(crate) fn models_on_same_datasource(relation: ImplicitManyToManyRelationWalker<'_, '_>, ctx: &mut Context<'_>) {
+    let default = ctx.datasources.first().map(|datasource| datasource.name.as_str());
+    let datasource_a = relation.model_a().datasource_name().or(default);
+    let datasource_b = relation.model_b().datasource_name().or(default);
+
+    if datasource_a == datasource_b {
+        return;
+    }
+
+    ctx.push_error(DatamodelError::new_validation_error(
+        format!(
+            "The relation between `{}` and `{}` crosses datasources (`{}` and `{}`). Relations can only connect models routed to the same datasource.",
+            relation.model_a().name(),
+            relation.model_b().name(),
+            datasource_a.unwrap_or("<none>"),
+            datasource_b.unwrap_or("<none>"),
+        ),
+        relation.field_a().ast_field().span,
+    ));
+}
@@ -19,6 +19,30 @@ pub fn add_sources_to_ast(config: &Configuration, ast_datamodel: &mut ast::Schem
             ))
         }
 
+        if let Some(migrations_table_name) = &source.migrations_table_name {
+            arguments.push(ast::ConfigBlockProperty {
+                name: ast::Identifier::new("migrationsTableName"),
+                value: ast::Expression::StringValue(migrations_table_name.clone(), ast::Span::empty()),
+                span: ast::Span::empty(),
+            });
+        }
+
+        if let Some(online_schema_change_tool) = &source.online_schema_change_tool {
+            arguments.push(ast::ConfigBlockProperty {
+                name: ast::Identifier::new("onlineSchemaChangeTool"),
+                value: ast::Expression::StringValue(online_schema_change_tool.clone(), ast::Span::empty()),
+                span: ast::Span::empty(),
+            });
+        }
+
+        if let Some(autoincrement_strategy) = &source.autoincrement_strategy {
+            arguments.push(ast::ConfigBlockProperty {
+                name: ast::Identifier::new("autoincrementStrategy"),
+                value: ast::Expression::StringValue(autoincrement_strategy.clone(), ast::Span::empty()),
+                span: ast::Span::empty(),
+            });
+        }
+
         if preview_features.contains(PreviewFeature::ReferentialIntegrity) {
             if let Some(referential_integrity) = source.referential_integrity {
                 let property = ast::ConfigBlockProperty {
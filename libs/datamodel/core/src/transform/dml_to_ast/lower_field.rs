@@ -159,6 +159,24 @@ impl<'a> LowerDmlToAst<'a> {
             attributes.push(ast::Attribute::new("updatedAt", Vec::new()));
         }
 
+        // @readonly
+        if field.is_readonly() {
+            attributes.push(ast::Attribute::new("readonly", Vec::new()));
+        }
+
+        // @encrypted
+        if field.is_encrypted() {
+            attributes.push(ast::Attribute::new("encrypted", Vec::new()));
+        }
+
+        // @computedSql
+        if let Some(expression) = field.computed_sql() {
+            attributes.push(ast::Attribute::new(
+                "computedSql",
+                vec![ast::Argument::new_unnamed(Self::lower_string(expression))],
+            ));
+        }
+
         // @map
         <LowerDmlToAst<'a>>::push_model_index_map_arg(field, &mut attributes);
 
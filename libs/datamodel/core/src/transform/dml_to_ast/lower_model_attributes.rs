@@ -65,6 +65,13 @@ impl<'a> LowerDmlToAst<'a> {
 
                 self.push_index_map_argument(model, index_def, &mut args);
 
+                if index_def.nulls_not_distinct {
+                    args.push(ast::Argument::new(
+                        "nullsNotDistinct",
+                        ast::Expression::ConstantValue("true".to_string(), Span::empty()),
+                    ));
+                }
+
                 attributes.push(ast::Attribute::new("unique", args));
             });
 
@@ -107,6 +114,27 @@ impl<'a> LowerDmlToAst<'a> {
             attributes.push(ast::Attribute::new("ignore", vec![]));
         }
 
+        // @@strict
+        if model.is_strict {
+            attributes.push(ast::Attribute::new("strict", vec![]));
+        }
+
+        // @@withoutRowid
+        if model.is_without_rowid {
+            attributes.push(ast::Attribute::new("withoutRowid", vec![]));
+        }
+
+        // @@datasource
+        if let Some(datasource) = &model.datasource {
+            attributes.push(ast::Attribute::new(
+                "datasource",
+                vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                    String::from(datasource),
+                    ast::Span::empty(),
+                ))],
+            ));
+        }
+
         attributes
     }
 
@@ -154,6 +182,13 @@ impl<'a> LowerDmlToAst<'a> {
                     ));
                 }
             }
+
+            if index_def.nulls_not_distinct {
+                args.push(ast::Argument::new(
+                    "nullsNotDistinct",
+                    ast::Expression::ConstantValue("true".to_string(), Span::empty()),
+                ));
+            }
         }
     }
 
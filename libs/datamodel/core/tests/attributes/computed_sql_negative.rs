@@ -0,0 +1,41 @@
+use crate::common::*;
+
+#[test]
+fn should_fail_if_field_arity_is_list() {
+    let dml = indoc! {r#"
+        datasource db {
+          provider = "postgres"
+          url = "postgres://"
+        }
+
+        model User {
+          id Int @id
+          tags String[] @computedSql("string_to_array(tags_raw, ',')")
+        }
+    "#};
+
+    let error = datamodel::parse_schema(dml).map(drop).unwrap_err();
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@computedSql": Fields that are marked with @computedSql cannot be lists.[0m
+          [1;94m-->[0m  [4mschema.prisma:8[0m
+        [1;94m   | [0m
+        [1;94m 7 | [0m  id Int @id
+        [1;94m 8 | [0m  tags String[] @[1;91mcomputedSql("string_to_array(tags_raw, ',')")[0m
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error)
+}
+
+#[test]
+fn should_fail_without_an_expression() {
+    let dml = indoc! {r#"
+        model User {
+          id Int @id
+          fullName String @computedSql
+        }
+    "#};
+
+    assert!(datamodel::parse_schema(dml).map(drop).is_err());
+}
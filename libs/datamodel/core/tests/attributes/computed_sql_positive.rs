@@ -0,0 +1,23 @@
+use crate::common::*;
+
+#[test]
+fn allow_computed_sql_on_scalar_fields() {
+    let dml = r#"
+    model User {
+      id        Int    @id
+      firstName String
+      lastName  String
+      fullName  String @computedSql("first_name || ' ' || last_name")
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("User");
+
+    user_model
+        .assert_has_scalar_field("firstName")
+        .assert_computed_sql(None);
+    user_model
+        .assert_has_scalar_field("fullName")
+        .assert_computed_sql(Some("first_name || ' ' || last_name"));
+}
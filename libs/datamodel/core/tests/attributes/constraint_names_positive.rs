@@ -35,6 +35,7 @@ fn multiple_indexes_with_same_name_on_different_models_are_supported_by_mysql()
         fields: vec![IndexField::new("id")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -44,6 +45,7 @@ fn multiple_indexes_with_same_name_on_different_models_are_supported_by_mysql()
         fields: vec![IndexField::new("id")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -80,6 +82,7 @@ fn foreign_keys_and_indexes_with_same_name_on_same_table_are_not_supported_on_my
         fields: vec![IndexField::new("bId")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -118,6 +121,7 @@ fn multiple_indexes_with_same_name_on_different_models_are_supported_by_mssql()
         fields: vec![IndexField::new("id")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -127,6 +131,7 @@ fn multiple_indexes_with_same_name_on_different_models_are_supported_by_mssql()
         fields: vec![IndexField::new("id")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -167,6 +172,7 @@ fn multiple_constraints_with_same_name_in_different_namespaces_are_supported_by_
         fields: vec![IndexField::new("id")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -176,6 +182,7 @@ fn multiple_constraints_with_same_name_in_different_namespaces_are_supported_by_
         fields: vec![IndexField::new("id")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
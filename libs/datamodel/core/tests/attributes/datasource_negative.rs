@@ -0,0 +1,77 @@
+use crate::common::*;
+use indoc::indoc;
+
+#[test]
+fn must_error_on_unknown_datasource_name() {
+    let dml = indoc! {r#"
+        datasource db {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+        }
+
+        model Event {
+          id Int @id
+
+          @@datasource("analytics")
+        }
+    "#};
+
+    let error = datamodel::parse_schema(dml).map(drop).unwrap_err();
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@datasource": Model `Event` is routed to the datasource `analytics`, but no datasource with that name is defined in the schema.[0m
+          [1;94m-->[0m  [4mschema.prisma:6[0m
+        [1;94m   | [0m
+        [1;94m 5 | [0m
+        [1;94m 6 | [0m[1;91mmodel Event {[0m
+        [1;94m 7 | [0m  id Int @id
+        [1;94m 8 | [0m
+        [1;94m 9 | [0m  @@datasource("analytics")
+        [1;94m10 | [0m}
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error)
+}
+
+#[test]
+fn must_error_on_cross_datasource_relation() {
+    let dml = indoc! {r#"
+        datasource operational {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+        }
+
+        datasource analytics {
+          provider = "postgresql"
+          url = "postgresql://localhost:5433"
+        }
+
+        model User {
+          id     Int     @id
+          events Event[]
+        }
+
+        model Event {
+          id     Int  @id
+          user   User @relation(fields: [userId], references: [id])
+          userId Int
+
+          @@datasource("analytics")
+        }
+    "#};
+
+    let error = datamodel::parse_schema(dml).map(drop).unwrap_err();
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError validating: The relation between `Event` and `User` crosses datasources (`analytics` and `operational`). Relations can only connect models routed to the same datasource.[0m
+          [1;94m-->[0m  [4mschema.prisma:18[0m
+        [1;94m   | [0m
+        [1;94m17 | [0m  id     Int  @id
+        [1;94m18 | [0m  [1;91muser   User @relation(fields: [userId], references: [id])[0m
+        [1;94m19 | [0m  userId Int
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error)
+}
@@ -0,0 +1,55 @@
+use crate::common::*;
+use indoc::indoc;
+
+#[test]
+fn datasource_attribute_should_work() {
+    let dml = indoc! {r#"
+        datasource operational {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+        }
+
+        datasource analytics {
+          provider = "postgresql"
+          url = "postgresql://localhost:5433"
+        }
+
+        model User {
+          id Int @id
+        }
+
+        model Event {
+          id Int @id
+
+          @@datasource("analytics")
+        }
+    "#};
+
+    let datamodel = parse(dml);
+
+    assert_eq!(datamodel.assert_has_model("User").datasource, None);
+    assert_eq!(
+        datamodel.assert_has_model("Event").datasource,
+        Some("analytics".to_owned())
+    );
+}
+
+#[test]
+fn naming_the_default_datasource_should_work() {
+    let dml = indoc! {r#"
+        datasource db {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+        }
+
+        model User {
+          id Int @id
+
+          @@datasource("db")
+        }
+    "#};
+
+    let datamodel = parse(dml);
+
+    assert_eq!(datamodel.assert_has_model("User").datasource, Some("db".to_owned()));
+}
@@ -848,3 +848,49 @@ fn boolean_defaults_must_be_true_or_false() {
 
     expected.assert_eq(&error)
 }
+
+#[test]
+fn must_error_on_unknown_uuid_version() {
+    let dml = indoc! {r#"
+        model Model {
+          id  Int    @id
+          ref String @default(uuid(5))
+        }
+    "#};
+
+    let error = datamodel::parse_schema(dml).map(drop).unwrap_err();
+
+    let expected = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@default": `uuid()` takes either no argument, or a single integer argument which is either 4 or 7.[0m
+          [1;94m-->[0m  [4mschema.prisma:3[0m
+        [1;94m   | [0m
+        [1;94m 2 | [0m  id  Int    @id
+        [1;94m 3 | [0m  ref String @[1;91mdefault(uuid(5))[0m
+        [1;94m   | [0m
+    "#]];
+
+    expected.assert_eq(&error)
+}
+
+#[test]
+fn must_error_on_invalid_nanoid_length() {
+    let dml = indoc! {r#"
+        model Model {
+          id  Int    @id
+          ref String @default(nanoid(1))
+        }
+    "#};
+
+    let error = datamodel::parse_schema(dml).map(drop).unwrap_err();
+
+    let expected = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@default": `nanoid()` takes either no argument, or a single integer argument between 2 and 255.[0m
+          [1;94m-->[0m  [4mschema.prisma:3[0m
+        [1;94m   | [0m
+        [1;94m 2 | [0m  id  Int    @id
+        [1;94m 3 | [0m  ref String @[1;91mdefault(nanoid(1))[0m
+        [1;94m   | [0m
+    "#]];
+
+    expected.assert_eq(&error)
+}
@@ -116,6 +116,36 @@ fn should_set_default_on_remapped_enum_type() {
         ))));
 }
 
+#[test]
+fn should_set_default_on_enum_list() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgres://"
+    }
+
+    model Model {
+        id    Int    @id
+        roles Role[] @default([ADMIN, MODERATOR])
+    }
+
+    enum Role {
+        ADMIN
+        MODERATOR
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("roles")
+        .assert_enum_type("Role")
+        .assert_default_value(DefaultValue::new_single(PrismaValue::List(vec![
+            PrismaValue::Enum(String::from("ADMIN")),
+            PrismaValue::Enum(String::from("MODERATOR")),
+        ])));
+}
+
 #[test]
 fn db_generated_function_must_work_for_enum_fields() {
     let dml = r#"
@@ -193,3 +223,52 @@ fn string_literals_with_double_quotes_work() {
         .assert_base_type(&ScalarType::String)
         .assert_default_value(DefaultValue::new_single(PrismaValue::String(String::from("\""))));
 }
+
+#[test]
+fn id_generator_functions_should_work() {
+    let dml = indoc! {r#"
+        model Model {
+          id     Int    @id
+          uuid4  String @default(uuid())
+          uuid7  String @default(uuid(7))
+          cuid2  String @default(cuid2())
+          nano   String @default(nanoid(12))
+          nano21 String @default(nanoid())
+          own    String @default(custom())
+          ksuid  String @default(custom("ksuid"))
+        }
+    "#};
+
+    let datamodel = parse(dml);
+    let model = datamodel.assert_has_model("Model");
+
+    model
+        .assert_has_scalar_field("uuid4")
+        .assert_default_value(DefaultValue::new_expression(ValueGenerator::new_uuid()));
+
+    model
+        .assert_has_scalar_field("uuid7")
+        .assert_default_value(DefaultValue::new_expression(ValueGenerator::new_uuid_v7()));
+
+    model
+        .assert_has_scalar_field("cuid2")
+        .assert_default_value(DefaultValue::new_expression(ValueGenerator::new_cuid2()));
+
+    model
+        .assert_has_scalar_field("nano")
+        .assert_default_value(DefaultValue::new_expression(ValueGenerator::new_nanoid(Some(12))));
+
+    model
+        .assert_has_scalar_field("nano21")
+        .assert_default_value(DefaultValue::new_expression(ValueGenerator::new_nanoid(None)));
+
+    model
+        .assert_has_scalar_field("own")
+        .assert_default_value(DefaultValue::new_expression(ValueGenerator::new_custom(None)));
+
+    model
+        .assert_has_scalar_field("ksuid")
+        .assert_default_value(DefaultValue::new_expression(ValueGenerator::new_custom(Some(
+            "ksuid".to_owned(),
+        ))));
+}
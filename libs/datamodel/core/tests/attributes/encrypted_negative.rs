@@ -0,0 +1,52 @@
+use crate::common::*;
+
+#[test]
+fn should_fail_if_field_type_is_not_string_or_bytes() {
+    let dml = indoc! {r#"
+        model User {
+          id Int @id
+          balance Int @encrypted
+        }
+    "#};
+
+    let error = datamodel::parse_schema(dml).map(drop).unwrap_err();
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@encrypted": Fields that are marked with @encrypted must be of type String or Bytes.[0m
+          [1;94m-->[0m  [4mschema.prisma:3[0m
+        [1;94m   | [0m
+        [1;94m 2 | [0m  id Int @id
+        [1;94m 3 | [0m  balance Int @[1;91mencrypted[0m
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error)
+}
+
+#[test]
+fn should_fail_if_field_arity_is_list() {
+    let dml = indoc! {r#"
+        datasource db {
+          provider = "postgres"
+          url = "postgres://"
+        }
+
+        model User {
+          id Int @id
+          secrets String[] @encrypted
+        }
+    "#};
+
+    let error = datamodel::parse_schema(dml).map(drop).unwrap_err();
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@encrypted": Fields that are marked with @encrypted cannot be lists.[0m
+          [1;94m-->[0m  [4mschema.prisma:8[0m
+        [1;94m   | [0m
+        [1;94m 7 | [0m  id Int @id
+        [1;94m 8 | [0m  secrets String[] @[1;91mencrypted[0m
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error)
+}
@@ -0,0 +1,39 @@
+use crate::common::*;
+
+#[test]
+fn allow_encrypted_on_string_and_bytes_fields() {
+    let dml = r#"
+    model User {
+      id     Int     @id
+      name   String
+      secret String  @encrypted
+      token  String? @encrypted
+      blob   Bytes   @encrypted
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("User");
+
+    user_model.assert_has_scalar_field("name").assert_is_encrypted(false);
+    user_model.assert_has_scalar_field("secret").assert_is_encrypted(true);
+    user_model.assert_has_scalar_field("token").assert_is_encrypted(true);
+    user_model.assert_has_scalar_field("blob").assert_is_encrypted(true);
+}
+
+#[test]
+fn allow_encrypted_combined_with_other_field_attributes() {
+    let dml = r#"
+    model User {
+      id     Int    @id
+      secret String @unique @encrypted @map("secret_col")
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    datamodel
+        .assert_has_model("User")
+        .assert_has_scalar_field("secret")
+        .assert_is_encrypted(true)
+        .assert_with_db_name("secret_col");
+}
@@ -22,6 +22,7 @@ fn basic_index_must_work() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -50,6 +51,7 @@ fn indexes_on_enum_fields_must_work() {
         fields: vec![IndexField::new("role")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -75,6 +77,7 @@ fn the_name_argument_must_work() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -104,6 +107,7 @@ fn the_map_argument_must_work() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -130,6 +134,7 @@ fn multiple_index_must_work() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -139,6 +144,7 @@ fn multiple_index_must_work() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -189,6 +195,7 @@ fn index_accepts_three_different_notations() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -198,6 +205,7 @@ fn index_accepts_three_different_notations() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -207,6 +215,7 @@ fn index_accepts_three_different_notations() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -232,6 +241,7 @@ fn mysql_allows_unique_length_prefix() {
         }],
         tpe: IndexType::Unique,
         defined_on_field: true,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -434,6 +444,7 @@ fn hash_index_works_on_postgres() {
         fields: vec![IndexField::new("a")],
         tpe: IndexType::Normal,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: Some(IndexAlgorithm::Hash),
     });
 }
@@ -459,6 +470,7 @@ fn mysql_fulltext_index() {
         tpe: IndexType::Fulltext,
         algorithm: None,
         defined_on_field: false,
+        nulls_not_distinct: false,
     });
 }
 
@@ -483,6 +495,7 @@ fn mysql_fulltext_index_map() {
         tpe: IndexType::Fulltext,
         algorithm: None,
         defined_on_field: false,
+        nulls_not_distinct: false,
     });
 }
 
@@ -507,6 +520,7 @@ fn fulltext_index_mongodb() {
         tpe: IndexType::Fulltext,
         algorithm: None,
         defined_on_field: false,
+        nulls_not_distinct: false,
     });
 }
 
@@ -538,6 +552,7 @@ fn fulltext_index_sort_mongodb() {
         tpe: IndexType::Fulltext,
         algorithm: None,
         defined_on_field: false,
+        nulls_not_distinct: false,
     });
 }
 
@@ -567,6 +582,7 @@ fn multiple_fulltext_indexes_allowed_per_model_in_mysql() {
             tpe: IndexType::Fulltext,
             algorithm: None,
             defined_on_field: false,
+            nulls_not_distinct: false,
         })
         .assert_has_index(IndexDefinition {
             name: None,
@@ -580,5 +596,6 @@ fn multiple_fulltext_indexes_allowed_per_model_in_mysql() {
             tpe: IndexType::Fulltext,
             algorithm: None,
             defined_on_field: false,
+            nulls_not_distinct: false,
         });
 }
@@ -1,10 +1,16 @@
 mod arg_parsing;
 mod builtin_attributes;
+mod computed_sql_negative;
+mod computed_sql_positive;
 mod constraint_names;
 mod constraint_names_negative;
 mod constraint_names_positive;
+mod datasource_negative;
+mod datasource_positive;
 mod default_negative;
 mod default_positive;
+mod encrypted_negative;
+mod encrypted_positive;
 mod id_negative;
 mod id_positive;
 mod ignore_negative;
@@ -13,6 +19,8 @@ mod index_negative;
 mod index_positive;
 mod map_negative;
 mod map_positive;
+mod readonly_negative;
+mod readonly_positive;
 mod relations;
 mod unique_negative;
 mod unique_positive;
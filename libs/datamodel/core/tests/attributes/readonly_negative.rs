@@ -0,0 +1,58 @@
+use crate::common::*;
+
+#[test]
+fn should_fail_on_unsupported_fields() {
+    let dml = indoc! {r#"
+        datasource db {
+          provider = "postgres"
+          url = "postgres://"
+        }
+
+        model Account {
+          id Int @id
+          balance Unsupported("money") @readonly
+        }
+    "#};
+
+    let error = datamodel::parse_schema(dml).map(drop).unwrap_err();
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@readonly": Fields of type `Unsupported` cannot take a `@readonly` attribute. They are never part of write operations due to their type.[0m
+          [1;94m-->[0m  [4mschema.prisma:8[0m
+        [1;94m   | [0m
+        [1;94m 7 | [0m  id Int @id
+        [1;94m 8 | [0m  balance Unsupported("money") @[1;91mreadonly[0m
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error)
+}
+
+#[test]
+fn should_fail_when_used_twice() {
+    let dml = indoc! {r#"
+        model Account {
+          id Int @id
+          balance Decimal @readonly @readonly
+        }
+    "#};
+
+    let error = datamodel::parse_schema(dml).map(drop).unwrap_err();
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mAttribute "@readonly" is defined twice.[0m
+          [1;94m-->[0m  [4mschema.prisma:3[0m
+        [1;94m   | [0m
+        [1;94m 2 | [0m  id Int @id
+        [1;94m 3 | [0m  balance Decimal @[1;91mreadonly[0m @readonly
+        [1;94m   | [0m
+        [1;91merror[0m: [1mAttribute "@readonly" is defined twice.[0m
+          [1;94m-->[0m  [4mschema.prisma:3[0m
+        [1;94m   | [0m
+        [1;94m 2 | [0m  id Int @id
+        [1;94m 3 | [0m  balance Decimal @readonly @[1;91mreadonly[0m
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error)
+}
@@ -0,0 +1,41 @@
+use crate::common::*;
+
+#[test]
+fn allow_readonly_on_scalar_fields() {
+    let dml = r#"
+    model Account {
+      id      Int      @id
+      name    String
+      balance Decimal  @readonly
+      syncedAt DateTime? @readonly
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let account_model = datamodel.assert_has_model("Account");
+
+    account_model.assert_has_scalar_field("name").assert_is_readonly(false);
+    account_model
+        .assert_has_scalar_field("balance")
+        .assert_is_readonly(true);
+    account_model
+        .assert_has_scalar_field("syncedAt")
+        .assert_is_readonly(true);
+}
+
+#[test]
+fn allow_readonly_combined_with_other_field_attributes() {
+    let dml = r#"
+    model Account {
+      id      Int     @id
+      balance Decimal @default(0) @readonly @map("balance_col")
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    datamodel
+        .assert_has_model("Account")
+        .assert_has_scalar_field("balance")
+        .assert_is_readonly(true)
+        .assert_with_db_name("balance_col");
+}
@@ -548,6 +548,13 @@ fn on_delete_cannot_be_defined_on_the_wrong_side_1_1() {
         [1;94m 8 | [0m    [1;91mcock      Chicken? @relation(name: "a_self_relation", onDelete: NoAction)[0m
         [1;94m 9 | [0m    hen       Chicken? @relation(name: "a_self_relation", fields: [chickenId], references: [id])
         [1;94m   | [0m
+        [1;91merror[0m: [1mError validating: The `onUpdate` referential action of a self-relation must not modify the updated record on this database. Set `onUpdate: NoAction` in the @relation attribute. (Implicit default `onUpdate`: `Cascade`) Read more at https://pris.ly/d/cyclic-referential-actions[0m
+          [1;94m-->[0m  [4mschema.prisma:9[0m
+        [1;94m   | [0m
+        [1;94m 8 | [0m    cock      Chicken? @relation(name: "a_self_relation", onDelete: NoAction)
+        [1;94m 9 | [0m    hen       Chicken? [1;91m@relation(name: "a_self_relation", fields: [chickenId], references: [id])[0m
+        [1;94m10 | [0m    chickenId Int?
+        [1;94m   | [0m
     "#]];
 
     expect.assert_eq(&datamodel::parse_schema(dml).map(drop).unwrap_err());
@@ -577,6 +584,13 @@ fn on_update_cannot_be_defined_on_the_wrong_side_1_1() {
         [1;94m 8 | [0m    [1;91mcock      Chicken? @relation(name: "a_self_relation", onUpdate: NoAction)[0m
         [1;94m 9 | [0m    hen       Chicken? @relation(name: "a_self_relation", fields: [chickenId], references: [id])
         [1;94m   | [0m
+        [1;91merror[0m: [1mError validating: The `onUpdate` referential action of a self-relation must not modify the updated record on this database. Set `onUpdate: NoAction` in the @relation attribute. (Implicit default `onUpdate`: `Cascade`) Read more at https://pris.ly/d/cyclic-referential-actions[0m
+          [1;94m-->[0m  [4mschema.prisma:9[0m
+        [1;94m   | [0m
+        [1;94m 8 | [0m    cock      Chicken? @relation(name: "a_self_relation", onUpdate: NoAction)
+        [1;94m 9 | [0m    hen       Chicken? [1;91m@relation(name: "a_self_relation", fields: [chickenId], references: [id])[0m
+        [1;94m10 | [0m    chickenId Int?
+        [1;94m   | [0m
     "#]];
 
     expect.assert_eq(&datamodel::parse_schema(dml).map(drop).unwrap_err());
@@ -86,8 +86,8 @@ fn cycles_are_allowed_outside_of_emulation_and_sqlserver() {
     let dml = indoc! {
         r#"
         datasource db {
-            provider = "mysql"
-            url = "mysql://"
+            provider = "postgres"
+            url = "postgres://"
         }
 
         generator js1 {
@@ -106,6 +106,56 @@ fn cycles_are_allowed_outside_of_emulation_and_sqlserver() {
     assert!(datamodel::parse_schema(dml).is_ok());
 }
 
+#[test]
+fn cascading_on_update_self_relations_on_mysql() {
+    let dml = indoc! {
+        r#"
+        datasource db {
+            provider = "mysql"
+            url = "mysql://"
+        }
+
+        model A {
+            id     Int  @id @default(autoincrement())
+            child  A?   @relation(name: "a_self_relation")
+            parent A?   @relation(name: "a_self_relation", fields: [aId], references: [id], onDelete: NoAction)
+            aId    Int?
+        }
+    "#};
+
+    let expect = expect![[r#"
+        [1;91merror[0m: [1mError validating: The `onUpdate` referential action of a self-relation must not modify the updated record on this database. Set `onUpdate: NoAction` in the @relation attribute. (Implicit default `onUpdate`: `Cascade`) Read more at https://pris.ly/d/cyclic-referential-actions[0m
+          [1;94m-->[0m  [4mschema.prisma:9[0m
+        [1;94m   | [0m
+        [1;94m 8 | [0m    child  A?   @relation(name: "a_self_relation")
+        [1;94m 9 | [0m    parent A?   [1;91m@relation(name: "a_self_relation", fields: [aId], references: [id], onDelete: NoAction)[0m
+        [1;94m10 | [0m    aId    Int?
+        [1;94m   | [0m
+    "#]];
+
+    expect.assert_eq(&datamodel::parse_schema(dml).map(drop).unwrap_err());
+}
+
+#[test]
+fn no_action_self_relations_are_allowed_on_mysql() {
+    let dml = indoc! {
+        r#"
+        datasource db {
+            provider = "mysql"
+            url = "mysql://"
+        }
+
+        model A {
+            id     Int  @id @default(autoincrement())
+            child  A?   @relation(name: "a_self_relation")
+            parent A?   @relation(name: "a_self_relation", fields: [aId], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            aId    Int?
+        }
+    "#};
+
+    assert!(datamodel::parse_schema(dml).is_ok());
+}
+
 #[test]
 fn emulated_cascading_on_delete_self_relations() {
     let dml = indoc! {
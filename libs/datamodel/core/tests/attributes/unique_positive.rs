@@ -22,6 +22,7 @@ fn basic_unique_index_must_work() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -101,6 +102,7 @@ fn the_name_argument_must_work() {
         fields: vec![IndexField::new("firstName"), IndexField::new("lastName")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -143,6 +145,7 @@ fn multiple_unique_must_work() {
                 tpe: Unique,
                 algorithm: None,
                 defined_on_field: false,
+                nulls_not_distinct: false,
             },
             IndexDefinition {
                 name: Some(
@@ -166,6 +169,7 @@ fn multiple_unique_must_work() {
                 tpe: Unique,
                 algorithm: None,
                 defined_on_field: false,
+                nulls_not_distinct: false,
             },
         ]
     "#]];
@@ -216,6 +220,7 @@ fn multi_field_unique_indexes_on_enum_fields_must_work() {
         fields: vec![IndexField::new("role")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -242,6 +247,7 @@ fn single_field_unique_indexes_on_enum_fields_must_work() {
         fields: vec![IndexField::new("role")],
         tpe: IndexType::Unique,
         defined_on_field: true,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -285,6 +291,46 @@ fn named_multi_field_unique_must_work() {
         fields: vec![IndexField::new("a"), IndexField::new("b")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
+        algorithm: None,
+    });
+}
+
+#[test]
+fn unique_with_nulls_not_distinct_must_work_on_postgres() {
+    let dml = with_header(
+        r#"
+     model User {
+         id    Int     @id
+         email String? @unique(nullsNotDistinct: true)
+         a     String?
+         b     Int?
+
+         @@unique([a, b], nullsNotDistinct: true)
+     }
+     "#,
+        Provider::Postgres,
+        &[],
+    );
+
+    let datamodel = parse(&dml);
+    let user_model = datamodel.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        db_name: Some("User_email_key".to_string()),
+        fields: vec![IndexField::new("email")],
+        tpe: IndexType::Unique,
+        defined_on_field: true,
+        nulls_not_distinct: true,
+        algorithm: None,
+    });
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        db_name: Some("User_a_b_key".to_string()),
+        fields: vec![IndexField::new("a"), IndexField::new("b")],
+        tpe: IndexType::Unique,
+        defined_on_field: false,
+        nulls_not_distinct: true,
         algorithm: None,
     });
 }
@@ -311,6 +357,7 @@ fn mapped_multi_field_unique_must_work() {
         fields: vec![IndexField::new("a"), IndexField::new("b")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -339,6 +386,7 @@ fn mapped_singular_unique_must_work() {
         fields: vec![IndexField::new("a")],
         tpe: IndexType::Unique,
         defined_on_field: true,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -349,6 +397,7 @@ fn mapped_singular_unique_must_work() {
         fields: vec![IndexField::new("a")],
         tpe: IndexType::Unique,
         defined_on_field: true,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -375,6 +424,7 @@ fn named_and_mapped_multi_field_unique_must_work() {
         fields: vec![IndexField::new("a"), IndexField::new("b")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -401,6 +451,7 @@ fn implicit_names_must_work() {
         fields: vec![IndexField::new("a"), IndexField::new("b")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -410,6 +461,7 @@ fn implicit_names_must_work() {
         fields: vec![IndexField::new("a")],
         tpe: IndexType::Unique,
         defined_on_field: true,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -436,6 +488,7 @@ fn defined_on_field_must_work() {
         fields: vec![IndexField::new("a")],
         tpe: IndexType::Unique,
         defined_on_field: true,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -445,6 +498,7 @@ fn defined_on_field_must_work() {
         fields: vec![IndexField::new("b")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -469,6 +523,7 @@ fn mapping_unique_to_a_field_name_should_work() {
         fields: vec![IndexField::new("name"), IndexField::new("identification")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
@@ -499,6 +554,7 @@ fn duplicate_custom_names_on_different_model_should_work() {
         fields: vec![IndexField::new("name"), IndexField::new("identification")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 
@@ -509,6 +565,7 @@ fn duplicate_custom_names_on_different_model_should_work() {
         fields: vec![IndexField::new("name"), IndexField::new("identification")],
         tpe: IndexType::Unique,
         defined_on_field: false,
+        nulls_not_distinct: false,
         algorithm: None,
     });
 }
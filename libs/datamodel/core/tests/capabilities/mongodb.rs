@@ -1,4 +1,5 @@
 use datamodel::parse_schema;
+use expect_test::expect;
 
 #[test]
 fn mongodb_supports_composite_types() {
@@ -20,3 +21,36 @@ fn mongodb_supports_composite_types() {
 
     assert!(parse_schema(schema).is_ok());
 }
+
+#[test]
+fn unique_on_optional_field_support() {
+    let schema = r#"
+        datasource db {
+            provider = "mongodb"
+            url = "mongodb://"
+        }
+
+        generator client {
+            provider = "prisma-client-js"
+            previewFeatures = ["mongoDb"]
+        }
+
+        model User {
+            id    String  @id @map("_id")
+            email String? @unique
+        }
+    "#;
+
+    let error = parse_schema(schema).map(drop).unwrap_err();
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@unique": The current connector does not allow more than one row with a NULL value in a unique constraint. Please make the fields required or remove the unique constraint.[0m
+          [1;94m-->[0m  [4mschema.prisma:17[0m
+        [1;94m   | [0m
+        [1;94m16 | [0m            id    String  @id @map("_id")
+        [1;94m17 | [0m            email String? @[1;91munique[0m
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error);
+}
@@ -29,6 +29,9 @@ pub(crate) trait ScalarFieldAsserts {
     fn assert_default_value(&self, t: dml::DefaultValue) -> &Self;
     fn assert_is_id(&self, model: &Model) -> &Self;
     fn assert_is_updated_at(&self, b: bool) -> &Self;
+    fn assert_is_encrypted(&self, b: bool) -> &Self;
+    fn assert_is_readonly(&self, b: bool) -> &Self;
+    fn assert_computed_sql(&self, expression: Option<&str>) -> &Self;
     fn assert_ignored(&self, state: bool) -> &Self;
 }
 
@@ -169,6 +172,21 @@ impl ScalarFieldAsserts for dml::ScalarField {
         self
     }
 
+    fn assert_is_encrypted(&self, b: bool) -> &Self {
+        assert_eq!(self.is_encrypted, b);
+        self
+    }
+
+    fn assert_is_readonly(&self, b: bool) -> &Self {
+        assert_eq!(self.is_readonly, b);
+        self
+    }
+
+    fn assert_computed_sql(&self, expression: Option<&str>) -> &Self {
+        assert_eq!(self.computed_sql.as_deref(), expression);
+        self
+    }
+
     fn assert_ignored(&self, state: bool) -> &Self {
         assert_eq!(self.is_ignored, state);
         self
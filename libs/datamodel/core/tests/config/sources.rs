@@ -4,7 +4,7 @@ use datamodel_connector::ReferentialIntegrity;
 use pretty_assertions::assert_eq;
 
 #[test]
-fn must_error_if_multiple_datasources_are_defined() {
+fn multiple_datasources_must_work() {
     let dml = indoc! {r#"
         datasource db1 {
           provider = "postgresql"
@@ -17,23 +17,35 @@ fn must_error_if_multiple_datasources_are_defined() {
         }
     "#};
 
+    let config = super::parse_config(dml).unwrap();
+
+    assert_eq!(config.subject.datasources.len(), 2);
+    config.subject.datasources[0].assert_name("db1");
+    config.subject.datasources[1].assert_name("db2");
+}
+
+#[test]
+fn must_error_if_datasource_names_are_not_unique() {
+    let dml = indoc! {r#"
+        datasource db {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+        }
+
+        datasource db {
+          provider = "mysql"
+          url = "mysql://localhost"
+        }
+    "#};
+
     let error = super::parse_config(dml).map(drop).unwrap_err();
 
     let expectation = expect![[r#"
-        [1;91merror[0m: [1mError validating datasource `db1`: You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.[0m
-          [1;94m-->[0m  [4mschema.prisma:1[0m
-        [1;94m   | [0m
-        [1;94m   | [0m
-        [1;94m 1 | [0m[1;91mdatasource db1 {[0m
-        [1;94m 2 | [0m  provider = "postgresql"
-        [1;94m 3 | [0m  url = "postgresql://localhost"
-        [1;94m 4 | [0m}
-        [1;94m   | [0m
-        [1;91merror[0m: [1mError validating datasource `db2`: You defined more than one datasource. This is not allowed yet because support for multiple databases has not been implemented yet.[0m
+        [1;91merror[0m: [1mError validating datasource `db`: You defined more than one datasource named `db`. Datasource names must be unique.[0m
           [1;94m-->[0m  [4mschema.prisma:6[0m
         [1;94m   | [0m
         [1;94m 5 | [0m
-        [1;94m 6 | [0m[1;91mdatasource db2 {[0m
+        [1;94m 6 | [0m[1;91mdatasource db {[0m
         [1;94m 7 | [0m  provider = "mysql"
         [1;94m 8 | [0m  url = "mysql://localhost"
         [1;94m 9 | [0m}
@@ -631,6 +643,35 @@ fn referential_integrity_default() {
     assert_eq!(config.referential_integrity(), Some(ReferentialIntegrity::ForeignKeys));
 }
 
+#[test]
+fn autoincrement_strategy_identity_works_on_postgres() {
+    let schema = indoc! {r#"
+        datasource ps {
+          provider = "postgresql"
+          autoincrementStrategy = "identity"
+          url = "postgresql://"
+        }
+    "#};
+
+    let config = parse_configuration(schema);
+
+    assert!(config.uses_identity_autoincrement());
+}
+
+#[test]
+fn autoincrement_strategy_defaults_to_serial() {
+    let schema = indoc! {r#"
+        datasource ps {
+          provider = "postgresql"
+          url = "postgresql://"
+        }
+    "#};
+
+    let config = parse_configuration(schema);
+
+    assert!(!config.uses_identity_autoincrement());
+}
+
 fn load_env_var(key: &str) -> Option<String> {
     std::env::var(key).ok()
 }
@@ -135,6 +135,25 @@ fn resolve_model_attributes<'ast>(model_id: ast::ModelId, ast_model: &'ast ast::
         attributes.visit_repeated("fulltext", ctx, |args, ctx| {
             model_fulltext(args, &mut model_attributes, model_id, ctx);
         });
+
+        // @@strict
+        attributes.visit_optional_single("strict", ctx, |_, _| {
+            model_attributes.is_strict = true;
+        });
+
+        // @@withoutRowid
+        attributes.visit_optional_single("withoutRowid", ctx, |_, _| {
+            model_attributes.is_without_rowid = true;
+        });
+
+        // @@datasource
+        attributes.visit_optional_single("datasource", ctx, |args, ctx| {
+            match args.default_arg("name").map(|value| value.as_str()) {
+                Ok(Ok(name)) => model_attributes.datasource = Some(name),
+                Err(err) => ctx.push_error(err),
+                Ok(Err(err)) => ctx.push_error(args.new_attribute_validation_error(&err.to_string())),
+            }
+        });
     });
 
     // Model-global validations
@@ -167,6 +186,48 @@ fn visit_scalar_field_attributes<'ast>(
             }
         });
 
+        // @readonly
+        attributes.visit_optional_single("readonly", ctx, |args, ctx| {
+            if matches!(scalar_field_data.r#type, ScalarFieldType::Unsupported) {
+                ctx.push_error(args.new_attribute_validation_error("Fields of type `Unsupported` cannot take a `@readonly` attribute. They are never part of write operations due to their type."));
+            } else {
+                scalar_field_data.is_readonly = true;
+            }
+        });
+
+        // @encrypted
+        attributes.visit_optional_single("encrypted", ctx, |args, ctx| {
+            if !matches!(
+                scalar_field_data.r#type,
+                ScalarFieldType::BuiltInScalar(crate::ScalarType::String | crate::ScalarType::Bytes)
+            ) {
+                ctx.push_error(args.new_attribute_validation_error(
+                    "Fields that are marked with @encrypted must be of type String or Bytes.",
+                ));
+            }
+
+            if ast_field.arity.is_list() {
+                ctx.push_error(args.new_attribute_validation_error("Fields that are marked with @encrypted cannot be lists."));
+            }
+
+            scalar_field_data.is_encrypted = true;
+        });
+
+        // @computedSql
+        attributes.visit_optional_single("computedSql", ctx, |args, ctx| {
+            let expression = match args.default_arg("expression").map(|value| value.as_str()) {
+                Ok(Ok(expression)) => expression,
+                Err(err) => return ctx.push_error(err),
+                Ok(Err(err)) => return ctx.push_error(args.new_attribute_validation_error(&err.to_string())),
+            };
+
+            if ast_field.arity.is_list() {
+                ctx.push_error(args.new_attribute_validation_error("Fields that are marked with @computedSql cannot be lists."));
+            }
+
+            scalar_field_data.computed_sql = Some(expression);
+        });
+
         // @relation
         attributes.visit_optional_single("relation", ctx, |args, ctx| {
             ctx.push_error(args.new_attribute_validation_error("Invalid field type, not a relation."));
@@ -256,6 +317,8 @@ fn visit_field_unique<'ast>(
         None => None,
     };
 
+    let nulls_not_distinct = nulls_not_distinct_argument(args, ctx);
+
     model_attributes.ast_indexes.push((
         args.attribute(),
         IndexAttribute {
@@ -267,11 +330,24 @@ fn visit_field_unique<'ast>(
             }],
             source_field: Some(field_id),
             db_name,
+            nulls_not_distinct,
             ..Default::default()
         },
     ))
 }
 
+/// Validates the `nullsNotDistinct` argument on `@unique`/`@@unique`.
+fn nulls_not_distinct_argument<'ast>(args: &mut Arguments<'ast>, ctx: &mut Context<'ast>) -> bool {
+    match args.optional_arg("nullsNotDistinct").map(|arg| arg.as_bool()) {
+        Some(Ok(value)) => value,
+        Some(Err(err)) => {
+            ctx.push_error(err);
+            false
+        }
+        None => false,
+    }
+}
+
 fn visit_relation_field_attributes<'ast>(
     model_id: ast::ModelId,
     ast_field: &'ast ast::Field,
@@ -522,6 +598,7 @@ fn model_unique<'ast>(
 
     index_attribute.name = name;
     index_attribute.db_name = db_name;
+    index_attribute.nulls_not_distinct = nulls_not_distinct_argument(args, ctx);
 
     data.ast_indexes.push((args.attribute(), index_attribute));
 }
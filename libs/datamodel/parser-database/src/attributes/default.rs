@@ -20,9 +20,7 @@ pub(super) fn visit_field_default<'ast>(
 
     let ast_model = &ctx.db.ast[model_id];
     let ast_field = &ast_model[field_id];
-    if ast_field.arity.is_list() {
-        return ctx.push_error(args.new_attribute_validation_error("Cannot set a default value on list field."));
-    }
+    let is_list = ast_field.arity.is_list();
 
     let mapped_name = default_attribute_mapped_name(args, ctx);
     let default_attribute = args.attribute();
@@ -53,9 +51,24 @@ pub(super) fn visit_field_default<'ast>(
                 ));
             }
             ScalarFieldType::Enum(enum_id) => {
+                let is_enum_value = |value: &str| ctx.db.ast[enum_id].values.iter().any(|v| v.name() == value);
+
                 match value.value {
-                    ast::Expression::ConstantValue(enum_value, _) => {
-                        if ctx.db.ast[enum_id].values.iter().any(|v| v.name() == enum_value) {
+                    ast::Expression::ConstantValue(enum_value, _) if !is_list => {
+                        if is_enum_value(enum_value) {
+                            accept()
+                        } else {
+                            ctx.push_error(args.new_attribute_validation_error(
+                                "The defined default value is not a valid value of the enum specified for the field.",
+                            ))
+                        }
+                    }
+                    ast::Expression::Array(items, _) if is_list => {
+                        let all_valid = items.iter().all(
+                            |item| matches!(item, ast::Expression::ConstantValue(value, _) if is_enum_value(value)),
+                        );
+
+                        if all_valid {
                             accept()
                         } else {
                             ctx.push_error(args.new_attribute_validation_error(
@@ -66,12 +79,18 @@ pub(super) fn visit_field_default<'ast>(
                     ast::Expression::Function(funcname, funcargs, _) if funcname == FN_DBGENERATED => {
                         validate_dbgenerated_args(&funcargs.arguments, args, accept, ctx);
                     }
+                    _ if is_list => ctx.push_error(
+                        args.new_attribute_validation_error("Expected an array of enum values for the list field."),
+                    ),
                     value => ctx.push_error(args.new_attribute_validation_error(&format!(
                         "Expected a an enum value, but found `{bad_value}`.",
                         bad_value = value
                     ))),
                 };
             }
+            ScalarFieldType::BuiltInScalar(_) | ScalarFieldType::Unsupported if is_list => {
+                ctx.push_error(args.new_attribute_validation_error("Cannot set a default value on list field."))
+            }
             ScalarFieldType::BuiltInScalar(scalar_type) => {
                 validate_builtin_scalar_type_default(scalar_type, value.value, mapped_name, accept, args, ctx)
             }
@@ -134,10 +153,19 @@ fn validate_builtin_scalar_type_default(
             validate_empty_function_args(funcname, &funcargs.arguments, args, accept, ctx)
         }
         (ScalarType::String, ast::Expression::Function(funcname, funcargs, _))
-            if funcname == FN_UUID || funcname == FN_CUID =>
+            if funcname == FN_CUID || funcname == FN_CUID2 =>
         {
             validate_empty_function_args(funcname, &funcargs.arguments, args, accept, ctx)
         }
+        (ScalarType::String, ast::Expression::Function(funcname, funcargs, _)) if funcname == FN_UUID => {
+            validate_uuid_args(&funcargs.arguments, args, accept, ctx)
+        }
+        (ScalarType::String, ast::Expression::Function(funcname, funcargs, _)) if funcname == FN_CUSTOM => {
+            validate_custom_args(&funcargs.arguments, args, accept, ctx)
+        }
+        (ScalarType::String, ast::Expression::Function(funcname, funcargs, _)) if funcname == FN_NANOID => {
+            validate_nanoid_args(&funcargs.arguments, args, accept, ctx)
+        }
         (ScalarType::DateTime, ast::Expression::Function(funcname, funcargs, _)) if funcname == FN_NOW => {
             validate_empty_function_args(FN_NOW, &funcargs.arguments, args, accept, ctx)
         }
@@ -229,10 +257,69 @@ fn validate_dbgenerated_args(
     }
 }
 
+fn validate_uuid_args(
+    args: &[ast::Argument],
+    arguments: &Arguments<'_>,
+    mut accept: impl FnMut(),
+    ctx: &mut Context<'_>,
+) {
+    match (args.len(), args.get(0).map(|arg| &arg.value)) {
+        (0, _) => accept(),
+        (1, Some(ast::Expression::NumericValue(version, _))) if matches!(version.as_str(), "4" | "7") => accept(),
+        _ => ctx.push_error(arguments.new_attribute_validation_error(
+            "`uuid()` takes either no argument, or a single integer argument which is either 4 or 7.",
+        )),
+    }
+}
+
+fn validate_custom_args(
+    args: &[ast::Argument],
+    arguments: &Arguments<'_>,
+    mut accept: impl FnMut(),
+    ctx: &mut Context<'_>,
+) {
+    match (args.len(), args.get(0).map(|arg| &arg.value)) {
+        (0, _) => accept(),
+        (1, Some(ast::Expression::StringValue(strategy, _))) if !strategy.is_empty() => accept(),
+        _ => ctx.push_error(arguments.new_attribute_validation_error(
+            "`custom()` takes either no argument, or a single nonempty string argument naming the generator strategy.",
+        )),
+    }
+}
+
+fn validate_nanoid_args(
+    args: &[ast::Argument],
+    arguments: &Arguments<'_>,
+    mut accept: impl FnMut(),
+    ctx: &mut Context<'_>,
+) {
+    let valid_length = |length: &str| length.parse::<u8>().map(|length| length >= 2).unwrap_or(false);
+
+    match (args.len(), args.get(0).map(|arg| &arg.value)) {
+        (0, _) => accept(),
+        (1, Some(ast::Expression::NumericValue(length, _))) if valid_length(length) => accept(),
+        _ => ctx.push_error(arguments.new_attribute_validation_error(
+            "`nanoid()` takes either no argument, or a single integer argument between 2 and 255.",
+        )),
+    }
+}
+
 const FN_AUTOINCREMENT: &str = "autoincrement";
 const FN_CUID: &str = "cuid";
+const FN_CUID2: &str = "cuid2";
+const FN_CUSTOM: &str = "custom";
 const FN_DBGENERATED: &str = "dbgenerated";
+const FN_NANOID: &str = "nanoid";
 const FN_NOW: &str = "now";
 const FN_UUID: &str = "uuid";
 
-const KNOWN_FUNCTIONS: &[&str] = &[FN_AUTOINCREMENT, FN_CUID, FN_DBGENERATED, FN_NOW, FN_UUID];
+const KNOWN_FUNCTIONS: &[&str] = &[
+    FN_AUTOINCREMENT,
+    FN_CUID,
+    FN_CUID2,
+    FN_CUSTOM,
+    FN_DBGENERATED,
+    FN_NANOID,
+    FN_NOW,
+    FN_UUID,
+];
@@ -110,6 +110,11 @@ pub(crate) struct ScalarField<'ast> {
     pub(crate) r#type: ScalarFieldType,
     pub(crate) is_ignored: bool,
     pub(crate) is_updated_at: bool,
+    pub(crate) is_encrypted: bool,
+    /// @readonly
+    pub(crate) is_readonly: bool,
+    /// @computedSql
+    pub(crate) computed_sql: Option<&'ast str>,
     pub(crate) default: Option<DefaultAttribute<'ast>>,
     /// @map
     pub(crate) mapped_name: Option<&'ast str>,
@@ -161,6 +166,12 @@ pub(crate) struct ModelAttributes<'ast> {
     pub(super) primary_key: Option<IdAttribute<'ast>>,
     /// @@ignore
     pub(crate) is_ignored: bool,
+    /// @@strict
+    pub(crate) is_strict: bool,
+    /// @@withoutRowid
+    pub(crate) is_without_rowid: bool,
+    /// @@datasource
+    pub(crate) datasource: Option<&'ast str>,
     /// @@index and @(@)unique explicitely written to the schema AST.
     pub(super) ast_indexes: Vec<(&'ast ast::Attribute, IndexAttribute<'ast>)>,
     /// @(@)unique added implicitely to the datamodel by us.
@@ -221,6 +232,7 @@ pub(crate) struct IndexAttribute<'ast> {
     pub(crate) name: Option<&'ast str>,
     pub(crate) db_name: Option<&'ast str>,
     pub(crate) algorithm: Option<IndexAlgorithm>,
+    pub(crate) nulls_not_distinct: bool,
 }
 
 impl<'ast> IndexAttribute<'ast> {
@@ -268,6 +280,9 @@ fn visit_model<'ast>(model_id: ast::ModelId, ast_model: &'ast ast::Model, ctx: &
                     r#type: scalar_field_type,
                     is_ignored: false,
                     is_updated_at: false,
+                    is_encrypted: false,
+                    is_readonly: false,
+                    computed_sql: None,
                     default: None,
                     mapped_name: None,
                     native_type: None,
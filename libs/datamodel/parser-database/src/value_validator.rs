@@ -72,6 +72,15 @@ impl<'a> ValueValidator<'a> {
         }
     }
 
+    /// Tries to convert the wrapped value to a Prisma Boolean.
+    pub(crate) fn as_bool(&self) -> Result<bool, DatamodelError> {
+        match self.as_constant_literal()? {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            _ => Err(self.construct_type_mismatch_error("boolean")),
+        }
+    }
+
     /// Unwraps the value as an array of constants.
     pub(crate) fn as_constant_array(&self) -> Result<Vec<&'a str>, DatamodelError> {
         if let ast::Expression::Array(values, _) = &self.value {
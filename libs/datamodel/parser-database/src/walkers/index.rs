@@ -55,6 +55,11 @@ impl<'ast, 'db> IndexWalker<'ast, 'db> {
         self.attribute().algorithm
     }
 
+    /// Whether the unique index treats nulls as not distinct (`nullsNotDistinct: true`).
+    pub fn nulls_not_distinct(self) -> bool {
+        self.attribute().nulls_not_distinct
+    }
+
     /// The AST node of the index/unique attribute.
     pub fn ast_attribute(self) -> Option<&'ast ast::Attribute> {
         self.index
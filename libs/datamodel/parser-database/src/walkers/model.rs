@@ -80,6 +80,21 @@ impl<'ast, 'db> ModelWalker<'ast, 'db> {
         self.attributes().is_ignored
     }
 
+    /// Model has the @@strict attribute.
+    pub fn is_strict(self) -> bool {
+        self.attributes().is_strict
+    }
+
+    /// Model has the @@withoutRowid attribute.
+    pub fn is_without_rowid(self) -> bool {
+        self.attributes().is_without_rowid
+    }
+
+    /// The name of the datasource the model is routed to (`@@datasource`), if any.
+    pub fn datasource_name(self) -> Option<&'ast str> {
+        self.attributes().datasource
+    }
+
     /// True if given fields are unique in the model.
     pub(crate) fn fields_are_unique(self, fields: &[ast::FieldId]) -> bool {
         self.model_attributes
@@ -72,6 +72,21 @@ impl<'ast, 'db> ScalarFieldWalker<'ast, 'db> {
         self.attributes().is_updated_at
     }
 
+    /// Is there an `@encrypted` attribute on the field?
+    pub fn is_encrypted(self) -> bool {
+        self.attributes().is_encrypted
+    }
+
+    /// Is there a `@readonly` attribute on the field?
+    pub fn is_readonly(self) -> bool {
+        self.attributes().is_readonly
+    }
+
+    /// The SQL expression in the `@computedSql(<expression>)` attribute, if any.
+    pub fn computed_sql(self) -> Option<&'ast str> {
+        self.attributes().computed_sql
+    }
+
     fn attributes(self) -> &'db ScalarField<'ast> {
         self.scalar_field
     }
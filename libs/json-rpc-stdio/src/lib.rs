@@ -3,9 +3,10 @@
 //! This crate implements JSON-RPC over standard IO. It uses tokio for async IO, and jsonrpc_core
 //! for the JSON-RPC part.
 //!
-//! Notifications in the Client are not supported yet.
+//! The client can send requests and notifications. Incoming notifications are not supported yet.
 
-use jsonrpc_core::{IoHandler, MethodCall, Request};
+use futures::stream::{FuturesUnordered, StreamExt};
+use jsonrpc_core::{IoHandler, MethodCall, Notification, Request};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
@@ -22,15 +23,21 @@ static REQUEST_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
 /// A handle to a connected client you can use to send requests.
 #[derive(Debug, Clone)]
 pub struct Client {
-    request_sender: mpsc::Sender<(MethodCall, oneshot::Sender<jsonrpc_core::Output>)>,
+    message_sender: mpsc::Sender<ClientMessage>,
+}
+
+/// A message travelling from the client handle to the IO loop.
+enum ClientMessage {
+    Request(MethodCall, oneshot::Sender<jsonrpc_core::Output>),
+    Notification(Notification),
 }
 
 /// Constructor a JSON-RPC client. Returns a tuple: the client you can use to send requests, and
 /// the adapter you must pass to `run_with_client()` to connect the client to the proper IO.
 pub fn new_client() -> (Client, ClientAdapter) {
-    let (request_sender, request_receiver) = mpsc::channel(30);
-    let client = Client { request_sender };
-    let adapter = ClientAdapter { request_receiver };
+    let (message_sender, message_receiver) = mpsc::channel(30);
+    let client = Client { message_sender };
+    let adapter = ClientAdapter { message_receiver };
 
     (client, adapter)
 }
@@ -43,20 +50,17 @@ impl Client {
         Res: serde::de::DeserializeOwned,
     {
         let id = REQUEST_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
-        let json_params = serde_json::to_value(params).map_err(|_err| jsonrpc_core::Error::invalid_request())?;
-        let params = match json_params {
-            jsonrpc_core::Value::Array(arr) => jsonrpc_core::Params::Array(arr),
-            jsonrpc_core::Value::Object(obj) => jsonrpc_core::Params::Map(obj),
-            _ => return Err(jsonrpc_core::Error::invalid_request()),
-        };
         let request = jsonrpc_core::MethodCall {
             jsonrpc: Some(jsonrpc_core::Version::V2),
             method,
-            params,
+            params: serialize_params(params)?,
             id: jsonrpc_core::Id::Num(id),
         };
         let (response_sender, response_receiver) = oneshot::channel();
-        self.request_sender.send((request, response_sender)).await.unwrap();
+        self.message_sender
+            .send(ClientMessage::Request(request, response_sender))
+            .await
+            .unwrap();
 
         let response = response_receiver.await.unwrap();
 
@@ -65,11 +69,44 @@ impl Client {
             jsonrpc_core::Output::Failure(res) => Err(res.error),
         }
     }
+
+    /// Send a JSON-RPC notification. Notifications are fire-and-forget: no response is expected,
+    /// and the notification is silently dropped when the transport queue is full or the IO loop is
+    /// gone.
+    pub fn notify<Req>(&self, method: String, params: Req) -> jsonrpc_core::Result<()>
+    where
+        Req: serde::Serialize,
+    {
+        let notification = Notification {
+            jsonrpc: Some(jsonrpc_core::Version::V2),
+            method,
+            params: serialize_params(params)?,
+        };
+
+        self.message_sender
+            .try_send(ClientMessage::Notification(notification))
+            .ok();
+
+        Ok(())
+    }
+}
+
+fn serialize_params<Req>(params: Req) -> jsonrpc_core::Result<jsonrpc_core::Params>
+where
+    Req: serde::Serialize,
+{
+    let json_params = serde_json::to_value(params).map_err(|_err| jsonrpc_core::Error::invalid_request())?;
+
+    match json_params {
+        jsonrpc_core::Value::Array(arr) => Ok(jsonrpc_core::Params::Array(arr)),
+        jsonrpc_core::Value::Object(obj) => Ok(jsonrpc_core::Params::Map(obj)),
+        _ => Err(jsonrpc_core::Error::invalid_request()),
+    }
 }
 
 /// The other side of the channels. Only used as a handle to be passed into run_with_client().
 pub struct ClientAdapter {
-    request_receiver: mpsc::Receiver<(MethodCall, oneshot::Sender<jsonrpc_core::Output>)>,
+    message_receiver: mpsc::Receiver<ClientMessage>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -102,57 +139,57 @@ async fn run_with_io(
     let mut input_lines = input.lines();
     let mut output = tokio::io::BufWriter::new(output);
     let mut in_flight: HashMap<jsonrpc_core::Id, oneshot::Sender<_>> = HashMap::new();
+    // Incoming requests are handled concurrently, so that a request like
+    // `cancel` can be processed while a long-running command is still in
+    // flight.
+    let mut in_progress = FuturesUnordered::new();
 
     loop {
         tokio::select! {
-            next_line = input_lines.next_line() => { handle_stdin_next_line(next_line, &mut output, handler, &mut in_flight).await? }
-            next_request = client_adapter.request_receiver.recv() => {
-                handle_next_client_request(next_request, &mut output, &mut in_flight).await?
+            next_line = input_lines.next_line() => {
+                let next_line = if let Some(next_line) = next_line? {
+                    next_line
+                } else {
+                    return Ok(());
+                };
+
+                match serde_json::from_str::<Message>(&next_line)? {
+                    Message::Request(request) => in_progress.push(handle_request(handler, request)),
+                    Message::Response(response) => {
+                        if let Some(chan) = in_flight.remove(response.id()) {
+                            chan.send(response).expect("Response channel broken");
+                        }
+                    }
+                }
+            }
+            Some(response) = in_progress.next() => {
+                output.write_all(response.as_bytes()).await?;
+                output.write_all(b"\n").await?;
+                output.flush().await?;
+            }
+            next_message = client_adapter.message_receiver.recv() => {
+                handle_next_client_message(next_message, &mut output, &mut in_flight).await?
             }
         }
     }
 }
 
-async fn handle_next_client_request<T: AsyncWrite + Unpin>(
-    next_request: Option<(jsonrpc_core::MethodCall, oneshot::Sender<jsonrpc_core::Output>)>,
-    output: &mut tokio::io::BufWriter<T>,
-    in_flight: &mut HashMap<jsonrpc_core::Id, oneshot::Sender<jsonrpc_core::Output>>,
-) -> io::Result<()> {
-    let (next_request, channel) = next_request.unwrap();
-    in_flight.insert(next_request.id.clone(), channel);
-    let request_json = serde_json::to_string(&next_request)?;
-    output.write_all(request_json.as_bytes()).await?;
-    output.write_all(b"\n").await?;
-    output.flush().await?;
-    Ok(())
-}
-
-async fn handle_stdin_next_line<T: AsyncWrite + Unpin>(
-    next_line: io::Result<Option<String>>,
+async fn handle_next_client_message<T: AsyncWrite + Unpin>(
+    next_message: Option<ClientMessage>,
     output: &mut tokio::io::BufWriter<T>,
-    handler: &IoHandler,
     in_flight: &mut HashMap<jsonrpc_core::Id, oneshot::Sender<jsonrpc_core::Output>>,
 ) -> io::Result<()> {
-    let next_line = if let Some(next_line) = next_line? {
-        next_line
-    } else {
-        return Ok(());
-    };
-
-    match serde_json::from_str::<Message>(&next_line)? {
-        Message::Request(request) => {
-            let response = handle_request(handler, request).await;
-            output.write_all(response.as_bytes()).await?;
-            output.write_all(b"\n").await?;
-            output.flush().await?;
-        }
-        Message::Response(response) => {
-            if let Some(chan) = in_flight.remove(response.id()) {
-                chan.send(response).expect("Response channel broken");
-            }
+    let message_json = match next_message.unwrap() {
+        ClientMessage::Request(request, channel) => {
+            in_flight.insert(request.id.clone(), channel);
+            serde_json::to_string(&request)?
         }
-    }
+        ClientMessage::Notification(notification) => serde_json::to_string(&notification)?,
+    };
 
+    output.write_all(message_json.as_bytes()).await?;
+    output.write_all(b"\n").await?;
+    output.flush().await?;
     Ok(())
 }
 
@@ -22,11 +22,16 @@ pub enum PostgresType {
     Date,
     Time(Option<u32>),
     Timetz(Option<u32>),
+    Interval(Option<u32>),
     Boolean,
     Bit(Option<u32>),
     VarBit(Option<u32>),
     Uuid,
     Xml,
+    /// PostGIS type, with an optional geometry type and SRID modifier, e.g. `geometry(Point,4326)`.
+    Geometry(Option<(String, u32)>),
+    /// PostGIS type, with an optional geometry type and SRID modifier, e.g. `geography(Point,4326)`.
+    Geography(Option<(String, u32)>),
     Json,
     JsonB,
 }
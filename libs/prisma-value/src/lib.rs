@@ -53,7 +53,15 @@ pub fn stringify_date(date: &DateTime<FixedOffset>) -> String {
     // The findUnique batch optimization expects date inputs to have exactly the same format as date outputs
     // This works today because clients always send date inputs in the same format as the serialized format below
     // Updating this without transforming date inputs to the same format WILL break the findUnique batch optimization
-    date.to_rfc3339_opts(SecondsFormat::Millis, true)
+    //
+    // Values read from microsecond-precision columns (e.g. `DATETIME(6)`, `timestamp(6)`)
+    // carry sub-millisecond information and are serialized with full microsecond precision
+    // so they round-trip; inputs in that format serialize back to the identical string.
+    if date.timestamp_subsec_nanos() % 1_000_000 == 0 {
+        date.to_rfc3339_opts(SecondsFormat::Millis, true)
+    } else {
+        date.to_rfc3339_opts(SecondsFormat::Micros, true)
+    }
 }
 
 pub fn encode_bytes(bytes: &[u8]) -> String {
@@ -2,7 +2,7 @@
 
 use std::{
     borrow::Cow,
-    fmt::{self, Display, Formatter},
+    fmt::{self, Display, Formatter, Write as _},
 };
 
 /// The indentation used throughout the crate. Four spaces.
@@ -41,6 +41,100 @@ where
     }
 }
 
+/// A quoted identifier or string literal, rendered with the delimiters of the
+/// flavour it was constructed for. The identifier variants escape embedded
+/// delimiters by doubling them, so names that need quoting (mixed case,
+/// reserved words, spaces, quote characters) cannot break out of the quotes.
+#[derive(Debug)]
+pub enum Quoted<T> {
+    /// Double quotes, the ANSI identifier delimiter (PostgreSQL, SQLite).
+    Double(T),
+    /// Single quotes, for string literals. The contents are written as-is:
+    /// literal escaping is dialect-specific and left to the caller.
+    Single(T),
+    /// Backticks, the MySQL identifier delimiter.
+    Backticks(T),
+    /// Square brackets, the SQL Server identifier delimiter.
+    SquareBrackets(T),
+}
+
+impl<T> Quoted<T> {
+    pub fn mssql_ident(name: T) -> Quoted<T> {
+        Quoted::SquareBrackets(name)
+    }
+
+    pub fn mssql_string(contents: T) -> Quoted<T> {
+        Quoted::Single(contents)
+    }
+
+    pub fn mysql_ident(name: T) -> Quoted<T> {
+        Quoted::Backticks(name)
+    }
+
+    pub fn mysql_string(contents: T) -> Quoted<T> {
+        Quoted::Single(contents)
+    }
+
+    pub fn postgres_ident(name: T) -> Quoted<T> {
+        Quoted::Double(name)
+    }
+
+    pub fn postgres_string(contents: T) -> Quoted<T> {
+        Quoted::Single(contents)
+    }
+
+    pub fn sqlite_ident(name: T) -> Quoted<T> {
+        Quoted::Double(name)
+    }
+
+    pub fn sqlite_string(contents: T) -> Quoted<T> {
+        Quoted::Single(contents)
+    }
+}
+
+impl<T> Display for Quoted<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (open, close, escaped, inner) = match self {
+            Quoted::Double(inner) => ('"', '"', '"', inner),
+            Quoted::Backticks(inner) => ('`', '`', '`', inner),
+            Quoted::SquareBrackets(inner) => ('[', ']', ']', inner),
+            Quoted::Single(inner) => return write!(f, "'{}'", inner),
+        };
+
+        f.write_char(open)?;
+        write!(EscapingWriter { inner: f, escaped }, "{}", inner)?;
+        f.write_char(close)
+    }
+}
+
+/// Doubles every occurrence of the escaped delimiter character in what is
+/// written through it.
+struct EscapingWriter<'a, 'b> {
+    inner: &'a mut Formatter<'b>,
+    escaped: char,
+}
+
+impl fmt::Write for EscapingWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut parts = s.split(self.escaped);
+
+        if let Some(part) = parts.next() {
+            self.inner.write_str(part)?;
+        }
+
+        for part in parts {
+            self.inner.write_char(self.escaped)?;
+            self.inner.write_char(self.escaped)?;
+            self.inner.write_str(part)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum SortOrder {
     Asc,
@@ -83,3 +177,27 @@ impl<'a> IndexColumn<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoted_identifiers_escape_embedded_delimiters() {
+        assert_eq!(
+            Quoted::postgres_ident(r#"he said "hi""#).to_string(),
+            r#""he said ""hi""""#
+        );
+        assert_eq!(Quoted::sqlite_ident(r#"weird" name"#).to_string(), r#""weird"" name""#);
+        assert_eq!(Quoted::mysql_ident("weird` name").to_string(), "`weird`` name`");
+        assert_eq!(Quoted::mssql_ident("weird] name").to_string(), "[weird]] name]");
+    }
+
+    #[test]
+    fn quoted_identifiers_leave_tame_names_untouched() {
+        assert_eq!(Quoted::postgres_ident("Cat").to_string(), "\"Cat\"");
+        assert_eq!(Quoted::mysql_ident("Cat").to_string(), "`Cat`");
+        assert_eq!(Quoted::mssql_ident("Cat").to_string(), "[Cat]");
+        assert_eq!(Quoted::sqlite_ident("select from").to_string(), "\"select from\"");
+    }
+}
@@ -12,4 +12,4 @@ pub mod postgres;
 #[cfg(feature = "sqlite")]
 pub mod sqlite;
 
-pub use common::{IndexColumn, SortOrder};
+pub use common::{IndexColumn, Quoted, SortOrder};
@@ -1,13 +1,11 @@
-use crate::common::{Indented, IndexColumn, IteratorJoin, SQL_INDENTATION};
+use crate::common::{Indented, IndexColumn, IteratorJoin, Quoted, SQL_INDENTATION};
 use std::{borrow::Cow, fmt::Display};
 
 struct Ident<'a>(&'a str);
 
 impl Display for Ident<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("`")?;
-        f.write_str(self.0)?;
-        f.write_str("`")
+        Quoted::mysql_ident(self.0).fmt(f)
     }
 }
 
@@ -55,13 +53,15 @@ pub enum AlterTableClause<'a> {
 impl Display for AlterTableClause<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AlterTableClause::RenameTo { next_name } => write!(f, "RENAME TO {}", next_name),
+            AlterTableClause::RenameTo { next_name } => write!(f, "RENAME TO {}", Ident(next_name)),
             AlterTableClause::RenameIndex {
                 previous_name,
                 next_name,
-            } => write!(f, "RENAME INDEX `{}` TO `{}`", previous_name, next_name),
-            AlterTableClause::DropColumn { column_name } => write!(f, "DROP COLUMN `{}`", column_name),
-            AlterTableClause::DropForeignKey { constraint_name } => write!(f, "DROP FOREIGN KEY `{}`", constraint_name),
+            } => write!(f, "RENAME INDEX {} TO {}", Ident(previous_name), Ident(next_name)),
+            AlterTableClause::DropColumn { column_name } => write!(f, "DROP COLUMN {}", Ident(column_name)),
+            AlterTableClause::DropForeignKey { constraint_name } => {
+                write!(f, "DROP FOREIGN KEY {}", Ident(constraint_name))
+            }
             AlterTableClause::DropPrimaryKey => f.write_str("DROP PRIMARY KEY"),
             AlterTableClause::AddForeignKey(fk) => write!(f, "ADD {}", fk),
         }
@@ -81,14 +81,18 @@ pub struct ForeignKey<'a> {
 impl<'a> Display for ForeignKey<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(constraint_name) = &self.constraint_name {
-            write!(f, "CONSTRAINT `{constraint_name}` ", constraint_name = constraint_name,)?;
+            write!(
+                f,
+                "CONSTRAINT {constraint_name} ",
+                constraint_name = Ident(constraint_name)
+            )?;
         }
 
         f.write_str("FOREIGN KEY (")?;
 
         self.constrained_columns.iter().map(|s| Ident(s)).join(", ", f)?;
 
-        write!(f, ") REFERENCES `{}`(", self.referenced_table)?;
+        write!(f, ") REFERENCES {}(", Ident(&self.referenced_table))?;
 
         self.referenced_columns.iter().map(|s| Ident(s)).join(", ", f)?;
 
@@ -187,10 +191,10 @@ impl Display for CreateIndex<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CREATE {maybe_unique}INDEX `{index_name}` ON `{table_name}`(",
+            "CREATE {maybe_unique}INDEX {index_name} ON {table_name}(",
             maybe_unique = if self.unique { "UNIQUE " } else { "" },
-            index_name = self.index_name,
-            table_name = self.on.0,
+            index_name = Ident(&self.index_name),
+            table_name = Ident(&self.on.0),
         )?;
 
         self.on
@@ -290,7 +294,7 @@ pub struct DropTable<'a> {
 
 impl Display for DropTable<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "DROP TABLE `{}`", self.table_name)
+        write!(f, "DROP TABLE {}", Ident(&self.table_name))
     }
 }
 
@@ -302,7 +306,12 @@ pub struct DropIndex<'a> {
 
 impl Display for DropIndex<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "DROP INDEX `{}` ON `{}`", self.index_name, self.table_name)
+        write!(
+            f,
+            "DROP INDEX {} ON {}",
+            Ident(&self.index_name),
+            Ident(&self.table_name)
+        )
     }
 }
 
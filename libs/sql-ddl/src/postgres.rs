@@ -1,4 +1,4 @@
-use crate::common::{IndexColumn, IteratorJoin};
+use crate::common::{IndexColumn, IteratorJoin, Quoted};
 use std::{borrow::Cow, fmt::Display};
 
 #[derive(Debug, Default)]
@@ -190,8 +190,8 @@ impl Display for ForeignKey<'_> {
         if let Some(constraint_name) = &self.constraint_name {
             write!(
                 f,
-                "CONSTRAINT \"{constraint_name}\" ",
-                constraint_name = constraint_name,
+                "CONSTRAINT {constraint_name} ",
+                constraint_name = Ident(constraint_name),
             )?;
         }
 
@@ -199,7 +199,7 @@ impl Display for ForeignKey<'_> {
 
         self.constrained_columns.iter().map(|s| Ident(s)).join(", ", f)?;
 
-        write!(f, ") REFERENCES \"{}\"(", self.referenced_table)?;
+        write!(f, ") REFERENCES {}(", Ident(&self.referenced_table))?;
 
         self.referenced_columns.iter().map(|s| Ident(s)).join(", ", f)?;
 
@@ -262,20 +262,12 @@ impl<'a> From<&'a str> for PostgresIdentifier<'a> {
 
 impl Display for PostgresIdentifier<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let delimiter = "\"";
-
         match self {
-            PostgresIdentifier::Simple(name) => {
-                f.write_str(delimiter)?;
-                f.write_str(name)?;
-                f.write_str(delimiter)
-            }
+            PostgresIdentifier::Simple(name) => Quoted::postgres_ident(name).fmt(f),
             PostgresIdentifier::WithSchema(prefix, name) => {
-                f.write_str(delimiter)?;
-                f.write_str(prefix)?;
-                f.write_str(r#"".""#)?;
-                f.write_str(name)?;
-                f.write_str(delimiter)
+                Quoted::postgres_ident(prefix).fmt(f)?;
+                f.write_str(".")?;
+                Quoted::postgres_ident(name).fmt(f)
             }
         }
     }
@@ -294,8 +286,7 @@ struct Ident<'a>(&'a str);
 
 impl Display for Ident<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "\"{}\"", self.0)?;
-        Ok(())
+        Quoted::postgres_ident(self.0).fmt(f)
     }
 }
 
@@ -329,6 +320,7 @@ pub struct CreateIndex<'a> {
     pub table_reference: PostgresIdentifier<'a>,
     pub columns: Vec<IndexColumn<'a>>,
     pub using: Option<IndexAlgorithm>,
+    pub nulls_not_distinct: bool,
 }
 
 impl<'a> Display for CreateIndex<'a> {
@@ -361,7 +353,13 @@ impl<'a> Display for CreateIndex<'a> {
             })
             .join(", ", f)?;
 
-        f.write_str(")")
+        f.write_str(")")?;
+
+        if self.nulls_not_distinct {
+            f.write_str(" NULLS NOT DISTINCT")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -405,6 +403,7 @@ mod tests {
             table_reference: "Cat".into(),
             columns,
             using: None,
+            nulls_not_distinct: false,
         };
 
         assert_eq!(
@@ -413,6 +412,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn create_unique_index_nulls_not_distinct() {
+        let columns = vec![IndexColumn::new("name")];
+
+        let create_index = CreateIndex {
+            is_unique: true,
+            index_name: "meow_idx".into(),
+            table_reference: "Cat".into(),
+            columns,
+            using: None,
+            nulls_not_distinct: true,
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE UNIQUE INDEX \"meow_idx\" ON \"Cat\"(\"name\") NULLS NOT DISTINCT"
+        )
+    }
+
     #[test]
     fn create_hash_index() {
         let columns = vec![IndexColumn::new("name")];
@@ -423,6 +441,7 @@ mod tests {
             table_reference: "Cat".into(),
             columns,
             using: Some(IndexAlgorithm::Hash),
+            nulls_not_distinct: false,
         };
 
         assert_eq!(
@@ -452,6 +471,7 @@ mod tests {
             table_reference: "Cat".into(),
             columns,
             using: None,
+            nulls_not_distinct: false,
         };
 
         assert_eq!(
@@ -1,11 +1,11 @@
-use crate::common::{Indented, IteratorJoin, SQL_INDENTATION};
+use crate::common::{Indented, IteratorJoin, Quoted, SQL_INDENTATION};
 use std::{borrow::Cow, fmt::Display};
 
 struct SqliteIdentifier<T>(T);
 
 impl<T: Display> Display for SqliteIdentifier<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "\"{}\"", self.0)
+        Quoted::sqlite_ident(&self.0).fmt(f)
     }
 }
 
@@ -15,11 +15,15 @@ pub struct CreateTable<'a> {
     pub columns: Vec<Column<'a>>,
     pub primary_key: Option<Vec<Cow<'a, str>>>,
     pub foreign_keys: Vec<ForeignKey<'a>>,
+    /// Render the `WITHOUT ROWID` table option.
+    pub without_rowid: bool,
+    /// Render the `STRICT` table option.
+    pub strict: bool,
 }
 
 impl Display for CreateTable<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        writeln!(f, "CREATE TABLE \"{}\" (", self.table_name)?;
+        writeln!(f, "CREATE TABLE {} (", SqliteIdentifier(&self.table_name))?;
 
         self.columns.iter().map(Indented).join(",\n", f)?;
 
@@ -40,7 +44,12 @@ impl Display for CreateTable<'_> {
             )?;
         }
 
-        write!(f, "\n)")
+        match (self.without_rowid, self.strict) {
+            (false, false) => write!(f, "\n)"),
+            (true, false) => write!(f, "\n) WITHOUT ROWID"),
+            (false, true) => write!(f, "\n) STRICT"),
+            (true, true) => write!(f, "\n) WITHOUT ROWID, STRICT"),
+        }
     }
 }
 
@@ -91,7 +100,7 @@ impl Display for ForeignKeyAction {
 impl Display for ForeignKey<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(constraint_name) = &self.constraint_name {
-            write!(f, "CONSTRAINT \"{}\" ", constraint_name)?;
+            write!(f, "CONSTRAINT {} ", SqliteIdentifier(constraint_name))?;
         }
 
         f.write_str("FOREIGN KEY (")?;
@@ -100,8 +109,8 @@ impl Display for ForeignKey<'_> {
 
         write!(
             f,
-            ") REFERENCES \"{referenced_table}\" (",
-            referenced_table = self.references.0,
+            ") REFERENCES {referenced_table} (",
+            referenced_table = SqliteIdentifier(&self.references.0),
         )?;
 
         self.references.1.iter().map(SqliteIdentifier).join(", ", f)?;
@@ -137,8 +146,8 @@ impl Display for Column<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "\"{name}\" {tpe}{not_null}{primary_key}{autoincrement}",
-            name = self.name,
+            "{name} {tpe}{not_null}{primary_key}{autoincrement}",
+            name = SqliteIdentifier(&self.name),
             tpe = self.r#type,
             not_null = if self.not_null { " NOT NULL" } else { "" },
             primary_key = if self.primary_key { " PRIMARY KEY" } else { "" },
@@ -192,6 +201,32 @@ mod tests {
         assert_eq!(create_table.to_string(), expected.trim_matches('\n'))
     }
 
+    #[test]
+    fn create_table_with_table_options() {
+        let create_table = CreateTable {
+            table_name: "Cat".into(),
+            columns: vec![Column {
+                name: "id".into(),
+                r#type: "INTEGER".into(),
+                primary_key: true,
+                ..Default::default()
+            }],
+            without_rowid: true,
+            strict: true,
+            ..Default::default()
+        };
+
+        let expected = indoc::indoc!(
+            r#"
+            CREATE TABLE "Cat" (
+                "id" INTEGER PRIMARY KEY
+            ) WITHOUT ROWID, STRICT
+            "#
+        );
+
+        assert_eq!(create_table.to_string(), expected.trim_matches('\n'))
+    }
+
     #[test]
     fn create_table_with_primary_key() {
         let create_table = CreateTable {
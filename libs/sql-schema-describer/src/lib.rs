@@ -5,6 +5,7 @@
 pub mod mssql;
 pub mod mysql;
 pub mod postgres;
+pub mod sql_dump;
 pub mod sqlite;
 pub mod walkers;
 
@@ -170,6 +171,18 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The SQLite-only table options. Always default on other connectors.
+    #[serde(default)]
+    pub sqlite_options: SqliteTableOptions,
+}
+
+/// The SQLite-specific options of a table (https://www.sqlite.org/lang_createtable.html).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct SqliteTableOptions {
+    /// The table is declared `STRICT`.
+    pub strict: bool,
+    /// The table is declared `WITHOUT ROWID`.
+    pub without_rowid: bool,
 }
 
 impl Table {
@@ -319,6 +332,9 @@ pub struct Index {
     pub tpe: IndexType,
     /// BTree or Hash
     pub algorithm: Option<SQLIndexAlgorithm>,
+    /// Whether a unique index treats nulls as not distinct (PostgreSQL 15+).
+    #[serde(default)]
+    pub nulls_not_distinct: bool,
 }
 
 impl Index {
@@ -409,6 +425,10 @@ pub struct Column {
     pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// The identity definition of the column (`GENERATED … AS IDENTITY`, PostgreSQL 10+).
+    /// Identity columns also report `auto_increment`.
+    #[serde(default)]
+    pub identity: Option<ColumnIdentity>,
 }
 
 impl Column {
@@ -417,6 +437,15 @@ impl Column {
     }
 }
 
+/// How the values of an identity column are generated (PostgreSQL 10+).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnIdentity {
+    /// `GENERATED ALWAYS AS IDENTITY`
+    Always,
+    /// `GENERATED BY DEFAULT AS IDENTITY`
+    ByDefault,
+}
+
 /// The type of a column.
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 pub struct ColumnType {
@@ -583,6 +612,12 @@ pub struct ForeignKey {
     pub referenced_table: String,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
+    /// The name of the unique constraint or index on the referenced table backing
+    /// the foreign key, when the database reports it. `None` on databases that do
+    /// not expose it (SQLite) — resolution then falls back to matching the
+    /// referenced column set. Note that the referenced primary key reports its
+    /// backing index name here as well (e.g. `PRIMARY` on MySQL).
+    pub referenced_constraint_name: Option<String>,
     /// Action on deletion.
     pub on_delete_action: ForeignKeyAction,
     /// Action on update.
@@ -235,6 +235,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            sqlite_options: Default::default(),
         }
     }
 
@@ -363,6 +364,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 tpe,
                 default,
                 auto_increment,
+                identity: None,
             });
         }
 
@@ -484,6 +486,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                                     false => IndexType::Normal,
                                 },
                                 algorithm: None,
+                                nulls_not_distinct: false,
                             },
                         );
                     }
@@ -601,6 +604,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 referenced_column.name                  AS referenced_column_name,
                 fk.delete_referential_action            AS delete_referential_action,
                 fk.update_referential_action            AS update_referential_action,
+                referenced_index.name                   AS referenced_index_name,
                 fkc.constraint_column_id                AS ordinal_position
             FROM sys.foreign_key_columns AS fkc
                     INNER JOIN sys.tables AS parent_table
@@ -616,6 +620,9 @@ impl<'a> SqlSchemaDescriber<'a> {
                     INNER JOIN sys.foreign_keys AS fk
                                 ON fkc.constraint_object_id = fk.object_id
                                     AND fkc.parent_object_id = fk.parent_object_id
+                    LEFT JOIN sys.indexes AS referenced_index
+                                ON fk.referenced_object_id = referenced_index.object_id
+                                    AND fk.key_index_id = referenced_index.index_id
             WHERE parent_table.is_ms_shipped = 0
             AND referenced_table.is_ms_shipped = 0
             AND OBJECT_SCHEMA_NAME(fkc.parent_object_id) = @P1
@@ -633,6 +640,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             let referenced_table = row.get_expect_string("referenced_table_name");
             let referenced_schema_name = row.get_expect_string("referenced_schema_name");
             let referenced_column = row.get_expect_string("referenced_column_name");
+            let referenced_constraint_name = row.get_string("referenced_index_name");
             let ord_pos = row.get_expect_i64("ordinal_position");
 
             if schema != referenced_schema_name {
@@ -683,6 +691,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                         columns: vec![column],
                         referenced_table,
                         referenced_columns: vec![referenced_column],
+                        referenced_constraint_name,
                         on_delete_action,
                         on_update_action,
                     };
@@ -16,6 +16,10 @@ fn is_mariadb(version: &str) -> bool {
     version.contains("MariaDB")
 }
 
+fn is_vitess(version: &str) -> bool {
+    version.contains("vitess") || version.contains("Vitess")
+}
+
 enum Flavour {
     Mysql,
     MariaDb,
@@ -58,12 +62,20 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
             .as_ref()
             .map(|s| Flavour::from_version(s))
             .unwrap_or(Flavour::Mysql);
+        let vitess = version.as_deref().map(is_vitess).unwrap_or(false);
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
         let mut columns = Self::get_all_columns(self.conn, schema, &flavour).await?;
         let mut indexes = self.get_all_indexes(schema).await?;
-        let mut fks = Self::get_foreign_keys(self.conn, schema).await?;
+
+        // Vitess does not support foreign keys, and the join on
+        // `information_schema.referential_constraints` fails outright there.
+        let mut fks = if vitess {
+            BTreeMap::new()
+        } else {
+            Self::get_foreign_keys(self.conn, schema).await?
+        };
 
         let mut enums = vec![];
         for table_name in &table_names {
@@ -74,8 +86,12 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
 
         purge_dangling_foreign_keys(&mut tables);
 
-        let views = self.get_views(schema).await?;
-        let procedures = self.get_procedures(schema).await?;
+        // The `information_schema` views and routines tables are not queryable on Vitess.
+        let (views, procedures) = if vitess {
+            (vec![], vec![])
+        } else {
+            (self.get_views(schema).await?, self.get_procedures(schema).await?)
+        };
 
         Ok(SqlSchema {
             tables,
@@ -221,6 +237,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                sqlite_options: Default::default(),
             },
             enums,
         )
@@ -384,6 +401,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 tpe,
                 default,
                 auto_increment,
+                identity: None,
             };
 
             entry.0.push(col);
@@ -520,6 +538,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                                 columns: vec![column],
                                 tpe,
                                 algorithm: None,
+                                nulls_not_distinct: false,
                             },
                         );
                     }
@@ -565,6 +584,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 kcu.referenced_column_name referenced_column_name,
                 kcu.ordinal_position ordinal_position,
                 kcu.table_name table_name,
+                rc.unique_constraint_name unique_constraint_name,
                 rc.delete_rule delete_rule,
                 rc.update_rule update_rule
             FROM information_schema.key_column_usage AS kcu
@@ -586,6 +606,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             let column = row.get_expect_string("column_name");
             let referenced_table = row.get_expect_string("referenced_table_name");
             let referenced_column = row.get_expect_string("referenced_column_name");
+            let referenced_constraint_name = row.get_string("unique_constraint_name");
             let ord_pos = row.get_expect_i64("ordinal_position");
             let on_delete_action = match row.get_expect_string("delete_rule").to_lowercase().as_str() {
                 "cascade" => ForeignKeyAction::Cascade,
@@ -624,6 +645,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                         columns: vec![column],
                         referenced_table,
                         referenced_columns: vec![referenced_column],
+                        referenced_constraint_name,
                         on_delete_action,
                         on_update_action,
                     };
@@ -655,7 +677,8 @@ impl<'a> SqlSchemaDescriber<'a> {
         arity: ColumnArity,
         default: Option<&Value>,
     ) -> (ColumnType, Option<Enum>) {
-        static UNSIGNEDNESS_RE: Lazy<Regex> = Lazy::new(|| Regex::new("(?i)unsigned$").unwrap());
+        // Not anchored at the end: `ZEROFILL` columns render as e.g. `int(10) unsigned zerofill`.
+        static UNSIGNEDNESS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\bunsigned\b").unwrap());
         // println!("Name: {}", column_name);
         // println!("DT: {}", data_type);
         // println!("FDT: {}", full_data_type);
@@ -236,6 +236,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             foreign_keys,
             indices,
             primary_key,
+            sqlite_options: Default::default(),
         }
     }
 
@@ -289,6 +290,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 info.column_default,
                 info.is_nullable,
                 info.is_identity,
+                info.identity_generation,
                 info.data_type,
                 info.character_maximum_length
             FROM information_schema.columns info
@@ -320,6 +322,13 @@ impl<'a> SqlSchemaDescriber<'a> {
                 None => false,
             };
 
+            let identity = match col.get_string("identity_generation") {
+                Some(generation) if generation.eq_ignore_ascii_case("always") => Some(ColumnIdentity::Always),
+                Some(generation) if generation.eq_ignore_ascii_case("by default") => Some(ColumnIdentity::ByDefault),
+                Some(generation) => panic!("unrecognized identity_generation variant '{}'", generation),
+                None => None,
+            };
+
             let data_type = col.get_expect_string("data_type");
             let tpe = get_column_type(&col, enums);
             let default = Self::get_default_value(&col, &data_type, &tpe, sequences, schema);
@@ -337,6 +346,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 tpe,
                 default,
                 auto_increment,
+                identity,
             };
 
             columns.entry(table_name).or_default().push(col);
@@ -416,6 +426,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 con.confdeltype,
                 con.confupdtype,
                 rel_ns.nspname as "referenced_schema_name",
+                idx_cl.relname  as "referenced_constraint_name",
                 conname         as constraint_name,
                 child,
                 parent,
@@ -428,6 +439,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                         con1.oid,
                         con1.confrelid,
                         con1.conrelid,
+                        con1.conindid,
                         con1.conname,
                         con1.confdeltype,
                         con1.confupdtype
@@ -444,6 +456,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                     JOIN pg_attribute att2 on att2.attrelid = con.conrelid and att2.attnum = con.parent
                     JOIN pg_class rel_cl on con.confrelid = rel_cl.oid
                     JOIN pg_namespace rel_ns on rel_cl.relnamespace = rel_ns.oid
+                    LEFT JOIN pg_class idx_cl on con.conindid = idx_cl.oid
             ORDER BY con_id, con.colidx;
         "#;
 
@@ -466,6 +479,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 .get_char("confupdtype")
                 .unwrap_or_else(|| row.get_expect_string("confupdtype").chars().next().unwrap());
             let constraint_name = row.get_expect_string("constraint_name");
+            let referenced_constraint_name = row.get_string("referenced_constraint_name");
 
             let referenced_schema_name = row.get_expect_string("referenced_schema_name");
 
@@ -504,6 +518,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                         columns: vec![column],
                         referenced_table,
                         referenced_columns: vec![referenced_column],
+                        referenced_constraint_name,
                         on_delete_action,
                         on_update_action,
                     };
@@ -549,6 +564,10 @@ impl<'a> SqlSchemaDescriber<'a> {
                rawIndex.indisprimary                       AS is_primary_key,
                tableInfos.relname                          AS table_name,
                indexAccess.amname                          AS index_algo,
+               -- `NULLS NOT DISTINCT` is only rendered by PostgreSQL 15+; on older
+               -- versions the definition never contains the clause.
+               pg_get_indexdef(rawIndex.indexrelid)
+                   LIKE '%NULLS NOT DISTINCT%'             AS nulls_not_distinct,
                rawIndex.indkeyidx,
                CASE rawIndex.sort_order & 1
                    WHEN 1 THEN 'DESC'
@@ -600,7 +619,7 @@ impl<'a> SqlSchemaDescriber<'a> {
           AND rawIndex.sort_order_colnum = columnInfos.attnum
           AND indexAccess.oid = indexInfos.relam
         GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname,
-                 rawIndex.indkeyidx, column_order, index_algo
+                 rawIndex.indkeyidx, column_order, index_algo, nulls_not_distinct
         ORDER BY rawIndex.indkeyidx;
         "#;
 
@@ -630,6 +649,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                 _ => None,
             };
 
+            let nulls_not_distinct = row.get_expect_bool("nulls_not_distinct");
+
             if is_primary_key {
                 let entry: &mut (Vec<_>, Option<PrimaryKey>) =
                     indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
@@ -672,6 +693,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                             false => IndexType::Normal,
                         },
                         algorithm,
+                        nulls_not_distinct,
                     })
                 }
             }
@@ -823,9 +845,17 @@ impl<'a> SqlSchemaDescriber<'a> {
                                 Cow::Owned(format!("\"{}\"", enum_name)),
                                 Cow::Owned(format!("{}.{}", schema, enum_name)),
                             ];
-                            match unsuffix_default_literal(&default_string, expected_suffixes) {
-                                Some(value) => DefaultValue::value(PrismaValue::Enum(Self::unquote_string(&value))),
-                                None => DefaultValue::db_generated(default_string),
+
+                            if tpe.arity.is_list() {
+                                match parse_enum_array_default(&default_string, expected_suffixes) {
+                                    Some(values) => DefaultValue::value(PrismaValue::List(values)),
+                                    None => DefaultValue::db_generated(default_string),
+                                }
+                            } else {
+                                match unsuffix_default_literal(&default_string, expected_suffixes) {
+                                    Some(value) => DefaultValue::value(PrismaValue::Enum(Self::unquote_string(&value))),
+                                    None => DefaultValue::db_generated(default_string),
+                                }
                             }
                         }
                         ColumnTypeFamily::Unsupported(_) => DefaultValue::db_generated(default_string),
@@ -897,10 +927,14 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         "timetz" | "_timetz" => (DateTime, Some(PostgresType::Timetz(precision.time_precision))),
         "timestamp" | "_timestamp" => (DateTime, Some(PostgresType::Timestamp(precision.time_precision))),
         "timestamptz" | "_timestamptz" => (DateTime, Some(PostgresType::Timestamptz(precision.time_precision))),
+        "interval" | "_interval" => (String, Some(PostgresType::Interval(precision.time_precision))),
         "tsquery" | "_tsquery" => unsupported_type(),
         "tsvector" | "_tsvector" => unsupported_type(),
         "txid_snapshot" | "_txid_snapshot" => unsupported_type(),
         "inet" | "_inet" => (String, Some(PostgresType::Inet)),
+        // PostGIS
+        "geometry" | "_geometry" => (String, Some(PostgresType::Geometry(get_geometry_modifier(row)))),
+        "geography" | "_geography" => (String, Some(PostgresType::Geography(get_geometry_modifier(row)))),
         //geometric
         "box" | "_box" => unsupported_type(),
         "circle" | "_circle" => unsupported_type(),
@@ -920,6 +954,23 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
     }
 }
 
+/// Extracts the type modifier of a PostGIS column from its formatted type, e.g.
+/// `geometry(Point,4326)` becomes `("Point", 4326)`. Unconstrained columns have no modifier.
+fn get_geometry_modifier(row: &ResultRow) -> Option<(String, u32)> {
+    static GEOMETRY_MODIFIER_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"^(?:geometry|geography)\((\w+)(?:,([0-9]+))?\)"#).unwrap());
+
+    let formatted_type = row.get_expect_string("formatted_type");
+    let captures = GEOMETRY_MODIFIER_RE.captures(&formatted_type)?;
+    let r#type = captures.get(1).unwrap().as_str().to_owned();
+    let srid = captures
+        .get(2)
+        .map(|srid| from_str::<u32>(srid.as_str()).unwrap())
+        .unwrap_or(0);
+
+    Some((r#type, srid))
+}
+
 static RE_SEQ: Lazy<Regex> = Lazy::new(|| Regex::new("^(?:.+\\.)?\"?([^.\"]+)\"?").expect("compile regex"));
 
 static AUTOINCREMENT_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -956,6 +1007,78 @@ fn fetch_dbgenerated(value: &str) -> Option<String> {
     }
 }
 
+/// Parses the enum array defaults reported by Postgres, in both the `ARRAY['a'::"Color", 'b'::"Color"]`
+/// and the `'{a,b}'::"Color"[]` form. Anything more exotic stays `dbgenerated()`.
+fn parse_enum_array_default(default_string: &str, expected_suffixes: &[Cow<'_, str>]) -> Option<Vec<PrismaValue>> {
+    let default_string = default_string.trim();
+
+    if let Some(body) = default_string.strip_prefix("ARRAY[").and_then(|s| s.strip_suffix(']')) {
+        return split_outside_single_quotes(body)
+            .into_iter()
+            .map(|element| {
+                let element = element.trim();
+                let element = unsuffix_default_literal(element, expected_suffixes)
+                    .unwrap_or(Cow::Borrowed(element))
+                    .trim()
+                    .to_owned();
+
+                if element.starts_with('\'') || element.starts_with("e'") || element.starts_with("E'") {
+                    Some(PrismaValue::Enum(process_string_literal(&element).into_owned()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+    }
+
+    let array_suffixes: Vec<String> = expected_suffixes.iter().map(|suffix| format!("{}[]", suffix)).collect();
+    let literal = unsuffix_default_literal(default_string, &array_suffixes)?;
+    let literal = process_string_literal(literal.as_ref()).into_owned();
+    let body = literal.strip_prefix('{')?.strip_suffix('}')?;
+
+    if body.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = body.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '\\' if in_quotes => current.push(chars.next()?),
+            ',' if !in_quotes => values.push(PrismaValue::Enum(std::mem::take(&mut current))),
+            c => current.push(c),
+        }
+    }
+
+    values.push(PrismaValue::Enum(current));
+
+    Some(values)
+}
+
+/// Splits an `ARRAY[…]` constructor body on the commas separating its elements, ignoring commas
+/// inside single-quoted string literals.
+fn split_outside_single_quotes(body: &str) -> Vec<String> {
+    let mut elements = vec![String::new()];
+    let mut in_quotes = false;
+
+    for c in body.chars() {
+        match c {
+            '\'' => {
+                in_quotes = !in_quotes;
+                elements.last_mut().unwrap().push(c);
+            }
+            ',' if !in_quotes => elements.push(String::new()),
+            c => elements.last_mut().unwrap().push(c),
+        }
+    }
+
+    elements
+}
+
 fn unsuffix_default_literal<'a, T: AsRef<str>>(literal: &'a str, expected_suffixes: &[T]) -> Option<Cow<'a, str>> {
     // Tries to match expressions of the form <expr> or <expr>::<type> or <expr>:::<type>.
     static POSTGRES_DATA_TYPE_SUFFIX_RE: Lazy<Regex> =
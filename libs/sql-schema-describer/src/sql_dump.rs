@@ -0,0 +1,681 @@
+//! Building a [`SqlSchema`] from a schema-only SQL dump (`pg_dump`,
+//! `mysqldump`), without connecting to a database.
+//!
+//! The parser covers the DDL subset those tools emit for schema dumps:
+//! `CREATE TABLE` with column definitions and inline or table-level
+//! constraints, `ALTER TABLE ... ADD CONSTRAINT` for primary keys, foreign
+//! keys and unique constraints, `CREATE [UNIQUE] INDEX`, and
+//! `CREATE TYPE ... AS ENUM`. Everything else (ownership, grants, comments,
+//! sequences, functions) is skipped. Defaults are parsed for plain literals
+//! and current-timestamp expressions; any other expression becomes a
+//! database-generated default.
+
+use crate::{
+    Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index,
+    IndexColumn, IndexType, PrimaryKey, PrimaryKeyColumn, SqlSchema, Table,
+};
+
+/// An error from parsing a SQL dump, with the statement that caused it.
+#[derive(Debug)]
+pub struct SqlDumpParseError {
+    /// The statement that could not be parsed.
+    pub statement: String,
+    /// What went wrong.
+    pub message: String,
+}
+
+impl std::fmt::Display for SqlDumpParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} in statement `{}`", self.message, self.statement)
+    }
+}
+
+impl std::error::Error for SqlDumpParseError {}
+
+/// Parses a schema-only SQL dump into a [`SqlSchema`].
+pub fn parse_sql_dump(dump: &str) -> Result<SqlSchema, SqlDumpParseError> {
+    let mut schema = SqlSchema::default();
+
+    for statement in split_statements(dump) {
+        let upper = statement.to_ascii_uppercase();
+
+        if upper.starts_with("CREATE TABLE") {
+            let table = parse_create_table(&statement)?;
+            schema.tables.push(table);
+        } else if upper.starts_with("CREATE INDEX") || upper.starts_with("CREATE UNIQUE INDEX") {
+            parse_create_index(&statement, &mut schema)?;
+        } else if upper.starts_with("ALTER TABLE") {
+            parse_alter_table(&statement, &mut schema)?;
+        } else if upper.starts_with("CREATE TYPE") && upper.contains(" AS ENUM") {
+            let r#enum = parse_create_enum(&statement)?;
+            schema.enums.push(r#enum);
+        }
+        // Everything else in a dump (SET, COMMENT, GRANT, sequences, ...) is
+        // irrelevant for the data model.
+    }
+
+    Ok(schema)
+}
+
+fn error(statement: &str, message: impl Into<String>) -> SqlDumpParseError {
+    SqlDumpParseError {
+        statement: statement.trim().to_owned(),
+        message: message.into(),
+    }
+}
+
+/// Splits the dump into statements on `;`, honoring string literals, quoted
+/// identifiers, dollar quoting and comments.
+fn split_statements(dump: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut chars = dump.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' | '`' => {
+                current.push(c);
+                for inner in chars.by_ref() {
+                    current.push(inner);
+                    if inner == c {
+                        break;
+                    }
+                }
+            }
+            '$' => {
+                // Dollar quoting (`$tag$ ... $tag$`), as pg_dump emits for
+                // function and trigger bodies. The tag is empty or an
+                // identifier; anything else (e.g. a `$1` parameter) is not an
+                // opening delimiter.
+                let mut tag = String::new();
+                while let Some(&inner) = chars.peek() {
+                    if inner.is_alphanumeric() || inner == '_' {
+                        tag.push(inner);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                if chars.peek() == Some(&'$') {
+                    chars.next();
+                    let delimiter = format!("${}$", tag);
+                    current.push_str(&delimiter);
+
+                    let mut body = String::new();
+                    for inner in chars.by_ref() {
+                        body.push(inner);
+                        if body.ends_with(&delimiter) {
+                            break;
+                        }
+                    }
+                    current.push_str(&body);
+                } else {
+                    current.push(c);
+                    current.push_str(&tag);
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for inner in chars.by_ref() {
+                    if inner == '\n' {
+                        current.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for inner in chars.by_ref() {
+                    if previous == '*' && inner == '/' {
+                        break;
+                    }
+                    previous = inner;
+                }
+            }
+            ';' => {
+                if !current.trim().is_empty() {
+                    statements.push(current.trim().to_owned());
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.trim().is_empty() {
+        statements.push(current.trim().to_owned());
+    }
+
+    statements
+}
+
+/// Strips `"`, `` ` `` and schema qualification from an identifier.
+fn unquote(identifier: &str) -> String {
+    let unqualified = match identifier.rfind('.') {
+        // Only split on a dot outside of quotes.
+        Some(index) if !identifier.starts_with('"') && !identifier.starts_with('`') => &identifier[index + 1..],
+        _ => identifier.split("\".\"").last().unwrap_or(identifier),
+    };
+
+    unqualified.trim_matches(|c| c == '"' || c == '`').to_owned()
+}
+
+/// Splits `input` on top-level commas, ignoring commas inside parentheses and
+/// string literals.
+fn split_top_level(input: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0u32;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' => {
+                depth = depth.saturating_sub(1);
+                current.push(c);
+            }
+            '\'' | '"' | '`' => {
+                current.push(c);
+                for inner in chars.by_ref() {
+                    current.push(inner);
+                    if inner == c {
+                        break;
+                    }
+                }
+            }
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_owned());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_owned());
+    }
+
+    parts
+}
+
+/// The body between the outermost parentheses of a statement.
+fn parenthesized_body(statement: &str) -> Option<&str> {
+    let start = statement.find('(')?;
+    let end = statement.rfind(')')?;
+    statement.get(start + 1..end)
+}
+
+/// The column list of a `(col, col, ...)` group, unquoted.
+fn column_list(group: &str) -> Vec<String> {
+    split_top_level(group)
+        .into_iter()
+        .map(|column| unquote(column.split_whitespace().next().unwrap_or("").trim()))
+        .filter(|column| !column.is_empty())
+        .collect()
+}
+
+fn parse_create_table(statement: &str) -> Result<Table, SqlDumpParseError> {
+    let header_end = statement
+        .find('(')
+        .ok_or_else(|| error(statement, "Missing column list"))?;
+    let header = &statement[..header_end];
+
+    let name = header
+        .split_whitespace()
+        .filter(|token| {
+            let upper = token.to_ascii_uppercase();
+            !matches!(
+                upper.as_str(),
+                "CREATE" | "TABLE" | "IF" | "NOT" | "EXISTS" | "ONLY" | "UNLOGGED"
+            )
+        })
+        .last()
+        .map(unquote)
+        .ok_or_else(|| error(statement, "Missing table name"))?;
+
+    let body = parenthesized_body(statement).ok_or_else(|| error(statement, "Missing column list"))?;
+
+    let mut table = Table {
+        name,
+        columns: Vec::new(),
+        indices: Vec::new(),
+        primary_key: None,
+        foreign_keys: Vec::new(),
+        sqlite_options: Default::default(),
+    };
+
+    for item in split_top_level(body) {
+        let upper = item.to_ascii_uppercase();
+
+        if upper.starts_with("PRIMARY KEY") {
+            let columns = column_list(parenthesized_body(&item).unwrap_or(""));
+            set_primary_key(&mut table, None, columns);
+        } else if upper.starts_with("CONSTRAINT") {
+            parse_table_constraint(&item, &mut table, statement)?;
+        } else if upper.starts_with("UNIQUE") {
+            let columns = column_list(parenthesized_body(&item).unwrap_or(""));
+            push_unique_index(&mut table, None, columns);
+        } else if upper.starts_with("FOREIGN KEY") {
+            let foreign_key = parse_foreign_key(&item, None, statement)?;
+            table.foreign_keys.push(foreign_key);
+        } else if upper.starts_with("CHECK") || upper.starts_with("KEY") || upper.starts_with("INDEX") {
+            // CHECK constraints are invisible to the data model; plain KEY
+            // entries (mysqldump) carry no uniqueness information we need.
+        } else {
+            parse_column(&item, &mut table, statement)?;
+        }
+    }
+
+    Ok(table)
+}
+
+fn parse_table_constraint(item: &str, table: &mut Table, statement: &str) -> Result<(), SqlDumpParseError> {
+    // CONSTRAINT <name> PRIMARY KEY (...) | FOREIGN KEY (...) REFERENCES ... | UNIQUE (...)
+    let mut tokens = item.split_whitespace();
+    tokens.next(); // CONSTRAINT
+
+    let name = tokens
+        .next()
+        .map(unquote)
+        .ok_or_else(|| error(statement, "Missing constraint name"))?;
+
+    let rest = item
+        .splitn(3, char::is_whitespace)
+        .nth(2)
+        .ok_or_else(|| error(statement, "Incomplete constraint"))?;
+    let upper = rest.to_ascii_uppercase();
+
+    if upper.starts_with("PRIMARY KEY") {
+        let columns = column_list(parenthesized_body(rest).unwrap_or(""));
+        set_primary_key(table, Some(name), columns);
+    } else if upper.starts_with("UNIQUE") {
+        let columns = column_list(parenthesized_body(rest).unwrap_or(""));
+        push_unique_index(table, Some(name), columns);
+    } else if upper.starts_with("FOREIGN KEY") {
+        let foreign_key = parse_foreign_key(rest, Some(name), statement)?;
+        table.foreign_keys.push(foreign_key);
+    }
+    // CHECK and EXCLUDE constraints are irrelevant for the data model.
+
+    Ok(())
+}
+
+fn set_primary_key(table: &mut Table, constraint_name: Option<String>, columns: Vec<String>) {
+    table.primary_key = Some(PrimaryKey {
+        columns: columns.into_iter().map(PrimaryKeyColumn::new).collect(),
+        sequence: None,
+        constraint_name,
+    });
+}
+
+fn push_unique_index(table: &mut Table, name: Option<String>, columns: Vec<String>) {
+    let name = name.unwrap_or_else(|| format!("{}_{}_key", table.name, columns.join("_")));
+
+    table.indices.push(Index {
+        name,
+        columns: columns.into_iter().map(IndexColumn::new).collect(),
+        tpe: IndexType::Unique,
+        algorithm: None,
+        nulls_not_distinct: false,
+    });
+}
+
+/// Parses `FOREIGN KEY (cols) REFERENCES table (cols) [ON DELETE ...] [ON UPDATE ...]`.
+fn parse_foreign_key(
+    definition: &str,
+    constraint_name: Option<String>,
+    statement: &str,
+) -> Result<ForeignKey, SqlDumpParseError> {
+    let upper = definition.to_ascii_uppercase();
+    let references = upper
+        .find("REFERENCES")
+        .ok_or_else(|| error(statement, "Foreign key without REFERENCES"))?;
+
+    let columns = column_list(parenthesized_body(&definition[..references]).unwrap_or(""));
+    let target = definition[references + "REFERENCES".len()..].trim();
+
+    let referenced_table = target
+        .split(|c: char| c.is_whitespace() || c == '(')
+        .next()
+        .map(unquote)
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| error(statement, "Foreign key without referenced table"))?;
+
+    let referenced_columns = parenthesized_body(target).map(column_list).unwrap_or_default();
+
+    Ok(ForeignKey {
+        constraint_name,
+        columns,
+        referenced_table,
+        referenced_columns,
+        // The dumped DDL references tables and columns, never a constraint by name.
+        referenced_constraint_name: None,
+        on_delete_action: parse_referential_action(&upper, "ON DELETE"),
+        on_update_action: parse_referential_action(&upper, "ON UPDATE"),
+    })
+}
+
+fn parse_referential_action(upper: &str, clause: &str) -> ForeignKeyAction {
+    let action = match upper.find(clause) {
+        Some(index) => upper[index + clause.len()..].trim_start(),
+        None => return ForeignKeyAction::NoAction,
+    };
+
+    if action.starts_with("CASCADE") {
+        ForeignKeyAction::Cascade
+    } else if action.starts_with("RESTRICT") {
+        ForeignKeyAction::Restrict
+    } else if action.starts_with("SET NULL") {
+        ForeignKeyAction::SetNull
+    } else if action.starts_with("SET DEFAULT") {
+        ForeignKeyAction::SetDefault
+    } else {
+        ForeignKeyAction::NoAction
+    }
+}
+
+/// Keywords ending the data type in a column definition.
+const COLUMN_KEYWORDS: &[&str] = &[
+    "NOT",
+    "NULL",
+    "DEFAULT",
+    "PRIMARY",
+    "UNIQUE",
+    "REFERENCES",
+    "CONSTRAINT",
+    "GENERATED",
+    "AUTO_INCREMENT",
+    "AUTOINCREMENT",
+    "COLLATE",
+    "CHECK",
+    "COMMENT",
+    "CHARACTER",
+];
+
+fn parse_column(definition: &str, table: &mut Table, statement: &str) -> Result<(), SqlDumpParseError> {
+    let mut tokens = definition.split_whitespace();
+
+    let name = tokens
+        .next()
+        .map(unquote)
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| error(statement, "Missing column name"))?;
+
+    // The data type can span several tokens (`double precision`, `timestamp
+    // with time zone`, `varchar(191)`); collect until a column keyword.
+    let mut data_type = String::new();
+    let mut rest = Vec::new();
+
+    for token in tokens {
+        if !rest.is_empty() || COLUMN_KEYWORDS.contains(&token.to_ascii_uppercase().as_str()) {
+            rest.push(token);
+        } else {
+            if !data_type.is_empty() {
+                data_type.push(' ');
+            }
+            data_type.push_str(token);
+        }
+    }
+
+    if data_type.is_empty() {
+        return Err(error(statement, format!("Missing data type for column `{}`", name)));
+    }
+
+    let rest = rest.join(" ");
+    let rest_upper = rest.to_ascii_uppercase();
+
+    let arity = if rest_upper.contains("NOT NULL") {
+        ColumnArity::Required
+    } else {
+        ColumnArity::Nullable
+    };
+
+    let (family, auto_increment) = column_type_family(&data_type);
+    let auto_increment = auto_increment
+        || rest_upper.contains("AUTO_INCREMENT")
+        || rest_upper.contains("AUTOINCREMENT")
+        || rest_upper.contains("GENERATED ALWAYS AS IDENTITY")
+        || rest_upper.contains("GENERATED BY DEFAULT AS IDENTITY")
+        || rest_upper.contains("NEXTVAL(");
+
+    let default = parse_default(&rest, &rest_upper, &family);
+
+    if rest_upper.contains("PRIMARY KEY") {
+        set_primary_key(table, None, vec![name.clone()]);
+    } else if rest_upper.contains("UNIQUE") {
+        push_unique_index(table, None, vec![name.clone()]);
+    }
+
+    if let Some(references) = rest_upper.find("REFERENCES") {
+        let foreign_key = parse_foreign_key(
+            // Fabricate the `FOREIGN KEY (col)` prefix an inline reference omits.
+            &format!("FOREIGN KEY (\"{}\") {}", name, &rest[references..]),
+            None,
+            statement,
+        )?;
+        table.foreign_keys.push(foreign_key);
+    }
+
+    table.columns.push(Column {
+        name,
+        tpe: ColumnType {
+            full_data_type: data_type.clone(),
+            family,
+            arity,
+            native_type: None,
+        },
+        default,
+        auto_increment,
+        identity: None,
+    });
+
+    Ok(())
+}
+
+/// Maps a SQL data type to a column type family. The bool marks types that
+/// imply auto-increment (`serial` and friends).
+fn column_type_family(data_type: &str) -> (ColumnTypeFamily, bool) {
+    let base = data_type
+        .split(|c: char| c == '(' || c.is_whitespace())
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let lower = data_type.to_ascii_lowercase();
+
+    let family = match base.as_str() {
+        "smallint" | "int" | "integer" | "int2" | "int4" | "mediumint" | "tinyint" | "smallserial" | "serial"
+        | "serial2" | "serial4" => ColumnTypeFamily::Int,
+        "bigint" | "int8" | "bigserial" | "serial8" => ColumnTypeFamily::BigInt,
+        "real" | "float" | "float4" | "float8" | "double" => ColumnTypeFamily::Float,
+        "numeric" | "decimal" | "money" => ColumnTypeFamily::Decimal,
+        "bool" | "boolean" => ColumnTypeFamily::Boolean,
+        "char" | "varchar" | "text" | "tinytext" | "mediumtext" | "longtext" | "citext" | "character"
+        | "character_varying" | "bpchar" | "xml" | "inet" | "cidr" | "enum" => ColumnTypeFamily::String,
+        "date" | "time" | "timetz" | "timestamp" | "timestamptz" | "datetime" => ColumnTypeFamily::DateTime,
+        "bytea" | "blob" | "tinyblob" | "mediumblob" | "longblob" | "binary" | "varbinary" | "bit" => {
+            ColumnTypeFamily::Binary
+        }
+        "json" | "jsonb" => ColumnTypeFamily::Json,
+        "uuid" => ColumnTypeFamily::Uuid,
+        _ if lower.starts_with("double precision") => ColumnTypeFamily::Float,
+        _ if lower.starts_with("character varying") => ColumnTypeFamily::String,
+        _ if lower.starts_with("timestamp") || lower.starts_with("time") => ColumnTypeFamily::DateTime,
+        _ => ColumnTypeFamily::Unsupported(data_type.to_owned()),
+    };
+
+    let auto_increment = base.starts_with("serial") || base.starts_with("smallserial") || base.starts_with("bigserial");
+
+    (family, auto_increment)
+}
+
+/// Parses the `DEFAULT ...` clause of a column definition, covering plain
+/// literals and current-timestamp expressions. Anything else is kept as a
+/// database-generated default.
+fn parse_default(rest: &str, rest_upper: &str, family: &ColumnTypeFamily) -> Option<DefaultValue> {
+    let index = rest_upper.find("DEFAULT")?;
+    let raw = rest[index + "DEFAULT".len()..].trim_start();
+
+    // Cut the expression off at the next column keyword (e.g. `NOT NULL`).
+    let mut expression = raw;
+    for keyword in ["NOT NULL", " NULL", " PRIMARY", " UNIQUE", " REFERENCES", " CHECK"] {
+        if let Some(end) = expression.to_ascii_uppercase().find(keyword) {
+            expression = &expression[..end];
+        }
+    }
+    let expression = expression.trim();
+    let upper = expression.to_ascii_uppercase();
+
+    if upper.starts_with("NULL") {
+        return None;
+    }
+
+    if upper.starts_with("CURRENT_TIMESTAMP") || upper.starts_with("NOW()") {
+        return Some(DefaultValue::now());
+    }
+
+    if upper.starts_with("NEXTVAL(") {
+        return Some(DefaultValue::sequence(expression));
+    }
+
+    if expression.starts_with('\'') {
+        // A string literal, possibly with a pg_dump type suffix (`'x'::text`).
+        let end = expression[1..].find('\'')? + 1;
+        return Some(DefaultValue::value(expression[1..end].to_owned()));
+    }
+
+    if upper == "TRUE" || upper == "FALSE" {
+        return Some(DefaultValue::value(upper == "TRUE"));
+    }
+
+    if let Ok(int) = expression.parse::<i64>() {
+        return match family {
+            ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => Some(DefaultValue::value(int)),
+            _ => Some(DefaultValue::db_generated(expression)),
+        };
+    }
+
+    Some(DefaultValue::db_generated(expression))
+}
+
+/// Parses `CREATE [UNIQUE] INDEX name ON table [USING method] (columns)`.
+fn parse_create_index(statement: &str, schema: &mut SqlSchema) -> Result<(), SqlDumpParseError> {
+    let upper = statement.to_ascii_uppercase();
+    let unique = upper.starts_with("CREATE UNIQUE INDEX");
+
+    let on = upper.find(" ON ").ok_or_else(|| error(statement, "Index without ON"))?;
+
+    let name = statement[..on]
+        .split_whitespace()
+        .filter(|token| {
+            let upper = token.to_ascii_uppercase();
+            !matches!(
+                upper.as_str(),
+                "CREATE" | "UNIQUE" | "INDEX" | "CONCURRENTLY" | "IF" | "NOT" | "EXISTS"
+            )
+        })
+        .last()
+        .map(unquote)
+        .ok_or_else(|| error(statement, "Index without a name"))?;
+
+    let target = statement[on + " ON ".len()..].trim_start();
+    let table_name = target
+        .split(|c: char| c.is_whitespace() || c == '(')
+        .find(|token| !token.is_empty() && !token.eq_ignore_ascii_case("only"))
+        .map(unquote)
+        .ok_or_else(|| error(statement, "Index without a table"))?;
+
+    let columns = parenthesized_body(target).map(column_list).unwrap_or_default();
+
+    let table = match schema.tables.iter_mut().find(|table| table.name == table_name) {
+        Some(table) => table,
+        // An index on a table the dump did not define; ignore it.
+        None => return Ok(()),
+    };
+
+    table.indices.push(Index {
+        name,
+        columns: columns.into_iter().map(IndexColumn::new).collect(),
+        tpe: if unique { IndexType::Unique } else { IndexType::Normal },
+        algorithm: None,
+        nulls_not_distinct: false,
+    });
+
+    Ok(())
+}
+
+/// Parses the `ADD CONSTRAINT` forms of `ALTER TABLE` statements; all other
+/// alterations in a schema dump are ignored.
+fn parse_alter_table(statement: &str, schema: &mut SqlSchema) -> Result<(), SqlDumpParseError> {
+    let upper = statement.to_ascii_uppercase();
+
+    let add = match upper.find(" ADD ") {
+        Some(index) => index,
+        None => return Ok(()),
+    };
+
+    let table_name = statement[..add]
+        .split_whitespace()
+        .filter(|token| {
+            let upper = token.to_ascii_uppercase();
+            !matches!(upper.as_str(), "ALTER" | "TABLE" | "ONLY" | "IF" | "EXISTS")
+        })
+        .last()
+        .map(unquote)
+        .ok_or_else(|| error(statement, "Missing table name"))?;
+
+    let table = match schema.tables.iter_mut().find(|table| table.name == table_name) {
+        Some(table) => table,
+        // A constraint on a table the dump did not define; ignore it.
+        None => return Ok(()),
+    };
+
+    let addition = statement[add + " ADD ".len()..].trim_start();
+    let addition_upper = addition.to_ascii_uppercase();
+
+    if addition_upper.starts_with("CONSTRAINT") {
+        parse_table_constraint(addition, table, statement)?;
+    } else if addition_upper.starts_with("PRIMARY KEY") {
+        let columns = column_list(parenthesized_body(addition).unwrap_or(""));
+        set_primary_key(table, None, columns);
+    } else if addition_upper.starts_with("UNIQUE") {
+        let columns = column_list(parenthesized_body(addition).unwrap_or(""));
+        push_unique_index(table, None, columns);
+    } else if addition_upper.starts_with("FOREIGN KEY") {
+        let foreign_key = parse_foreign_key(addition, None, statement)?;
+        table.foreign_keys.push(foreign_key);
+    }
+
+    Ok(())
+}
+
+/// Parses `CREATE TYPE name AS ENUM ('a', 'b', ...)`.
+fn parse_create_enum(statement: &str) -> Result<Enum, SqlDumpParseError> {
+    let upper = statement.to_ascii_uppercase();
+    let as_enum = upper
+        .find(" AS ENUM")
+        .ok_or_else(|| error(statement, "Missing AS ENUM"))?;
+
+    let name = statement[..as_enum]
+        .split_whitespace()
+        .filter(|token| {
+            let upper = token.to_ascii_uppercase();
+            !matches!(upper.as_str(), "CREATE" | "TYPE")
+        })
+        .last()
+        .map(unquote)
+        .ok_or_else(|| error(statement, "Missing enum name"))?;
+
+    let values = parenthesized_body(statement)
+        .map(split_top_level)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|value| value.trim().trim_matches('\'').to_owned())
+        .collect();
+
+    Ok(Enum { name, values })
+}
@@ -1,11 +1,14 @@
 //! SQLite description.
+pub mod create_table_parser;
+
 use crate::{
     common::purge_dangling_foreign_keys, getters::Getter, parsers::Parser, Column, ColumnArity, ColumnType,
     ColumnTypeFamily, DefaultValue, DescriberResult, ForeignKey, ForeignKeyAction, Index, IndexColumn, IndexType, Lazy,
     PrimaryKey, PrimaryKeyColumn, PrismaValue, Regex, SQLSortOrder, SqlMetadata, SqlSchema, SqlSchemaDescriberBackend,
-    Table, View,
+    SqliteTableOptions, Table, View,
 };
 use quaint::{ast::Value, prelude::Queryable};
+use sql_ddl::Quoted;
 use std::{any::type_name, borrow::Cow, collections::BTreeMap, convert::TryInto, fmt::Debug, path::Path};
 use tracing::trace;
 
@@ -153,13 +156,31 @@ impl<'a> SqlSchemaDescriber<'a> {
         let foreign_keys = self.get_foreign_keys(name).await?;
         let indices = self.get_indices(name).await?;
 
-        Ok(Table {
+        let mut table = Table {
             name: name.to_string(),
             columns,
             indices,
             primary_key,
             foreign_keys,
-        })
+            sqlite_options: Default::default(),
+        };
+
+        // The PRAGMAs miss details only present in the original DDL, so we
+        // additionally parse the CREATE TABLE statement.
+        if let Some(sql) = self.get_create_table_sql(name).await? {
+            if let Some(parsed) = create_table_parser::parse_create_table(&sql) {
+                merge_parsed_create_table(&mut table, &parsed);
+            }
+        }
+
+        Ok(table)
+    }
+
+    async fn get_create_table_sql(&self, table: &str) -> DescriberResult<Option<String>> {
+        let sql = "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?";
+        let result_set = self.conn.query_raw(sql, &[Value::text(table)]).await?;
+
+        Ok(result_set.into_iter().next().and_then(|row| row.get_string("sql")))
     }
 
     #[tracing::instrument]
@@ -180,7 +201,7 @@ impl<'a> SqlSchemaDescriber<'a> {
 
     #[tracing::instrument]
     async fn get_columns(&self, table: &str) -> DescriberResult<(Vec<Column>, Option<PrimaryKey>)> {
-        let sql = format!(r#"PRAGMA table_info ("{}")"#, table);
+        let sql = format!("PRAGMA table_info ({})", Quoted::sqlite_ident(table));
         let result_set = self.conn.query_raw(&sql, &[]).await?;
         let mut pk_cols: BTreeMap<i64, String> = BTreeMap::new();
         let mut cols: Vec<Column> = result_set
@@ -258,6 +279,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                     tpe,
                     default,
                     auto_increment: false,
+                    identity: None,
                 };
 
                 if pk_col > 0 {
@@ -326,7 +348,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             pub on_update_action: ForeignKeyAction,
         }
 
-        let sql = format!(r#"PRAGMA foreign_key_list("{}");"#, table);
+        let sql = format!("PRAGMA foreign_key_list({});", Quoted::sqlite_ident(table));
         trace!("describing table foreign keys, SQL: '{}'", sql);
         let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for foreign keys");
 
@@ -426,6 +448,10 @@ impl<'a> SqlSchemaDescriber<'a> {
                     // Not relevant in SQLite since we cannot ALTER or DROP foreign keys by
                     // constraint name.
                     constraint_name: None,
+
+                    // `PRAGMA foreign_key_list` does not report which index backs the
+                    // reference — consumers fall back to matching the referenced columns.
+                    referenced_constraint_name: None,
                 };
 
                 trace!("Detected foreign key {:?}", fk);
@@ -440,7 +466,7 @@ impl<'a> SqlSchemaDescriber<'a> {
     }
 
     async fn get_indices(&self, table: &str) -> DescriberResult<Vec<Index>> {
-        let sql = format!(r#"PRAGMA index_list("{}");"#, table);
+        let sql = format!("PRAGMA index_list({});", Quoted::sqlite_ident(table));
         let result_set = self.conn.query_raw(&sql, &[]).await?;
         trace!("Got indices description results: {:?}", result_set);
 
@@ -465,9 +491,10 @@ impl<'a> SqlSchemaDescriber<'a> {
                 },
                 columns: vec![],
                 algorithm: None,
+                nulls_not_distinct: false,
             };
 
-            let sql = format!(r#"PRAGMA index_info("{}");"#, name);
+            let sql = format!("PRAGMA index_info({});", Quoted::sqlite_ident(&name));
             let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
             trace!("Got index description results: {:?}", result_set);
 
@@ -485,7 +512,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 }
             }
 
-            let sql = format!(r#"PRAGMA index_xinfo("{}");"#, name);
+            let sql = format!("PRAGMA index_xinfo({});", Quoted::sqlite_ident(&name));
             let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
             trace!("Got index description results: {:?}", result_set);
 
@@ -512,6 +539,60 @@ impl<'a> SqlSchemaDescriber<'a> {
     }
 }
 
+/// Merges the DDL-only details from the parsed CREATE TABLE statement into the
+/// PRAGMA-based description: the `STRICT` / `WITHOUT ROWID` table options, and
+/// generated columns, which `PRAGMA table_info` omits as hidden columns. The
+/// generation expression is recorded as a database-generated default, the
+/// closest the schema model gets to one. Collation is parsed but has no
+/// representation in the schema model yet.
+fn merge_parsed_create_table(table: &mut Table, parsed: &create_table_parser::ParsedCreateTable) {
+    table.sqlite_options = SqliteTableOptions {
+        strict: parsed.strict,
+        without_rowid: parsed.without_rowid,
+    };
+
+    // `get_columns` infers auto_increment for every single-column INTEGER PRIMARY KEY, but such a
+    // column is only a rowid alias unless the `AUTOINCREMENT` keyword was actually written. Align
+    // the flag with the DDL so that only explicit autoincrement round-trips.
+    for column in table.columns.iter_mut() {
+        if let Some(parsed_column) = parsed
+            .columns
+            .iter()
+            .find(|parsed_column| parsed_column.name == column.name)
+        {
+            column.auto_increment = parsed_column.autoincrement;
+        }
+    }
+
+    for (position, parsed_column) in parsed.columns.iter().enumerate() {
+        let generated = match &parsed_column.generated {
+            Some(generated) => generated,
+            None => continue,
+        };
+
+        if table.columns.iter().any(|column| column.name == parsed_column.name) {
+            continue;
+        }
+
+        let arity = if parsed_column.not_null {
+            ColumnArity::Required
+        } else {
+            ColumnArity::Nullable
+        };
+
+        let column = Column {
+            name: parsed_column.name.clone(),
+            tpe: get_column_type(parsed_column.tpe.as_deref().unwrap_or(""), arity),
+            default: Some(DefaultValue::db_generated(&generated.expression)),
+            auto_increment: false,
+            identity: None,
+        };
+
+        // Keep the declaration order from the DDL.
+        table.columns.insert(position.min(table.columns.len()), column);
+    }
+}
+
 fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
     let tpe_lower = tpe.to_lowercase();
 
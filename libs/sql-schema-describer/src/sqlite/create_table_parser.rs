@@ -0,0 +1,430 @@
+//! Parsing of the `CREATE TABLE` statements stored in `sqlite_master.sql`.
+//!
+//! The PRAGMA-based description misses details that are only present in the
+//! original DDL: column-level `COLLATE` clauses, generated columns (which
+//! `PRAGMA table_info` omits as hidden columns) and the `STRICT` / `WITHOUT
+//! ROWID` table options. This module extracts them so the describer can merge
+//! them into the described schema.
+
+/// The DDL-only details of one `CREATE TABLE` statement.
+#[derive(Debug, Default, PartialEq)]
+pub struct ParsedCreateTable {
+    /// The column definitions, in declaration order.
+    pub columns: Vec<ParsedColumn>,
+    /// Whether the table is declared `STRICT`.
+    pub strict: bool,
+    /// Whether the table is declared `WITHOUT ROWID`.
+    pub without_rowid: bool,
+}
+
+/// One column definition as written in the DDL.
+#[derive(Debug, Default, PartialEq)]
+pub struct ParsedColumn {
+    /// The column name, unquoted.
+    pub name: String,
+    /// The declared type, if any (SQLite allows omitting it).
+    pub tpe: Option<String>,
+    /// The `COLLATE` clause, if any.
+    pub collation: Option<String>,
+    /// The generation expression and storage mode for generated columns.
+    pub generated: Option<GeneratedColumn>,
+    /// Whether the `AUTOINCREMENT` keyword is present.
+    pub autoincrement: bool,
+    /// Whether the column is declared `NOT NULL`.
+    pub not_null: bool,
+}
+
+/// A `GENERATED ALWAYS AS (...)` or `AS (...)` clause.
+#[derive(Debug, PartialEq)]
+pub struct GeneratedColumn {
+    /// The generation expression, without the enclosing parentheses.
+    pub expression: String,
+    /// `STORED` rather than the default `VIRTUAL`.
+    pub stored: bool,
+}
+
+/// Parses a `CREATE TABLE` statement as found in `sqlite_master.sql`. Returns
+/// `None` when the statement does not look like one (e.g. a virtual table
+/// definition), since the PRAGMA-based description is then all we have.
+pub fn parse_create_table(sql: &str) -> Option<ParsedCreateTable> {
+    let trimmed = sql.trim();
+
+    if !trimmed.get(..12)?.eq_ignore_ascii_case("CREATE TABLE") && !starts_with_create_temp_table(trimmed) {
+        return None;
+    }
+
+    let body_start = trimmed.find('(')?;
+    let body_end = matching_paren(trimmed, body_start)?;
+    let body = &trimmed[body_start + 1..body_end];
+
+    let mut parsed = ParsedCreateTable::default();
+
+    for option in trimmed[body_end + 1..].split(',') {
+        let option = option.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if option.eq_ignore_ascii_case("STRICT") {
+            parsed.strict = true;
+        } else if option.eq_ignore_ascii_case("WITHOUT ROWID") {
+            parsed.without_rowid = true;
+        }
+    }
+
+    for item in split_top_level(body) {
+        let tokens = tokenize(&item);
+
+        // The tokenizer already strips quoting, so the first token is the
+        // plain column name unless it starts a table-level constraint.
+        let name = match tokens.first() {
+            Some(token) if token.quoted || !is_constraint_start(&token.text) => token.text.clone(),
+            _ => continue,
+        };
+
+        parsed.columns.push(parse_column(name, &tokens[1..]));
+    }
+
+    Some(parsed)
+}
+
+fn starts_with_create_temp_table(sql: &str) -> bool {
+    let mut tokens = sql.split_whitespace();
+
+    matches!(
+        (tokens.next(), tokens.next(), tokens.next()),
+        (Some(create), Some(temp), Some(table))
+            if create.eq_ignore_ascii_case("CREATE")
+                && (temp.eq_ignore_ascii_case("TEMP") || temp.eq_ignore_ascii_case("TEMPORARY"))
+                && table.eq_ignore_ascii_case("TABLE")
+    )
+}
+
+/// Keywords that can follow the type in a column definition and therefore end
+/// it.
+const CONSTRAINT_KEYWORDS: &[&str] = &[
+    "CONSTRAINT",
+    "PRIMARY",
+    "NOT",
+    "NULL",
+    "UNIQUE",
+    "CHECK",
+    "DEFAULT",
+    "COLLATE",
+    "REFERENCES",
+    "GENERATED",
+    "AS",
+];
+
+fn is_constraint_start(token: &str) -> bool {
+    let upper = token.to_ascii_uppercase();
+    matches!(
+        upper.as_str(),
+        "CONSTRAINT" | "PRIMARY" | "UNIQUE" | "CHECK" | "FOREIGN"
+    )
+}
+
+fn parse_column(name: String, tokens: &[Token]) -> ParsedColumn {
+    let mut column = ParsedColumn {
+        name,
+        ..Default::default()
+    };
+
+    // The type can span several tokens (`UNSIGNED BIG INT`, `VARCHAR(191)`);
+    // it ends at the first constraint keyword.
+    let mut index = 0;
+    let mut tpe = String::new();
+
+    while let Some(token) = tokens.get(index) {
+        if token.quoted || CONSTRAINT_KEYWORDS.contains(&token.text.to_ascii_uppercase().as_str()) {
+            break;
+        }
+
+        if !tpe.is_empty() && !token.text.starts_with('(') {
+            tpe.push(' ');
+        }
+        tpe.push_str(&token.text);
+        index += 1;
+    }
+
+    if !tpe.is_empty() {
+        column.tpe = Some(tpe);
+    }
+
+    while let Some(token) = tokens.get(index) {
+        let upper = token.text.to_ascii_uppercase();
+
+        match upper.as_str() {
+            "NOT" if next_is(tokens, index, "NULL") => {
+                column.not_null = true;
+                index += 1;
+            }
+            "COLLATE" => {
+                if let Some(next) = tokens.get(index + 1) {
+                    column.collation = Some(next.text.clone());
+                    index += 1;
+                }
+            }
+            "AUTOINCREMENT" => column.autoincrement = true,
+            "GENERATED" if next_is(tokens, index, "ALWAYS") && next_is(tokens, index + 1, "AS") => {
+                index += 2;
+                index += parse_generated(tokens, index, &mut column);
+            }
+            "AS" => {
+                index += parse_generated(tokens, index, &mut column);
+            }
+            _ => (),
+        }
+
+        index += 1;
+    }
+
+    column
+}
+
+/// Parses the `(expr) [STORED | VIRTUAL]` part following `AS`, starting at the
+/// `AS` token. Returns the number of additional tokens consumed.
+fn parse_generated(tokens: &[Token], as_index: usize, column: &mut ParsedColumn) -> usize {
+    let expression = match tokens.get(as_index + 1) {
+        Some(token) if token.text.starts_with('(') && token.text.ends_with(')') => {
+            token.text[1..token.text.len() - 1].trim().to_owned()
+        }
+        _ => return 0,
+    };
+
+    let stored = next_is(tokens, as_index + 1, "STORED");
+
+    column.generated = Some(GeneratedColumn { expression, stored });
+
+    if stored || next_is(tokens, as_index + 1, "VIRTUAL") {
+        2
+    } else {
+        1
+    }
+}
+
+fn next_is(tokens: &[Token], index: usize, keyword: &str) -> bool {
+    tokens
+        .get(index + 1)
+        .map(|token| !token.quoted && token.text.eq_ignore_ascii_case(keyword))
+        .unwrap_or(false)
+}
+
+struct Token {
+    text: String,
+    /// Whether the token was a quoted identifier or string literal.
+    quoted: bool,
+}
+
+/// Tokenizes a column definition. Quoted identifiers and string literals
+/// become single tokens with the quotes stripped off recorded as quoted;
+/// parenthesized groups become single tokens including the parentheses.
+fn tokenize(item: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = item.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            _ if c.is_whitespace() => {
+                chars.next();
+            }
+            '"' | '`' | '\'' | '[' => {
+                chars.next();
+                let closing = if c == '[' { ']' } else { c };
+                let mut text = String::new();
+
+                while let Some(inner) = chars.next() {
+                    if inner == closing {
+                        // Doubled quotes escape the quote character itself.
+                        if closing != ']' && chars.peek() == Some(&closing) {
+                            text.push(closing);
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    } else {
+                        text.push(inner);
+                    }
+                }
+
+                tokens.push(Token { text, quoted: true });
+            }
+            '(' => {
+                let mut text = String::new();
+                let mut depth = 0u32;
+
+                for inner in chars.by_ref() {
+                    text.push(inner);
+                    match inner {
+                        '(' => depth += 1,
+                        ')' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+
+                tokens.push(Token { text, quoted: false });
+            }
+            _ => {
+                let mut text = String::new();
+
+                while let Some(&inner) = chars.peek() {
+                    if inner.is_whitespace() || matches!(inner, '"' | '`' | '\'' | '[' | '(') {
+                        break;
+                    }
+                    text.push(inner);
+                    chars.next();
+                }
+
+                tokens.push(Token { text, quoted: false });
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Splits the table body on top-level commas, ignoring commas inside
+/// parentheses, quoted identifiers and string literals.
+fn split_top_level(body: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0u32;
+    let mut chars = body.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' => {
+                depth = depth.saturating_sub(1);
+                current.push(c);
+            }
+            '"' | '`' | '\'' => {
+                current.push(c);
+                for inner in chars.by_ref() {
+                    current.push(inner);
+                    if inner == c {
+                        break;
+                    }
+                }
+            }
+            '[' => {
+                current.push(c);
+                for inner in chars.by_ref() {
+                    current.push(inner);
+                    if inner == ']' {
+                        break;
+                    }
+                }
+            }
+            ',' if depth == 0 => {
+                items.push(current.trim().to_owned());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.trim().is_empty() {
+        items.push(current.trim().to_owned());
+    }
+
+    items
+}
+
+fn matching_paren(input: &str, open: usize) -> Option<usize> {
+    let mut depth = 0u32;
+    let mut skip_until: Option<char> = None;
+
+    for (index, c) in input.char_indices().skip(open) {
+        if let Some(closing) = skip_until {
+            if c == closing {
+                skip_until = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '`' | '\'' => skip_until = Some(c),
+            '[' => skip_until = Some(']'),
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_collation_generated_columns_and_table_options() {
+        let sql = r#"CREATE TABLE "User" (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            email TEXT NOT NULL COLLATE NOCASE,
+            "full name" TEXT,
+            initials TEXT GENERATED ALWAYS AS (substr("full name", 1, 1)) STORED,
+            doubled INT AS (id * 2),
+            CONSTRAINT no_admin CHECK (email <> 'admin')
+        ) STRICT"#;
+
+        let parsed = parse_create_table(sql).unwrap();
+
+        assert!(parsed.strict);
+        assert!(!parsed.without_rowid);
+        assert_eq!(parsed.columns.len(), 5);
+
+        assert!(parsed.columns[0].autoincrement);
+        assert_eq!(parsed.columns[1].collation.as_deref(), Some("NOCASE"));
+        assert!(parsed.columns[1].not_null);
+        assert_eq!(parsed.columns[2].name, "full name");
+
+        assert_eq!(
+            parsed.columns[3].generated,
+            Some(GeneratedColumn {
+                expression: r#"substr("full name", 1, 1)"#.to_owned(),
+                stored: true,
+            })
+        );
+        assert_eq!(
+            parsed.columns[4].generated,
+            Some(GeneratedColumn {
+                expression: "id * 2".to_owned(),
+                stored: false,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_quoted_identifiers_and_without_rowid() {
+        let sql =
+            "CREATE TABLE [odd table] (`a, b` TEXT, \"c\"\"d\" INTEGER, PRIMARY KEY (`a, b`)) WITHOUT ROWID, STRICT";
+
+        let parsed = parse_create_table(sql).unwrap();
+
+        assert!(parsed.without_rowid);
+        assert!(parsed.strict);
+        assert_eq!(parsed.columns.len(), 2);
+        assert_eq!(parsed.columns[0].name, "a, b");
+        assert_eq!(parsed.columns[1].name, "c\"d");
+        assert_eq!(parsed.columns[1].tpe.as_deref(), Some("INTEGER"));
+    }
+
+    #[test]
+    fn ignores_non_create_table_statements() {
+        assert!(parse_create_table("CREATE VIRTUAL TABLE ft USING fts5(content)").is_none());
+        assert!(parse_create_table("CREATE INDEX idx ON t (a)").is_none());
+    }
+}
@@ -3,9 +3,9 @@
 #![deny(missing_docs)]
 
 use crate::{
-    Column, ColumnArity, ColumnId, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction,
-    Index, IndexColumn, IndexType, PrimaryKey, PrimaryKeyColumn, SQLIndexAlgorithm, SQLSortOrder, SqlSchema, Table,
-    TableId, UserDefinedType, View,
+    Column, ColumnArity, ColumnId, ColumnIdentity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey,
+    ForeignKeyAction, Index, IndexColumn, IndexType, PrimaryKey, PrimaryKeyColumn, SQLIndexAlgorithm, SQLSortOrder,
+    SqlSchema, Table, TableId, UserDefinedType, View,
 };
 use serde::de::DeserializeOwned;
 use std::fmt;
@@ -119,6 +119,11 @@ impl<'a> ColumnWalker<'a> {
         self.column().auto_increment
     }
 
+    /// The identity definition of the column (`GENERATED … AS IDENTITY`, PostgreSQL 10+).
+    pub fn identity(&self) -> Option<ColumnIdentity> {
+        self.column().identity
+    }
+
     /// Is this column a part of the table's primary key?
     pub fn is_part_of_primary_key(&self) -> bool {
         self.table().table().is_part_of_primary_key(self.name())
@@ -472,6 +477,37 @@ impl<'schema> ForeignKeyWalker<'schema> {
         self.foreign_key().constraint_name.as_deref()
     }
 
+    /// The name of the unique constraint or index the foreign key references,
+    /// as reported by the database.
+    pub fn referenced_constraint_name(&self) -> Option<&'schema str> {
+        self.foreign_key().referenced_constraint_name.as_deref()
+    }
+
+    /// The unique index on the referenced table backing the foreign key, if it
+    /// can be resolved. The constraint name reported by the database takes
+    /// precedence; when it is missing (SQLite), resolution falls back to the
+    /// unique index covering exactly the referenced column set. `None` when the
+    /// foreign key references the primary key or no unique index matches.
+    pub fn referenced_unique_index(&self) -> Option<IndexWalker<'schema>> {
+        let table = self.referenced_table();
+        let referenced = self.referenced_column_names();
+
+        if let Some(name) = self.referenced_constraint_name() {
+            if let Some(index) = table
+                .indexes()
+                .find(|index| index.index_type().is_unique() && index.name() == name)
+            {
+                return Some(index);
+            }
+        }
+
+        table.indexes().find(|index| {
+            index.index_type().is_unique()
+                && index.columns().len() == referenced.len()
+                && index.column_names().all(|name| referenced.iter().any(|r| r == name))
+        })
+    }
+
     /// The underlying ForeignKey struct.
     pub fn foreign_key(&self) -> &'schema ForeignKey {
         &self.table().table().foreign_keys[self.foreign_key_index]
@@ -674,6 +710,11 @@ impl<'a> IndexWalker<'a> {
     pub fn algorithm(&self) -> Option<SQLIndexAlgorithm> {
         self.get().algorithm
     }
+
+    /// Whether the unique index treats nulls as not distinct (PostgreSQL 15+).
+    pub fn nulls_not_distinct(&self) -> bool {
+        self.get().nulls_not_distinct
+    }
 }
 
 /// Traverse an enum.
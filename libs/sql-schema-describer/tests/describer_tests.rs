@@ -213,6 +213,7 @@ fn composite_primary_keys_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "name".to_string(),
@@ -224,6 +225,7 @@ fn composite_primary_keys_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -261,6 +263,7 @@ fn composite_primary_keys_must_work(api: TestApi) {
                 }
             }),
             foreign_keys: vec![],
+            sqlite_options: Default::default(),
         }
     );
 }
@@ -292,6 +295,7 @@ fn indices_must_work(api: TestApi) {
 
             default,
             auto_increment: true,
+            identity: None,
         },
         Column {
             name: "count".to_string(),
@@ -303,6 +307,7 @@ fn indices_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
     ];
 
@@ -331,7 +336,8 @@ fn indices_must_work(api: TestApi) {
                 length: None,
             }],
             tpe: IndexType::Normal,
-            algorithm
+            algorithm,
+            nulls_not_distinct: false,
         }],
         user_table.indices
     );
@@ -160,6 +160,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: true,
+            identity: None,
         },
         Column {
             name: "bit_col".to_string(),
@@ -172,6 +173,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -184,6 +186,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -195,6 +198,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "money_col".to_string(),
@@ -207,6 +211,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -219,6 +224,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -231,6 +237,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "smallmoney_col".to_string(),
@@ -243,6 +250,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tinyint_col".to_string(),
@@ -255,6 +263,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -267,6 +276,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -279,6 +289,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -291,6 +302,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -302,6 +314,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "datetime2_col".to_string(),
@@ -314,6 +327,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "datetimeoffset_col".to_string(),
@@ -326,6 +340,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "smalldatetime_col".to_string(),
@@ -338,6 +353,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -350,6 +366,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -362,6 +379,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -374,6 +392,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "varchar_max_col".to_string(),
@@ -386,6 +405,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -398,6 +418,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "nvarchar_col".to_string(),
@@ -410,6 +431,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "nvarchar_max_col".to_string(),
@@ -422,6 +444,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "ntext_col".to_string(),
@@ -434,6 +457,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -446,6 +470,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -458,6 +483,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "varbinary_max_col".to_string(),
@@ -470,6 +496,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "image_col".to_string(),
@@ -482,6 +509,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "xml_col".to_string(),
@@ -494,6 +522,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
     ];
 
@@ -646,6 +675,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
 
                     default: None,
                     auto_increment: true,
+                    identity: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -657,6 +687,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -668,6 +699,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
             ],
             indices: vec![],
@@ -683,6 +715,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
             foreign_keys: vec![
                 ForeignKey {
                     constraint_name: Some("FK__city".to_owned()),
+                    referenced_constraint_name: None,
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
@@ -691,6 +724,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                 },
                 ForeignKey {
                     constraint_name: Some("FK__city_cascade".to_owned()),
+                    referenced_constraint_name: None,
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
@@ -698,6 +732,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
             ],
+            sqlite_options: Default::default(),
         }
     );
 }
@@ -737,6 +772,7 @@ fn mssql_multi_field_indexes_must_be_inferred(api: TestApi) {
             columns,
             tpe: IndexType::Unique,
             algorithm: None,
+            nulls_not_distinct: false,
         }]
     );
 }
@@ -787,6 +823,7 @@ fn mssql_join_table_unique_indexes_must_be_inferred(api: TestApi) {
             columns,
             tpe: IndexType::Unique,
             algorithm: None,
+            nulls_not_distinct: false,
         }]
     );
 }
@@ -115,6 +115,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: true,
+            identity: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -127,6 +128,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -139,6 +141,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -150,6 +153,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -162,6 +166,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -174,6 +179,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -186,6 +192,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -198,6 +205,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -210,6 +218,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -222,6 +231,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -234,6 +244,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -246,6 +257,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -258,6 +270,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -270,6 +283,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -282,6 +296,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: Some(DefaultValue::now()),
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -294,6 +309,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -306,6 +322,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -318,6 +335,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -330,6 +348,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -342,6 +361,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -354,6 +374,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -366,6 +387,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -378,6 +400,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -390,6 +413,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -402,6 +426,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -414,6 +439,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -426,6 +452,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -438,6 +465,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -450,6 +478,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -462,6 +491,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -474,6 +504,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -485,6 +516,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -496,6 +528,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -507,6 +540,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -518,6 +552,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -529,6 +564,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -540,6 +576,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -551,6 +588,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -574,6 +612,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -590,6 +629,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            sqlite_options: Default::default(),
         }
     );
 }
@@ -657,6 +697,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: true,
+            identity: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -669,6 +710,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -681,6 +723,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -692,6 +735,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -704,6 +748,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -716,6 +761,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -728,6 +774,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -740,6 +787,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -752,6 +800,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -764,6 +813,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -776,6 +826,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -788,6 +839,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -800,6 +852,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -812,6 +865,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -824,6 +878,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -836,6 +891,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -848,6 +904,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -860,6 +917,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -872,6 +930,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -884,6 +943,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -896,6 +956,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -908,6 +969,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -920,6 +982,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -932,6 +995,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -944,6 +1008,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -956,6 +1021,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -968,6 +1034,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -980,6 +1047,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -992,6 +1060,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -1004,6 +1073,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -1016,6 +1086,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -1027,6 +1098,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -1038,6 +1110,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -1049,6 +1122,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -1060,6 +1134,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -1071,6 +1146,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -1082,6 +1158,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -1093,6 +1170,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -1104,6 +1182,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -1120,6 +1199,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            sqlite_options: Default::default(),
         }
     );
 }
@@ -1205,6 +1285,7 @@ fn mysql_multi_field_indexes_must_be_inferred(api: TestApi) {
             columns,
             tpe: IndexType::Unique,
             algorithm: None,
+            nulls_not_distinct: false,
         }]
     );
 }
@@ -1244,6 +1325,7 @@ fn old_mysql_multi_field_indexes_must_be_inferred(api: TestApi) {
             columns,
             tpe: IndexType::Unique,
             algorithm: None,
+            nulls_not_distinct: false,
         }]
     );
 }
@@ -1308,6 +1390,7 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
         fks,
         &[ForeignKey {
             constraint_name: Some("Post_ibfk_1".into()),
+            referenced_constraint_name: None,
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
             referenced_columns: vec!["id".into()],
@@ -1340,6 +1423,7 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
         fks,
         &[ForeignKey {
             constraint_name: Some("Post_ibfk_1".into()),
+            referenced_constraint_name: None,
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
             referenced_columns: vec!["id".into()],
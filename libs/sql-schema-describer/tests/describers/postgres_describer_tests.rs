@@ -92,6 +92,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -103,6 +104,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "array_date_col".into(),
@@ -114,6 +116,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -125,6 +128,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -136,6 +140,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -147,6 +152,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -158,6 +164,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -169,6 +176,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "binary_col".into(),
@@ -180,6 +188,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -191,6 +200,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "date_time_col".into(),
@@ -202,6 +212,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "double_col".into(),
@@ -213,6 +224,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "float_col".into(),
@@ -224,6 +236,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "int_col".into(),
@@ -235,6 +248,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "primary_col".into(),
@@ -246,6 +260,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: Some(DefaultValue::sequence("User_primary_col_seq".to_string())),
             auto_increment: true,
+            identity: None,
         },
         Column {
             name: "string1_col".into(),
@@ -257,6 +272,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "string2_col".into(),
@@ -268,6 +284,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -279,6 +296,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -290,6 +308,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: Some(DefaultValue::sequence("User_bigserial_col_seq".to_string())),
             auto_increment: true,
+            identity: None,
         },
         Column {
             name: "bit_col".into(),
@@ -301,6 +320,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -312,6 +332,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "box_col".into(),
@@ -323,6 +344,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "char_col".into(),
@@ -334,6 +356,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "circle_col".into(),
@@ -345,6 +368,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "line_col".into(),
@@ -356,6 +380,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "time_col".into(),
@@ -367,6 +392,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "timetz_col".into(),
@@ -378,6 +404,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "timestamp_col".into(),
@@ -389,6 +416,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "timestamptz_col".into(),
@@ -400,6 +428,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -411,6 +440,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "numeric_col".into(),
@@ -422,6 +452,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "path_col".into(),
@@ -433,6 +464,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -444,6 +476,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -455,6 +488,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -466,6 +500,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -477,6 +512,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: Some(DefaultValue::sequence("User_smallserial_col_seq".to_string())),
             auto_increment: true,
+            identity: None,
         },
         Column {
             name: "serial_col".into(),
@@ -488,6 +524,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: Some(DefaultValue::sequence("User_serial_col_seq".to_string())),
             auto_increment: true,
+            identity: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -499,6 +536,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -510,6 +548,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "txid_col".into(),
@@ -521,6 +560,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "json_col".into(),
@@ -532,6 +572,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -543,6 +584,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -554,6 +596,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -572,6 +615,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                 }],
                 tpe: IndexType::Unique,
                 algorithm: Some(SQLIndexAlgorithm::BTree),
+                nulls_not_distinct: false,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec![PrimaryKeyColumn::new("primary_col")],
@@ -581,6 +625,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                 constraint_name: Some("User_pkey".into()),
             }),
             foreign_keys: vec![],
+            sqlite_options: Default::default(),
         }
     );
 }
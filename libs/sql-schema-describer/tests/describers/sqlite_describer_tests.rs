@@ -31,6 +31,25 @@ async fn views_can_be_described() {
     assert_eq!(expected_sql, &view.definition.unwrap());
 }
 
+#[tokio::test]
+async fn tables_with_quotes_in_their_names_can_be_described() {
+    // The PRAGMA statements used for description embed the table and index
+    // names in the SQL, so names containing the identifier delimiter must be
+    // escaped properly.
+    let full_sql = r#"
+        CREATE TABLE "weird ""table"" name" (id INTEGER PRIMARY KEY, name TEXT);
+        CREATE INDEX "weird ""index"" name" ON "weird ""table"" name" (name);
+        "#;
+
+    let result = describe_sqlite(full_sql).await;
+    let table = result.table_bang(r#"weird "table" name"#);
+
+    assert_eq!(table.columns.len(), 2);
+    assert_eq!(table.indices.len(), 1);
+    assert_eq!(table.indices[0].name, r#"weird "index" name"#);
+    assert_eq!(table.indices[0].columns[0].name(), "name");
+}
+
 #[tokio::test]
 async fn sqlite_column_types_must_work() {
     let mut migration = Migration::new();
@@ -57,6 +76,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "int4_col".to_string(),
@@ -68,6 +88,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -79,6 +100,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -90,6 +112,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -101,6 +124,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: true,
+            identity: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -112,6 +136,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            identity: None,
         },
     ];
 
@@ -127,6 +152,7 @@ async fn sqlite_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            sqlite_options: Default::default(),
         }
     );
 }
@@ -161,7 +187,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         native_type: None,
                     },
                     default: None,
-                    auto_increment: true,
+                    auto_increment: false,
+                    identity: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -173,6 +200,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -184,6 +212,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -195,6 +224,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -206,6 +236,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -217,6 +248,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
             ],
             indices: vec![],
@@ -228,6 +260,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
             foreign_keys: vec![
                 ForeignKey {
                     constraint_name: None,
+                    referenced_constraint_name: None,
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
@@ -236,6 +269,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                 },
                 ForeignKey {
                     constraint_name: None,
+                    referenced_constraint_name: None,
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
@@ -244,6 +278,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                 },
                 ForeignKey {
                     constraint_name: None,
+                    referenced_constraint_name: None,
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
@@ -252,6 +287,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                 },
                 ForeignKey {
                     constraint_name: None,
+                    referenced_constraint_name: None,
                     columns: vec!["city_set_default".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
@@ -260,6 +296,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                 },
                 ForeignKey {
                     constraint_name: None,
+                    referenced_constraint_name: None,
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
@@ -267,6 +304,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            sqlite_options: Default::default(),
         }
     );
 }
@@ -154,6 +154,7 @@ impl From<ApplyMigrations> for ApplyMigrationsInput {
     fn from(am: ApplyMigrations) -> Self {
         Self {
             migrations_directory_path: am.migrations_directory_path,
+            dry_run: false,
         }
     }
 }
@@ -338,6 +339,7 @@ async fn schema_push(cmd: &SchemaPush) -> anyhow::Result<()> {
         .schema_push(&SchemaPushInput {
             schema,
             force: cmd.force,
+            dry_run: false,
         })
         .await?;
 
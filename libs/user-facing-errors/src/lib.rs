@@ -6,6 +6,7 @@ pub mod migration_engine;
 #[cfg(feature = "sql")]
 pub mod quaint;
 pub mod query_engine;
+pub mod redaction;
 
 pub use panic_hook::set_panic_hook;
 
@@ -75,7 +76,7 @@ impl Error {
     pub fn new_non_panic_with_current_backtrace(message: String) -> Self {
         Error {
             inner: ErrorType::Unknown(UnknownError {
-                message,
+                message: redaction::redact_connection_strings(&message),
                 backtrace: Some(format!("{:?}", backtrace::Backtrace::new())),
             }),
             is_panic: false,
@@ -85,7 +86,7 @@ impl Error {
     pub fn from_dyn_error(err: &dyn std::error::Error) -> Self {
         Error {
             inner: ErrorType::Unknown(UnknownError {
-                message: err.to_string(),
+                message: redaction::redact_connection_strings(&err.to_string()),
                 backtrace: None,
             }),
             is_panic: false,
@@ -110,7 +111,7 @@ impl Error {
 
         Error {
             inner: ErrorType::Unknown(UnknownError {
-                message: format!("[{}] {}", location, message),
+                message: redaction::redact_connection_strings(&format!("[{}] {}", location, message)),
                 backtrace,
             }),
             is_panic: true,
@@ -130,7 +131,7 @@ impl Error {
 
         Error {
             inner: ErrorType::Unknown(UnknownError {
-                message,
+                message: redaction::redact_connection_strings(&message),
                 backtrace: None,
             }),
             is_panic: true,
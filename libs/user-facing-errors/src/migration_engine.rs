@@ -269,6 +269,13 @@ pub struct ForeignKeyCreationNotAllowed;
 )]
 pub struct DirectDdlNotAllowed;
 
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3023",
+    message = "The command was cancelled. The engine stopped at a safe point; steps that already ran were not rolled back."
+)]
+pub struct CommandCancelled;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,3 +305,35 @@ pub struct QueryParameterLimitExceeded {
     message = "Cannot find a fulltext index to use for the search, try adding a @@fulltext([Fields...]) to your schema"
 )]
 pub struct MissingFullTextSearchIndex {}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2031",
+    message = "The query engine is overloaded and shed this request. It is currently limited to {queue_depth} admitted requests with an admission wait timeout of {timeout_millis}ms."
+)]
+pub struct EngineOverloaded {
+    pub queue_depth: u64,
+    pub timeout_millis: u64,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2032",
+    message = "The field `{field_name}` on `{object_name}` cannot be written to. {reason}"
+)]
+pub struct FieldNotWritable {
+    pub field_name: String,
+    pub object_name: String,
+    pub reason: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2033",
+    message = "The value {value} for the `{argument_name}` argument is out of range for the current connector, which supports values up to {maximum}."
+)]
+pub struct PaginationOutOfRange {
+    pub argument_name: String,
+    pub value: i64,
+    pub maximum: i64,
+}
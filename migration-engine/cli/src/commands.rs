@@ -4,7 +4,7 @@ use migration_core::migration_api;
 use structopt::StructOpt;
 use user_facing_errors::common::{InvalidConnectionString, SchemaParserError};
 
-#[derive(Debug, StructOpt)]
+#[derive(StructOpt)]
 pub(crate) struct Cli {
     /// The connection string to the database
     #[structopt(long, short = "d", parse(try_from_str = parse_base64_string))]
@@ -13,6 +13,20 @@ pub(crate) struct Cli {
     command: CliCommand,
 }
 
+// The datasource is a connection string with credentials, so the `Debug`
+// impl redacts it before it can end up in a log line.
+impl std::fmt::Debug for Cli {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cli")
+            .field(
+                "datasource",
+                &user_facing_errors::redaction::RedactedUrl::new(&self.datasource),
+            )
+            .field("command", &self.command)
+            .finish()
+    }
+}
+
 impl Cli {
     pub(crate) async fn run(self) {
         match self.run_inner().await {
@@ -82,9 +82,13 @@ async fn start_engine(datamodel_location: &str) {
     let mut datamodel = String::new();
     file.read_to_string(&mut datamodel).unwrap();
 
-    match rpc_api(&datamodel).await {
+    // The client half is used by the engine to send notifications (e.g. progress events) back to
+    // the CLI.
+    let (client, adapter) = json_rpc_stdio::new_client();
+
+    match rpc_api(&datamodel, Some(client)).await {
         // Block the thread and handle IO in async until EOF.
-        Ok(api) => json_rpc_stdio::run(&api).await.unwrap(),
+        Ok(api) => json_rpc_stdio::run_with_client(&api, adapter).await.unwrap(),
         Err(err) => {
             log_error_and_exit(err);
         }
@@ -2,16 +2,60 @@
 //! engine to ensure integrity. This module contains common logic that should be
 //! used everywhere for consistency.
 
+/// The algorithm prefix of checksums rendered by the current engine. A future
+/// change of the hashing algorithm must pick a new prefix, so that checksums
+/// written by older engines remain verifiable.
+const CHECKSUM_PREFIX: &str = "sha256:";
+
+/// The versions of the checksum format that were ever written to the
+/// migrations table. Detection is based on the shape of the stored string, so
+/// existing records keep validating without a schema change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ChecksumVersion {
+    /// Hexadecimal Sha256 without zero-padding, written by early versions of
+    /// the engine (https://github.com/prisma/prisma-engines/issues/1887).
+    V1,
+    /// Zero-padded hexadecimal Sha256.
+    V2,
+    /// Zero-padded hexadecimal Sha256 with an explicit algorithm prefix.
+    V3,
+}
+
+impl ChecksumVersion {
+    /// Detects the version an existing checksum string was written with.
+    pub(crate) fn detect(checksum: &str) -> Self {
+        if checksum.starts_with(CHECKSUM_PREFIX) {
+            ChecksumVersion::V3
+        } else if !checksum.is_empty() && checksum.len() != CHECKSUM_STR_LEN {
+            ChecksumVersion::V1
+        } else {
+            ChecksumVersion::V2
+        }
+    }
+
+    /// Renders a raw checksum in this version of the format.
+    fn render(self, checksum: [u8; 32]) -> String {
+        match self {
+            ChecksumVersion::V1 => checksum.format_checksum_old(),
+            ChecksumVersion::V2 => checksum.format_checksum(),
+            ChecksumVersion::V3 => format!("{}{}", CHECKSUM_PREFIX, checksum.format_checksum()),
+        }
+    }
+}
+
 /// Compute the checksum for a new migration script, and render it formatted to
 /// a human readable string.
 pub(crate) fn render_checksum(script: &str) -> String {
-    compute_checksum(script).format_checksum()
+    ChecksumVersion::V3.render(compute_checksum(script))
 }
 
-/// Returns whether a migration script matches an existing checksum.
+/// Returns whether a migration script matches an existing checksum, in
+/// whatever version of the format the checksum was stored.
 pub(crate) fn script_matches_checksum(script: &str, checksum: &str) -> bool {
     use std::iter::{once, once_with};
 
+    let version = ChecksumVersion::detect(checksum);
+
     // Checksum with potentially different line endings, so checksums will match
     // between Unix-like systems and Windows.
     //
@@ -22,21 +66,7 @@ pub(crate) fn script_matches_checksum(script: &str, checksum: &str) -> bool {
         .chain(once_with(|| compute_checksum(&script.replace("\r\n", "\n"))))
         .chain(once_with(|| compute_checksum(&script.replace("\n", "\r\n"))));
 
-    script_checksums.any(|script_checksum| {
-        // Due to an omission in a previous version of the migration engine,
-        // some migrations tables will have old migrations with checksum strings
-        // that have not been zero-padded.
-        //
-        // Corresponding issue:
-        // https://github.com/prisma/prisma-engines/issues/1887
-        let script_checksum_str = if !checksum.is_empty() && checksum.len() != CHECKSUM_STR_LEN {
-            script_checksum.format_checksum_old()
-        } else {
-            script_checksum.format_checksum()
-        };
-
-        script_checksum_str == checksum
-    })
+    script_checksums.any(|script_checksum| version.render(script_checksum) == checksum)
 }
 
 /// Checksumming implementation. This should be the single place where we do this.
@@ -102,9 +132,27 @@ mod tests {
     fn format_checksum_does_not_strip_zeros() {
         assert_eq!(
             render_checksum("hello"),
-            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+            "sha256:2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
         );
-        assert_eq!(render_checksum("abcd").len(), CHECKSUM_STR_LEN);
+        assert_eq!(render_checksum("abcd").len(), CHECKSUM_PREFIX.len() + CHECKSUM_STR_LEN);
+    }
+
+    #[test]
+    fn older_checksum_versions_are_detected_and_still_match() {
+        // The "hello" checksum contains bytes under 0x10, so the unpadded
+        // rendering is shorter than 64 characters.
+        let v1 = compute_checksum("hello").format_checksum_old();
+        let v2 = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        let v3 = "sha256:2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+        assert_eq!(ChecksumVersion::detect(&v1), ChecksumVersion::V1);
+        assert_eq!(ChecksumVersion::detect(v2), ChecksumVersion::V2);
+        assert_eq!(ChecksumVersion::detect(v3), ChecksumVersion::V3);
+
+        assert!(script_matches_checksum("hello", &v1));
+        assert!(script_matches_checksum("hello", v2));
+        assert!(script_matches_checksum("hello", v3));
+        assert!(!script_matches_checksum("hello!", v3));
     }
 
     #[test]
@@ -0,0 +1,90 @@
+//! Machine-readable description of schema drift.
+
+use serde::Serialize;
+
+/// A machine-readable description of the changes needed to get from the
+/// schema expected from the migration history to the actual schema of the
+/// database. The structured counterpart of the human-readable drift summary.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriftDocument {
+    /// Tables present in the database but not in the migrations.
+    pub added_tables: Vec<String>,
+    /// Tables present in the migrations but not in the database.
+    pub removed_tables: Vec<String>,
+    /// Tables present on both sides, with differences.
+    pub changed_tables: Vec<ChangedTable>,
+    /// Tables that would need to be completely recreated to account for the
+    /// changes.
+    pub redefined_tables: Vec<String>,
+    /// Enums present in the database but not in the migrations.
+    pub added_enums: Vec<String>,
+    /// Enums present in the migrations but not in the database.
+    pub removed_enums: Vec<String>,
+    /// Enums present on both sides, with differences.
+    pub changed_enums: Vec<ChangedEnum>,
+    /// Views present in the migrations but not in the database.
+    pub removed_views: Vec<String>,
+    /// User-defined types present in the migrations but not in the database.
+    pub removed_user_defined_types: Vec<String>,
+}
+
+/// The differences in a table present on both sides of the drift diff.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedTable {
+    /// The table name.
+    pub name: String,
+    /// Columns present in the database but not in the migrations.
+    pub added_columns: Vec<String>,
+    /// Columns present in the migrations but not in the database.
+    pub removed_columns: Vec<String>,
+    /// Columns present on both sides, with different attributes.
+    pub changed_columns: Vec<ChangedColumn>,
+    /// Indexes present in the database but not in the migrations.
+    pub added_indexes: Vec<String>,
+    /// Indexes present in the migrations but not in the database.
+    pub removed_indexes: Vec<String>,
+    /// Indexes present on both sides, with a different name or definition.
+    pub changed_indexes: Vec<String>,
+    /// Foreign keys present in the database but not in the migrations.
+    pub added_foreign_keys: Vec<String>,
+    /// Foreign keys present in the migrations but not in the database.
+    pub removed_foreign_keys: Vec<String>,
+    /// Whether the primary key of the table differs.
+    pub primary_key_changed: bool,
+}
+
+/// A column with different attributes on each side of the drift diff.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedColumn {
+    /// The column name.
+    pub name: String,
+    /// The attributes of the column as expected from the migrations.
+    pub before: ColumnAttributes,
+    /// The attributes of the column in the database.
+    pub after: ColumnAttributes,
+}
+
+/// The attributes of a column on one side of the drift diff.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnAttributes {
+    /// The data type of the column.
+    pub data_type: String,
+    /// The arity of the column: `Required`, `Nullable` or `List`.
+    pub arity: String,
+}
+
+/// The differences in an enum present on both sides of the drift diff.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedEnum {
+    /// The enum name.
+    pub name: String,
+    /// Variants present in the database but not in the migrations.
+    pub added_variants: Vec<String>,
+    /// Variants present in the migrations but not in the database.
+    pub removed_variants: Vec<String>,
+}
@@ -2,22 +2,30 @@
 
 //! This crate defines the API exposed by the connectors to the migration engine core. The entry point for this API is the [MigrationConnector](trait.MigrationConnector.html) trait.
 
+mod cancellation;
 mod checksum;
 mod database_migration_step_applier;
 mod destructive_change_checker;
 mod diff;
+mod drift;
 mod error;
 mod migration_persistence;
+mod privileges;
+mod progress;
 
 pub mod migrations_directory;
 
+pub use cancellation::CancellationToken;
 pub use database_migration_step_applier::DatabaseMigrationStepApplier;
 pub use destructive_change_checker::{
     DestructiveChangeChecker, DestructiveChangeDiagnostics, MigrationWarning, UnexecutableMigration,
 };
 pub use diff::DiffTarget;
+pub use drift::{ChangedColumn, ChangedEnum, ChangedTable, ColumnAttributes, DriftDocument};
 pub use error::{ConnectorError, ConnectorResult};
 pub use migration_persistence::{MigrationPersistence, MigrationRecord, PersistenceNotInitializedError, Timestamp};
+pub use privileges::DatabasePrivileges;
+pub use progress::{ProgressEvent, ProgressHandler};
 
 use migrations_directory::MigrationDirectory;
 
@@ -67,9 +75,26 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// The version of the underlying database.
     async fn version(&self) -> ConnectorResult<String>;
 
+    /// Check which privileges the connected database user has, as far as the
+    /// connector can determine them, so missing permissions can be reported
+    /// before a migration starts. The default implementation reports nothing.
+    async fn database_privileges(&self) -> ConnectorResult<DatabasePrivileges> {
+        Ok(DatabasePrivileges::default())
+    }
+
     /// Drop all database state.
     async fn reset(&self) -> ConnectorResult<()>;
 
+    /// Install a handler for progress events emitted by long-running
+    /// operations. The default implementation drops the handler: connectors
+    /// that do not report progress can ignore it.
+    fn set_progress_handler(&mut self, _handler: ProgressHandler) {}
+
+    /// Install the token through which cancellation of the currently running
+    /// operation is requested. The default implementation drops the token:
+    /// connectors that cannot stop mid-operation can ignore it.
+    fn set_cancellation_token(&mut self, _token: CancellationToken) {}
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
@@ -97,6 +122,13 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Render a human-readable drift summary for the migration.
     fn migration_summary(&self, migration: &Migration) -> String;
 
+    /// A machine-readable document describing the changes contained in the
+    /// migration, for drift reporting. The default implementation returns an
+    /// empty document.
+    fn migration_drift_document(&self, _migration: &Migration) -> DriftDocument {
+        DriftDocument::default()
+    }
+
     /// See [DatabaseMigrationStepApplier](trait.DatabaseMigrationStepApplier.html).
     fn database_migration_step_applier(&self) -> &dyn DatabaseMigrationStepApplier;
 
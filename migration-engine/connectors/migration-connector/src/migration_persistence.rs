@@ -65,6 +65,26 @@ pub trait MigrationPersistence: Send + Sync {
     /// populating the `finished_at` field in the migration record.
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()>;
 
+    /// Overwrite the checksum of an applied migration with the checksum of the
+    /// intentionally edited script, and replace the record's logs with an audit
+    /// entry. Backs the core's RepairMigrations command.
+    ///
+    /// Connectors should implement rebaseline_migration_checksum_impl to avoid
+    /// doing the checksuming themselves.
+    async fn rebaseline_migration_checksum(&self, id: &str, script: &str, audit_log: &str) -> ConnectorResult<()> {
+        self.rebaseline_migration_checksum_impl(id, &checksum::render_checksum(script), audit_log)
+            .await
+    }
+
+    /// This is an implementation detail, consumers should use
+    /// `rebaseline_migration_checksum()` instead.
+    async fn rebaseline_migration_checksum_impl(
+        &self,
+        id: &str,
+        checksum: &str,
+        audit_log: &str,
+    ) -> ConnectorResult<()>;
+
     /// List all applied migrations, ordered by `started_at`. This should fail
     /// with a PersistenceNotInitializedError when the migration persistence is
     /// not initialized.
@@ -0,0 +1,27 @@
+//! Reporting on the privileges of the database user the engine is connected
+//! as, so missing permissions can surface before a migration starts instead
+//! of halfway through it, as a cryptic database error.
+
+use serde::Serialize;
+
+/// A report on the privileges of the current database connection.
+///
+/// Each field is `Some(true)` when the privilege was positively verified,
+/// `Some(false)` when it is known to be missing, and `None` when the
+/// connector cannot determine it — for example because the database ties the
+/// operation to object ownership rather than to a grantable privilege.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabasePrivileges {
+    /// Can the connected user create tables?
+    pub create_tables: Option<bool>,
+    /// Can the connected user alter existing tables?
+    pub alter_tables: Option<bool>,
+    /// Can the connected user drop tables?
+    pub drop_tables: Option<bool>,
+    /// Can the connected user create foreign keys referencing other tables?
+    pub references: Option<bool>,
+    /// Can the connected user create databases? This is what the shadow
+    /// database needs, unless an explicit shadow database URL is configured.
+    pub create_databases: Option<bool>,
+}
@@ -0,0 +1,23 @@
+//! Progress reporting from long-running connector operations.
+
+use std::sync::Arc;
+
+/// A callback through which connectors report the progress of long-running
+/// operations, for example so it can be forwarded to a CLI as JSON-RPC
+/// notifications. Handlers must be cheap and must not block: they are called
+/// from the middle of database operations.
+pub type ProgressHandler = Arc<dyn Fn(ProgressEvent) + Send + Sync>;
+
+/// A snapshot of the progress of a long-running operation, taken after each
+/// completed step.
+#[derive(Debug, Clone, Copy)]
+pub struct ProgressEvent {
+    /// The index of the step that just completed, starting at zero.
+    pub step_index: usize,
+    /// The total number of steps in the operation.
+    pub step_count: usize,
+    /// The number of statements executed since the start of the operation.
+    pub statement_count: usize,
+    /// Milliseconds elapsed since the start of the operation.
+    pub elapsed_ms: u64,
+}
@@ -43,6 +43,15 @@ impl MigrationPersistence for MongoDbMigrationConnector {
         Err(crate::unsupported_command_error())
     }
 
+    async fn rebaseline_migration_checksum_impl(
+        &self,
+        _id: &str,
+        _checksum: &str,
+        _audit_log: &str,
+    ) -> migration_connector::ConnectorResult<()> {
+        Err(crate::unsupported_command_error())
+    }
+
     async fn list_migrations(
         &self,
     ) -> migration_connector::ConnectorResult<
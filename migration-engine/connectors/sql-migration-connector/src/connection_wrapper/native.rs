@@ -65,9 +65,18 @@ fn sql_error(quaint_error: QuaintError, connection_info: &ConnectionInfo) -> Sql
 /// An internal helper for the SQL connector. It wraps a `Quaint` struct and
 /// exposes a similar API, with additional error handling to return
 /// `ConnectorResult`s.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub(crate) struct Connection(ConnectionInner, ConnectionInfo);
 
+// `ConnectionInfo` contains the connection URL, credentials included, and
+// `Connection` ends up in trace spans through `#[tracing::instrument]`, so
+// the `Debug` impl must not expose any of the fields.
+impl std::fmt::Debug for Connection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Connection").field("url", &"<REDACTED>").finish()
+    }
+}
+
 #[derive(Clone, Debug)]
 enum ConnectionInner {
     Postgres(Arc<(quaint::connector::PostgreSql, PostgresUrl)>),
@@ -17,10 +17,12 @@ use user_facing_errors::migration_engine::ApplyMigrationError;
 use crate::{
     connection_wrapper::Connection, sql_destructive_change_checker::DestructiveChangeCheckerFlavour,
     sql_renderer::SqlRenderer, sql_schema_calculator::SqlSchemaCalculatorFlavour,
-    sql_schema_differ::SqlSchemaDifferFlavour, SqlMigrationConnector,
+    sql_schema_differ::SqlSchemaDifferFlavour, OnlineSchemaChangeTool, SqlMigrationConnector,
 };
 use datamodel::{common::preview_features::PreviewFeature, Datamodel};
-use migration_connector::{migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult};
+use migration_connector::{
+    migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult, DatabasePrivileges,
+};
 use quaint::prelude::{ConnectionInfo, Table};
 use sql_schema_describer::SqlSchema;
 use std::fmt::Debug;
@@ -36,6 +38,7 @@ pub(crate) fn from_connection_info(
             file_path: file_path.clone(),
             attached_name: db_name.clone(),
             preview_features,
+            migrations_table_name: None,
         }),
         ConnectionInfo::Mssql(url) => Box::new(MssqlFlavour::new(url.clone(), preview_features)),
         ConnectionInfo::InMemorySqlite { .. } => unreachable!("SqlFlavour for in-memory SQLite"),
@@ -74,11 +77,27 @@ pub(crate) trait SqlFlavour:
     /// Drop the migrations table
     async fn drop_migrations_table(&self, connection: &Connection) -> ConnectorResult<()>;
 
+    /// Widen the `checksum` column of an existing migrations table so it can
+    /// hold checksums carrying an algorithm prefix. Tables created by older
+    /// engines used `VARCHAR(64)`, which is too narrow for the prefixed
+    /// format. The default implementation is a no-op, for flavours whose
+    /// checksum column has no length limit.
+    async fn widen_migrations_checksum_column(&self, _connection: &Connection) -> ConnectorResult<()> {
+        Ok(())
+    }
+
     /// Check a connection to make sure it is usable by the migration engine.
     /// This can include some set up on the database, like ensuring that the
     /// schema we connect to exists.
     async fn ensure_connection_validity(&self, connection: &Connection) -> ConnectorResult<()>;
 
+    /// Check which privileges the connected user has. The default
+    /// implementation reports nothing: flavours override it with
+    /// database-specific queries.
+    async fn database_privileges(&self, _connection: &Connection) -> ConnectorResult<DatabasePrivileges> {
+        Ok(DatabasePrivileges::default())
+    }
+
     /// Drop the database and recreate it empty.
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()>;
 
@@ -101,10 +120,27 @@ pub(crate) trait SqlFlavour:
     fn preview_features(&self) -> BitFlags<PreviewFeature>;
 
     /// Table to store applied migrations, the name part.
-    fn migrations_table_name(&self) -> &'static str {
-        "_prisma_migrations"
+    fn migrations_table_name(&self) -> &str {
+        self.custom_migrations_table_name().unwrap_or("_prisma_migrations")
     }
 
+    /// The datasource-configured override for the migrations table name, if any.
+    fn custom_migrations_table_name(&self) -> Option<&str>;
+
+    /// Override the name of the migrations table, as configured on the datasource.
+    fn set_migrations_table_name(&mut self, name: String);
+
+    /// The online schema change tool to render `AlterTable` steps with in
+    /// migration scripts, if one is configured on the datasource. Only the
+    /// MySQL flavour supports one.
+    fn online_schema_change_tool(&self) -> Option<OnlineSchemaChangeTool> {
+        None
+    }
+
+    /// Configure the online schema change tool. The default implementation
+    /// ignores the setting, since only MySQL supports one.
+    fn set_online_schema_change_tool(&mut self, _tool: OnlineSchemaChangeTool) {}
+
     /// Table to store applied migrations.
     fn migrations_table(&self) -> Table<'_> {
         self.migrations_table_name().into()
@@ -6,7 +6,9 @@ use connection_string::JdbcString;
 use datamodel::common::preview_features::PreviewFeature;
 use enumflags2::BitFlags;
 use indoc::formatdoc;
-use migration_connector::{migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult};
+use migration_connector::{
+    migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult, DatabasePrivileges,
+};
 use quaint::{connector::MssqlUrl, prelude::Table};
 use sql_schema_describer::SqlSchema;
 use std::str::FromStr;
@@ -14,6 +16,7 @@ use std::str::FromStr;
 pub(crate) struct MssqlFlavour {
     url: MssqlUrl,
     preview_features: BitFlags<PreviewFeature>,
+    migrations_table_name: Option<String>,
 }
 
 impl std::fmt::Debug for MssqlFlavour {
@@ -24,7 +27,11 @@ impl std::fmt::Debug for MssqlFlavour {
 
 impl MssqlFlavour {
     pub fn new(url: MssqlUrl, preview_features: BitFlags<PreviewFeature>) -> Self {
-        Self { url, preview_features }
+        Self {
+            url,
+            preview_features,
+            migrations_table_name: None,
+        }
     }
 
     fn is_running_on_azure_sql(&self) -> bool {
@@ -107,6 +114,14 @@ impl MssqlFlavour {
 
 #[async_trait::async_trait]
 impl SqlFlavour for MssqlFlavour {
+    fn custom_migrations_table_name(&self) -> Option<&str> {
+        self.migrations_table_name.as_deref()
+    }
+
+    fn set_migrations_table_name(&mut self, name: String) {
+        self.migrations_table_name = Some(name);
+    }
+
     async fn acquire_lock(&self, connection: &Connection) -> ConnectorResult<()> {
         // see
         // https://docs.microsoft.com/en-us/sql/relational-databases/system-stored-procedures/sp-getapplock-transact-sql?view=sql-server-ver15
@@ -156,7 +171,7 @@ impl SqlFlavour for MssqlFlavour {
         let sql = formatdoc! { r#"
             CREATE TABLE [{}].[{}] (
                 id                      VARCHAR(36) PRIMARY KEY NOT NULL,
-                checksum                VARCHAR(64) NOT NULL,
+                checksum                VARCHAR(96) NOT NULL,
                 finished_at             DATETIMEOFFSET,
                 migration_name          NVARCHAR(250) NOT NULL,
                 logs                    NVARCHAR(MAX) NULL,
@@ -169,6 +184,16 @@ impl SqlFlavour for MssqlFlavour {
         Ok(connection.raw_cmd(&sql).await?)
     }
 
+    async fn widen_migrations_checksum_column(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = format!(
+            "ALTER TABLE [{}].[{}] ALTER COLUMN checksum VARCHAR(96) NOT NULL",
+            self.schema_name(),
+            self.migrations_table_name()
+        );
+
+        Ok(connection.raw_cmd(&sql).await?)
+    }
+
     async fn drop_database(&self, database_url: &str) -> ConnectorResult<()> {
         {
             let conn_str: JdbcString = format!("jdbc:{}", database_url)
@@ -209,6 +234,40 @@ impl SqlFlavour for MssqlFlavour {
         Ok(connection.raw_cmd(sql).await?)
     }
 
+    async fn database_privileges(&self, connection: &Connection) -> ConnectorResult<DatabasePrivileges> {
+        let result_set = connection
+            .query_raw(
+                r#"
+                SELECT
+                    HAS_PERMS_BY_NAME(DB_NAME(), 'DATABASE', 'CREATE TABLE'),
+                    HAS_PERMS_BY_NAME(DB_NAME(), 'DATABASE', 'ALTER'),
+                    HAS_PERMS_BY_NAME(DB_NAME(), 'DATABASE', 'REFERENCES'),
+                    HAS_PERMS_BY_NAME(NULL, NULL, 'CREATE ANY DATABASE')
+                "#,
+                &[],
+            )
+            .await?;
+
+        let row = result_set.into_single().ok();
+        let flag = |index: usize| {
+            row.as_ref()
+                .and_then(|row| row.at(index))
+                .and_then(|value| value.as_i64().map(|i| i != 0).or_else(|| value.as_bool()))
+        };
+
+        let alter_tables = flag(1);
+
+        Ok(DatabasePrivileges {
+            create_tables: flag(0),
+            // Database-level ALTER implies ALTER on all contained schemas and
+            // their objects, which includes dropping tables.
+            drop_tables: alter_tables,
+            alter_tables,
+            references: flag(2),
+            create_databases: flag(3),
+        })
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let schema_name = connection.connection_info().schema_name();
 
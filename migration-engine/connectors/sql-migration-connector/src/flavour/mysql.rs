@@ -2,12 +2,14 @@ use super::SqlFlavour;
 use crate::{
     connection_wrapper::{connect, quaint_error_to_connector_error, Connection},
     error::SystemDatabase,
-    SqlMigrationConnector,
+    OnlineSchemaChangeTool, SqlMigrationConnector,
 };
 use datamodel::{common::preview_features::PreviewFeature, walkers::walk_scalar_fields, Datamodel};
 use enumflags2::BitFlags;
-use indoc::indoc;
-use migration_connector::{migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult};
+use indoc::formatdoc;
+use migration_connector::{
+    migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult, DatabasePrivileges,
+};
 use once_cell::sync::Lazy;
 use quaint::connector::{
     mysql_async::{self as my, prelude::Query},
@@ -30,6 +32,8 @@ pub(crate) struct MysqlFlavour {
     /// See the [Circumstances] enum.
     circumstances: AtomicU8,
     preview_features: BitFlags<PreviewFeature>,
+    migrations_table_name: Option<String>,
+    online_schema_change_tool: Option<OnlineSchemaChangeTool>,
 }
 
 impl std::fmt::Debug for MysqlFlavour {
@@ -44,6 +48,8 @@ impl MysqlFlavour {
             url,
             circumstances: Default::default(),
             preview_features,
+            migrations_table_name: None,
+            online_schema_change_tool: None,
         }
     }
 
@@ -125,6 +131,9 @@ impl MysqlFlavour {
                 my::Error::Server(se) if se.code == 1105 && se.message == "direct DDL is disabled" => {
                     Some(KnownError::new(DirectDdlNotAllowed))
                 }
+                my::Error::Server(se) if se.code == 1105 && se.message.starts_with("unsupported: foreign keys") => {
+                    Some(KnownError::new(ForeignKeyCreationNotAllowed))
+                }
                 _ => None,
             }
         } else {
@@ -135,6 +144,22 @@ impl MysqlFlavour {
 
 #[async_trait::async_trait]
 impl SqlFlavour for MysqlFlavour {
+    fn custom_migrations_table_name(&self) -> Option<&str> {
+        self.migrations_table_name.as_deref()
+    }
+
+    fn set_migrations_table_name(&mut self, name: String) {
+        self.migrations_table_name = Some(name);
+    }
+
+    fn online_schema_change_tool(&self) -> Option<OnlineSchemaChangeTool> {
+        self.online_schema_change_tool
+    }
+
+    fn set_online_schema_change_tool(&mut self, tool: OnlineSchemaChangeTool) {
+        self.online_schema_change_tool = Some(tool);
+    }
+
     async fn acquire_lock(&self, connection: &Connection) -> ConnectorResult<()> {
         // https://dev.mysql.com/doc/refman/8.0/en/locking-functions.html
         let query = format!("SELECT GET_LOCK('prisma_migrate', {})", ADVISORY_LOCK_TIMEOUT.as_secs());
@@ -229,30 +254,38 @@ impl SqlFlavour for MysqlFlavour {
         &self,
         datamodel: &Datamodel,
     ) -> Option<user_facing_errors::common::DatabaseVersionIncompatibility> {
-        if self.is_mysql_5_6() {
+        let (database_version, errors) = if self.is_mysql_5_6() {
             let mut errors = Vec::new();
 
             check_datamodel_for_mysql_5_6(datamodel, &mut errors);
 
-            if errors.is_empty() {
-                return None;
-            }
-
-            let mut errors_string = String::with_capacity(errors.iter().map(|err| err.len() + 3).sum());
+            ("MySQL 5.6", errors)
+        } else if self.is_vitess() {
+            let mut errors = Vec::new();
 
-            for error in &errors {
-                errors_string.push_str("- ");
-                errors_string.push_str(error);
-                errors_string.push('\n');
-            }
+            check_datamodel_for_vitess(datamodel, &mut errors);
 
-            Some(user_facing_errors::common::DatabaseVersionIncompatibility {
-                errors: errors_string,
-                database_version: "MySQL 5.6".into(),
-            })
+            ("Vitess", errors)
         } else {
-            None
+            return None;
+        };
+
+        if errors.is_empty() {
+            return None;
         }
+
+        let mut errors_string = String::with_capacity(errors.iter().map(|err| err.len() + 3).sum());
+
+        for error in &errors {
+            errors_string.push_str("- ");
+            errors_string.push_str(error);
+            errors_string.push('\n');
+        }
+
+        Some(user_facing_errors::common::DatabaseVersionIncompatibility {
+            errors: errors_string,
+            database_version: database_version.into(),
+        })
     }
 
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
@@ -273,10 +306,10 @@ impl SqlFlavour for MysqlFlavour {
     }
 
     async fn create_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = indoc! {r#"
-            CREATE TABLE _prisma_migrations (
+        let sql = formatdoc! {r#"
+            CREATE TABLE {} (
                 id                      VARCHAR(36) PRIMARY KEY NOT NULL,
-                checksum                VARCHAR(64) NOT NULL,
+                checksum                VARCHAR(96) NOT NULL,
                 finished_at             DATETIME(3),
                 migration_name          VARCHAR(255) NOT NULL,
                 logs                    TEXT,
@@ -284,9 +317,18 @@ impl SqlFlavour for MysqlFlavour {
                 started_at              DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
                 applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0
             ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
-        "#};
+        "#, self.migrations_table_name()};
 
-        Ok(self.run_query_script(sql, connection).await?)
+        Ok(self.run_query_script(&sql, connection).await?)
+    }
+
+    async fn widen_migrations_checksum_column(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = format!(
+            "ALTER TABLE {} MODIFY checksum VARCHAR(96) NOT NULL",
+            self.migrations_table_name()
+        );
+
+        Ok(self.run_query_script(&sql, connection).await?)
     }
 
     async fn drop_database(&self, database_url: &str) -> ConnectorResult<()> {
@@ -300,7 +342,9 @@ impl SqlFlavour for MysqlFlavour {
     }
 
     async fn drop_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        connection.raw_cmd("DROP TABLE _prisma_migrations").await?;
+        connection
+            .raw_cmd(&format!("DROP TABLE {}", self.migrations_table_name()))
+            .await?;
 
         Ok(())
     }
@@ -364,6 +408,48 @@ impl SqlFlavour for MysqlFlavour {
         Ok(())
     }
 
+    async fn database_privileges(&self, connection: &Connection) -> ConnectorResult<DatabasePrivileges> {
+        let result_set = connection.query_raw("SHOW GRANTS", &[]).await?;
+
+        let mut privileges = DatabasePrivileges {
+            create_tables: Some(false),
+            alter_tables: Some(false),
+            drop_tables: Some(false),
+            references: Some(false),
+            create_databases: Some(false),
+        };
+
+        for row in result_set {
+            let grant = match row.at(0).and_then(|value| value.to_string()) {
+                Some(grant) => grant.to_uppercase(),
+                None => continue,
+            };
+
+            // Each row has the shape `GRANT <privilege list> ON <scope> TO <grantee>`.
+            let (granted, scope) = match grant.strip_prefix("GRANT ").and_then(|rest| rest.split_once(" ON ")) {
+                Some((granted, rest)) => (
+                    granted.split(", ").collect::<Vec<_>>(),
+                    rest.split(" TO ").next().unwrap_or("").trim().to_owned(),
+                ),
+                None => continue,
+            };
+
+            let has = |privilege: &str| granted.contains(&"ALL PRIVILEGES") || granted.contains(&privilege);
+
+            privileges.create_tables = privileges.create_tables.map(|p| p || has("CREATE"));
+            privileges.alter_tables = privileges.alter_tables.map(|p| p || has("ALTER"));
+            privileges.drop_tables = privileges.drop_tables.map(|p| p || has("DROP"));
+            privileges.references = privileges.references.map(|p| p || has("REFERENCES"));
+
+            // Creating the shadow database needs a global CREATE grant.
+            if scope == "*.*" {
+                privileges.create_databases = privileges.create_databases.map(|p| p || has("CREATE"));
+            }
+        }
+
+        Ok(privileges)
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         if self.is_vitess() {
             return Err(ConnectorError::from_msg(
@@ -468,6 +554,18 @@ fn check_datamodel_for_mysql_5_6(datamodel: &Datamodel, errors: &mut Vec<String>
     });
 }
 
+fn check_datamodel_for_vitess(datamodel: &Datamodel, errors: &mut Vec<String>) {
+    // Vitess requires every table to have a primary key.
+    for model in datamodel.models().filter(|model| !model.is_ignored) {
+        if model.primary_key.is_none() {
+            errors.push(format!(
+                "The model `{}` has no `@id` or `@@id` defined. Vitess requires a primary key on every table.",
+                model.name
+            ))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
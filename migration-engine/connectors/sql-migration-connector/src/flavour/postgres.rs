@@ -5,8 +5,10 @@ use crate::{
 };
 use datamodel::common::preview_features::PreviewFeature;
 use enumflags2::BitFlags;
-use indoc::indoc;
-use migration_connector::{migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult};
+use indoc::formatdoc;
+use migration_connector::{
+    migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult, DatabasePrivileges,
+};
 use quaint::connector::{tokio_postgres::error::ErrorPosition, PostgresUrl};
 use sql_schema_describer::SqlSchema;
 use std::collections::HashMap;
@@ -22,6 +24,7 @@ const ADVISORY_LOCK_TIMEOUT: std::time::Duration = std::time::Duration::from_sec
 pub(crate) struct PostgresFlavour {
     url: PostgresUrl,
     preview_features: BitFlags<PreviewFeature>,
+    migrations_table_name: Option<String>,
 }
 
 impl std::fmt::Debug for PostgresFlavour {
@@ -32,7 +35,11 @@ impl std::fmt::Debug for PostgresFlavour {
 
 impl PostgresFlavour {
     pub fn new(url: PostgresUrl, preview_features: BitFlags<PreviewFeature>) -> Self {
-        Self { url, preview_features }
+        Self {
+            url,
+            preview_features,
+            migrations_table_name: None,
+        }
     }
 
     pub(crate) fn schema_name(&self) -> &str {
@@ -92,6 +99,14 @@ impl PostgresFlavour {
 
 #[async_trait::async_trait]
 impl SqlFlavour for PostgresFlavour {
+    fn custom_migrations_table_name(&self) -> Option<&str> {
+        self.migrations_table_name.as_deref()
+    }
+
+    fn set_migrations_table_name(&mut self, name: String) {
+        self.migrations_table_name = Some(name);
+    }
+
     async fn acquire_lock(&self, connection: &Connection) -> ConnectorResult<()> {
         // https://www.postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
 
@@ -244,10 +259,10 @@ impl SqlFlavour for PostgresFlavour {
     }
 
     async fn create_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = indoc! {r#"
-            CREATE TABLE _prisma_migrations (
+        let sql = formatdoc! {r#"
+            CREATE TABLE "{}" (
                 id                      VARCHAR(36) PRIMARY KEY NOT NULL,
-                checksum                VARCHAR(64) NOT NULL,
+                checksum                VARCHAR(96) NOT NULL,
                 finished_at             TIMESTAMPTZ,
                 migration_name          VARCHAR(255) NOT NULL,
                 logs                    TEXT,
@@ -255,9 +270,18 @@ impl SqlFlavour for PostgresFlavour {
                 started_at              TIMESTAMPTZ NOT NULL DEFAULT now(),
                 applied_steps_count     INTEGER NOT NULL DEFAULT 0
             );
-        "#};
+        "#, self.migrations_table_name()};
 
-        Ok(connection.raw_cmd(sql).await?)
+        Ok(connection.raw_cmd(&sql).await?)
+    }
+
+    async fn widen_migrations_checksum_column(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = format!(
+            r#"ALTER TABLE "{}" ALTER COLUMN checksum TYPE VARCHAR(96)"#,
+            self.migrations_table_name()
+        );
+
+        Ok(connection.raw_cmd(&sql).await?)
     }
 
     async fn drop_database(&self, database_str: &str) -> ConnectorResult<()> {
@@ -274,7 +298,9 @@ impl SqlFlavour for PostgresFlavour {
     }
 
     async fn drop_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        connection.raw_cmd("DROP TABLE _prisma_migrations").await?;
+        connection
+            .raw_cmd(&format!("DROP TABLE \"{}\"", self.migrations_table_name()))
+            .await?;
 
         Ok(())
     }
@@ -308,6 +334,30 @@ impl SqlFlavour for PostgresFlavour {
         Ok(())
     }
 
+    async fn database_privileges(&self, connection: &Connection) -> ConnectorResult<DatabasePrivileges> {
+        let result_set = connection
+            .query_raw(
+                "SELECT has_schema_privilege(current_schema(), 'CREATE'), (SELECT rolcreatedb OR rolsuper FROM pg_roles WHERE rolname = current_user)",
+                &[],
+            )
+            .await?;
+
+        let row = result_set.into_single().ok();
+        let create_tables = row.as_ref().and_then(|row| row.at(0).and_then(|value| value.as_bool()));
+        let create_databases = row.as_ref().and_then(|row| row.at(1).and_then(|value| value.as_bool()));
+
+        // Altering and dropping a table, as well as referencing it from a
+        // foreign key, is tied to table ownership on PostgreSQL, so it cannot
+        // be answered for the connection as a whole.
+        Ok(DatabasePrivileges {
+            create_tables,
+            alter_tables: None,
+            drop_tables: None,
+            references: None,
+            create_databases,
+        })
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let schema_name = connection.connection_info().schema_name();
 
@@ -5,8 +5,10 @@ use crate::{
 };
 use datamodel::common::preview_features::PreviewFeature;
 use enumflags2::BitFlags;
-use indoc::indoc;
-use migration_connector::{migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult};
+use indoc::formatdoc;
+use migration_connector::{
+    migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult, DatabasePrivileges,
+};
 use quaint::prelude::ConnectionInfo;
 use sql_schema_describer::SqlSchema;
 use std::path::Path;
@@ -16,10 +18,19 @@ pub(crate) struct SqliteFlavour {
     pub(super) file_path: String,
     pub(super) attached_name: String,
     pub(super) preview_features: BitFlags<PreviewFeature>,
+    pub(super) migrations_table_name: Option<String>,
 }
 
 #[async_trait::async_trait]
 impl SqlFlavour for SqliteFlavour {
+    fn custom_migrations_table_name(&self) -> Option<&str> {
+        self.migrations_table_name.as_deref()
+    }
+
+    fn set_migrations_table_name(&mut self, name: String) {
+        self.migrations_table_name = Some(name);
+    }
+
     async fn acquire_lock(&self, connection: &Connection) -> ConnectorResult<()> {
         connection.raw_cmd("PRAGMA main.locking_mode=EXCLUSIVE").await?;
 
@@ -59,8 +70,8 @@ impl SqlFlavour for SqliteFlavour {
     }
 
     async fn create_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = indoc! {r#"
-            CREATE TABLE "_prisma_migrations" (
+        let sql = formatdoc! {r#"
+            CREATE TABLE "{}" (
                 "id"                    TEXT PRIMARY KEY NOT NULL,
                 "checksum"              TEXT NOT NULL,
                 "finished_at"           DATETIME,
@@ -70,9 +81,9 @@ impl SqlFlavour for SqliteFlavour {
                 "started_at"            DATETIME NOT NULL DEFAULT current_timestamp,
                 "applied_steps_count"   INTEGER UNSIGNED NOT NULL DEFAULT 0
             );
-        "#};
+        "#, self.migrations_table_name()};
 
-        Ok(connection.raw_cmd(sql).await?)
+        Ok(connection.raw_cmd(&sql).await?)
     }
 
     async fn drop_database(&self, database_url: &str) -> ConnectorResult<()> {
@@ -90,7 +101,9 @@ impl SqlFlavour for SqliteFlavour {
     }
 
     async fn drop_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        connection.raw_cmd("DROP TABLE _prisma_migrations").await?;
+        connection
+            .raw_cmd(&format!("DROP TABLE \"{}\"", self.migrations_table_name()))
+            .await?;
 
         Ok(())
     }
@@ -99,6 +112,24 @@ impl SqlFlavour for SqliteFlavour {
         Ok(())
     }
 
+    async fn database_privileges(&self, _connection: &Connection) -> ConnectorResult<DatabasePrivileges> {
+        // SQLite has no user accounts: every schema change is possible as
+        // long as the database file is writable. The shadow database is a
+        // separate temporary file, so creating it does not depend on the main
+        // database file.
+        let writable = std::fs::metadata(&self.file_path)
+            .ok()
+            .map(|metadata| !metadata.permissions().readonly());
+
+        Ok(DatabasePrivileges {
+            create_tables: writable,
+            alter_tables: writable,
+            drop_tables: writable,
+            references: writable,
+            create_databases: Some(true),
+        })
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let connection_info = connection.connection_info();
         let file_path = connection_info.file_path().unwrap();
@@ -27,6 +27,28 @@ use sql_schema_describer::{walkers::SqlSchemaExt, ColumnId, SqlSchema, TableId};
 use std::env;
 use user_facing_errors::KnownError;
 
+/// An external online schema change tool that can apply `ALTER TABLE`s
+/// without locking the table, configured through the `onlineSchemaChangeTool`
+/// datasource property. Only supported on MySQL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnlineSchemaChangeTool {
+    /// GitHub's gh-ost.
+    GhOst,
+    /// pt-online-schema-change, from the Percona toolkit.
+    PtOsc,
+}
+
+impl OnlineSchemaChangeTool {
+    /// Parse the value of the `onlineSchemaChangeTool` datasource property.
+    pub fn parse(tool: &str) -> Option<Self> {
+        match tool {
+            "gh-ost" => Some(OnlineSchemaChangeTool::GhOst),
+            "pt-osc" => Some(OnlineSchemaChangeTool::PtOsc),
+            _ => None,
+        }
+    }
+}
+
 /// The top-level SQL migration connector.
 pub struct SqlMigrationConnector {
     connection: tokio::sync::OnceCell<ConnectorResult<Connection>>,
@@ -35,6 +57,8 @@ pub struct SqlMigrationConnector {
     flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
     shadow_database_connection_string: Option<String>,
     preview_features: BitFlags<PreviewFeature>,
+    progress_handler: Option<ProgressHandler>,
+    cancellation_token: CancellationToken,
 }
 
 impl SqlMigrationConnector {
@@ -58,6 +82,8 @@ impl SqlMigrationConnector {
             flavour,
             shadow_database_connection_string,
             preview_features,
+            progress_handler: None,
+            cancellation_token: CancellationToken::new(),
         })
     }
 
@@ -79,6 +105,38 @@ impl SqlMigrationConnector {
         self.flavour.as_ref()
     }
 
+    /// Use the datasource-configured migrations table name instead of the
+    /// `_prisma_migrations` default.
+    pub fn set_migrations_table_name(&mut self, name: String) {
+        self.flavour.set_migrations_table_name(name);
+    }
+
+    /// Render `ALTER TABLE` steps in migration scripts as invocations of the
+    /// configured online schema change tool instead of plain SQL. Only has an
+    /// effect on MySQL.
+    pub fn set_online_schema_change_tool(&mut self, tool: OnlineSchemaChangeTool) {
+        self.flavour.set_online_schema_change_tool(tool);
+    }
+
+    /// Report progress on a long-running operation, if a handler is installed.
+    pub(crate) fn emit_progress(&self, event: ProgressEvent) {
+        if let Some(handler) = &self.progress_handler {
+            handler(event)
+        }
+    }
+
+    /// Error out with a "cancelled" error if cancellation of the current
+    /// command was requested. To be called at points where stopping is safe.
+    pub(crate) fn check_cancellation(&self) -> ConnectorResult<()> {
+        if self.cancellation_token.is_cancelled() {
+            return Err(ConnectorError::user_facing(
+                user_facing_errors::migration_engine::CommandCancelled,
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Made public for tests.
     pub async fn describe_schema(&self) -> ConnectorResult<SqlSchema> {
         self.conn().await?.describe_schema(self.preview_features).await
@@ -131,7 +189,11 @@ impl SqlMigrationConnector {
             steps,
         };
 
-        if migration.before.table_walker("_prisma_migrations").is_some() {
+        if migration
+            .before
+            .table_walker(self.flavour.migrations_table_name())
+            .is_some()
+        {
             self.flavour.drop_migrations_table(connection).await?;
         }
 
@@ -240,6 +302,11 @@ impl MigrationConnector for SqlMigrationConnector {
             .unwrap_or_else(|| "Database version information not available.".into()))
     }
 
+    async fn database_privileges(&self) -> ConnectorResult<DatabasePrivileges> {
+        let conn = self.conn().await?;
+        self.flavour().database_privileges(conn).await
+    }
+
     async fn create_database(&self) -> ConnectorResult<String> {
         self.flavour.create_database(&self.connection_string).await
     }
@@ -251,32 +318,35 @@ impl MigrationConnector for SqlMigrationConnector {
         let steps =
             sql_schema_differ::calculate_steps(Pair::new(&previous_schema, &next_schema), self.flavour.as_ref());
 
-        let added_columns_with_virtual_defaults: Vec<(TableId, ColumnId)> =
-            if let Some((_, next_datamodel)) = to.as_datamodel() {
-                walk_added_columns(&steps)
-                    .map(|(table_index, column_index)| {
-                        let table = next_schema.table_walker_at(table_index);
-                        let column = table.column_at(column_index);
-
-                        (table, column)
-                    })
-                    .filter(|(table, column)| {
-                        walk_models(next_datamodel)
-                            .find(|model| model.database_name() == table.name())
-                            .and_then(|model| model.find_scalar_field(column.name()))
-                            .filter(|field| {
-                                field
-                                    .default_value()
-                                    .map(|default| default.is_uuid() || default.is_cuid())
-                                    .unwrap_or(false)
-                            })
-                            .is_some()
-                    })
-                    .map(move |(table, column)| (table.table_id(), column.column_id()))
-                    .collect()
-            } else {
-                Vec::new()
-            };
+        let added_columns_with_virtual_defaults: Vec<(TableId, ColumnId)> = if let Some((_, next_datamodel)) =
+            to.as_datamodel()
+        {
+            walk_added_columns(&steps)
+                .map(|(table_index, column_index)| {
+                    let table = next_schema.table_walker_at(table_index);
+                    let column = table.column_at(column_index);
+
+                    (table, column)
+                })
+                .filter(|(table, column)| {
+                    walk_models(next_datamodel)
+                        .find(|model| model.database_name() == table.name())
+                        .and_then(|model| model.find_scalar_field(column.name()))
+                        .filter(|field| {
+                            field
+                                .default_value()
+                                .map(|default| {
+                                    default.is_uuid() || default.is_cuid() || default.is_cuid2() || default.is_nanoid()
+                                })
+                                .unwrap_or(false)
+                        })
+                        .is_some()
+                })
+                .map(move |(table, column)| (table.table_id(), column.column_id()))
+                .collect()
+        } else {
+            Vec::new()
+        };
 
         Ok(Migration::new(SqlMigration {
             before: previous_schema,
@@ -307,10 +377,22 @@ impl MigrationConnector for SqlMigrationConnector {
         Ok(())
     }
 
+    fn set_progress_handler(&mut self, handler: ProgressHandler) {
+        self.progress_handler = Some(handler);
+    }
+
+    fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation_token = token;
+    }
+
     fn migration_summary(&self, migration: &Migration) -> String {
         migration.downcast_ref::<SqlMigration>().drift_summary()
     }
 
+    fn migration_drift_document(&self, migration: &Migration) -> DriftDocument {
+        migration.downcast_ref::<SqlMigration>().drift_document()
+    }
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
@@ -1,9 +1,11 @@
 use crate::{
     pair::Pair,
     sql_migration::{SqlMigration, SqlMigrationStep},
-    SqlFlavour, SqlMigrationConnector,
+    OnlineSchemaChangeTool, SqlFlavour, SqlMigrationConnector,
+};
+use migration_connector::{
+    ConnectorResult, DatabaseMigrationStepApplier, DestructiveChangeDiagnostics, Migration, ProgressEvent,
 };
-use migration_connector::{ConnectorResult, DatabaseMigrationStepApplier, DestructiveChangeDiagnostics, Migration};
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 
 #[async_trait::async_trait]
@@ -13,13 +15,28 @@ impl DatabaseMigrationStepApplier for SqlMigrationConnector {
         let migration: &SqlMigration = migration.downcast_ref();
         tracing::debug!("{} steps to execute", migration.steps.len());
         let conn = self.conn().await?;
+        let started_at = std::time::Instant::now();
+        let mut statement_count = 0;
 
         for (index, step) in migration.steps.iter().enumerate() {
             for sql_string in render_raw_sql(step, self.flavour(), Pair::new(&migration.before, &migration.after)) {
+                // Stopping before a statement is safe: the statements that
+                // already ran each committed on their own, and nothing is left
+                // running in the background.
+                self.check_cancellation()?;
+
                 assert!(!sql_string.is_empty());
                 tracing::debug!(index, %sql_string);
                 self.flavour().run_query_script(&sql_string, conn).await?;
+                statement_count += 1;
             }
+
+            self.emit_progress(ProgressEvent {
+                step_index: index,
+                step_count: migration.steps.len(),
+                statement_count,
+                elapsed_ms: started_at.elapsed().as_millis() as u64,
+            });
         }
 
         Ok(migration.steps.len() as u32)
@@ -80,11 +97,46 @@ impl DatabaseMigrationStepApplier for SqlMigrationConnector {
                 // edit the migration.
                 script.push_str("-- ");
                 script.push_str(step.description());
+
+                // Annotate the steps that are not safe to apply while the previous version of
+                // the application is still running, so expand/contract deployments can split
+                // the script mechanically. Unannotated steps are expand steps.
+                if step.is_contract_step() {
+                    script.push_str(" (contract)");
+                }
+
                 script.push('\n');
 
-                for statement in statements {
-                    script.push_str(&statement);
-                    script.push_str(";\n");
+                // When an online schema change tool is configured, `ALTER
+                // TABLE`s are rendered as invocations of the tool instead of
+                // plain SQL, because a direct ALTER locks the table for its
+                // whole duration. The invocations are SQL comments, so the
+                // script still applies cleanly — the alteration itself is
+                // expected to be run through the tool, out of band.
+                let online_schema_change_tool = match step {
+                    SqlMigrationStep::AlterTable(alter_table) => self
+                        .flavour()
+                        .online_schema_change_tool()
+                        .map(|tool| (tool, alter_table)),
+                    _ => None,
+                };
+
+                if let Some((tool, alter_table)) = online_schema_change_tool {
+                    let table_name = Pair::new(&migration.before, &migration.after)
+                        .tables(&alter_table.table_ids)
+                        .previous()
+                        .name()
+                        .to_owned();
+
+                    for statement in statements {
+                        script.push_str(&render_online_schema_change(tool, &table_name, &statement));
+                        script.push('\n');
+                    }
+                } else {
+                    for statement in statements {
+                        script.push_str(&statement);
+                        script.push_str(";\n");
+                    }
                 }
             }
         }
@@ -106,6 +158,32 @@ impl DatabaseMigrationStepApplier for SqlMigrationConnector {
     }
 }
 
+/// Render an `ALTER TABLE` statement as an invocation of the configured
+/// online schema change tool, commented out. The user is expected to complete
+/// the invocation with their connection flags.
+fn render_online_schema_change(tool: OnlineSchemaChangeTool, table_name: &str, statement: &str) -> String {
+    // The MySQL renderer produces `ALTER TABLE `<name>` <changes>` — the
+    // tools only want the `<changes>` fragment, on a single line.
+    let fragment = statement
+        .strip_prefix(&format!("ALTER TABLE `{}` ", table_name))
+        .unwrap_or(statement)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('"', "\\\"");
+
+    match tool {
+        OnlineSchemaChangeTool::GhOst => format!(
+            "-- gh-ost --table \"{}\" --alter \"{}\" --execute",
+            table_name, fragment
+        ),
+        OnlineSchemaChangeTool::PtOsc => format!(
+            "-- pt-online-schema-change --alter \"{}\" t={} --execute",
+            fragment, table_name
+        ),
+    }
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
@@ -3,11 +3,15 @@ use crate::{
     sql_renderer::IteratorJoin,
     sql_schema_differ::{ColumnChange, ColumnChanges},
 };
+use migration_connector::{ChangedColumn, ChangedEnum, ChangedTable, ColumnAttributes, DriftDocument};
 use sql_schema_describer::{
-    walkers::{ColumnWalker, SqlSchemaExt},
+    walkers::{ColumnWalker, ForeignKeyWalker, SqlSchemaExt},
     ColumnId, SqlSchema, TableId,
 };
-use std::{collections::BTreeSet, fmt::Write as _};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+};
 
 /// The database migration type for SqlMigrationConnector.
 #[derive(Debug)]
@@ -383,6 +387,180 @@ impl SqlMigration {
 
         out
     }
+
+    /// The structured counterpart of [`drift_summary()`](Self::drift_summary),
+    /// for machine consumption.
+    pub(crate) fn drift_document(&self) -> DriftDocument {
+        let mut doc = DriftDocument::default();
+        let mut changed_tables: BTreeMap<String, ChangedTable> = BTreeMap::new();
+
+        fn changed_table<'a>(tables: &'a mut BTreeMap<String, ChangedTable>, name: &str) -> &'a mut ChangedTable {
+            tables.entry(name.to_owned()).or_insert_with(|| ChangedTable {
+                name: name.to_owned(),
+                ..Default::default()
+            })
+        }
+
+        for step in &self.steps {
+            match step {
+                SqlMigrationStep::DropView(drop_view) => {
+                    doc.removed_views.push(
+                        self.schemas()
+                            .previous()
+                            .view_walker_at(drop_view.view_index)
+                            .name()
+                            .to_owned(),
+                    );
+                }
+                SqlMigrationStep::DropUserDefinedType(drop_udt) => {
+                    doc.removed_user_defined_types.push(
+                        self.schemas()
+                            .previous()
+                            .udt_walker_at(drop_udt.udt_index)
+                            .name()
+                            .to_owned(),
+                    );
+                }
+                SqlMigrationStep::CreateEnum { enum_index } => {
+                    doc.added_enums
+                        .push(self.schemas().next().enum_walker_at(*enum_index).name().to_owned());
+                }
+                SqlMigrationStep::DropEnum { enum_index } => {
+                    doc.removed_enums
+                        .push(self.schemas().previous().enum_walker_at(*enum_index).name().to_owned());
+                }
+                SqlMigrationStep::AlterEnum(alter_enum) => {
+                    doc.changed_enums.push(ChangedEnum {
+                        name: self.schemas().enums(&alter_enum.index).previous().name().to_owned(),
+                        added_variants: alter_enum.created_variants.clone(),
+                        removed_variants: alter_enum.dropped_variants.clone(),
+                    });
+                }
+                SqlMigrationStep::CreateTable { table_id } => {
+                    doc.added_tables
+                        .push(self.schemas().next().table_walker_at(*table_id).name().to_owned());
+                }
+                SqlMigrationStep::DropTable { table_id } => {
+                    doc.removed_tables
+                        .push(self.schemas().previous().table_walker_at(*table_id).name().to_owned());
+                }
+                SqlMigrationStep::RedefineTables(redefines) => {
+                    for redefine in redefines {
+                        doc.redefined_tables
+                            .push(self.schemas().tables(&redefine.table_ids).previous().name().to_owned());
+                    }
+                }
+                SqlMigrationStep::CreateIndex {
+                    table_id: (_, table_id),
+                    index_index,
+                } => {
+                    let table = self.schemas().next().table_walker_at(*table_id);
+                    changed_table(&mut changed_tables, table.name())
+                        .added_indexes
+                        .push(table.index_at(*index_index).name().to_owned());
+                }
+                SqlMigrationStep::DropIndex { table_id, index_index } => {
+                    let table = self.schemas().previous().table_walker_at(*table_id);
+                    changed_table(&mut changed_tables, table.name())
+                        .removed_indexes
+                        .push(table.index_at(*index_index).name().to_owned());
+                }
+                SqlMigrationStep::RenameIndex { table, index } | SqlMigrationStep::RedefineIndex { table, index } => {
+                    let tables = self.schemas().tables(table);
+                    changed_table(&mut changed_tables, tables.previous().name())
+                        .changed_indexes
+                        .push(tables.indexes(index).previous().name().to_owned());
+                }
+                SqlMigrationStep::AddForeignKey {
+                    table_id,
+                    foreign_key_index,
+                } => {
+                    let table = self.schemas().next().table_walker_at(*table_id);
+                    changed_table(&mut changed_tables, table.name())
+                        .added_foreign_keys
+                        .push(describe_foreign_key(&table.foreign_key_at(*foreign_key_index)));
+                }
+                SqlMigrationStep::DropForeignKey {
+                    table_id,
+                    foreign_key_index,
+                } => {
+                    let table = self.schemas().previous().table_walker_at(*table_id);
+                    changed_table(&mut changed_tables, table.name())
+                        .removed_foreign_keys
+                        .push(describe_foreign_key(&table.foreign_key_at(*foreign_key_index)));
+                }
+                SqlMigrationStep::RenameForeignKey {
+                    table_id,
+                    foreign_key_id,
+                } => {
+                    let tables = self.schemas().tables(table_id);
+                    let fks = tables.foreign_keys(foreign_key_id);
+                    let table = changed_table(&mut changed_tables, tables.previous().name());
+                    table.removed_foreign_keys.push(describe_foreign_key(fks.previous()));
+                    table.added_foreign_keys.push(describe_foreign_key(fks.next()));
+                }
+                SqlMigrationStep::AlterTable(alter_table) => {
+                    let tables = self.schemas().tables(&alter_table.table_ids);
+                    let table = changed_table(&mut changed_tables, tables.previous().name());
+
+                    for change in &alter_table.changes {
+                        match change {
+                            TableChange::AddColumn { column_id } => {
+                                table
+                                    .added_columns
+                                    .push(tables.next().column_at(*column_id).name().to_owned());
+                            }
+                            TableChange::DropColumn { column_id } => {
+                                table
+                                    .removed_columns
+                                    .push(tables.previous().column_at(*column_id).name().to_owned());
+                            }
+                            TableChange::AlterColumn(AlterColumn { column_id, .. })
+                            | TableChange::DropAndRecreateColumn { column_id, .. } => {
+                                let columns = tables.columns(column_id);
+                                table.changed_columns.push(ChangedColumn {
+                                    name: columns.previous().name().to_owned(),
+                                    before: column_attributes(columns.previous()),
+                                    after: column_attributes(columns.next()),
+                                });
+                            }
+                            TableChange::DropPrimaryKey
+                            | TableChange::AddPrimaryKey
+                            | TableChange::RenamePrimaryKey => {
+                                table.primary_key_changed = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        doc.changed_tables = changed_tables.into_values().collect();
+
+        doc
+    }
+}
+
+/// Identify a foreign key by its constraint name when it has one, by its
+/// constrained columns otherwise.
+fn describe_foreign_key(foreign_key: &ForeignKeyWalker<'_>) -> String {
+    foreign_key
+        .constraint_name()
+        .map(ToOwned::to_owned)
+        .unwrap_or_else(|| format!("on ({})", foreign_key.constrained_column_names().join(", ")))
+}
+
+fn column_attributes(column: &ColumnWalker<'_>) -> ColumnAttributes {
+    let column_type = column.column_type();
+
+    ColumnAttributes {
+        data_type: if column_type.full_data_type.is_empty() {
+            format!("{:?}", column_type.family)
+        } else {
+            column_type.full_data_type.clone()
+        },
+        arity: format!("{:?}", column_type.arity),
+    }
 }
 
 fn render_column_changes(columns: Pair<ColumnWalker<'_>>, changes: &ColumnChanges, sink: &mut String) {
@@ -513,6 +691,46 @@ impl SqlMigrationStep {
             SqlMigrationStep::RenameIndex { .. } => "RenameIndex",
         }
     }
+
+    /// Whether the step belongs to the contract phase of an expand-and-contract deployment.
+    ///
+    /// Expand steps only add schema and are safe to apply while the previous version of the
+    /// application is still running. Contract steps remove or change schema the previous
+    /// version may still rely on, so they should only run once the new application version is
+    /// deployed. Steps not flagged here are expand steps.
+    pub(crate) fn is_contract_step(&self) -> bool {
+        match self {
+            SqlMigrationStep::CreateEnum { .. }
+            | SqlMigrationStep::CreateIndex { .. }
+            | SqlMigrationStep::CreateTable { .. }
+            | SqlMigrationStep::AddForeignKey { .. }
+            | SqlMigrationStep::RenameForeignKey { .. }
+            // Renaming or redefining an index keeps an equivalent index around, which is all
+            // the running application can depend on.
+            | SqlMigrationStep::RenameIndex { .. }
+            | SqlMigrationStep::RedefineIndex { .. } => false,
+
+            SqlMigrationStep::DropEnum { .. }
+            | SqlMigrationStep::DropForeignKey { .. }
+            | SqlMigrationStep::DropIndex { .. }
+            | SqlMigrationStep::DropTable { .. }
+            | SqlMigrationStep::DropUserDefinedType(_)
+            | SqlMigrationStep::DropView(_)
+            // Redefinitions rewrite the whole table, including dropped and changed columns.
+            | SqlMigrationStep::RedefineTables(_) => true,
+
+            SqlMigrationStep::AlterEnum(alter_enum) => !alter_enum.dropped_variants.is_empty(),
+            SqlMigrationStep::AlterTable(alter_table) => {
+                alter_table.changes.iter().any(|change| match change {
+                    TableChange::AddColumn { .. } | TableChange::AddPrimaryKey | TableChange::RenamePrimaryKey => false,
+                    TableChange::AlterColumn(_)
+                    | TableChange::DropColumn { .. }
+                    | TableChange::DropAndRecreateColumn { .. }
+                    | TableChange::DropPrimaryKey => true,
+                })
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
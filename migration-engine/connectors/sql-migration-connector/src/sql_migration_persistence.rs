@@ -3,6 +3,7 @@ use migration_connector::{
     ConnectorError, ConnectorResult, MigrationPersistence, MigrationRecord, PersistenceNotInitializedError,
 };
 use quaint::ast::*;
+use sql_schema_describer::Table;
 use uuid::Uuid;
 
 #[async_trait::async_trait]
@@ -17,11 +18,21 @@ impl MigrationPersistence for SqlMigrationConnector {
     async fn initialize(&self) -> ConnectorResult<()> {
         let schema = self.describe_schema().await?;
 
-        if schema
+        if let Some(table) = schema
             .tables
             .iter()
-            .any(|table| table.name == self.flavour().migrations_table_name())
+            .find(|table| table.name == self.flavour().migrations_table_name())
         {
+            // Tables created by older engines have a `checksum` column too
+            // narrow for the current, algorithm-prefixed checksum format.
+            // Widen it before we record any new migration, but only when the
+            // described column is actually narrower - re-altering on every run
+            // would require DDL rights the migration user may not have.
+            if checksum_column_width(table).map(|width| width < 96).unwrap_or(false) {
+                let conn = self.conn().await?;
+                self.flavour.widen_migrations_checksum_column(conn).await?;
+            }
+
             return Ok(());
         }
 
@@ -114,6 +125,22 @@ impl MigrationPersistence for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn rebaseline_migration_checksum_impl(
+        &self,
+        id: &str,
+        checksum: &str,
+        audit_log: &str,
+    ) -> ConnectorResult<()> {
+        let update = Update::table(self.flavour().migrations_table())
+            .so_that(Column::from("id").equals(id))
+            .set("checksum", checksum)
+            .set("logs", audit_log);
+
+        self.conn().await?.query(update).await?;
+
+        Ok(())
+    }
+
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()> {
         let update = Update::table(self.flavour().migrations_table())
             .so_that(Column::from("id").equals(id))
@@ -183,3 +210,22 @@ impl MigrationPersistence for SqlMigrationConnector {
         Ok(Ok(rows))
     }
 }
+
+/// The declared character length of the `checksum` column, `None` when it has no
+/// limit. The length only surfaces in the native type parameters: `{"VarChar": 64}`
+/// on Postgres and MySQL, `{"VarChar": {"Number": 64}}` on MSSQL. SQLite stores the
+/// checksum as unlimited TEXT and has no native types at all.
+fn checksum_column_width(table: &Table) -> Option<u64> {
+    fn first_number(value: &serde_json::Value) -> Option<u64> {
+        match value {
+            serde_json::Value::Number(number) => number.as_u64(),
+            serde_json::Value::Array(values) => values.iter().find_map(first_number),
+            serde_json::Value::Object(fields) => fields.values().find_map(first_number),
+            _ => None,
+        }
+    }
+
+    let column = table.columns.iter().find(|column| column.name == "checksum")?;
+
+    column.tpe.native_type.as_ref().and_then(first_number)
+}
@@ -3,61 +3,10 @@ use std::fmt::{Display, Write as _};
 
 pub(super) const SQL_INDENTATION: &str = "    ";
 
-#[derive(Debug)]
-pub(crate) enum Quoted<T> {
-    Double(T),
-    Single(T),
-    Backticks(T),
-    SquareBrackets(T),
-}
-
-impl<T> Quoted<T> {
-    pub(crate) fn mssql_string(contents: T) -> Quoted<T> {
-        Quoted::Single(contents)
-    }
-
-    pub(crate) fn mysql_string(contents: T) -> Quoted<T> {
-        Quoted::Single(contents)
-    }
-
-    pub(crate) fn mysql_ident(name: T) -> Quoted<T> {
-        Quoted::Backticks(name)
-    }
-
-    pub(crate) fn postgres_string(contents: T) -> Quoted<T> {
-        Quoted::Single(contents)
-    }
-
-    pub(crate) fn postgres_ident(name: T) -> Quoted<T> {
-        Quoted::Double(name)
-    }
-
-    pub(crate) fn sqlite_ident(name: T) -> Quoted<T> {
-        Quoted::Double(name)
-    }
-
-    pub(crate) fn sqlite_string(name: T) -> Quoted<T> {
-        Quoted::Single(name)
-    }
-
-    pub(crate) fn mssql_ident(name: T) -> Quoted<T> {
-        Quoted::SquareBrackets(name)
-    }
-}
-
-impl<T> Display for Quoted<T>
-where
-    T: Display,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Quoted::Double(inner) => write!(f, "\"{}\"", inner),
-            Quoted::Single(inner) => write!(f, "'{}'", inner),
-            Quoted::Backticks(inner) => write!(f, "`{}`", inner),
-            Quoted::SquareBrackets(inner) => write!(f, "[{}]", inner),
-        }
-    }
-}
+/// Flavour-aware identifier and string literal quoting, shared with the DDL
+/// rendering in the `sql_ddl` crate. The identifier constructors escape
+/// embedded delimiters.
+pub(crate) use sql_ddl::Quoted;
 
 pub(crate) fn render_nullability(column: &ColumnWalker<'_>) -> &'static str {
     if column.arity().is_required() {
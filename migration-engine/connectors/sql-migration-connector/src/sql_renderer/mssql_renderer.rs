@@ -150,12 +150,26 @@ impl SqlRenderer for MssqlFlavour {
             rendered
         });
 
+        // SQL Server considers NULLs as equal in unique indexes. A filtered index over the
+        // non-NULL rows gives us the multiple-NULLs semantics of the other connectors.
+        let filter = if index.index_type().is_unique() && index_has_nullable_columns(index) {
+            let conditions = index
+                .columns()
+                .filter(|c| c.get().arity().is_nullable())
+                .map(|c| format!("{} IS NOT NULL", self.quote(c.get().name())));
+
+            format!(" WHERE {}", conditions.join(" AND "))
+        } else {
+            String::new()
+        };
+
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns}){filter}",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
             columns = columns.join(", "),
+            filter = filter,
         )
     }
 
@@ -190,9 +204,11 @@ impl SqlRenderer for MssqlFlavour {
             String::new()
         };
 
+        // Uniques over nullable columns are rendered as filtered unique indexes instead, so they
+        // allow more than one row with a NULL value.
         let constraints = table
             .indexes()
-            .filter(|index| index.index_type().is_unique())
+            .filter(|index| index.index_type().is_unique() && !index_has_nullable_columns(index))
             .collect::<Vec<_>>();
 
         let constraints = if !constraints.is_empty() {
@@ -341,8 +357,13 @@ impl SqlRenderer for MssqlFlavour {
             // Rename the temporary table with the name defined in the migration.
             result.push(self.render_rename_table(&temporary_table_name, tables.next().name()));
 
-            // Recreate the indexes.
-            for index in tables.next().indexes().filter(|i| !i.index_type().is_unique()) {
+            // Recreate the indexes. Uniques over required columns are part of the table
+            // definition already.
+            for index in tables
+                .next()
+                .indexes()
+                .filter(|i| !i.index_type().is_unique() || index_has_nullable_columns(i))
+            {
                 result.push(self.render_create_index(&index));
             }
         }
@@ -451,6 +472,10 @@ impl SqlRenderer for MssqlFlavour {
     }
 }
 
+fn index_has_nullable_columns(index: &IndexWalker<'_>) -> bool {
+    index.columns().any(|col| col.get().arity().is_nullable())
+}
+
 fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
     fn format_u32_arg(arg: Option<u32>) -> String {
         match arg {
@@ -14,8 +14,8 @@ use prisma_value::PrismaValue;
 use regex::Regex;
 use sql_ddl::{postgres as ddl, IndexColumn, SortOrder};
 use sql_schema_describer::{
-    walkers::*, ColumnArity, ColumnTypeFamily, DefaultKind, DefaultValue, ForeignKeyAction, SQLIndexAlgorithm,
-    SQLSortOrder, SqlSchema,
+    walkers::*, ColumnArity, ColumnIdentity, ColumnTypeFamily, DefaultKind, DefaultValue, ForeignKeyAction,
+    SQLIndexAlgorithm, SQLSortOrder, SqlSchema,
 };
 use std::borrow::Cow;
 
@@ -24,6 +24,11 @@ impl PostgresFlavour {
         let column_name = self.quote(column.name());
         let tpe_str = render_column_type(column);
         let nullability_str = render_nullability(column);
+        let identity_str = match column.identity() {
+            Some(ColumnIdentity::Always) => " GENERATED ALWAYS AS IDENTITY",
+            Some(ColumnIdentity::ByDefault) => " GENERATED BY DEFAULT AS IDENTITY",
+            None => "",
+        };
         let default_str = column
             .default()
             .map(render_default)
@@ -32,8 +37,8 @@ impl PostgresFlavour {
             .unwrap_or_else(String::new);
 
         format!(
-            "{}{} {}{}{}",
-            SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str
+            "{}{} {}{}{}{}",
+            SQL_INDENTATION, column_name, tpe_str, nullability_str, identity_str, default_str
         )
     }
 }
@@ -374,6 +379,7 @@ impl SqlRenderer for PostgresFlavour {
                     }),
                 })
                 .collect(),
+            nulls_not_distinct: index.nulls_not_distinct(),
         }
         .to_string()
     }
@@ -471,7 +477,9 @@ impl SqlRenderer for PostgresFlavour {
 
 pub(crate) fn render_column_type(col: &ColumnWalker<'_>) -> Cow<'static, str> {
     let t = col.column_type();
-    let is_autoincrement = col.is_autoincrement();
+    // Identity columns keep their plain integer type; only plain autoincrementing columns render
+    // as the `SERIAL` pseudo-types.
+    let is_autoincrement = col.is_autoincrement() && col.identity().is_none();
 
     if let ColumnTypeFamily::Enum(name) = &t.family {
         return format!("\"{}\"{}", name, if t.arity.is_list() { "[]" } else { "" }).into();
@@ -499,6 +507,13 @@ pub(crate) fn render_column_type(col: &ColumnWalker<'_>) -> Cow<'static, str> {
         }
     }
 
+    fn render_geometry(prefix: &str, modifier: Option<(String, u32)>) -> String {
+        match modifier {
+            None => prefix.to_string(),
+            Some((r#type, srid)) => format!("{}({},{})", prefix, r#type, srid),
+        }
+    }
+
     let tpe: Cow<'_, str> = match native_type {
         PostgresType::Citext => "CITEXT".into(),
         PostgresType::Oid => "OID".into(),
@@ -522,11 +537,14 @@ pub(crate) fn render_column_type(col: &ColumnWalker<'_>) -> Cow<'static, str> {
         PostgresType::Timestamptz(precision) => format!("TIMESTAMPTZ{}", render(precision)).into(),
         PostgresType::Time(precision) => format!("TIME{}", render(precision)).into(),
         PostgresType::Timetz(precision) => format!("TIMETZ{}", render(precision)).into(),
+        PostgresType::Interval(precision) => format!("INTERVAL{}", render(precision)).into(),
         PostgresType::Boolean => "BOOLEAN".into(),
         PostgresType::Bit(length) => format!("BIT{}", render(length)).into(),
         PostgresType::VarBit(length) => format!("VARBIT{}", render(length)).into(),
         PostgresType::Uuid => "UUID".into(),
         PostgresType::Xml => "XML".into(),
+        PostgresType::Geometry(modifier) => render_geometry("GEOMETRY", modifier).into(),
+        PostgresType::Geography(modifier) => render_geometry("GEOGRAPHY", modifier).into(),
         PostgresType::Json => "JSON".into(),
         PostgresType::JsonB => "JSONB".into(),
     };
@@ -610,10 +628,28 @@ fn render_alter_column(
                     column_name = column_name,
                 ));
             }
+            PostgresAlterColumn::AddIdentity(identity) => clauses.push(format!(
+                "{} ADD GENERATED {} AS IDENTITY",
+                &alter_column_prefix,
+                render_identity_generation(identity)
+            )),
+            PostgresAlterColumn::SetIdentityGeneration(identity) => clauses.push(format!(
+                "{} SET GENERATED {}",
+                &alter_column_prefix,
+                render_identity_generation(identity)
+            )),
+            PostgresAlterColumn::DropIdentity => clauses.push(format!("{} DROP IDENTITY", &alter_column_prefix)),
         }
     }
 }
 
+fn render_identity_generation(identity: ColumnIdentity) -> &'static str {
+    match identity {
+        ColumnIdentity::Always => "ALWAYS",
+        ColumnIdentity::ByDefault => "BY DEFAULT",
+    }
+}
+
 fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &ColumnChanges) -> Vec<PostgresAlterColumn> {
     let mut changes = Vec::new();
     let mut set_type = false;
@@ -643,15 +679,37 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
                 | (ColumnArity::List, ColumnArity::List) => (),
             },
             ColumnChange::TypeChanged => set_type = true,
-            ColumnChange::Sequence => {
-                if columns.previous().is_autoincrement() {
-                    // The sequence should be dropped.
-                    changes.push(PostgresAlterColumn::DropDefault)
-                } else {
-                    // The sequence should be created.
-                    changes.push(PostgresAlterColumn::AddSequence)
+            ColumnChange::Sequence => match (columns.previous().identity(), columns.next().identity()) {
+                (Some(_), Some(next_identity)) => {
+                    // Only the generation mode changed.
+                    changes.push(PostgresAlterColumn::SetIdentityGeneration(next_identity))
                 }
-            }
+                (Some(_), None) => {
+                    changes.push(PostgresAlterColumn::DropIdentity);
+
+                    if columns.next().is_autoincrement() {
+                        // Identity is replaced by an imitated `SERIAL` sequence.
+                        changes.push(PostgresAlterColumn::AddSequence)
+                    }
+                }
+                (None, Some(next_identity)) => {
+                    if columns.previous().is_autoincrement() {
+                        // The `SERIAL` sequence default is replaced by the identity.
+                        changes.push(PostgresAlterColumn::DropDefault)
+                    }
+
+                    changes.push(PostgresAlterColumn::AddIdentity(next_identity))
+                }
+                (None, None) => {
+                    if columns.previous().is_autoincrement() {
+                        // The sequence should be dropped.
+                        changes.push(PostgresAlterColumn::DropDefault)
+                    } else {
+                        // The sequence should be created.
+                        changes.push(PostgresAlterColumn::AddSequence)
+                    }
+                }
+            },
             ColumnChange::Renaming => unreachable!("column renaming"),
         }
     }
@@ -674,6 +732,12 @@ enum PostgresAlterColumn {
     SetNotNull,
     /// Add an auto-incrementing sequence as a default on the column.
     AddSequence,
+    /// Make the column an identity column (`ADD GENERATED … AS IDENTITY`).
+    AddIdentity(ColumnIdentity),
+    /// Switch the generation mode of an identity column (`SET GENERATED …`).
+    SetIdentityGeneration(ColumnIdentity),
+    /// Remove the identity from the column (`DROP IDENTITY`).
+    DropIdentity,
 }
 
 fn render_default(default: &DefaultValue) -> Cow<'_, str> {
@@ -685,6 +749,21 @@ fn render_default(default: &DefaultValue) -> Cow<'_, str> {
         DefaultKind::Value(PrismaValue::Bytes(b)) => Quoted::postgres_string(format_hex(b)).to_string().into(),
         DefaultKind::Now => "CURRENT_TIMESTAMP".into(),
         DefaultKind::Value(PrismaValue::DateTime(val)) => Quoted::postgres_string(val).to_string().into(),
+        DefaultKind::Value(PrismaValue::List(values)) => {
+            // Only enum list defaults reach the renderer. The untyped array constructor is
+            // coerced to the enum array type of the column.
+            let values = values
+                .iter()
+                .map(|value| match value {
+                    PrismaValue::Enum(val) | PrismaValue::String(val) => {
+                        format!("E'{}'", escape_string_literal(val))
+                    }
+                    other => unreachable!("non-enum value in enum list default: {:?}", other),
+                })
+                .join(", ");
+
+            format!("ARRAY[{}]", values).into()
+        }
         DefaultKind::Value(PrismaValue::Json(json_value)) => {
             let mut out = String::with_capacity(json_value.len() + 2);
             out.push('\'');
@@ -112,6 +112,8 @@ impl SqlRenderer for SqliteFlavour {
             table_name: table_name.into(),
             columns: table.columns().map(|col| render_column(&col)).collect(),
             primary_key: None,
+            without_rowid: table.table().sqlite_options.without_rowid,
+            strict: table.table().sqlite_options.strict,
             foreign_keys: table
                 .foreign_keys()
                 .map(move |fk| sql_ddl::sqlite::ForeignKey {
@@ -305,7 +307,9 @@ fn copy_current_table_into_new_table(
 
 fn render_column<'a>(column: &ColumnWalker<'a>) -> ddl::Column<'a> {
     sql_ddl::sqlite::Column {
-        autoincrement: column.is_single_primary_key() && column.column_type_family().is_int(),
+        autoincrement: column.is_autoincrement()
+            && column.is_single_primary_key()
+            && column.column_type_family().is_int(),
         default: column
             .default()
             .filter(|default| !matches!(default.kind(), DefaultKind::Sequence(_)))
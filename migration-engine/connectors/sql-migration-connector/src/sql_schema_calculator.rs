@@ -24,17 +24,76 @@ pub(crate) fn calculate_sql_schema(
     let mut relation_tables: Vec<_> = calculate_relation_tables(configuration, datamodel, flavour, &schema).collect();
     schema.tables.append(&mut relation_tables);
 
+    if configuration.uses_identity_autoincrement() {
+        // The datasource opted into identity columns (PostgreSQL 10+): autoincrementing columns
+        // become `GENERATED BY DEFAULT AS IDENTITY` instead of `SERIAL` types.
+        for column in schema.tables.iter_mut().flat_map(|table| table.columns.iter_mut()) {
+            if column.auto_increment {
+                column.identity = Some(sql::ColumnIdentity::ByDefault);
+            }
+        }
+    }
+
     let referential_integrity = configuration.referential_integrity().unwrap_or_default();
 
     if !referential_integrity.uses_foreign_keys() {
+        // Without foreign keys, the indexes the database creates along with them are missing as
+        // well, so every relation traversal would scan the whole table. Create companion indexes
+        // over the referencing columns instead, unless the user already covers them with an index
+        // of their own or opted out via `PRISMA_MIGRATE_SKIP_RELATION_INDEXES`.
+        let create_companion_indexes = std::env::var_os("PRISMA_MIGRATE_SKIP_RELATION_INDEXES").is_none();
+        let max_identifier_length = configuration.max_identifier_length();
+
         for table in &mut schema.tables {
-            table.foreign_keys.clear();
+            for fk in std::mem::take(&mut table.foreign_keys) {
+                if !create_companion_indexes || columns_covered_by_existing_index(table, &fk.columns) {
+                    continue;
+                }
+
+                table.indices.push(sql::Index {
+                    name: companion_index_name(&table.name, &fk.columns, max_identifier_length),
+                    columns: fk.columns.into_iter().map(sql::IndexColumn::new).collect(),
+                    tpe: sql::IndexType::Normal,
+                    algorithm: None,
+                    nulls_not_distinct: false,
+                });
+            }
         }
     }
 
     schema
 }
 
+/// A leftmost prefix of the primary key or of any existing index makes a companion index for the
+/// relation columns redundant.
+fn columns_covered_by_existing_index(table: &sql::Table, columns: &[String]) -> bool {
+    let covered_by_pk = table.primary_key.as_ref().map(|pk| {
+        pk.columns.len() >= columns.len() && pk.columns.iter().zip(columns).all(|(pk_col, col)| &pk_col.name == col)
+    });
+
+    covered_by_pk.unwrap_or(false)
+        || table.indices.iter().any(|index| {
+            index.columns.len() >= columns.len()
+                && index
+                    .columns
+                    .iter()
+                    .zip(columns)
+                    .all(|(idx_col, col)| &idx_col.name == col)
+        })
+}
+
+fn companion_index_name(table_name: &str, columns: &[String], max_identifier_length: usize) -> String {
+    const INDEX_SUFFIX: &str = "_idx";
+
+    let mut name: String = format!("{}_{}", table_name, columns.join("_"))
+        .chars()
+        .take(max_identifier_length - INDEX_SUFFIX.len())
+        .collect();
+
+    name.push_str(INDEX_SUFFIX);
+    name
+}
+
 fn calculate_model_tables<'a>(
     datamodel: &'a Datamodel,
     flavour: &'a dyn SqlFlavour,
@@ -101,6 +160,7 @@ fn calculate_model_tables<'a>(
                     columns,
                     tpe: index_type,
                     algorithm,
+                    nulls_not_distinct: index_definition.nulls_not_distinct,
                 }
             })
             .collect();
@@ -111,6 +171,10 @@ fn calculate_model_tables<'a>(
             indices,
             primary_key,
             foreign_keys: Vec::new(),
+            sqlite_options: sql::SqliteTableOptions {
+                strict: model.get().is_strict,
+                without_rowid: model.get().is_without_rowid,
+            },
         };
 
         push_inline_relations(model, &mut table, flavour);
@@ -131,6 +195,7 @@ fn push_inline_relations(model: ModelWalker<'_>, table: &mut sql::Table, flavour
         {
             let fk = sql::ForeignKey {
                 constraint_name: relation_field.constraint_name(),
+                referenced_constraint_name: None,
                 columns: fk_columns,
                 referenced_table: relation_field.referenced_model().database_name().to_owned(),
                 referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
@@ -161,6 +226,7 @@ fn calculate_relation_tables<'a>(
             let foreign_keys = vec![
                 sql::ForeignKey {
                     constraint_name: None,
+                    referenced_constraint_name: None,
                     columns: vec![m2m.model_a_column().into()],
                     referenced_table: model_a.db_name().into(),
                     referenced_columns: vec![model_a_id.db_name().into()],
@@ -169,6 +235,7 @@ fn calculate_relation_tables<'a>(
                 },
                 sql::ForeignKey {
                     constraint_name: None,
+                    referenced_constraint_name: None,
                     columns: vec![m2m.model_b_column().into()],
                     referenced_table: model_b.db_name().into(),
                     referenced_columns: vec![model_b_id.db_name().into()],
@@ -192,6 +259,7 @@ fn calculate_relation_tables<'a>(
                     ],
                     tpe: sql::IndexType::Unique,
                     algorithm: None,
+                    nulls_not_distinct: false,
                 },
                 sql::Index {
                     name: format!(
@@ -204,6 +272,7 @@ fn calculate_relation_tables<'a>(
                     columns: vec![sql::IndexColumn::new(m2m.model_b_column())],
                     tpe: sql::IndexType::Normal,
                     algorithm: None,
+                    nulls_not_distinct: false,
                 },
             ];
 
@@ -213,12 +282,14 @@ fn calculate_relation_tables<'a>(
                     tpe: column_type_for_implicit_relation(&model_a_id, schema),
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
                 sql::Column {
                     name: m2m.model_b_column().into(),
                     tpe: column_type_for_implicit_relation(&model_b_id, schema),
                     default: None,
                     auto_increment: false,
+                    identity: None,
                 },
             ];
 
@@ -231,6 +302,7 @@ fn calculate_relation_tables<'a>(
                 indices: indexes,
                 primary_key: None,
                 foreign_keys,
+                sqlite_options: Default::default(),
             }
         })
 }
@@ -267,26 +339,36 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
             return sql::Column {
                 name: field.db_name().to_owned(),
                 tpe: flavour.enum_column_type(field, r#enum.db_name()),
-                default: field
-                    .default_value()
-                    .and_then(|default| {
-                        let as_enum = default.as_single().and_then(|v| v.as_enum_value());
-                        as_enum.map(|enm| (enm, default.db_name()))
-                    })
-                    .map(|(value, db_name)| {
+                default: field.default_value().and_then(|default| {
+                    let to_database_value = |value: &str| {
                         let corresponding_value = r#enum.value(value).expect("Could not find enum value");
 
-                        let mut default = sql::DefaultValue::value(PrismaValue::Enum(
-                            corresponding_value.final_database_name().to_owned(),
-                        ));
-
-                        if let Some(db_name) = db_name {
-                            default.set_constraint_name(db_name);
-                        }
-
-                        default
-                    }),
+                        PrismaValue::Enum(corresponding_value.final_database_name().to_owned())
+                    };
+
+                    let mut mapped = match default.as_single()? {
+                        PrismaValue::Enum(value) => sql::DefaultValue::value(to_database_value(value)),
+                        PrismaValue::List(values) => sql::DefaultValue::value(PrismaValue::List(
+                            values
+                                .iter()
+                                .map(|value| {
+                                    to_database_value(
+                                        value.as_enum_value().expect("Non-enum value in enum list default"),
+                                    )
+                                })
+                                .collect(),
+                        )),
+                        _ => return None,
+                    };
+
+                    if let Some(db_name) = default.db_name() {
+                        mapped.set_constraint_name(db_name);
+                    }
+
+                    Some(mapped)
+                }),
                 auto_increment: false,
+                identity: None,
             }
         }
         TypeWalker::Base(scalar_type) => (
@@ -313,6 +395,7 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
                 },
                 default,
                 auto_increment: false,
+                identity: None,
             };
         }
     };
@@ -351,7 +434,8 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
     });
 
     sql::Column {
-        auto_increment: has_auto_increment_default || flavour.field_is_implicit_autoincrement_primary_key(field),
+        auto_increment: has_auto_increment_default,
+        identity: None,
         name: field.db_name().to_owned(),
         tpe: sql::ColumnType {
             full_data_type: String::new(),
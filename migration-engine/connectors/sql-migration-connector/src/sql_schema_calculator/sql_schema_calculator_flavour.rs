@@ -35,10 +35,6 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
         unreachable!("unreachable enum_column_type")
     }
 
-    fn field_is_implicit_autoincrement_primary_key(&self, _field: &ScalarFieldWalker<'_>) -> bool {
-        false
-    }
-
     fn on_update_action(&self, rf: &RelationFieldWalker<'_>) -> sql::ForeignKeyAction {
         rf.on_update_action()
             .map(convert_referential_action)
@@ -414,7 +414,8 @@ fn foreign_keys_match(fks: Pair<&ForeignKeyWalker<'_>>, db: &DifferDatabase<'_>)
     // Foreign key references different columns or the same columns in a different order.
     let references_same_columns = fks
         .interleave(|fk| fk.referenced_column_names())
-        .all(|pair| pair.previous == pair.next);
+        .all(|pair| pair.previous == pair.next)
+        || references_same_unique_index(&fks);
 
     let same_on_delete_action = fks.previous.on_delete_action() == fks.next.on_delete_action();
     let same_on_update_action = fks.previous.on_update_action() == fks.next.on_update_action();
@@ -428,6 +429,36 @@ fn foreign_keys_match(fks: Pair<&ForeignKeyWalker<'_>>, db: &DifferDatabase<'_>)
         && same_on_update_action
 }
 
+/// Fallback for compound foreign keys referencing a non-primary unique index:
+/// some databases report the referenced columns in index definition order
+/// rather than constraint definition order, so an in-order comparison sees a
+/// difference where there is none and the differ churns on the foreign key.
+/// The foreign keys are considered equivalent when both sides reference the
+/// same column set and resolve to unique indexes with identical column order.
+fn references_same_unique_index(fks: &Pair<&ForeignKeyWalker<'_>>) -> bool {
+    let same_column_set = fks.previous().referenced_column_names().len() == fks.next().referenced_column_names().len()
+        && fks
+            .previous()
+            .referenced_column_names()
+            .iter()
+            .all(|column| fks.next().referenced_column_names().contains(column));
+
+    if !same_column_set {
+        return false;
+    }
+
+    match (
+        fks.previous().referenced_unique_index(),
+        fks.next().referenced_unique_index(),
+    ) {
+        (Some(previous), Some(next)) => {
+            previous.columns().len() == next.columns().len()
+                && previous.column_names().zip(next.column_names()).all(|(a, b)| a == b)
+        }
+        _ => false,
+    }
+}
+
 fn push_foreign_key_pair_changes(
     fk: Pair<ForeignKeyWalker<'_>>,
     steps: &mut Vec<SqlMigrationStep>,
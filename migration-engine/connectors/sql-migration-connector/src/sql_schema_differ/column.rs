@@ -23,7 +23,10 @@ pub(crate) fn all_changes(cols: Pair<ColumnWalker<'_>>, flavour: &dyn SqlFlavour
         changes |= ColumnChange::Default;
     };
 
-    if cols.previous.is_autoincrement() != cols.next.is_autoincrement() {
+    if !flavour.should_ignore_autoincrement_changes()
+        && (cols.previous.is_autoincrement() != cols.next.is_autoincrement()
+            || cols.previous.identity() != cols.next.identity())
+    {
         changes |= ColumnChange::Sequence;
     };
 
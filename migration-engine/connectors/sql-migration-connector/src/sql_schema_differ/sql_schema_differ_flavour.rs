@@ -86,6 +86,11 @@ pub(crate) trait SqlSchemaDifferFlavour {
         true
     }
 
+    /// Whether to skip diffing the autoincrement property of columns.
+    fn should_ignore_autoincrement_changes(&self) -> bool {
+        false
+    }
+
     /// Whether to skip diffing JSON defaults.
     fn should_ignore_json_defaults(&self) -> bool {
         false
@@ -17,7 +17,9 @@ impl SqlSchemaDifferFlavour for MssqlFlavour {
     }
 
     fn should_skip_index_for_new_table(&self, index: &IndexWalker<'_>) -> bool {
-        index.index_type().is_unique()
+        // Uniques over required columns are inlined as table constraints. Uniques over nullable
+        // columns become filtered unique indexes and need their own CREATE INDEX statement.
+        index.index_type().is_unique() && index.columns().all(|col| col.get().arity().is_required())
     }
 
     fn should_recreate_the_primary_key_on_column_recreate(&self) -> bool {
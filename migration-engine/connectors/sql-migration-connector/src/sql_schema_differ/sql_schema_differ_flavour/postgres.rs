@@ -353,6 +353,15 @@ fn native_type_change_riskyness(previous: PostgresType, next: PostgresType) -> O
                 Timetz(_) | Time(_) => SafeCast,
                 _ => NotCastable,
             },
+            Interval(a) => match next {
+                Text | VarChar(None) => SafeCast,
+                // The longest verbose interval representation.
+                Char(Some(len)) | VarChar(Some(len)) if len > 63 => SafeCast,
+                PostgresType::Interval(None) => return None,
+                PostgresType::Interval(Some(b)) if a.is_none() || a == Some(b) => return None,
+                Interval(_) => SafeCast,
+                _ => NotCastable,
+            },
             Boolean => match next {
                 Text | VarChar(_) => SafeCast,
                 Char(Some(length)) if length > 4 => SafeCast,
@@ -394,6 +403,12 @@ fn native_type_change_riskyness(previous: PostgresType, next: PostgresType) -> O
                 VarChar(_) | Char(_) => RiskyCast,
                 _ => NotCastable,
             },
+            // Changing the geometry type or SRID of a PostGIS column requires an explicit
+            // conversion, so anything but an unchanged modifier means recreating the column.
+            Geometry(_) | Geography(_) => match next {
+                Text | VarChar(None) => SafeCast,
+                _ => NotCastable,
+            },
             Json => match next {
                 Text | JsonB | VarChar(None) => SafeCast,
                 VarChar(_) | Char(_) => RiskyCast,
@@ -30,6 +30,12 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
         true
     }
 
+    // An integer primary key aliases the rowid and auto-increments whether or not the
+    // AUTOINCREMENT keyword was written, so a change in the flag alone is not actionable.
+    fn should_ignore_autoincrement_changes(&self) -> bool {
+        true
+    }
+
     fn set_tables_to_redefine(&self, differ: &mut DifferDatabase<'_>) {
         differ.tables_to_redefine = differ
             .table_pairs()
@@ -41,6 +47,8 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
                     || differ.any_column_changed()
                     || differ.created_foreign_keys().next().is_some()
                     || differ.dropped_foreign_keys().next().is_some()
+                    // STRICT and WITHOUT ROWID can only change by recreating the table.
+                    || differ.tables.previous.table().sqlite_options != differ.tables.next.table().sqlite_options
             })
             .map(|table| table.table_ids())
             .collect();
@@ -174,4 +174,5 @@ fn indexes_match(first: &IndexWalker<'_>, second: &IndexWalker<'_>) -> bool {
         })
         && first.index_type() == second.index_type()
         && first.algorithm() == second.algorithm()
+        && first.nulls_not_distinct() == second.nulls_not_distinct()
 }
@@ -1,7 +1,9 @@
 //! The external facing programmatic API to the migration engine.
 
 use crate::{commands::*, CoreResult};
-use migration_connector::{migrations_directory, MigrationConnector};
+use migration_connector::{
+    migrations_directory, CancellationToken, DatabasePrivileges, MigrationConnector, ProgressHandler,
+};
 use std::path::Path;
 use tracing_futures::Instrument;
 
@@ -14,6 +16,10 @@ pub trait GenericApi: Send + Sync + 'static {
     /// Apply all the unapplied migrations from the migrations folder.
     async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput>;
 
+    /// Check which privileges the connected database user has, so missing
+    /// permissions can be reported before a migration starts.
+    async fn database_privileges(&self) -> CoreResult<DatabasePrivileges>;
+
     /// Create the database referenced by Prisma schema that was used to initialize the connector.
     async fn create_database(&self) -> CoreResult<String>;
 
@@ -59,14 +65,29 @@ pub trait GenericApi: Send + Sync + 'static {
         input: &MarkMigrationRolledBackInput,
     ) -> CoreResult<MarkMigrationRolledBackOutput>;
 
+    /// Re-baseline the checksums of intentionally edited, already applied migrations.
+    async fn repair_migrations(&self, input: &RepairMigrationsInput) -> CoreResult<RepairMigrationsOutput>;
+
     /// Reset a database to an empty state (no data, no schema).
     async fn reset(&self) -> CoreResult<()>;
 
+    /// Compare the schema the migrations describe with the actual database
+    /// schema, and return the drift as a machine-readable document.
+    async fn schema_drift(&self, input: &SchemaDriftInput) -> CoreResult<SchemaDriftOutput>;
+
     /// The command behind `prisma db push`.
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput>;
 
     /// Access to the migration connector.
     fn connector(&self) -> &dyn MigrationConnector;
+
+    /// Install a handler for progress events emitted by long-running
+    /// commands, so they can be forwarded to the client.
+    fn set_progress_handler(&mut self, handler: ProgressHandler);
+
+    /// Install the token through which cancellation of the currently running
+    /// command is requested.
+    fn set_cancellation_token(&mut self, token: CancellationToken);
 }
 
 #[async_trait::async_trait]
@@ -75,6 +96,14 @@ impl<C: MigrationConnector> GenericApi for C {
         self
     }
 
+    fn set_progress_handler(&mut self, handler: ProgressHandler) {
+        MigrationConnector::set_progress_handler(self, handler)
+    }
+
+    fn set_cancellation_token(&mut self, token: CancellationToken) {
+        MigrationConnector::set_cancellation_token(self, token)
+    }
+
     async fn version(&self) -> CoreResult<String> {
         Ok(self.version().await?)
     }
@@ -85,6 +114,12 @@ impl<C: MigrationConnector> GenericApi for C {
             .await
     }
 
+    async fn database_privileges(&self) -> CoreResult<DatabasePrivileges> {
+        Ok(MigrationConnector::database_privileges(self)
+            .instrument(tracing::info_span!("CheckDatabasePrivileges"))
+            .await?)
+    }
+
     async fn create_database(&self) -> CoreResult<String> {
         MigrationConnector::create_database(self).await
     }
@@ -171,6 +206,12 @@ impl<C: MigrationConnector> GenericApi for C {
             .await
     }
 
+    async fn repair_migrations(&self, input: &RepairMigrationsInput) -> CoreResult<RepairMigrationsOutput> {
+        repair_migrations(input, self)
+            .instrument(tracing::info_span!("RepairMigrations"))
+            .await
+    }
+
     async fn reset(&self) -> CoreResult<()> {
         tracing::debug!("Resetting the database.");
 
@@ -179,6 +220,12 @@ impl<C: MigrationConnector> GenericApi for C {
             .await?)
     }
 
+    async fn schema_drift(&self, input: &SchemaDriftInput) -> CoreResult<SchemaDriftOutput> {
+        schema_drift(input, self)
+            .instrument(tracing::info_span!("SchemaDrift"))
+            .await
+    }
+
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput> {
         schema_push(input, self)
             .instrument(tracing::info_span!("SchemaPush"))
@@ -9,11 +9,13 @@ mod evaluate_data_loss;
 mod list_migration_directories;
 mod mark_migration_applied;
 mod mark_migration_rolled_back;
+mod repair_migrations;
+mod schema_drift;
 mod schema_push;
 
-pub use apply_migrations::{ApplyMigrationsInput, ApplyMigrationsOutput};
+pub use apply_migrations::{ApplyMigrationsInput, ApplyMigrationsOutput, PlannedMigration};
 pub use create_migration::{CreateMigrationInput, CreateMigrationOutput};
-pub use dev_diagnostic::{DevAction, DevDiagnosticInput, DevDiagnosticOutput};
+pub use dev_diagnostic::{DevAction, DevDiagnosticInput, DevDiagnosticOutput, ResetCause};
 pub use diagnose_migration_history::{
     DiagnoseMigrationHistoryInput, DiagnoseMigrationHistoryOutput, DriftDiagnostic, HistoryDiagnostic,
 };
@@ -21,6 +23,8 @@ pub use evaluate_data_loss::*;
 pub use list_migration_directories::*;
 pub use mark_migration_applied::{MarkMigrationAppliedInput, MarkMigrationAppliedOutput};
 pub use mark_migration_rolled_back::{MarkMigrationRolledBackInput, MarkMigrationRolledBackOutput};
+pub use repair_migrations::{RepairMigrationsInput, RepairMigrationsOutput};
+pub use schema_drift::{SchemaDriftInput, SchemaDriftOutput};
 pub use schema_push::{SchemaPushInput, SchemaPushOutput};
 
 pub(crate) use apply_migrations::apply_migrations;
@@ -29,4 +33,6 @@ pub(crate) use dev_diagnostic::dev_diagnostic;
 pub(crate) use diagnose_migration_history::diagnose_migration_history;
 pub(crate) use mark_migration_applied::mark_migration_applied;
 pub(crate) use mark_migration_rolled_back::mark_migration_rolled_back;
+pub(crate) use repair_migrations::repair_migrations;
+pub(crate) use schema_drift::schema_drift;
 pub(crate) use schema_push::schema_push;
@@ -13,6 +13,9 @@ use user_facing_errors::migration_engine::FoundFailedMigrations;
 pub struct ApplyMigrationsInput {
     /// The location of the migrations directory.
     pub migrations_directory_path: String,
+    /// Only report the scripts of the unapplied migrations, without writing to the database.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 /// The output of the `ApplyMigrations` command.
@@ -21,6 +24,18 @@ pub struct ApplyMigrationsInput {
 pub struct ApplyMigrationsOutput {
     /// The names of the migrations that were just applied. Empty if no migration was applied.
     pub applied_migration_names: Vec<String>,
+    /// The migrations that would be applied. Only filled in by dry runs.
+    pub planned_migrations: Vec<PlannedMigration>,
+}
+
+/// A migration an `ApplyMigrations` dry run would apply.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedMigration {
+    /// The name of the migration.
+    pub migration_name: String,
+    /// The script that would be executed.
+    pub script: String,
 }
 
 pub(crate) async fn apply_migrations<C>(
@@ -36,15 +51,20 @@ where
 
     error_on_changed_provider(&input.migrations_directory_path, connector.connector_type())?;
 
-    connector.acquire_lock().await?;
+    // Dry runs must not write to the database, so we neither take the advisory lock nor create the
+    // migrations table. A missing migrations table then simply means nothing was applied yet.
+    if !input.dry_run {
+        connector.acquire_lock().await?;
 
-    migration_persistence.initialize().await?;
+        migration_persistence.initialize().await?;
+    }
 
     let migrations_from_filesystem = list_migrations(Path::new(&input.migrations_directory_path))?;
-    let migrations_from_database = migration_persistence
-        .list_migrations()
-        .await?
-        .map_err(PersistenceNotInitializedError::into_connector_error)?;
+    let migrations_from_database = match migration_persistence.list_migrations().await? {
+        Ok(migrations) => migrations,
+        Err(PersistenceNotInitializedError) if input.dry_run => Vec::new(),
+        Err(err) => return Err(err.into_connector_error().into()),
+    };
 
     detect_failed_migrations(&migrations_from_database)?;
 
@@ -63,6 +83,27 @@ where
     let analysis_duration_ms = Instant::now().duration_since(start).as_millis() as u64;
     tracing::info!(analysis_duration_ms, "Analysis run in {}ms", analysis_duration_ms,);
 
+    if input.dry_run {
+        let planned_migrations = unapplied_migrations
+            .into_iter()
+            .map(|unapplied_migration| {
+                let script = unapplied_migration
+                    .read_migration_script()
+                    .map_err(ConnectorError::from)?;
+
+                Ok(PlannedMigration {
+                    migration_name: unapplied_migration.migration_name().to_owned(),
+                    script,
+                })
+            })
+            .collect::<CoreResult<Vec<_>>>()?;
+
+        return Ok(ApplyMigrationsOutput {
+            applied_migration_names: Vec::new(),
+            planned_migrations,
+        });
+    }
+
     let mut applied_migration_names: Vec<String> = Vec::with_capacity(unapplied_migrations.len());
     let apply_migrations_start = Instant::now();
 
@@ -125,6 +166,7 @@ where
 
     Ok(ApplyMigrationsOutput {
         applied_migration_names,
+        planned_migrations: Vec::new(),
     })
 }
 
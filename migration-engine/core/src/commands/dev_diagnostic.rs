@@ -37,9 +37,9 @@ pub(crate) async fn dev_diagnostic(
 
     check_for_broken_migrations(&diagnose_migration_history_output)?;
 
-    if let Some(reason) = check_for_reset_conditions(&diagnose_migration_history_output) {
+    if let Some((reason, causes)) = check_for_reset_conditions(&diagnose_migration_history_output) {
         return Ok(DevDiagnosticOutput {
-            action: DevAction::Reset { reason },
+            action: DevAction::Reset { reason, causes },
         });
     }
 
@@ -60,21 +60,28 @@ fn check_for_broken_migrations(output: &DiagnoseMigrationHistoryOutput) -> Conne
     Ok(())
 }
 
-fn check_for_reset_conditions(output: &DiagnoseMigrationHistoryOutput) -> Option<String> {
+fn check_for_reset_conditions(output: &DiagnoseMigrationHistoryOutput) -> Option<(String, Vec<ResetCause>)> {
     let mut reset_reasons = Vec::new();
+    let mut causes = Vec::new();
 
     for failed_migration_name in &output.failed_migration_names {
         reset_reasons.push(format!("The migration `{}` failed.", failed_migration_name));
+        causes.push(ResetCause::MigrationFailed {
+            migration_name: failed_migration_name.clone(),
+        });
     }
 
     for edited_migration_name in &output.edited_migration_names {
         reset_reasons.push(format!(
             "The migration `{}` was modified after it was applied.",
             edited_migration_name
-        ))
+        ));
+        causes.push(ResetCause::MigrationEdited {
+            migration_name: edited_migration_name.clone(),
+        });
     }
 
-    if let Some(DriftDiagnostic::DriftDetected { summary }) = &output.drift {
+    if let Some(DriftDiagnostic::DriftDetected { summary, .. }) = &output.drift {
         let mut reason = DRIFT_DETECTED_MESSAGE.trim_start().to_owned();
 
         if !output.has_migrations_table {
@@ -83,25 +90,46 @@ fn check_for_reset_conditions(output: &DiagnoseMigrationHistoryOutput) -> Option
 
         reason.push_str(summary);
         reset_reasons.push(reason);
+        causes.push(ResetCause::DriftDetected {
+            summary: summary.clone(),
+        });
     }
 
     match &output.history {
-        Some(HistoryDiagnostic::HistoriesDiverge { last_common_migration_name, unapplied_migration_names: _, unpersisted_migration_names }) => {
+        Some(HistoryDiagnostic::HistoriesDiverge {
+            last_common_migration_name,
+            unapplied_migration_names: _,
+            unpersisted_migration_names,
+        }) => {
             let details = last_common_migration_name.as_ref().map(|last_common_migration_name|{
                 format!(" Last common migration: `{}`. Migrations applied to the database but absent from the migrations directory are: {}", last_common_migration_name, unpersisted_migration_names.join(", "))
             }).unwrap_or_else(String::new);
 
-            reset_reasons.push(format!("The migrations recorded in the database diverge from the local migrations directory.{}", details))
-        },
-        Some(HistoryDiagnostic::MigrationsDirectoryIsBehind { unpersisted_migration_names}) => reset_reasons.push(
-           format!("The following migration(s) are applied to the database but missing from the local migrations directory: {}", unpersisted_migration_names.join(", ")),
-        ),
+            reset_reasons.push(format!(
+                "The migrations recorded in the database diverge from the local migrations directory.{}",
+                details
+            ));
+            causes.push(ResetCause::HistoriesDiverge {
+                last_common_migration_name: last_common_migration_name.clone(),
+                unpersisted_migration_names: unpersisted_migration_names.clone(),
+            });
+        }
+        Some(HistoryDiagnostic::MigrationsDirectoryIsBehind {
+            unpersisted_migration_names,
+        }) => {
+            reset_reasons.push(
+                format!("The following migration(s) are applied to the database but missing from the local migrations directory: {}", unpersisted_migration_names.join(", ")),
+            );
+            causes.push(ResetCause::MigrationsDirectoryIsBehind {
+                unpersisted_migration_names: unpersisted_migration_names.clone(),
+            });
+        }
         None | Some(HistoryDiagnostic::DatabaseIsBehind { .. }) => (),
     }
 
     match reset_reasons.as_slice() {
         [] => None,
-        [first_reason] => Some(first_reason.clone()),
+        [first_reason] => Some((first_reason.clone(), causes)),
         _ => {
             let mut message = String::with_capacity(reset_reasons.iter().map(|s| s.len() + 3).sum::<usize>());
 
@@ -111,7 +139,7 @@ fn check_for_reset_conditions(output: &DiagnoseMigrationHistoryOutput) -> Option
                 message.push('\n');
             }
 
-            Some(message)
+            Some((message, causes))
         }
     }
 }
@@ -135,18 +163,62 @@ https://www.prisma.io/docs/guides/database/developing-with-prisma-migrate/troubl
 pub enum DevAction {
     /// Reset the database.
     Reset {
-        /// Why do we need to reset?
+        /// Why do we need to reset, as a human-readable message.
         reason: String,
+        /// The structured causes behind `reason`, so tooling does not have to
+        /// parse the message.
+        causes: Vec<ResetCause>,
     },
     /// Proceed to the next step.
     CreateMigration,
 }
 
+/// A structured cause for a [`DevAction::Reset`].
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag", rename_all = "camelCase")]
+pub enum ResetCause {
+    /// A migration failed when it was applied to the database.
+    #[serde(rename_all = "camelCase")]
+    MigrationFailed {
+        /// The name of the failed migration.
+        migration_name: String,
+    },
+    /// A migration was modified after it was applied to the database.
+    #[serde(rename_all = "camelCase")]
+    MigrationEdited {
+        /// The name of the edited migration.
+        migration_name: String,
+    },
+    /// The database schema is not in sync with the migration history.
+    DriftDetected {
+        /// A human-readable summary of the differences, including the names
+        /// of the drifted objects.
+        summary: String,
+    },
+    /// The migrations applied to the database diverge from the local
+    /// migrations directory.
+    #[serde(rename_all = "camelCase")]
+    HistoriesDiverge {
+        /// The name of the last migration common to both histories, if any.
+        last_common_migration_name: Option<String>,
+        /// Migrations applied to the database but absent from the migrations
+        /// directory.
+        unpersisted_migration_names: Vec<String>,
+    },
+    /// Migrations are applied to the database but missing from the local
+    /// migrations directory.
+    #[serde(rename_all = "camelCase")]
+    MigrationsDirectoryIsBehind {
+        /// The missing migrations.
+        unpersisted_migration_names: Vec<String>,
+    },
+}
+
 impl DevAction {
     /// Attempts to convert to a `Reset` and returns the reason.
     pub fn as_reset(&self) -> Option<&str> {
         match self {
-            DevAction::Reset { reason } => Some(reason),
+            DevAction::Reset { reason, .. } => Some(reason),
             _ => None,
         }
     }
@@ -166,10 +238,20 @@ mod tests {
     fn dev_action_serializes_as_expected() {
         let reset = serde_json::to_value(DevAction::Reset {
             reason: "Because I said so".to_owned(),
+            causes: vec![ResetCause::MigrationEdited {
+                migration_name: "01_init".to_owned(),
+            }],
         })
         .unwrap();
 
-        assert_eq!(reset, json!({ "tag": "reset", "reason": "Because I said so" }));
+        assert_eq!(
+            reset,
+            json!({
+                "tag": "reset",
+                "reason": "Because I said so",
+                "causes": [{ "tag": "migrationEdited", "migrationName": "01_init" }],
+            })
+        );
 
         let create_migration = serde_json::to_value(DevAction::CreateMigration).unwrap();
 
@@ -1,6 +1,6 @@
 use crate::CoreResult;
 use migration_connector::{
-    migrations_directory::*, ConnectorError, DiffTarget, MigrationConnector, MigrationRecord,
+    migrations_directory::*, ConnectorError, DiffTarget, DriftDocument, MigrationConnector, MigrationRecord,
     PersistenceNotInitializedError,
 };
 use serde::{Deserialize, Serialize};
@@ -142,6 +142,7 @@ pub(crate) async fn diagnose_migration_history(
                 }) {
                 Ok(Some(drift)) => Some(DriftDiagnostic::DriftDetected {
                     summary: connector.migration_summary(&drift),
+                    document: connector.migration_drift_document(&drift),
                 }),
                 Err(error) => Some(DriftDiagnostic::MigrationFailedToApply { error }),
                 _ => None,
@@ -287,6 +288,8 @@ pub enum DriftDiagnostic {
     DriftDetected {
         /// The human-readable contents of the drift.
         summary: String,
+        /// The machine-readable contents of the drift.
+        document: DriftDocument,
     },
     /// When a migration fails to apply cleanly to a shadow database.
     MigrationFailedToApply {
@@ -299,7 +302,7 @@ impl DriftDiagnostic {
     /// For tests.
     pub fn unwrap_drift_detected(self) -> String {
         match self {
-            DriftDiagnostic::DriftDetected { summary } => summary,
+            DriftDiagnostic::DriftDetected { summary, .. } => summary,
             other => panic!("unwrap_drift_detected on {:?}", other),
         }
     }
@@ -0,0 +1,92 @@
+use crate::CoreResult;
+use migration_connector::{
+    migrations_directory::{error_on_changed_provider, list_migrations},
+    ConnectorError, PersistenceNotInitializedError,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `RepairMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RepairMigrationsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+/// The output of the `RepairMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RepairMigrationsOutput {
+    /// The names of the migrations whose checksum was re-baselined.
+    pub repaired_migration_names: Vec<String>,
+}
+
+/// Re-baseline the checksums of applied migrations whose scripts were edited on
+/// purpose, so the history validates again without marking anything as rolled
+/// back. The previous checksum is recorded in the logs column of the migrations
+/// table for auditability.
+pub(crate) async fn repair_migrations<C>(
+    input: &RepairMigrationsInput,
+    connector: &C,
+) -> CoreResult<RepairMigrationsOutput>
+where
+    C: migration_connector::MigrationConnector,
+{
+    let migration_persistence = connector.migration_persistence();
+
+    error_on_changed_provider(&input.migrations_directory_path, connector.connector_type())?;
+
+    connector.acquire_lock().await?;
+
+    let migrations_from_filesystem = list_migrations(Path::new(&input.migrations_directory_path))?;
+    let migrations_from_database = migration_persistence
+        .list_migrations()
+        .await?
+        .map_err(PersistenceNotInitializedError::into_connector_error)?;
+
+    let mut repaired_migration_names = Vec::new();
+
+    for db_migration in migrations_from_database
+        .iter()
+        .filter(|db_migration| db_migration.rolled_back_at.is_none())
+    {
+        let fs_migration = match migrations_from_filesystem
+            .iter()
+            .find(|fs_migration| fs_migration.migration_name() == db_migration.migration_name)
+        {
+            Some(fs_migration) => fs_migration,
+            None => continue,
+        };
+
+        if fs_migration
+            .matches_checksum(&db_migration.checksum)
+            .map_err(ConnectorError::from)?
+        {
+            continue;
+        }
+
+        let script = fs_migration.read_migration_script().map_err(ConnectorError::from)?;
+        let audit_log = format!(
+            "Checksum re-baselined by the repairMigrations command at {now} (previous checksum: {previous}).",
+            now = chrono::Utc::now().to_rfc3339(),
+            previous = db_migration.checksum,
+        );
+
+        tracing::info!(
+            migration_name = db_migration.migration_name.as_str(),
+            "Re-baselining the checksum of `{}`.",
+            db_migration.migration_name
+        );
+
+        migration_persistence
+            .rebaseline_migration_checksum(&db_migration.id, &script, &audit_log)
+            .await?;
+
+        repaired_migration_names.push(db_migration.migration_name.clone());
+    }
+
+    Ok(RepairMigrationsOutput {
+        repaired_migration_names,
+    })
+}
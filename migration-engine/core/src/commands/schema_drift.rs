@@ -0,0 +1,52 @@
+use crate::CoreResult;
+use migration_connector::{migrations_directory::*, DiffTarget, DriftDocument, MigrationConnector};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `schemaDrift` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDriftInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+/// The output of the `schemaDrift` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDriftOutput {
+    /// The machine-readable contents of the drift. `None` when the database
+    /// schema matches the migrations.
+    pub drift: Option<DriftDocument>,
+    /// The human-readable contents of the drift. `None` when the database
+    /// schema matches the migrations.
+    pub summary: Option<String>,
+}
+
+/// Compare the schema the migrations describe with the actual schema of the
+/// database, and return the differences as a machine-readable document, so CI
+/// can gate on drift.
+pub(crate) async fn schema_drift(
+    input: &SchemaDriftInput,
+    connector: &dyn MigrationConnector,
+) -> CoreResult<SchemaDriftOutput> {
+    error_on_changed_provider(&input.migrations_directory_path, connector.connector_type())?;
+
+    let migrations = list_migrations(Path::new(&input.migrations_directory_path))?;
+
+    let drift = connector
+        .diff(DiffTarget::Migrations(&migrations), DiffTarget::Database)
+        .await?;
+
+    if connector.migration_is_empty(&drift) {
+        return Ok(SchemaDriftOutput {
+            drift: None,
+            summary: None,
+        });
+    }
+
+    Ok(SchemaDriftOutput {
+        drift: Some(connector.migration_drift_document(&drift)),
+        summary: Some(connector.migration_summary(&drift)),
+    })
+}
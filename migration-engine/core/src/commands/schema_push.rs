@@ -26,12 +26,25 @@ pub(crate) async fn schema_push(
 
     let checks = checker.check(&database_migration).await?;
 
+    // Dry runs render the exact script the push would execute, together with the destructive
+    // change diagnostics, but never write to the database.
+    let rendered_script = if input.dry_run {
+        Some(applier.render_script(&database_migration, &checks))
+    } else {
+        None
+    };
+
     let executed_steps = match (checks.unexecutable_migrations.len(), checks.warnings.len(), input.force) {
         (unexecutable, _, _) if unexecutable > 0 => {
             tracing::warn!(unexecutable = ?checks.unexecutable_migrations, "Aborting migration because at least one unexecutable step was detected.");
 
             0
         }
+        _ if input.dry_run => {
+            tracing::info!("The migration was not applied because the dry run flag was passed.");
+
+            0
+        }
         (0, 0, _) | (0, _, true) => applier.apply_migration(&database_migration).await?,
         _ => {
             tracing::info!(
@@ -54,6 +67,7 @@ pub(crate) async fn schema_push(
         executed_steps,
         warnings,
         unexecutable,
+        rendered_script,
     })
 }
 
@@ -65,6 +79,9 @@ pub struct SchemaPushInput {
     pub schema: String,
     /// Push the schema ignoring destructive change warnings.
     pub force: bool,
+    /// Only diff and render the script that would be applied, without writing to the database.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 /// Output of the `schemaPush` command.
@@ -77,6 +94,8 @@ pub struct SchemaPushOutput {
     pub warnings: Vec<String>,
     /// Steps that cannot be executed in the current state of the database.
     pub unexecutable: Vec<String>,
+    /// The script that would have been applied. Only rendered for dry runs.
+    pub rendered_script: Option<String>,
 }
 
 impl SchemaPushOutput {
@@ -56,13 +56,29 @@ pub fn migration_api(datamodel: &str) -> CoreResult<Box<dyn api::GenericApi>> {
                 u.query_pairs_mut().append_pair("statement_cache_size", "0");
             }
 
-            let connector = SqlMigrationConnector::new(u.to_string(), preview_features, shadow_database_url)?;
+            let mut connector = SqlMigrationConnector::new(u.to_string(), preview_features, shadow_database_url)?;
+
+            if let Some(migrations_table_name) = &source.migrations_table_name {
+                connector.set_migrations_table_name(migrations_table_name.clone());
+            }
 
             Ok(Box::new(connector))
         }
         #[cfg(feature = "sql")]
         MYSQL_SOURCE_NAME | SQLITE_SOURCE_NAME | MSSQL_SOURCE_NAME => {
-            let connector = SqlMigrationConnector::new(url, preview_features, shadow_database_url)?;
+            let mut connector = SqlMigrationConnector::new(url, preview_features, shadow_database_url)?;
+
+            if let Some(migrations_table_name) = &source.migrations_table_name {
+                connector.set_migrations_table_name(migrations_table_name.clone());
+            }
+
+            if let Some(tool) = source
+                .online_schema_change_tool
+                .as_deref()
+                .and_then(sql_migration_connector::OnlineSchemaChangeTool::parse)
+            {
+                connector.set_online_schema_change_tool(tool);
+            }
 
             Ok(Box::new(connector))
         }
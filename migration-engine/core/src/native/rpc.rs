@@ -1,8 +1,10 @@
 use crate::{CoreError, CoreResult, GenericApi};
 use jsonrpc_core::{types::error::Error as JsonRpcError, IoHandler, Params};
+use migration_connector::{CancellationToken, ProgressEvent};
 use std::sync::Arc;
 
 const APPLY_MIGRATIONS: &str = "applyMigrations";
+const CHECK_DATABASE_PRIVILEGES: &str = "checkDatabasePrivileges";
 const CREATE_MIGRATION: &str = "createMigration";
 const DEBUG_PANIC: &str = "debugPanic";
 const DEV_DIAGNOSTIC: &str = "devDiagnostic";
@@ -12,11 +14,23 @@ const GET_DATABASE_VERSION: &str = "getDatabaseVersion";
 const LIST_MIGRATION_DIRECTORIES: &str = "listMigrationDirectories";
 const MARK_MIGRATION_APPLIED: &str = "markMigrationApplied";
 const MARK_MIGRATION_ROLLED_BACK: &str = "markMigrationRolledBack";
+const REPAIR_MIGRATIONS: &str = "repairMigrations";
 const RESET: &str = "reset";
+const SCHEMA_DRIFT: &str = "schemaDrift";
 const SCHEMA_PUSH: &str = "schemaPush";
 
+/// The method of the progress notifications sent from the engine to the
+/// client during long-running commands. Not a command: it takes no response.
+const MIGRATE_PROGRESS: &str = "migrateProgress";
+
+/// Request cancellation of the command currently running. Not in
+/// `AVAILABLE_COMMANDS`: it is handled out of band, while another command is
+/// still in flight.
+const CANCEL: &str = "cancel";
+
 const AVAILABLE_COMMANDS: &[&str] = &[
     APPLY_MIGRATIONS,
+    CHECK_DATABASE_PRIVILEGES,
     CREATE_MIGRATION,
     DEBUG_PANIC,
     DEV_DIAGNOSTIC,
@@ -26,37 +40,75 @@ const AVAILABLE_COMMANDS: &[&str] = &[
     LIST_MIGRATION_DIRECTORIES,
     MARK_MIGRATION_APPLIED,
     MARK_MIGRATION_ROLLED_BACK,
+    REPAIR_MIGRATIONS,
     RESET,
+    SCHEMA_DRIFT,
     SCHEMA_PUSH,
 ];
 
 /// Initialize a JSON-RPC ready migration engine API. This entails starting
-/// a database connection.
-pub async fn rpc_api(datamodel: &str) -> CoreResult<IoHandler> {
+/// a database connection. When a client handle is passed in, progress on
+/// long-running commands is reported to it as `migrateProgress`
+/// notifications.
+pub async fn rpc_api(datamodel: &str, client: Option<json_rpc_stdio::Client>) -> CoreResult<IoHandler> {
     let mut io_handler = IoHandler::default();
-    let executor = Arc::new(crate::migration_api(datamodel)?);
+    let mut executor = crate::migration_api(datamodel)?;
+
+    if let Some(client) = client {
+        executor.set_progress_handler(Arc::new(move |event: ProgressEvent| {
+            let params = serde_json::json!({
+                "stepIndex": event.step_index,
+                "stepCount": event.step_count,
+                "statementCount": event.statement_count,
+                "elapsedMs": event.elapsed_ms,
+            });
+
+            // Progress is best-effort: a client that cannot receive the
+            // notification should not fail the command.
+            client.notify(MIGRATE_PROGRESS.to_owned(), params).ok();
+        }));
+    }
+
+    let cancellation_token = CancellationToken::new();
+    executor.set_cancellation_token(cancellation_token.clone());
+
+    let executor = Arc::new(executor);
 
     executor.ensure_connection_validity().await?;
 
     for cmd in AVAILABLE_COMMANDS {
         let executor = executor.clone();
+        let cancellation_token = cancellation_token.clone();
         io_handler.add_method(cmd, move |params: Params| {
-            Box::pin(run_command(executor.clone(), cmd, params))
+            Box::pin(run_command(executor.clone(), cancellation_token.clone(), cmd, params))
         });
     }
 
+    io_handler.add_method(CANCEL, move |_params: Params| {
+        let cancellation_token = cancellation_token.clone();
+        async move {
+            cancellation_token.cancel();
+            Ok(serde_json::json!({ "status": "cancelling" }))
+        }
+    });
+
     Ok(io_handler)
 }
 
 #[allow(clippy::redundant_allocation)]
 async fn run_command(
     executor: Arc<Box<dyn GenericApi>>,
+    cancellation_token: CancellationToken,
     cmd: &str,
     params: Params,
 ) -> Result<serde_json::Value, JsonRpcError> {
     tracing::debug!(?cmd, "running the command");
+
+    // A cancellation requested for a previous command must not abort this one.
+    cancellation_token.reset();
     match cmd {
         APPLY_MIGRATIONS => render(executor.apply_migrations(&params.parse()?).await),
+        CHECK_DATABASE_PRIVILEGES => render(executor.database_privileges().await),
         CREATE_MIGRATION => render(executor.create_migration(&params.parse()?).await),
         DEV_DIAGNOSTIC => render(executor.dev_diagnostic(&params.parse()?).await),
         DEBUG_PANIC => render(executor.debug_panic().await),
@@ -66,7 +118,9 @@ async fn run_command(
         LIST_MIGRATION_DIRECTORIES => render(executor.list_migration_directories(&params.parse()?).await),
         MARK_MIGRATION_APPLIED => render(executor.mark_migration_applied(&params.parse()?).await),
         MARK_MIGRATION_ROLLED_BACK => render(executor.mark_migration_rolled_back(&params.parse()?).await),
+        REPAIR_MIGRATIONS => render(executor.repair_migrations(&params.parse()?).await),
         RESET => render(executor.reset().await),
+        SCHEMA_DRIFT => render(executor.schema_drift(&params.parse()?).await),
         SCHEMA_PUSH => render(executor.schema_push(&params.parse()?).await),
         other => unreachable!("Unknown command {}", other),
     }
@@ -36,6 +36,7 @@ impl<'a> ApplyMigrations<'a> {
             .api
             .apply_migrations(&ApplyMigrationsInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                dry_run: false,
             })
             .await?;
 
@@ -39,6 +39,7 @@ impl<'a> SchemaPush<'a> {
         let input = SchemaPushInput {
             schema: self.schema,
             force: self.force,
+            dry_run: false,
         };
 
         let fut = self
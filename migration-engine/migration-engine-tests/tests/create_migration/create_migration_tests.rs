@@ -47,7 +47,7 @@ fn basic_create_migration_works(api: TestApi) {
                     r#"
                         -- CreateTable
                         CREATE TABLE "Cat" (
-                            "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+                            "id" INTEGER NOT NULL PRIMARY KEY,
                             "name" TEXT NOT NULL
                         );
                         "#
@@ -155,7 +155,7 @@ fn creating_a_second_migration_should_have_the_previous_sql_schema_as_baseline(a
                         r#"
                         -- CreateTable
                         CREATE TABLE "Dog" (
-                            "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+                            "id" INTEGER NOT NULL PRIMARY KEY,
                             "name" TEXT NOT NULL
                         );
                         "#
@@ -665,7 +665,7 @@ fn create_constraint_name_tests_w_implicit_names(api: TestApi) {
                 indoc!{r#"
                  -- CreateTable
                  CREATE TABLE "A" (
-                     "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+                     "id" INTEGER NOT NULL PRIMARY KEY,
                      "name" TEXT NOT NULL,
                      "a" TEXT NOT NULL,
                      "b" TEXT NOT NULL
@@ -860,7 +860,7 @@ fn create_constraint_name_tests_w_explicit_names(api: TestApi) {
                 indoc!{r#"
                  -- CreateTable
                  CREATE TABLE "A" (
-                     "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+                     "id" INTEGER NOT NULL PRIMARY KEY,
                      "name" TEXT NOT NULL,
                      "a" TEXT NOT NULL,
                      "b" TEXT NOT NULL
@@ -1034,7 +1034,7 @@ fn alter_constraint_name(api: TestApi) {
             } else if api.is_mysql_5_6() || api.is_mariadb(){
                 indoc! {
                      r#"
-                 -- DropForeignKey
+                 -- DropForeignKey (contract)
                  ALTER TABLE `B` DROP FOREIGN KEY `B_aId_fkey`;
 
                  -- AddForeignKey
@@ -1061,7 +1061,7 @@ fn alter_constraint_name(api: TestApi) {
             else if api.is_mysql(){
                 indoc! {
                      r#"
-                 -- DropForeignKey
+                 -- DropForeignKey (contract)
                  ALTER TABLE `B` DROP FOREIGN KEY `B_aId_fkey`;
 
                  -- AddForeignKey
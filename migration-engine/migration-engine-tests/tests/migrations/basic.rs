@@ -213,8 +213,10 @@ fn adding_an_id_field_of_type_int_must_work_for_sqlite(api: TestApi) {
 
     api.schema_push_w_datasource(dm2).send().assert_green();
 
+    // The integer primary key aliases the rowid and auto-assigns ids without the `AUTOINCREMENT`
+    // keyword, so the column must not be rendered (or described) as explicitly autoincrementing.
     api.assert_schema().assert_table("Test", |table| {
-        table.assert_column("myId", |col| col.assert_auto_increments())
+        table.assert_column("myId", |col| col.assert_no_auto_increment())
     });
 }
 
@@ -74,6 +74,23 @@ macro_rules! run_query_json {
     };
 }
 
+#[macro_export]
+macro_rules! run_query_with_sql {
+    ($runner:expr, $q:expr) => {{
+        let (res, sql) = $runner.query_with_captured_sql($q).await?;
+        res.assert_success();
+        (res.to_string(), sql)
+    }};
+}
+
+#[macro_export]
+macro_rules! assert_sql_contains {
+    ($runner:expr, $q:expr, $fragment:expr) => {{
+        let (_, sql) = $runner.query_with_captured_sql($q).await?;
+        sql.assert_contains($fragment);
+    }};
+}
+
 #[macro_export]
 macro_rules! assert_error {
     ($runner:expr, $q:expr, $code:expr) => {
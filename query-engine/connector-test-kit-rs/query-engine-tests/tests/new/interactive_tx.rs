@@ -9,7 +9,7 @@ mod interactive_tx {
 
     #[connector_test]
     async fn basic_commit_workflow(mut runner: Runner) -> TestResult<()> {
-        let tx_id = runner.executor().start_tx(5000, 5000).await?;
+        let tx_id = runner.executor().start_tx(5000, 5000, None).await?;
         runner.set_active_tx(tx_id.clone());
 
         insta::assert_snapshot!(
@@ -35,7 +35,7 @@ mod interactive_tx {
 
     #[connector_test]
     async fn basic_rollback_workflow(mut runner: Runner) -> TestResult<()> {
-        let tx_id = runner.executor().start_tx(5000, 5000).await?;
+        let tx_id = runner.executor().start_tx(5000, 5000, None).await?;
         runner.set_active_tx(tx_id.clone());
 
         insta::assert_snapshot!(
@@ -62,7 +62,7 @@ mod interactive_tx {
     #[connector_test]
     async fn tx_expiration_cycle(mut runner: Runner) -> TestResult<()> {
         // Tx expires after one second.
-        let tx_id = runner.executor().start_tx(5000, 1000).await?;
+        let tx_id = runner.executor().start_tx(5000, 1000, None).await?;
         runner.set_active_tx(tx_id.clone());
 
         insta::assert_snapshot!(
@@ -83,12 +83,7 @@ mod interactive_tx {
         let res = runner.executor().commit_tx(tx_id.clone()).await;
 
         if let Err(query_core::CoreError::TransactionError(txe)) = res {
-            assert_eq!(
-                txe,
-                TransactionError::Closed {
-                    reason: "Transaction is no longer valid. Last state: 'Expired'".to_string()
-                }
-            );
+            assert_eq!(txe, TransactionError::Expired);
         } else {
             panic!("Expected error, got success.");
         }
@@ -109,7 +104,7 @@ mod interactive_tx {
     #[connector_test]
     async fn no_auto_rollback(mut runner: Runner) -> TestResult<()> {
         // Tx expires after five second.
-        let tx_id = runner.executor().start_tx(5000, 5000).await?;
+        let tx_id = runner.executor().start_tx(5000, 5000, None).await?;
         runner.set_active_tx(tx_id.clone());
 
         // Row is created
@@ -136,7 +131,7 @@ mod interactive_tx {
     #[connector_test(only(Postgres))]
     async fn raw_queries(mut runner: Runner) -> TestResult<()> {
         // Tx expires after five second.
-        let tx_id = runner.executor().start_tx(5000, 5000).await?;
+        let tx_id = runner.executor().start_tx(5000, 5000, None).await?;
         runner.set_active_tx(tx_id.clone());
 
         insta::assert_snapshot!(
@@ -164,7 +159,7 @@ mod interactive_tx {
     #[connector_test]
     async fn batch_queries_success(mut runner: Runner) -> TestResult<()> {
         // Tx expires after five second.
-        let tx_id = runner.executor().start_tx(5000, 5000).await?;
+        let tx_id = runner.executor().start_tx(5000, 5000, None).await?;
         runner.set_active_tx(tx_id.clone());
 
         let queries = vec![
@@ -189,7 +184,7 @@ mod interactive_tx {
     #[connector_test]
     async fn batch_queries_rollback(mut runner: Runner) -> TestResult<()> {
         // Tx expires after five second.
-        let tx_id = runner.executor().start_tx(5000, 5000).await?;
+        let tx_id = runner.executor().start_tx(5000, 5000, None).await?;
         runner.set_active_tx(tx_id.clone());
 
         let queries = vec![
@@ -214,7 +209,7 @@ mod interactive_tx {
     #[connector_test]
     async fn batch_queries_failure(mut runner: Runner) -> TestResult<()> {
         // Tx expires after five second.
-        let tx_id = runner.executor().start_tx(5000, 5000).await?;
+        let tx_id = runner.executor().start_tx(5000, 5000, None).await?;
         runner.set_active_tx(tx_id.clone());
 
         // One dup key, will cause failure of the batch.
@@ -247,7 +242,7 @@ mod interactive_tx {
     #[connector_test]
     async fn tx_expiration_failure_cycle(mut runner: Runner) -> TestResult<()> {
         // Tx expires after one seconds.
-        let tx_id = runner.executor().start_tx(5000, 1000).await?;
+        let tx_id = runner.executor().start_tx(5000, 1000, None).await?;
         runner.set_active_tx(tx_id.clone());
 
         // Row is created
@@ -271,12 +266,7 @@ mod interactive_tx {
         let res = runner.executor().commit_tx(tx_id.clone()).await;
 
         if let Err(query_core::CoreError::TransactionError(txe)) = res {
-            assert_eq!(
-                txe,
-                TransactionError::Closed {
-                    reason: "Transaction is no longer valid. Last state: 'Expired'".to_string()
-                }
-            );
+            assert_eq!(txe, TransactionError::Expired);
         } else {
             panic!("Expected error, got success.");
         }
@@ -288,10 +278,10 @@ mod interactive_tx {
     #[connector_test(exclude(Sqlite))]
     async fn multiple_tx(mut runner: Runner) -> TestResult<()> {
         // First transaction.
-        let tx_id_a = runner.executor().start_tx(2000, 2000).await?;
+        let tx_id_a = runner.executor().start_tx(2000, 2000, None).await?;
 
         // Second transaction.
-        let tx_id_b = runner.executor().start_tx(2000, 2000).await?;
+        let tx_id_b = runner.executor().start_tx(2000, 2000, None).await?;
 
         // Execute on first transaction.
         runner.set_active_tx(tx_id_a.clone());
@@ -3,5 +3,7 @@ mod cursor;
 mod disconnect;
 mod interactive_tx;
 mod native_types;
+mod query_generator;
 mod ref_actions;
 mod regressions;
+mod sql_assertions;
@@ -0,0 +1,85 @@
+use query_engine_tests::*;
+
+/// Tests for the SQL capture support itself, plus first query-shape
+/// regression tests it enables.
+#[test_suite(schema(schema))]
+mod sql_assertions {
+    use indoc::indoc;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model User {
+              #id(id, Int, @id)
+              email String?
+              posts Post[]
+            }
+
+            model Post {
+              #id(id, Int, @id)
+              title  String?
+              userId Int?
+              user   User? @relation(fields: [userId], references: [id])
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    async fn seed(runner: &Runner) -> TestResult<()> {
+        for id in 1..=5 {
+            runner
+                .query(format!(
+                    r#"mutation {{
+                        createOneUser(data: {{
+                            id: {id},
+                            email: "user{id}@example.com",
+                            posts: {{ create: [{{ id: {p1} }}, {{ id: {p2} }}] }}
+                        }}) {{ id }}
+                    }}"#,
+                    id = id,
+                    p1 = id * 2 - 1,
+                    p2 = id * 2,
+                ))
+                .await?
+                .assert_success();
+        }
+
+        Ok(())
+    }
+
+    #[connector_test(exclude(MongoDb))]
+    async fn captures_executed_statements(runner: Runner) -> TestResult<()> {
+        seed(&runner).await?;
+
+        let (_, sql) = run_query_with_sql!(&runner, "query { findManyUser { id email } }");
+
+        assert!(
+            !sql.statements().is_empty(),
+            "Expected at least one captured SQL statement."
+        );
+        sql.assert_contains("SELECT");
+
+        assert_sql_contains!(&runner, "query { findManyUser { id email } }", "SELECT");
+
+        Ok(())
+    }
+
+    // A to-many include must load all related records in one statement per
+    // relation, not one statement per parent record.
+    #[connector_test(exclude(MongoDb))]
+    async fn include_is_not_n_plus_one(runner: Runner) -> TestResult<()> {
+        seed(&runner).await?;
+
+        let (_, sql) = run_query_with_sql!(&runner, "query { findManyUser { id posts { id title } } }");
+        let selects = sql.count_matching("SELECT");
+
+        assert!(
+            selects <= 2,
+            "Expected at most 2 SELECT statements for an include over 5 parents, got {}:\n{}",
+            selects,
+            sql.statements().join("\n")
+        );
+
+        Ok(())
+    }
+}
@@ -5,6 +5,8 @@ mod order_by_aggregation;
 mod order_by_dependent;
 mod order_by_dependent_pagination;
 mod order_by_mutation;
+mod order_by_nulls;
 mod order_by_relevance;
+mod order_stabilization;
 mod pagination;
 mod relation_filter_ordering;
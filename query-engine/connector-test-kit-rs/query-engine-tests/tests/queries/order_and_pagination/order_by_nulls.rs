@@ -0,0 +1,146 @@
+use query_engine_tests::*;
+
+/// Explicit NULL placement in orderBy (`{ sort: ..., nulls: first | last }`).
+/// Databases disagree on where NULLs sort by default - PostgreSQL treats them as
+/// largest, MySQL and SQLite as smallest - so every test pins the placement and
+/// asserts the same order on all connectors with the capability.
+#[test_suite(schema(schema), capabilities(OrderByNullsFirstLast))]
+mod order_by_nulls {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model OrderTest {
+                #id(id, Int, @id)
+                fieldA Int?
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    #[connector_test]
+    async fn asc_nulls_first(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyOrderTest(orderBy: [{ fieldA: { sort: asc, nulls: first } }, { id: asc }]) {
+              id
+              fieldA
+            }
+          }"#),
+          @r###"{"data":{"findManyOrderTest":[{"id":3,"fieldA":null},{"id":5,"fieldA":null},{"id":2,"fieldA":1},{"id":4,"fieldA":2},{"id":1,"fieldA":3}]}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn asc_nulls_last(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyOrderTest(orderBy: [{ fieldA: { sort: asc, nulls: last } }, { id: asc }]) {
+              id
+              fieldA
+            }
+          }"#),
+          @r###"{"data":{"findManyOrderTest":[{"id":2,"fieldA":1},{"id":4,"fieldA":2},{"id":1,"fieldA":3},{"id":3,"fieldA":null},{"id":5,"fieldA":null}]}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn desc_nulls_first(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyOrderTest(orderBy: [{ fieldA: { sort: desc, nulls: first } }, { id: asc }]) {
+              id
+              fieldA
+            }
+          }"#),
+          @r###"{"data":{"findManyOrderTest":[{"id":3,"fieldA":null},{"id":5,"fieldA":null},{"id":1,"fieldA":3},{"id":4,"fieldA":2},{"id":2,"fieldA":1}]}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn desc_nulls_last(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyOrderTest(orderBy: [{ fieldA: { sort: desc, nulls: last } }, { id: asc }]) {
+              id
+              fieldA
+            }
+          }"#),
+          @r###"{"data":{"findManyOrderTest":[{"id":1,"fieldA":3},{"id":4,"fieldA":2},{"id":2,"fieldA":1},{"id":3,"fieldA":null},{"id":5,"fieldA":null}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // A negative take reads in reversed order - the placement has to flip together
+    // with the sort order for the result to be the tail of the ascending order.
+    #[connector_test]
+    async fn negative_take(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyOrderTest(take: -3, orderBy: [{ fieldA: { sort: asc, nulls: last } }, { id: asc }]) {
+              id
+              fieldA
+            }
+          }"#),
+          @r###"{"data":{"findManyOrderTest":[{"id":1,"fieldA":3},{"id":3,"fieldA":null},{"id":5,"fieldA":null}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // Omitting `nulls` in the object form orders exactly like the plain sort order.
+    #[connector_test]
+    async fn sort_object_without_nulls(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyOrderTest(where: { fieldA: { not: null } }, orderBy: [{ fieldA: { sort: desc } }, { id: asc }]) {
+              id
+              fieldA
+            }
+          }"#),
+          @r###"{"data":{"findManyOrderTest":[{"id":1,"fieldA":3},{"id":4,"fieldA":2},{"id":2,"fieldA":1}]}}"###
+        );
+
+        Ok(())
+    }
+
+    async fn create_test_data(runner: &Runner) -> TestResult<()> {
+        create_row(runner, r#"{ id: 1, fieldA: 3 }"#).await?;
+        create_row(runner, r#"{ id: 2, fieldA: 1 }"#).await?;
+        create_row(runner, r#"{ id: 3 }"#).await?;
+        create_row(runner, r#"{ id: 4, fieldA: 2 }"#).await?;
+        create_row(runner, r#"{ id: 5 }"#).await?;
+
+        Ok(())
+    }
+
+    async fn create_row(runner: &Runner, data: &str) -> TestResult<()> {
+        runner
+            .query(format!("mutation {{ createOneOrderTest(data: {}) {{ id }} }}", data))
+            .await?
+            .assert_success();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use query_engine_tests::*;
+
+/// Paginating over a non-unique orderBy gets the primary identifier appended as an
+/// implicit tiebreaker, so records with equal values have a defined relative position
+/// and pages neither repeat nor skip records. The tests pin the stabilized order; the
+/// `PRISMA_DISABLE_ORDER_STABILIZATION` escape hatch is process-wide and read once at
+/// startup, so it cannot be exercised from this suite.
+#[test_suite(schema(schema))]
+mod order_stabilization {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model TestModel {
+                #id(id, Int, @id)
+                grade String
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    // Ties in a non-unique ascending ordering resolve to ascending ids.
+    #[connector_test]
+    async fn non_unique_order_take(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyTestModel(orderBy: { grade: asc }, take: 3) {
+              id
+              grade
+            }
+          }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":2,"grade":"A"},{"id":3,"grade":"A"},{"id":5,"grade":"A"}]}}"###
+        );
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyTestModel(orderBy: { grade: asc }, skip: 2, take: 2) {
+              id
+              grade
+            }
+          }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":5,"grade":"A"},{"id":1,"grade":"B"}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // The tiebreaker is appended after the requested ordering, so ties in a
+    // descending ordering still resolve to ascending ids.
+    #[connector_test]
+    async fn non_unique_order_desc_take(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyTestModel(orderBy: { grade: desc }, take: 3) {
+              id
+              grade
+            }
+          }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":1,"grade":"B"},{"id":4,"grade":"B"},{"id":2,"grade":"A"}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // Cursor paging over the non-unique ordering walks every record exactly once.
+    // Stabilized order: [2, 3, 5, 1, 4].
+    #[connector_test]
+    async fn cursor_pages_without_gaps(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyTestModel(orderBy: { grade: asc }, take: 2) {
+              id
+            }
+          }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":2},{"id":3}]}}"###
+        );
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyTestModel(orderBy: { grade: asc }, cursor: { id: 3 }, skip: 1, take: 2) {
+              id
+            }
+          }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":5},{"id":1}]}}"###
+        );
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyTestModel(orderBy: { grade: asc }, cursor: { id: 1 }, skip: 1, take: 2) {
+              id
+            }
+          }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":4}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // An ordering that already contains the primary key is left untouched.
+    #[connector_test]
+    async fn explicit_pk_order_untouched(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyTestModel(orderBy: { id: desc }, take: 4) {
+              id
+            }
+          }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":5},{"id":4},{"id":3},{"id":2}]}}"###
+        );
+
+        insta::assert_snapshot!(
+          run_query!(runner, r#"{
+            findManyTestModel(orderBy: [{ grade: asc }, { id: desc }], take: 3) {
+              id
+              grade
+            }
+          }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":5,"grade":"A"},{"id":3,"grade":"A"},{"id":2,"grade":"A"}]}}"###
+        );
+
+        Ok(())
+    }
+
+    async fn create_test_data(runner: &Runner) -> TestResult<()> {
+        create_row(runner, r#"{ id: 1, grade: "B" }"#).await?;
+        create_row(runner, r#"{ id: 2, grade: "A" }"#).await?;
+        create_row(runner, r#"{ id: 3, grade: "A" }"#).await?;
+        create_row(runner, r#"{ id: 4, grade: "B" }"#).await?;
+        create_row(runner, r#"{ id: 5, grade: "A" }"#).await?;
+
+        Ok(())
+    }
+
+    async fn create_row(runner: &Runner, data: &str) -> TestResult<()> {
+        runner
+            .query(format!("mutation {{ createOneTestModel(data: {}) {{ id }} }}", data))
+            .await?
+            .assert_success();
+
+        Ok(())
+    }
+}
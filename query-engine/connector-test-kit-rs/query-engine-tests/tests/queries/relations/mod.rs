@@ -1,5 +1,7 @@
 mod duplicate_col_regression;
 mod inline_relation;
+mod partial_response;
 mod related_null_queries;
+mod to_one_join;
 // See TODO in mod below
 // mod unnecessary_db_reqs;
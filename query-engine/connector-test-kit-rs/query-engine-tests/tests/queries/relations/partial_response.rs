@@ -0,0 +1,53 @@
+use query_engine_tests::*;
+
+#[test_suite(schema(schema), only(MongoDb))]
+mod partial_response {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model Author {
+              #id(id, Int, @id)
+              name  String
+              posts Post[]
+            }
+
+            model Post {
+              #id(id, Int, @id)
+              title    String
+              authorId Int
+              author   Author @relation(fields: [authorId], references: [id])
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    // A broken required relation nulls the affected data up to the nearest nullable field and
+    // surfaces a field-level error with a path, instead of discarding the whole response.
+    #[connector_test]
+    async fn broken_required_relation(runner: Runner) -> TestResult<()> {
+        // MongoDB does not enforce references, so the unchecked create leaves `author`
+        // dangling: no Author with id 10 exists.
+        run_query!(
+            &runner,
+            r#"mutation { createOnePost(data: { id: 1, title: "Hello", authorId: 10 }) { id } }"#
+        );
+
+        let res = runner.query(r#"{ findManyPost { id, author { name } } }"#).await?;
+        res.assert_failure(0, None);
+
+        let response = res.to_string();
+
+        // The error is recorded at the broken field ...
+        assert!(response.contains("Field author is required to return data"));
+        assert!(response.contains(r#""path":["findManyPost","author"]"#));
+
+        // ... and the data portion is retained: the broken record collapses to null, which
+        // propagates to the list since list items are non-nullable.
+        assert!(response.contains(r#""findManyPost":null"#));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,253 @@
+use query_engine_tests::*;
+
+/// Plain to-one relation selections are hydrated through a `LEFT JOIN` on the parent
+/// query where the connector supports it. The tests cover the join path itself as well
+/// as the conditions under which the connector falls back to the separate-query path
+/// (cursors, `take: 0`, relation aggregations, deeper nesting) - results must be
+/// identical either way.
+#[test_suite(schema(schema))]
+mod to_one_join {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model Parent {
+              #id(id, Int, @id)
+              name     String?
+              child_id Int?
+              child    Child?  @relation(fields: [child_id], references: [id])
+              posts    Post[]
+            }
+
+            model Child {
+              #id(id, Int, @id)
+              name    String?
+              parents Parent[]
+            }
+
+            model Post {
+              #id(id, Int, @id)
+              parent_id Int?
+              parent    Parent? @relation(fields: [parent_id], references: [id])
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    // "Selecting a to-one relation on findMany" should "hydrate hits and misses alike"
+    #[connector_test]
+    async fn find_many(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        // Parent 3 has no child: the join misses and the relation must come back null,
+        // not drop the parent record.
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findManyParent(orderBy: { id: asc }) {
+              id
+              name
+              child {
+                id
+                name
+              }
+            }
+          }"#),
+          @r###"{"data":{"findManyParent":[{"id":1,"name":"first","child":{"id":1,"name":"one"}},{"id":2,"name":"second","child":{"id":2,"name":"two"}},{"id":3,"name":"third","child":null}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // "Selecting a to-one relation on findUnique" should "hydrate hits and misses alike"
+    #[connector_test]
+    async fn find_unique(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findUniqueParent(where: { id: 1 }) {
+              id
+              child {
+                id
+                name
+              }
+            }
+          }"#),
+          @r###"{"data":{"findUniqueParent":{"id":1,"child":{"id":1,"name":"one"}}}}"###
+        );
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findUniqueParent(where: { id: 3 }) {
+              id
+              child {
+                id
+              }
+            }
+          }"#),
+          @r###"{"data":{"findUniqueParent":{"id":3,"child":null}}}"###
+        );
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findUniqueParent(where: { id: 4 }) {
+              id
+              child {
+                id
+              }
+            }
+          }"#),
+          @r###"{"data":{"findUniqueParent":null}}"###
+        );
+
+        Ok(())
+    }
+
+    // "Filter, order and take arguments on the parent" should "not affect relation hydration"
+    #[connector_test]
+    async fn find_many_with_arguments(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findManyParent(where: { id: { gt: 1 } }, orderBy: { id: desc }, take: 2) {
+              id
+              child {
+                id
+              }
+            }
+          }"#),
+          @r###"{"data":{"findManyParent":[{"id":3,"child":null},{"id":2,"child":{"id":2}}]}}"###
+        );
+
+        // Negative take reads in reversed order and reverses the result in the connector.
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findManyParent(orderBy: { id: asc }, take: -2) {
+              id
+              child {
+                id
+              }
+            }
+          }"#),
+          @r###"{"data":{"findManyParent":[{"id":2,"child":{"id":2}},{"id":3,"child":null}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // "Relation aggregations on the parent" should "work alongside a joined to-one relation"
+    #[connector_test]
+    async fn find_many_with_aggregations(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        // The aggregation columns are interleaved with the joined relation columns in
+        // the selection set and must be split apart correctly.
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findManyParent(orderBy: { id: asc }) {
+              id
+              _count {
+                posts
+              }
+              child {
+                id
+              }
+            }
+          }"#),
+          @r###"{"data":{"findManyParent":[{"id":1,"_count":{"posts":2},"child":{"id":1}},{"id":2,"_count":{"posts":0},"child":{"id":2}},{"id":3,"_count":{"posts":1},"child":null}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // "Cursor pagination" should "return the same results as the non-joined path"
+    #[connector_test]
+    async fn find_many_with_cursor(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        // Cursors make the connector fall back to a separate query per relation.
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findManyParent(cursor: { id: 2 }, take: 2, orderBy: { id: asc }) {
+              id
+              child {
+                id
+              }
+            }
+          }"#),
+          @r###"{"data":{"findManyParent":[{"id":2,"child":{"id":2}},{"id":3,"child":null}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // "take: 0" should "return no records"
+    #[connector_test]
+    async fn find_many_take_zero(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findManyParent(take: 0) {
+              id
+              child {
+                id
+              }
+            }
+          }"#),
+          @r###"{"data":{"findManyParent":[]}}"###
+        );
+
+        Ok(())
+    }
+
+    // "A to-one relation with deeper nesting" should "fall back to the separate-query path"
+    #[connector_test]
+    async fn find_many_nested_below_to_one(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findManyParent(orderBy: { id: asc }) {
+              id
+              child {
+                id
+                parents(orderBy: { id: asc }) {
+                  id
+                }
+              }
+            }
+          }"#),
+          @r###"{"data":{"findManyParent":[{"id":1,"child":{"id":1,"parents":[{"id":1}]}},{"id":2,"child":{"id":2,"parents":[{"id":2}]}},{"id":3,"child":null}]}}"###
+        );
+
+        Ok(())
+    }
+
+    async fn create_test_data(runner: &Runner) -> TestResult<()> {
+        create_row(
+            runner,
+            r#"{ id: 1, name: "first", child: { create: { id: 1, name: "one" } }, posts: { create: [{ id: 1 }, { id: 2 }] } }"#,
+        )
+        .await?;
+        create_row(
+            runner,
+            r#"{ id: 2, name: "second", child: { create: { id: 2, name: "two" } } }"#,
+        )
+        .await?;
+        create_row(runner, r#"{ id: 3, name: "third", posts: { create: [{ id: 3 }] } }"#).await?;
+
+        Ok(())
+    }
+
+    async fn create_row(runner: &Runner, data: &str) -> TestResult<()> {
+        runner
+            .query(format!("mutation {{ createOneParent(data: {}) {{ id }} }}", data))
+            .await?
+            .assert_success();
+        Ok(())
+    }
+}
@@ -4,4 +4,5 @@ mod datetime;
 mod decimal;
 mod json;
 mod native_types;
+mod round_trip;
 mod scalar_lists;
@@ -285,6 +285,42 @@ mod mysql {
         Ok(())
     }
 
+    fn schema_date_precision() -> String {
+        let schema = indoc! {
+            r#"model Model {
+              #id(id, String, @id, @default(cuid()))
+              dtime3 DateTime @test.DateTime(3)
+              dtime6 DateTime @test.DateTime(6)
+              ts6    DateTime @test.Timestamp(6)
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    // "MySQL fractional second precisions" should "round-trip losslessly"
+    #[connector_test(schema(schema_date_precision))]
+    async fn native_date_precision(runner: Runner) -> TestResult<()> {
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            createOneModel(
+              data: {
+                dtime3: "2016-09-24T12:29:32.342Z"
+                dtime6: "2016-09-24T12:29:32.342117Z"
+                ts6: "2016-09-24T12:29:32.342117Z"
+              }
+            ) {
+              dtime3
+              dtime6
+              ts6
+            }
+          }"#),
+          @r###"{"data":{"createOneModel":{"dtime3":"2016-09-24T12:29:32.342Z","dtime6":"2016-09-24T12:29:32.342117Z","ts6":"2016-09-24T12:29:32.342117Z"}}}"###
+        );
+
+        Ok(())
+    }
+
     fn schema_binary() -> String {
         let schema = indoc! {
             r#"model Model {
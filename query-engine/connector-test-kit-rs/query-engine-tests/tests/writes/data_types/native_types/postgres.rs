@@ -313,6 +313,42 @@ mod postgres {
         Ok(())
     }
 
+    fn schema_date_precision() -> String {
+        let schema = indoc! {
+            r#"model Model {
+              #id(id, String, @id, @default(cuid()))
+              ts3    DateTime @test.Timestamp(3)
+              ts6    DateTime @test.Timestamp(6)
+              ts_tz6 DateTime @test.Timestamptz(6)
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    // "Postgres fractional second precisions" should "round-trip losslessly"
+    #[connector_test(schema(schema_date_precision))]
+    async fn native_date_precision(runner: Runner) -> TestResult<()> {
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            createOneModel(
+              data: {
+                ts3: "2016-09-24T14:01:30.213Z"
+                ts6: "2016-09-24T14:01:30.213456Z"
+                ts_tz6: "2016-09-24T14:01:30.213456+03:00"
+              }
+            ) {
+              ts3
+              ts6
+              ts_tz6
+            }
+          }"#),
+          @r###"{"data":{"createOneModel":{"ts3":"2016-09-24T14:01:30.213Z","ts6":"2016-09-24T14:01:30.213456Z","ts_tz6":"2016-09-24T11:01:30.213456Z"}}}"###
+        );
+
+        Ok(())
+    }
+
     fn schema_native_fixed_size_char() -> String {
         let schema = indoc! {
             r#"model ModelA {
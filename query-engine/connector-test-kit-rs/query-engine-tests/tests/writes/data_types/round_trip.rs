@@ -0,0 +1,175 @@
+use query_engine_tests::*;
+
+/// Property-style round-trip tests: many generated values per scalar type are
+/// written and read back, asserting the response matches the input exactly.
+/// The generator is a seeded xorshift, so failures reproduce deterministically.
+#[test_suite(schema(schema))]
+mod round_trip {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    /// Generated cases per scalar type.
+    const CASES: u64 = 25;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model TestModel {
+              #id(id, Int, @id)
+              string  String?
+              int     Int?
+              float   Float?
+              decimal Decimal?
+              dt      DateTime?
+              bytes   Bytes?
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    /// Minimal deterministic generator (xorshift64), so no dependency on a
+    /// property testing framework is needed and failures are reproducible.
+    struct Rng(u64);
+
+    impl Rng {
+        fn new() -> Self {
+            Self(0x5DEECE66D)
+        }
+
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, bound: u64) -> u64 {
+            self.next() % bound
+        }
+    }
+
+    async fn assert_round_trip(runner: &Runner, id: u64, field: &str, literal: &str) -> TestResult<()> {
+        run_query!(
+            runner,
+            format!(
+                r#"mutation {{ createOneTestModel(data: {{ id: {}, {}: {} }}) {{ id }} }}"#,
+                id, field, literal
+            )
+        );
+
+        let response = run_query!(
+            runner,
+            format!(
+                r#"query {{ findUniqueTestModel(where: {{ id: {} }}) {{ {} }} }}"#,
+                id, field
+            )
+        );
+
+        let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+        let written: serde_json::Value = serde_json::from_str(literal).unwrap();
+        let read = &response["data"]["findUniqueTestModel"][field];
+
+        assert_eq!(
+            read, &written,
+            "value {} did not round-trip for field `{}` (case {})",
+            literal, field, id
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn strings(runner: Runner) -> TestResult<()> {
+        // Alphabet with quotes, backslashes, control characters, a combining
+        // character and astral-plane symbols (surrogate pairs in JSON).
+        let alphabet: Vec<char> = "ab \"\\\n\tßé\u{0301}中😀𝄞".chars().collect();
+        let mut rng = Rng::new();
+
+        for id in 1..=CASES {
+            let length = rng.below(20) as usize;
+            let value: String = (0..length)
+                .map(|_| alphabet[rng.below(alphabet.len() as u64) as usize])
+                .collect();
+
+            // JSON escaping is valid GraphQL string escaping.
+            let literal = serde_json::to_string(&value).unwrap();
+            assert_round_trip(&runner, id, "string", &literal).await?;
+        }
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn ints(runner: Runner) -> TestResult<()> {
+        let mut rng = Rng::new();
+
+        for id in 1..=CASES {
+            let value = rng.next() as i32;
+            assert_round_trip(&runner, id, "int", &value.to_string()).await?;
+        }
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn floats(runner: Runner) -> TestResult<()> {
+        let mut rng = Rng::new();
+
+        for id in 1..=CASES {
+            // Dyadic rationals are exactly representable as f64, so the only
+            // way they fail to round-trip is an actual encoding bug.
+            let value = (rng.next() as i32) as f64 / 1024.0;
+            let literal = serde_json::to_string(&value).unwrap();
+            assert_round_trip(&runner, id, "float", &literal).await?;
+        }
+
+        Ok(())
+    }
+
+    // Low-precision connectors are excluded, as in the other decimal suites.
+    #[connector_test(exclude(SqlServer, Sqlite, MongoDb))]
+    async fn decimals(runner: Runner) -> TestResult<()> {
+        let mut rng = Rng::new();
+
+        for id in 1..=CASES {
+            let integer = rng.next() as i16;
+            // Up to ten fraction digits with a non-zero last digit, so the
+            // canonical response rendering matches the input.
+            let fraction = rng.below(999_999_999) * 10 + rng.below(9) + 1;
+            let value = format!("{}.{}", integer, fraction);
+
+            assert_round_trip(&runner, id, "decimal", &format!("\"{}\"", value)).await?;
+        }
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn datetimes(runner: Runner) -> TestResult<()> {
+        let mut rng = Rng::new();
+
+        for id in 1..=CASES {
+            let datetime = chrono::NaiveDateTime::from_timestamp(rng.below(4_000_000_000) as i64, 0)
+                + chrono::Duration::milliseconds(rng.below(1000) as i64);
+            let value = datetime.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+            assert_round_trip(&runner, id, "dt", &format!("\"{}\"", value)).await?;
+        }
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn bytes(runner: Runner) -> TestResult<()> {
+        let mut rng = Rng::new();
+
+        for id in 1..=CASES {
+            let length = rng.below(32) as usize;
+            let value: Vec<u8> = (0..length).map(|_| rng.next() as u8).collect();
+
+            assert_round_trip(&runner, id, "bytes", &format!("\"{}\"", base64::encode(&value))).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1017,4 +1017,77 @@ mod compound_pk_rel {
 
         Ok(())
     }
+
+    // Connecting a different child on update moves the relation scalar that is part of the
+    // compound ID. The connect must run as part of the parent update itself, and the final
+    // re-read must use the new identifier values instead of the stale ones.
+    // Regression test for https://github.com/prisma/prisma/issues/8858
+    #[connector_test(schema(schema_1_1_single_rel))]
+    async fn cpd_1_1_connect_on_update(runner: Runner) -> TestResult<()> {
+        run_query!(
+            &runner,
+            r#"mutation { createOneParent(data: { name: "Paul", age: 40, child: { create: { id: 1, name: "Panther" }}}) { name }}"#
+        );
+        run_query!(
+            &runner,
+            r#"mutation { createOneChild(data: { id: 2, name: "Nikola" }) { id }}"#
+        );
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            updateOneParent(
+              where: {
+                name_child_id: {
+                  name: "Paul"
+                  child_id: 1
+                }
+              }
+              data: { child: { connect: { id: 2 }}}
+            ) {
+              name
+              child {
+                id
+              }
+            }
+          }"#),
+          @r###"{"data":{"updateOneParent":{"name":"Paul","child":{"id":2}}}}"###
+        );
+
+        Ok(())
+    }
+
+    // Same as above for the 1!:M cardinality.
+    #[connector_test(schema(schema_1_m_single_rel))]
+    async fn cpd_1_m_connect_on_update(runner: Runner) -> TestResult<()> {
+        run_query!(
+            &runner,
+            r#"mutation { createOneParent(data: { name: "Paul", age: 40, child: { create: { id: 1, name: "Panther" }}}) { name }}"#
+        );
+        run_query!(
+            &runner,
+            r#"mutation { createOneChild(data: { id: 2, name: "Nikola" }) { id }}"#
+        );
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            updateOneParent(
+              where: {
+                name_child_id: {
+                  name: "Paul"
+                  child_id: 1
+                }
+              }
+              data: { child: { connect: { id: 2 }}}
+            ) {
+              name
+              child {
+                id
+              }
+            }
+          }"#),
+          @r###"{"data":{"updateOneParent":{"name":"Paul","child":{"id":2}}}}"###
+        );
+
+        Ok(())
+    }
 }
@@ -3,7 +3,7 @@ use query_engine_tests::*;
 #[test_suite(schema(schema))]
 mod delete_many {
     use indoc::indoc;
-    use query_engine_tests::{run_query, run_query_json};
+    use query_engine_tests::{assert_error, run_query, run_query_json};
 
     fn schema() -> String {
         let schema = indoc! {
@@ -176,6 +176,60 @@ mod delete_many {
         Ok(())
     }
 
+    // "The delete many Mutation" should "delete at most `limit` items"
+    #[connector_test]
+    async fn should_delete_at_most_limit(runner: Runner) -> TestResult<()> {
+        create_row(&runner, r#"{ id: 1, title: "title1" }"#).await?;
+        create_row(&runner, r#"{ id: 2, title: "title2" }"#).await?;
+        create_row(&runner, r#"{ id: 3, title: "title3" }"#).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            deleteManyTodo(where: {}, limit: 2){
+              count
+            }
+          }"#),
+          @r###"{"data":{"deleteManyTodo":{"count":2}}}"###
+        );
+
+        assert_todo_count(&runner, 1).await?;
+
+        // A limit higher than the number of matching items deletes all of them.
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            deleteManyTodo(where: {}, limit: 5){
+              count
+            }
+          }"#),
+          @r###"{"data":{"deleteManyTodo":{"count":1}}}"###
+        );
+
+        assert_todo_count(&runner, 0).await?;
+
+        Ok(())
+    }
+
+    // "The delete many Mutation" should "reject a negative limit"
+    #[connector_test]
+    async fn should_reject_negative_limit(runner: Runner) -> TestResult<()> {
+        create_row(&runner, r#"{ id: 1, title: "title1" }"#).await?;
+
+        assert_error!(
+            &runner,
+            r#"mutation {
+              deleteManyTodo(where: {}, limit: -1){
+                count
+              }
+            }"#,
+            2019,
+            "Provided limit (-1) must not be negative."
+        );
+
+        assert_todo_count(&runner, 1).await?;
+
+        Ok(())
+    }
+
     fn nested_del_many() -> String {
         let schema = indoc! {
             r#"model ZChild{
@@ -134,6 +134,50 @@ mod update {
         Ok(())
     }
 
+    // "An updateOne mutation" should "return the old values if requested"
+    #[connector_test(schema(schema_1))]
+    async fn update_returning_old_values(runner: Runner) -> TestResult<()> {
+        create_row(&runner, r#"{ id: 1, optString: "old", optInt: 1 }"#).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            updateOneTestModel(
+              where: { id: 1 }
+              data: { optString: { set: "new" }, optInt: { set: 2 } }
+              returnOldValues: true
+            ) {
+              optString
+              optInt
+            }
+          }"#),
+          @r###"{"data":{"updateOneTestModel":{"optString":"old","optInt":1}}}"###
+        );
+
+        // The update itself is applied regardless.
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{ findManyTestModel { optString optInt } }"#),
+          @r###"{"data":{"findManyTestModel":[{"optString":"new","optInt":2}]}}"###
+        );
+
+        // A missing record still errors out the same way as a regular update.
+        assert_error!(
+            &runner,
+            r#"mutation {
+              updateOneTestModel(
+                where: { id: 2 }
+                data: { optInt: { set: 3 } }
+                returnOldValues: true
+              ) {
+                optInt
+              }
+            }"#,
+            2025,
+            "Record to update not found."
+        );
+
+        Ok(())
+    }
+
     // "An updateOne mutation" should "update an item with shorthand notation"
     #[connector_test(schema(schema_1))]
     async fn update_with_shorthand_notation(runner: Runner) -> TestResult<()> {
@@ -122,6 +122,40 @@ mod update_many {
         Ok(())
     }
 
+    // "An updateMany mutation" should "update at most `limit` records"
+    #[connector_test]
+    async fn update_at_most_limit(runner: Runner) -> TestResult<()> {
+        create_row(&runner, r#"{ id: 1, optStr: "str1" }"#).await?;
+        create_row(&runner, r#"{ id: 2, optStr: "str2" }"#).await?;
+        create_row(&runner, r#"{ id: 3, optStr: "str3" }"#).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            updateManyTestModel(
+              where: { }
+              data: { optInt: { set: 1337 } }
+              limit: 2
+            ){
+              count
+            }
+          }"#),
+          @r###"{"data":{"updateManyTestModel":{"count":2}}}"###
+        );
+
+        // Which two of the three records are updated is not defined, only the count is.
+        let res = run_query_json!(
+            &runner,
+            r#"{ findManyTestModel(where: { optInt: { equals: 1337 }}) { id } }"#
+        );
+
+        match &res["data"]["findManyTestModel"] {
+            serde_json::Value::Array(records) => assert_eq!(records.len(), 2),
+            _ => panic!("Unexpected result when counting updated records: {}", res.to_string()),
+        }
+
+        Ok(())
+    }
+
     // "An updateMany mutation" should "correctly apply all number operations for Int"
     #[connector_test(exclude(CockroachDb))]
     async fn apply_number_ops_for_int(runner: Runner) -> TestResult<()> {
@@ -166,17 +166,27 @@ impl ConnectorTag {
     ) -> bool {
         let current_connector = config.test_connector_tag().unwrap();
         if !enabled.contains(&current_connector) {
-            println!("Skipping test '{}', current test connector is not enabled.", test_name);
+            crate::record_skip(
+                test_name,
+                format!("connector `{}` is not enabled for this test", current_connector),
+            );
             return false;
         }
 
-        if capabilities
+        let missing_capabilities: Vec<_> = capabilities
             .iter()
-            .any(|cap| !current_connector.capabilities().contains(cap))
-        {
-            println!(
-                "Skipping test '{}', current test connector doesn't offer one or more capabilities that are required.",
-                test_name
+            .filter(|cap| !current_connector.capabilities().contains(cap))
+            .map(|cap| format!("{:?}", cap))
+            .collect();
+
+        if !missing_capabilities.is_empty() {
+            crate::record_skip(
+                test_name,
+                format!(
+                    "connector `{}` is missing the required capabilities: {}",
+                    current_connector,
+                    missing_capabilities.join(", ")
+                ),
             );
             return false;
         }
@@ -3,9 +3,12 @@ mod connector_tag;
 mod datamodel_rendering;
 mod error;
 mod logging;
+mod query_gen;
 mod query_result;
 mod runner;
 mod schema_gen;
+mod skip_registry;
+mod sql_capture;
 mod templating;
 
 pub use config::*;
@@ -14,9 +17,12 @@ pub use datamodel_rendering::*;
 pub use error::*;
 pub use logging::*;
 pub use query_core;
+pub use query_gen::*;
 pub use query_result::*;
 pub use runner::*;
 pub use schema_gen::*;
+pub use skip_registry::*;
+pub use sql_capture::*;
 pub use templating::*;
 
 use colored::Colorize;
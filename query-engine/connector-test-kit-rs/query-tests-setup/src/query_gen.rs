@@ -0,0 +1,223 @@
+//! Schema-driven random operation generator for differential testing.
+//!
+//! Given the fields of a test model, [`QueryGenerator`] produces random but
+//! valid read operations combining filters, includes and pagination. The
+//! companion [`assert_identical_responses`] runs the same operations against
+//! two runners and panics on the first divergence, so runner implementations
+//! (direct executor vs. binary HTTP, once the `BinaryRunner` is implemented)
+//! can be diffed systematically instead of through hand-picked queries.
+//!
+//! The generator is seeded and fully deterministic: re-running with the same
+//! seed reproduces the exact same operations.
+
+use crate::{Runner, TestResult};
+
+/// The kind of a scalar field, deciding which filter operations and literals
+/// the generator may produce for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenFieldKind {
+    Int,
+    Float,
+    String,
+    Boolean,
+}
+
+/// A field the generator may use in filters, order-bys and selections.
+#[derive(Debug, Clone)]
+pub struct GenField {
+    pub name: String,
+    pub kind: GenFieldKind,
+}
+
+impl GenField {
+    pub fn new(name: impl Into<String>, kind: GenFieldKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+        }
+    }
+}
+
+/// Generates random valid `findMany` operations for one model.
+pub struct QueryGenerator {
+    model: String,
+    id_field: String,
+    fields: Vec<GenField>,
+    relations: Vec<(String, Vec<GenField>)>,
+    state: u64,
+}
+
+impl QueryGenerator {
+    /// A generator for the given model. `id_field` is appended to every
+    /// order-by so result order is always fully determined; `relations` maps
+    /// to-many relation field names to the scalar fields of the related model.
+    pub fn new(
+        model: impl Into<String>,
+        id_field: impl Into<String>,
+        fields: Vec<GenField>,
+        relations: Vec<(String, Vec<GenField>)>,
+        seed: u64,
+    ) -> Self {
+        Self {
+            model: model.into(),
+            id_field: id_field.into(),
+            fields,
+            relations,
+            // A zero state would get xorshift stuck.
+            state: seed.max(1),
+        }
+    }
+
+    /// The next random operation. Always a `findMany` with a deterministic
+    /// order, optionally with a filter, pagination and an included relation.
+    pub fn next_operation(&mut self) -> String {
+        let mut arguments = Vec::new();
+
+        if self.chance(70) {
+            arguments.push(format!("where: {}", self.filter(2)));
+        }
+
+        let order_field = self.pick_field().name.clone();
+        let direction = if self.chance(50) { "asc" } else { "desc" };
+        arguments.push(format!(
+            "orderBy: [{{ {}: {} }}, {{ {}: asc }}]",
+            order_field, direction, self.id_field
+        ));
+
+        if self.chance(50) {
+            arguments.push(format!("take: {}", self.below(20) + 1));
+        }
+
+        if self.chance(30) {
+            arguments.push(format!("skip: {}", self.below(10)));
+        }
+
+        let mut selection: Vec<String> = self.fields.iter().map(|field| field.name.clone()).collect();
+
+        if !self.relations.is_empty() && self.chance(50) {
+            let index = self.below(self.relations.len() as u64) as usize;
+            let (relation, fields) = &self.relations[index];
+            let nested: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+
+            selection.push(format!("{} {{ {} }}", relation, nested.join(" ")));
+        }
+
+        format!(
+            "query {{ findMany{}({}) {{ {} }} }}",
+            self.model,
+            arguments.join(", "),
+            selection.join(" ")
+        )
+    }
+
+    /// A filter object, possibly combining conditions with AND/OR/NOT up to
+    /// the given depth.
+    fn filter(&mut self, depth: u8) -> String {
+        if depth > 0 && self.chance(40) {
+            let combinator = match self.below(3) {
+                0 => "AND",
+                1 => "OR",
+                _ => "NOT",
+            };
+
+            let left = self.filter(depth - 1);
+            let right = self.filter(depth - 1);
+
+            return format!("{{ {}: [{}, {}] }}", combinator, left, right);
+        }
+
+        let condition = self.condition();
+        format!("{{ {} }}", condition)
+    }
+
+    /// A single scalar condition for a random field.
+    fn condition(&mut self) -> String {
+        let field = self.pick_field().clone();
+
+        let operation = match field.kind {
+            GenFieldKind::Boolean => "equals",
+            GenFieldKind::String => match self.below(5) {
+                0 => "equals",
+                1 => "contains",
+                2 => "startsWith",
+                3 => "endsWith",
+                _ => "in",
+            },
+            GenFieldKind::Int | GenFieldKind::Float => match self.below(7) {
+                0 => "equals",
+                1 => "not",
+                2 => "lt",
+                3 => "lte",
+                4 => "gt",
+                5 => "gte",
+                _ => "in",
+            },
+        };
+
+        let value = if operation == "in" {
+            let values: Vec<String> = (0..self.below(4) + 1).map(|_| self.literal(field.kind)).collect();
+            format!("[{}]", values.join(", "))
+        } else {
+            self.literal(field.kind)
+        };
+
+        format!("{}: {{ {}: {} }}", field.name, operation, value)
+    }
+
+    /// A random literal of the given kind.
+    fn literal(&mut self, kind: GenFieldKind) -> String {
+        match kind {
+            GenFieldKind::Int => (self.below(200) as i64 - 100).to_string(),
+            // Dyadic rationals render and parse exactly.
+            GenFieldKind::Float => ((self.below(4000) as i64 - 2000) as f64 / 8.0).to_string(),
+            GenFieldKind::Boolean => (self.below(2) == 0).to_string(),
+            GenFieldKind::String => {
+                const WORDS: &[&str] = &["alpha", "Beta", "gamma", "DELTA", "epsilon", ""];
+                format!("\"{}\"", WORDS[self.below(WORDS.len() as u64) as usize])
+            }
+        }
+    }
+
+    fn pick_field(&mut self) -> &GenField {
+        let index = self.below(self.fields.len() as u64) as usize;
+        &self.fields[index]
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+}
+
+/// Runs every operation against both runners and asserts byte-identical JSON
+/// responses. Panics on the first divergence, printing the operation and both
+/// responses.
+pub async fn assert_identical_responses(
+    left: &Runner,
+    right: &Runner,
+    operations: impl IntoIterator<Item = String>,
+) -> TestResult<()> {
+    for operation in operations {
+        let left_response = left.query(operation.clone()).await?.to_string();
+        let right_response = right.query(operation.clone()).await?.to_string();
+
+        if left_response != right_response {
+            panic!(
+                "Runners diverged on operation `{}`:\n  left:  {}\n  right: {}",
+                operation, left_response, right_response
+            );
+        }
+    }
+
+    Ok(())
+}
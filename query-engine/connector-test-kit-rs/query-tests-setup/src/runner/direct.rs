@@ -28,6 +28,7 @@ impl RunnerInterface for DirectRunner {
             internal_data_model,
             BuildMode::Modern,
             true,
+            false,
             data_source.capabilities(),
             preview_features,
             data_source.referential_integrity(),
@@ -77,6 +77,28 @@ impl Runner {
         Ok(response)
     }
 
+    /// Queries the engine like [`Runner::query`], additionally capturing the
+    /// SQL statements the connector executes. Other log output of the query is
+    /// suppressed for the duration of the call, since the capture temporarily
+    /// replaces the task-local subscriber.
+    pub async fn query_with_captured_sql<T>(&self, gql_query: T) -> TestResult<(QueryResult, crate::SqlCapture)>
+    where
+        T: Into<String>,
+    {
+        use crate::sql_capture::SqlCaptureLayer;
+        use tracing_futures::WithSubscriber;
+        use tracing_subscriber::prelude::*;
+
+        let capture = crate::SqlCapture::default();
+        let subscriber = tracing_subscriber::registry().with(SqlCaptureLayer {
+            capture: capture.clone(),
+        });
+
+        let response = self.query(gql_query).with_subscriber(subscriber).await?;
+
+        Ok((response, capture))
+    }
+
     pub async fn batch(&self, queries: Vec<String>, transaction: bool) -> TestResult<QueryResult> {
         match self {
             Runner::Direct(r) => r.batch(queries, transaction).await,
@@ -0,0 +1,30 @@
+use lazy_static::lazy_static;
+use std::sync::Mutex;
+
+/// A test that did not run on the current connector, with the reason why.
+#[derive(Debug, Clone)]
+pub struct SkippedTest {
+    pub name: String,
+    pub reason: String,
+}
+
+lazy_static! {
+    static ref SKIPPED: Mutex<Vec<SkippedTest>> = Mutex::new(Vec::new());
+}
+
+/// Records a skipped test with its reason. The reason is printed, so a plain
+/// test run still shows what was skipped and why, and recorded, so tooling can
+/// report coverage of the connector under test.
+pub fn record_skip(name: &str, reason: String) {
+    println!("Skipping test '{}': {}", name, reason);
+
+    SKIPPED.lock().unwrap().push(SkippedTest {
+        name: name.to_owned(),
+        reason,
+    });
+}
+
+/// All skips recorded in this test process so far.
+pub fn skipped_tests() -> Vec<SkippedTest> {
+    SKIPPED.lock().unwrap().clone()
+}
@@ -0,0 +1,94 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+use tracing::field::{Field, Visit};
+use tracing_subscriber::layer::Context;
+
+/// The SQL statements the connector executed while a capture was active, so
+/// tests can assert on query shape (statement counts, operators used) and not
+/// just on response correctness.
+#[derive(Debug, Clone, Default)]
+pub struct SqlCapture {
+    statements: Arc<Mutex<Vec<String>>>,
+}
+
+impl SqlCapture {
+    /// The captured statements, in execution order.
+    pub fn statements(&self) -> Vec<String> {
+        self.statements.lock().unwrap().clone()
+    }
+
+    /// Number of captured statements containing the fragment.
+    pub fn count_matching(&self, fragment: &str) -> usize {
+        self.statements
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|statement| statement.contains(fragment))
+            .count()
+    }
+
+    /// Panics unless at least one captured statement contains the fragment.
+    pub fn assert_contains(&self, fragment: &str) {
+        if self.count_matching(fragment) == 0 {
+            panic!(
+                "No executed SQL statement contains `{}`. Executed statements:\n{}",
+                fragment,
+                self.statements().join("\n")
+            );
+        }
+    }
+
+    /// Panics if any captured statement contains the fragment.
+    pub fn assert_not_contains(&self, fragment: &str) {
+        if self.count_matching(fragment) > 0 {
+            panic!(
+                "An executed SQL statement contains `{}`, but none should. Executed statements:\n{}",
+                fragment,
+                self.statements().join("\n")
+            );
+        }
+    }
+}
+
+/// Feeds quaint's query log events into a [`SqlCapture`]. Quaint tags every
+/// executed statement as a `query` field on events under the `quaint` target,
+/// the same events `LOG_QUERIES` prints in the engine binaries.
+pub(crate) struct SqlCaptureLayer {
+    pub(crate) capture: SqlCapture,
+}
+
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SqlCaptureLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        if !event.metadata().target().starts_with("quaint") {
+            return;
+        }
+
+        let mut visitor = QueryVisitor::default();
+        event.record(&mut visitor);
+
+        if let Some(query) = visitor.query {
+            self.capture.statements.lock().unwrap().push(query);
+        }
+    }
+}
+
+#[derive(Default)]
+struct QueryVisitor {
+    query: Option<String>,
+}
+
+impl Visit for QueryVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "query" {
+            self.query = Some(value.to_owned());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "query" && self.query.is_none() {
+            self.query = Some(format!("{:?}", value));
+        }
+    }
+}
@@ -52,6 +52,11 @@ pub(crate) fn convert_filter(filter: Filter, invert: bool, is_having_filter: boo
         Filter::ScalarList(slf) => scalar_list_filter(slf, invert)?,
         Filter::OneRelationIsNull(filter) => one_is_null(filter, invert),
         Filter::Relation(rfilter) => relation_filter(rfilter, invert)?,
+        Filter::RelationCount(_) => {
+            return Err(MongoError::Unsupported(
+                "Relation count filters are not supported yet on MongoDB".to_string(),
+            ))
+        }
         // Filter::BoolFilter(b) => {} // Potentially not doable.
         Filter::Aggregation(filter) => aggregation_filter(filter, invert)?,
         _ => todo!("Incomplete filter implementation."),
@@ -226,6 +231,16 @@ fn default_scalar_filter(field: &ScalarFieldRef, condition: ScalarCondition) ->
         },
         ScalarCondition::Search(_, _) => unimplemented!("Full-text search is not supported yet on MongoDB"),
         ScalarCondition::NotSearch(_, _) => unimplemented!("Full-text search is not supported yet on MongoDB"),
+        ScalarCondition::EqualsField(_)
+        | ScalarCondition::NotEqualsField(_)
+        | ScalarCondition::LessThanField(_)
+        | ScalarCondition::LessThanOrEqualsField(_)
+        | ScalarCondition::GreaterThanField(_)
+        | ScalarCondition::GreaterThanOrEqualsField(_) => {
+            return Err(MongoError::Unsupported(
+                "Field reference filters are not supported yet on MongoDB".to_string(),
+            ))
+        }
     })
 }
 
@@ -270,6 +285,14 @@ fn insensitive_scalar_filter(field: &ScalarFieldRef, condition: ScalarCondition)
         ScalarCondition::Search(_, _) | ScalarCondition::NotSearch(_, _) => Err(MongoError::Unsupported(
             "Full-text search is not supported yet on MongoDB".to_string(),
         )),
+        ScalarCondition::EqualsField(_)
+        | ScalarCondition::NotEqualsField(_)
+        | ScalarCondition::LessThanField(_)
+        | ScalarCondition::LessThanOrEqualsField(_)
+        | ScalarCondition::GreaterThanField(_)
+        | ScalarCondition::GreaterThanOrEqualsField(_) => Err(MongoError::Unsupported(
+            "Field reference filters are not supported yet on MongoDB".to_string(),
+        )),
     }
 }
 
@@ -63,17 +63,24 @@ impl WriteOperations for MongoDbConnection {
         model: &ModelRef,
         record_filter: connector_interface::RecordFilter,
         args: WriteArgs,
+        limit: Option<usize>,
     ) -> connector_interface::Result<Vec<SelectionResult>> {
-        catch(async move { write::update_records(&self.database, &mut self.session, model, record_filter, args).await })
-            .await
+        catch(async move {
+            write::update_records(&self.database, &mut self.session, model, record_filter, args, limit).await
+        })
+        .await
     }
 
     async fn delete_records(
         &mut self,
         model: &ModelRef,
         record_filter: connector_interface::RecordFilter,
+        limit: Option<usize>,
     ) -> connector_interface::Result<usize> {
-        catch(async move { write::delete_records(&self.database, &mut self.session, model, record_filter).await }).await
+        catch(
+            async move { write::delete_records(&self.database, &mut self.session, model, record_filter, limit).await },
+        )
+        .await
     }
 
     async fn m2m_connect(
@@ -159,6 +166,19 @@ impl ReadOperations for MongoDbConnection {
         .await
     }
 
+    async fn get_many_records_joined(
+        &mut self,
+        _model: &ModelRef,
+        _query_arguments: connector_interface::QueryArguments,
+        _selected_fields: &FieldSelection,
+        _aggregation_selections: &[RelAggregationSelection],
+        _relations: &[connector_interface::ToOneRelationSelection],
+    ) -> connector_interface::Result<Option<(ManyRecords, Vec<ManyRecords>)>> {
+        // No single-query relation hydration wired up for MongoDB - the core
+        // falls back to querying each relation separately.
+        Ok(None)
+    }
+
     async fn get_related_m2m_record_ids(
         &mut self,
         from_field: &RelationFieldRef,
@@ -96,6 +96,7 @@ impl<'conn> WriteOperations for MongoDbTransaction<'conn> {
         model: &ModelRef,
         record_filter: connector_interface::RecordFilter,
         args: connector_interface::WriteArgs,
+        limit: Option<usize>,
     ) -> connector_interface::Result<Vec<SelectionResult>> {
         catch(async move {
             write::update_records(
@@ -104,6 +105,7 @@ impl<'conn> WriteOperations for MongoDbTransaction<'conn> {
                 model,
                 record_filter,
                 args,
+                limit,
             )
             .await
         })
@@ -114,6 +116,7 @@ impl<'conn> WriteOperations for MongoDbTransaction<'conn> {
         &mut self,
         model: &ModelRef,
         record_filter: connector_interface::RecordFilter,
+        limit: Option<usize>,
     ) -> connector_interface::Result<usize> {
         catch(async move {
             write::delete_records(
@@ -121,6 +124,7 @@ impl<'conn> WriteOperations for MongoDbTransaction<'conn> {
                 &mut self.connection.session,
                 model,
                 record_filter,
+                limit,
             )
             .await
         })
@@ -226,6 +230,19 @@ impl<'conn> ReadOperations for MongoDbTransaction<'conn> {
         .await
     }
 
+    async fn get_many_records_joined(
+        &mut self,
+        _model: &ModelRef,
+        _query_arguments: connector_interface::QueryArguments,
+        _selected_fields: &FieldSelection,
+        _aggregation_selections: &[RelAggregationSelection],
+        _relations: &[connector_interface::ToOneRelationSelection],
+    ) -> connector_interface::Result<Option<(ManyRecords, Vec<ManyRecords>)>> {
+        // No single-query relation hydration wired up for MongoDB - the core
+        // falls back to querying each relation separately.
+        Ok(None)
+    }
+
     async fn get_related_m2m_record_ids(
         &mut self,
         from_field: &RelationFieldRef,
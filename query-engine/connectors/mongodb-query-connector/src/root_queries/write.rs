@@ -120,6 +120,7 @@ pub async fn update_records<'conn>(
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
+    limit: Option<usize>,
 ) -> crate::Result<Vec<SelectionResult>> {
     let coll = database.collection::<Document>(model.db_name());
 
@@ -135,7 +136,7 @@ pub async fn update_records<'conn>(
         .unwrap();
 
     let id_meta = output_meta::from_field(&id_field);
-    let ids: Vec<Bson> = if let Some(selectors) = record_filter.selectors {
+    let mut ids: Vec<Bson> = if let Some(selectors) = record_filter.selectors {
         selectors
             .into_iter()
             .map(|p| (&id_field, p.values().next().unwrap()).into_bson())
@@ -145,6 +146,10 @@ pub async fn update_records<'conn>(
         find_ids(coll.clone(), session, model, filter).await?
     };
 
+    if let Some(limit) = limit {
+        ids.truncate(limit);
+    }
+
     if ids.is_empty() {
         return Ok(vec![]);
     }
@@ -254,6 +259,7 @@ pub async fn delete_records<'conn>(
     session: &mut ClientSession,
     model: &ModelRef,
     record_filter: RecordFilter,
+    limit: Option<usize>,
 ) -> crate::Result<usize> {
     let coll = database.collection::<Document>(model.db_name());
     let id_field = ModelProjection::from(model.primary_identifier())
@@ -261,7 +267,7 @@ pub async fn delete_records<'conn>(
         .next()
         .unwrap();
 
-    let ids = if let Some(selectors) = record_filter.selectors {
+    let mut ids = if let Some(selectors) = record_filter.selectors {
         selectors
             .into_iter()
             .map(|p| (&id_field, p.values().next().unwrap()).into_bson())
@@ -271,6 +277,10 @@ pub async fn delete_records<'conn>(
         find_ids(coll.clone(), session, model, filter).await?
     };
 
+    if let Some(limit) = limit {
+        ids.truncate(limit);
+    }
+
     if ids.is_empty() {
         return Ok(0);
     }
@@ -1,5 +1,5 @@
-use crate::{filter::Filter, JsonFilterPath, JsonTargetType};
-use prisma_models::PrismaValue;
+use crate::{filter::Filter, JsonFilterPath, JsonTargetType, ScalarCondition};
+use prisma_models::{PrismaValue, ScalarFieldRef};
 
 /// Comparing methods for scalar fields.
 pub trait ScalarCompare {
@@ -66,6 +66,18 @@ pub trait ScalarCompare {
     fn not_search<T>(&self, val: T) -> Filter
     where
         T: Into<PrismaValue>;
+
+    fn equals_field(&self, other: ScalarFieldRef) -> Filter;
+
+    fn not_equals_field(&self, other: ScalarFieldRef) -> Filter;
+
+    fn less_than_field(&self, other: ScalarFieldRef) -> Filter;
+
+    fn less_than_or_equals_field(&self, other: ScalarFieldRef) -> Filter;
+
+    fn greater_than_field(&self, other: ScalarFieldRef) -> Filter;
+
+    fn greater_than_or_equals_field(&self, other: ScalarFieldRef) -> Filter;
 }
 
 /// Comparison methods for relational fields.
@@ -86,6 +98,8 @@ pub trait RelationCompare {
     where
         T: Into<Filter>;
 
+    fn related_count(&self, condition: ScalarCondition) -> Filter;
+
     fn one_relation_is_null(&self) -> Filter;
 }
 
@@ -25,6 +25,7 @@ pub enum Filter {
     ScalarList(ScalarListFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
+    RelationCount(RelationCountFilter),
     BoolFilter(bool),
     Aggregation(AggregationFilter),
     Empty,
@@ -195,6 +196,12 @@ impl From<RelationFilter> for Filter {
     }
 }
 
+impl From<RelationCountFilter> for Filter {
+    fn from(cf: RelationCountFilter) -> Self {
+        Filter::RelationCount(cf)
+    }
+}
+
 impl From<bool> for Filter {
     fn from(b: bool) -> Self {
         Filter::BoolFilter(b)
@@ -1,5 +1,5 @@
 use crate::compare::RelationCompare;
-use crate::filter::Filter;
+use crate::filter::{Filter, ScalarCondition};
 use prisma_models::RelationField;
 use std::sync::Arc;
 
@@ -17,6 +17,17 @@ pub struct RelationFilter {
     pub condition: RelationCondition,
 }
 
+/// Filter on the number of related records, e.g.
+/// `{ posts: { _count: { gt: 5 } } }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelationCountFilter {
+    /// Starting field of the relation traversal.
+    pub field: Arc<RelationField>,
+
+    /// Condition the related record count needs to fulfill.
+    pub condition: ScalarCondition,
+}
+
 /// Filter that is solely responsible for checking if
 /// a to-one related record is null.
 /// Todo there's no good, obvious reason why this is a separate filter.
@@ -98,6 +109,14 @@ impl RelationCompare for Arc<RelationField> {
         })
     }
 
+    /// The number of related records matches the condition.
+    fn related_count(&self, condition: ScalarCondition) -> Filter {
+        Filter::from(RelationCountFilter {
+            field: Arc::clone(self),
+            condition,
+        })
+    }
+
     /// One of the relations is `Null`.
     fn one_relation_is_null(&self) -> Filter {
         Filter::from(OneRelationIsNullFilter {
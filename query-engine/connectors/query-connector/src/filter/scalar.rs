@@ -70,6 +70,7 @@ impl ScalarFilter {
             self.condition,
             ScalarCondition::NotContains(_)
                 | ScalarCondition::NotEquals(_)
+                | ScalarCondition::NotEqualsField(_)
                 | ScalarCondition::NotIn(_)
                 | ScalarCondition::NotSearch(..)
                 | ScalarCondition::NotStartsWith(_)
@@ -145,6 +146,15 @@ pub enum ScalarCondition {
     GreaterThanOrEquals(PrismaValue),
     In(PrismaListValue),
     NotIn(PrismaListValue),
+
+    // Comparisons against another scalar field of the same model (`_ref` filters).
+    EqualsField(ScalarFieldRef),
+    NotEqualsField(ScalarFieldRef),
+    LessThanField(ScalarFieldRef),
+    LessThanOrEqualsField(ScalarFieldRef),
+    GreaterThanField(ScalarFieldRef),
+    GreaterThanOrEqualsField(ScalarFieldRef),
+
     JsonCompare(JsonCondition),
     Search(PrismaValue, Vec<ScalarProjection>),
     NotSearch(PrismaValue, Vec<ScalarProjection>),
@@ -175,6 +185,12 @@ impl ScalarCondition {
                 Self::GreaterThanOrEquals(v) => Self::LessThan(v),
                 Self::In(v) => Self::NotIn(v),
                 Self::NotIn(v) => Self::In(v),
+                Self::EqualsField(f) => Self::NotEqualsField(f),
+                Self::NotEqualsField(f) => Self::EqualsField(f),
+                Self::LessThanField(f) => Self::GreaterThanOrEqualsField(f),
+                Self::LessThanOrEqualsField(f) => Self::GreaterThanField(f),
+                Self::GreaterThanField(f) => Self::LessThanOrEqualsField(f),
+                Self::GreaterThanOrEqualsField(f) => Self::LessThanField(f),
                 Self::JsonCompare(json_compare) => {
                     let inverted_cond = json_compare.condition.invert(true);
 
@@ -383,6 +399,59 @@ impl ScalarCompare for ScalarFieldRef {
             mode: QueryMode::Default,
         })
     }
+    /// Field equals the value of the other field.
+    fn equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::EqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field does not equal the value of the other field.
+    fn not_equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::NotEqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is less than the value of the other field.
+    fn less_than_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::LessThanField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is less than or equal to the value of the other field.
+    fn less_than_or_equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::LessThanOrEqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is greater than the value of the other field.
+    fn greater_than_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::GreaterThanField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is greater than or equal to the value of the other field.
+    fn greater_than_or_equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::GreaterThanOrEqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
 }
 
 impl ScalarCompare for ModelProjection {
@@ -575,6 +644,59 @@ impl ScalarCompare for ModelProjection {
             mode: QueryMode::Default,
         })
     }
+    /// Field equals the value of the other field.
+    fn equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::EqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field does not equal the value of the other field.
+    fn not_equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::NotEqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is less than the value of the other field.
+    fn less_than_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::LessThanField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is less than or equal to the value of the other field.
+    fn less_than_or_equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::LessThanOrEqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is greater than the value of the other field.
+    fn greater_than_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::GreaterThanField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is greater than or equal to the value of the other field.
+    fn greater_than_or_equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::GreaterThanOrEqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
 }
 
 impl ScalarCompare for FieldSelection {
@@ -767,4 +889,57 @@ impl ScalarCompare for FieldSelection {
             mode: QueryMode::Default,
         })
     }
+    /// Field equals the value of the other field.
+    fn equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::EqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field does not equal the value of the other field.
+    fn not_equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::NotEqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is less than the value of the other field.
+    fn less_than_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::LessThanField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is less than or equal to the value of the other field.
+    fn less_than_or_equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::LessThanOrEqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is greater than the value of the other field.
+    fn greater_than_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::GreaterThanField(other),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field is greater than or equal to the value of the other field.
+    fn greater_than_or_equals_field(&self, other: ScalarFieldRef) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::GreaterThanOrEqualsField(other),
+            mode: QueryMode::Default,
+        })
+    }
 }
@@ -208,6 +208,17 @@ impl RelAggregationSelection {
     }
 }
 
+/// A to-one relation of the queried model, requested to be hydrated together
+/// with the parent records (in the same database query where possible).
+#[derive(Debug, Clone)]
+pub struct ToOneRelationSelection {
+    /// The relation field on the parent model. Never a list.
+    pub field: RelationFieldRef,
+
+    /// The selection of the related records.
+    pub selected_fields: FieldSelection,
+}
+
 #[async_trait]
 pub trait ReadOperations {
     /// Gets a single record or `None` back from the database.
@@ -238,6 +249,26 @@ pub trait ReadOperations {
         aggregation_selections: &[RelAggregationSelection],
     ) -> crate::Result<ManyRecords>;
 
+    /// Gets multiple records plus the records of the given to-one relations,
+    /// hydrated in the same database query where the connector supports it
+    /// (a `LEFT JOIN` on the same statement for SQL connectors).
+    ///
+    /// Returns `None` if the relations cannot be hydrated together with this
+    /// particular query (or not at all), in which case the caller is expected
+    /// to fall back to `get_many_records` plus a separate query per relation.
+    ///
+    /// On success, the related records are returned in the order of `relations`,
+    /// with their `parent_id` set to the primary identifier of the parent record
+    /// they belong to.
+    async fn get_many_records_joined(
+        &mut self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &FieldSelection,
+        aggregation_selections: &[RelAggregationSelection],
+        relations: &[ToOneRelationSelection],
+    ) -> crate::Result<Option<(ManyRecords, Vec<ManyRecords>)>>;
+
     /// Retrieves pairs of IDs that belong together from a intermediate join
     /// table.
     ///
@@ -280,16 +311,23 @@ pub trait WriteOperations {
     ) -> crate::Result<usize>;
 
     /// Update records in the `Model` with the given `WriteArgs` filtered by the
-    /// `Filter`.
+    /// `Filter`. If `limit` is set, only the first `n` matching records are updated.
     async fn update_records(
         &mut self,
         model: &ModelRef,
         record_filter: RecordFilter,
         args: WriteArgs,
+        limit: Option<usize>,
     ) -> crate::Result<Vec<SelectionResult>>;
 
-    /// Delete records in the `Model` with the given `Filter`.
-    async fn delete_records(&mut self, model: &ModelRef, record_filter: RecordFilter) -> crate::Result<usize>;
+    /// Delete records in the `Model` with the given `Filter`. If `limit` is set,
+    /// only the first `n` matching records are deleted.
+    async fn delete_records(
+        &mut self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        limit: Option<usize>,
+    ) -> crate::Result<usize>;
 
     // We plan to remove the methods below in the future. We want emulate them with the ones above. Those should suffice.
 
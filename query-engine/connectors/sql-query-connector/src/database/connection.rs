@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use connector::{ConnectionLike, RelAggregationSelection};
 use connector_interface::{
     self as connector, filter::Filter, AggregationRow, AggregationSelection, Connection, QueryArguments,
-    ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
+    ReadOperations, RecordFilter, ToOneRelationSelection, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::{prelude::*, SelectionResult};
 use prisma_value::PrismaValue;
@@ -89,6 +89,29 @@ where
         .await
     }
 
+    async fn get_many_records_joined(
+        &mut self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &FieldSelection,
+        aggr_selections: &[RelAggregationSelection],
+        relations: &[ToOneRelationSelection],
+    ) -> connector::Result<Option<(ManyRecords, Vec<ManyRecords>)>> {
+        catch(self.connection_info.clone(), async move {
+            read::get_many_records_joined(
+                &self.inner,
+                model,
+                query_arguments,
+                &selected_fields.into(),
+                aggr_selections,
+                relations,
+                SqlInfo::from(&self.connection_info),
+            )
+            .await
+        })
+        .await
+    }
+
     async fn get_related_m2m_record_ids(
         &mut self,
         from_field: &RelationFieldRef,
@@ -151,16 +174,22 @@ where
         model: &ModelRef,
         record_filter: RecordFilter,
         args: WriteArgs,
+        limit: Option<usize>,
     ) -> connector::Result<Vec<SelectionResult>> {
         catch(self.connection_info.clone(), async move {
-            write::update_records(&self.inner, model, record_filter, args).await
+            write::update_records(&self.inner, model, record_filter, args, limit).await
         })
         .await
     }
 
-    async fn delete_records(&mut self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
+    async fn delete_records(
+        &mut self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        limit: Option<usize>,
+    ) -> connector::Result<usize> {
         catch(self.connection_info.clone(), async move {
-            write::delete_records(&self.inner, model, record_filter).await
+            write::delete_records(&self.inner, model, record_filter, limit).await
         })
         .await
     }
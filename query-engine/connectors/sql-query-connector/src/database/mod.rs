@@ -33,6 +33,47 @@ pub trait FromSource {
         Self: Connector + Sized;
 }
 
+/// How many times a checkout is retried when the pool hands out a stale
+/// connection, before the error is returned to the caller.
+const MAX_STALE_CONNECTION_RETRIES: u32 = 2;
+
+/// Check out a connection from the pool, retrying a bounded number of times
+/// when the pool hands out a connection that died — typically right after a
+/// database restart. The broken connection is dropped on the way, which
+/// evicts it from the pool, so every retry gets a fresh connection.
+async fn check_out_with_retries(
+    pool: &quaint::pooled::Quaint,
+) -> Result<quaint::pooled::PooledConnection, crate::SqlError> {
+    let mut attempt = 0;
+
+    loop {
+        match pool.check_out().await {
+            Ok(conn) => return Ok(conn),
+            Err(err) if attempt < MAX_STALE_CONNECTION_RETRIES && is_stale_connection_error(&err) => {
+                attempt += 1;
+
+                tracing::warn!(
+                    attempt,
+                    error = %err,
+                    "Evicted a broken connection from the pool. Retrying the checkout."
+                );
+
+                tokio::time::sleep(std::time::Duration::from_millis(50 * u64::from(attempt))).await;
+            }
+            Err(err) => return Err(crate::SqlError::from(err)),
+        }
+    }
+}
+
+fn is_stale_connection_error(err: &quaint::error::Error) -> bool {
+    matches!(
+        err.kind(),
+        quaint::error::ErrorKind::ConnectionError(_)
+            | quaint::error::ErrorKind::ConnectionClosed
+            | quaint::error::ErrorKind::IoError(_)
+    )
+}
+
 async fn catch<O>(
     connection_info: quaint::prelude::ConnectionInfo,
     fut: impl std::future::Future<Output = Result<O, crate::SqlError>>,
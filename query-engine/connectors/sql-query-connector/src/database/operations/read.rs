@@ -1,5 +1,5 @@
 use crate::{
-    column_metadata,
+    column_metadata, join_utils,
     model_extensions::*,
     query_arguments_ext::QueryArgumentsExt,
     query_builder::{self, read},
@@ -137,6 +137,118 @@ pub async fn get_many_records(
     Ok(records)
 }
 
+/// Variant of `get_many_records` that additionally hydrates the given to-one
+/// relations through `LEFT JOIN`s on the same statement, saving one roundtrip
+/// per relation. Returns `None` if the relations cannot be hydrated on this
+/// particular query, in which case the caller is expected to fall back to a
+/// separate query per relation.
+#[tracing::instrument(skip(conn, model, query_arguments, selected_fields, aggr_selections, relations, sql_info))]
+pub async fn get_many_records_joined(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    selected_fields: &ModelProjection,
+    aggr_selections: &[RelAggregationSelection],
+    relations: &[ToOneRelationSelection],
+    sql_info: SqlInfo,
+) -> crate::Result<Option<(ManyRecords, Vec<ManyRecords>)>> {
+    // Queries split into multiple queries due to parameter limits can't hydrate
+    // relations in the same statement.
+    if matches!(sql_info.max_bind_values, Some(chunk_size) if query_arguments.should_batch(chunk_size)) {
+        return Ok(None);
+    }
+
+    if let Some(0) = query_arguments.take {
+        return Ok(None);
+    }
+
+    // Cursor conditions introduce an additional table in the `FROM` clause that the
+    // appended joins wouldn't attach to correctly.
+    if query_arguments.cursor.is_some() {
+        return Ok(None);
+    }
+
+    let reversed = query_arguments.needs_reversed_order();
+    let child_projections: Vec<ModelProjection> = relations
+        .iter()
+        .map(|relation| ModelProjection::from(&relation.selected_fields))
+        .collect();
+
+    let mut field_names: Vec<_> = selected_fields.db_names().collect();
+    let mut aggr_field_names: Vec<_> = aggr_selections.iter().map(|aggr_sel| aggr_sel.db_alias()).collect();
+
+    field_names.append(&mut aggr_field_names);
+
+    let mut idents = selected_fields.type_identifiers_with_arities();
+    let mut aggr_idents = aggr_selections
+        .iter()
+        .map(|aggr_sel| aggr_sel.type_identifier_with_arity())
+        .collect();
+
+    idents.append(&mut aggr_idents);
+
+    // The selection set is laid out as the parent columns (including aggregations),
+    // followed by the columns of each joined relation, in order.
+    let parent_len = field_names.len();
+    let mut all_field_names = field_names.clone();
+
+    for projection in child_projections.iter() {
+        all_field_names.extend(projection.db_names());
+        idents.extend(projection.type_identifiers_with_arities());
+    }
+
+    let meta = column_metadata::create(all_field_names.as_slice(), idents.as_slice());
+    let mut query = read::get_records(model, selected_fields.as_columns(), aggr_selections, query_arguments);
+
+    for (index, (relation, projection)) in relations.iter().zip(child_projections.iter()).enumerate() {
+        let join = join_utils::compute_to_one_join(&relation.field, &format!("to_one_{}", index));
+
+        query = query.left_join(join.data);
+
+        for db_name in projection.db_names() {
+            query = query.column(Column::from((join.alias.clone(), db_name)));
+        }
+    }
+
+    let model_id = model.primary_identifier();
+    let mut records = ManyRecords::new(field_names.clone());
+    let mut nested_records: Vec<ManyRecords> = child_projections
+        .iter()
+        .map(|projection| ManyRecords::new(projection.db_names().collect()))
+        .collect();
+
+    for item in conn.filter(query.into(), meta.as_slice()).await?.into_iter() {
+        let mut record = Record::from(item);
+        let mut remainder = record.values.split_off(parent_len);
+        let parent_id = record.extract_selection_result(&field_names, &model_id)?;
+
+        for (index, projection) in child_projections.iter().enumerate() {
+            let rest = remainder.split_off(projection.db_names().count());
+            let values = std::mem::replace(&mut remainder, rest);
+
+            // A `LEFT JOIN` miss materializes as an all-`NULL` child - the child's
+            // primary identifier is always part of the selection and can never be
+            // null for an existing record.
+            if values.iter().all(|value| value.is_null()) {
+                continue;
+            }
+
+            let mut child_record = Record::new(values);
+
+            child_record.parent_id = Some(parent_id.clone());
+            nested_records[index].push(child_record);
+        }
+
+        records.push(record);
+    }
+
+    if reversed {
+        records.reverse();
+    }
+
+    Ok(Some((records, nested_records)))
+}
+
 #[tracing::instrument(skip(conn, from_field, from_record_ids))]
 pub async fn get_related_m2m_record_ids(
     conn: &dyn QueryExt,
@@ -219,15 +219,17 @@ async fn create_many_empty(
 
 /// Update multiple records in a database defined in `conn` and the records
 /// defined in `args`, resulting the identifiers that were modified in the
-/// operation.
+/// operation. An optional `limit` restricts the update to the first `n`
+/// matching records.
 #[tracing::instrument(skip(conn, model, record_filter, args))]
 pub async fn update_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
+    limit: Option<usize>,
 ) -> crate::Result<Vec<SelectionResult>> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn.filter_selectors(model, record_filter, limit).await?;
     let id_args = pick_args(&model.primary_identifier().into(), &args);
 
     if ids.is_empty() {
@@ -247,13 +249,15 @@ pub async fn update_records(
 }
 
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
+/// An optional `limit` restricts the delete to the first `n` matching records.
 #[tracing::instrument(skip(conn, model, record_filter))]
 pub async fn delete_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
     record_filter: RecordFilter,
+    limit: Option<usize>,
 ) -> crate::Result<usize> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn.filter_selectors(model, record_filter, limit).await?;
     let ids: Vec<&SelectionResult> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
@@ -277,8 +281,9 @@ pub async fn m2m_connect(
     parent_id: &SelectionResult,
     child_ids: &[SelectionResult],
 ) -> crate::Result<()> {
-    let query = write::create_relation_table_records(field, parent_id, child_ids);
-    conn.query(query).await?;
+    for query in write::create_relation_table_records(field, parent_id, child_ids) {
+        conn.query(query).await?;
+    }
 
     Ok(())
 }
@@ -292,8 +297,9 @@ pub async fn m2m_disconnect(
     parent_id: &SelectionResult,
     child_ids: &[SelectionResult],
 ) -> crate::Result<()> {
-    let query = write::delete_relation_table_records(field, parent_id, child_ids);
-    conn.delete(query).await?;
+    for query in write::delete_relation_table_records(field, parent_id, child_ids) {
+        conn.query(query).await?;
+    }
 
     Ok(())
 }
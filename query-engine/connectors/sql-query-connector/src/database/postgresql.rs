@@ -44,7 +44,7 @@ impl Connector for PostgreSql {
     #[tracing::instrument(skip(self))]
     async fn get_connection<'a>(&'a self) -> connector_interface::Result<Box<dyn Connection + Send + Sync + 'static>> {
         super::catch(self.connection_info.clone(), async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
+            let conn = super::check_out_with_retries(&self.pool).await?;
             let conn = SqlConnection::new(conn, &self.connection_info);
             Ok(Box::new(conn) as Box<dyn Connection + Send + Sync + 'static>)
         })
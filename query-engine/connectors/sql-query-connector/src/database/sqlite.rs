@@ -7,12 +7,24 @@ use connector_interface::{
     Connection, Connector,
 };
 use datamodel::Datasource;
-use quaint::{connector::SqliteParams, error::ErrorKind as QuaintKind, pooled::Quaint, prelude::ConnectionInfo};
+use quaint::{
+    connector::SqliteParams,
+    error::ErrorKind as QuaintKind,
+    pooled::Quaint,
+    prelude::{ConnectionInfo, Queryable},
+};
 use std::{convert::TryFrom, time::Duration};
 
+/// The SQLite pragmas that can be configured through the datasource URL, e.g.
+/// `file:dev.db?journal_mode=WAL&busy_timeout=5000`.
+const SUPPORTED_PRAGMAS: &[&str] = &["journal_mode", "busy_timeout", "foreign_keys", "synchronous"];
+
 pub struct Sqlite {
     pool: Quaint,
     file_path: String,
+    /// Pragma statements from the datasource URL. Pragmas only affect the
+    /// connection that issued them, so they are applied on every checkout.
+    pragmas: Vec<String>,
 }
 
 impl Sqlite {
@@ -39,8 +51,9 @@ impl FromSource for Sqlite {
 
         let file_path = params.file_path;
         let url = database_str.split('?').next();
+        let is_in_memory = file_path.contains(":memory:") || database_str.contains("mode=memory");
 
-        if url.is_none() || std::path::Path::new(url.unwrap()).file_stem().is_none() {
+        if !is_in_memory && (url.is_none() || std::path::Path::new(url.unwrap()).file_stem().is_none()) {
             return Err(invalid_file_path_error(&file_path, &connection_info));
         }
 
@@ -51,12 +64,51 @@ impl FromSource for Sqlite {
         builder.health_check_interval(Duration::from_secs(15));
         builder.test_on_check_out(true);
 
+        if is_in_memory {
+            // Every connection to an in-memory database sees its own, initially
+            // empty database, so the pool must hand out one shared connection.
+            builder.connection_limit(1);
+        }
+
         let pool = builder.build();
+        let pragmas = pragma_statements(database_str);
 
-        Ok(Sqlite { pool, file_path })
+        Ok(Sqlite {
+            pool,
+            file_path,
+            pragmas,
+        })
     }
 }
 
+fn pragma_statements(url: &str) -> Vec<String> {
+    let query = match url.splitn(2, '?').nth(1) {
+        Some(query) => query,
+        None => return Vec::new(),
+    };
+
+    query
+        .split('&')
+        .filter_map(|pair| {
+            let mut split = pair.splitn(2, '=');
+            let name = split.next()?;
+            let value = split.next()?;
+
+            if !SUPPORTED_PRAGMAS.contains(&name) {
+                return None;
+            }
+
+            // Pragma values are interpolated into the statement, so only plain
+            // keywords and numbers are accepted.
+            if value.is_empty() || !value.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+
+            Some(format!("PRAGMA {} = {}", name, value))
+        })
+        .collect()
+}
+
 fn invalid_file_path_error(file_path: &str, connection_info: &ConnectionInfo) -> ConnectorError {
     SqlError::ConnectionError(QuaintKind::DatabaseUrlIsInvalid(format!(
         "\"{}\" is not a valid sqlite file path",
@@ -71,6 +123,11 @@ impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + Send + Sync + 'static>> {
         super::catch(self.connection_info().clone(), async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
+
+            for pragma in &self.pragmas {
+                conn.query_raw(pragma, &[]).await.map_err(SqlError::from)?;
+            }
+
             let conn = SqlConnection::new(conn, self.connection_info());
 
             Ok(Box::new(conn) as Box<dyn Connection + Send + Sync + 'static>)
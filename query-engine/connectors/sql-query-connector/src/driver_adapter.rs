@@ -0,0 +1,69 @@
+//! Extension point for delegating SQL execution to an external driver.
+//!
+//! In environments where the engine cannot open native TCP connections —
+//! serverless JavaScript runtimes being the prime example — the actual
+//! database driver lives outside the engine (e.g. `pg`, Neon or the
+//! PlanetScale serverless driver) and the engine only produces SQL and
+//! consumes result sets. The traits in this module describe the protocol
+//! between the engine and such a driver: plain parameterized queries and
+//! executes, plus explicitly managed transactions.
+//!
+//! An adapter is meant to be selected at engine construction time by the
+//! bindings (Node-API, eventually Wasm) and used for every connection instead
+//! of the quaint pool. The wiring into the connection handling is not in
+//! place yet; it requires the bindings to be able to pass a driver down,
+//! which is tracked separately.
+
+use async_trait::async_trait;
+use quaint::{connector::ResultSet, Value};
+
+/// The SQL dialect spoken by an external driver. The engine uses this to pick
+/// the right visitor for rendering queries and the right coercion rules for
+/// result values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterFlavour {
+    Mysql,
+    Postgres,
+    Sqlite,
+}
+
+/// A database driver living outside the engine.
+///
+/// All SQL produced by the engine is parameterized; the adapter must bind the
+/// given values and may not interpolate them into the SQL string.
+#[async_trait]
+pub trait DriverAdapter: Send + Sync + 'static {
+    /// The SQL dialect the driver speaks.
+    fn flavour(&self) -> AdapterFlavour;
+
+    /// Run a query returning rows.
+    async fn query_raw(&self, sql: &str, params: &[Value<'_>]) -> crate::Result<ResultSet>;
+
+    /// Run a statement, returning the number of affected rows.
+    async fn execute_raw(&self, sql: &str, params: &[Value<'_>]) -> crate::Result<u64>;
+
+    /// Start a transaction. All statements issued through the returned handle
+    /// must run on the same underlying connection.
+    async fn start_transaction(&self) -> crate::Result<Box<dyn DriverAdapterTransaction>>;
+}
+
+/// A transaction opened through a [`DriverAdapter`].
+///
+/// Dropping a handle without calling [`commit`](Self::commit) or
+/// [`rollback`](Self::rollback) must leave the transaction to be rolled back
+/// by the driver.
+#[async_trait]
+pub trait DriverAdapterTransaction: Send + Sync {
+    /// Run a query returning rows inside the transaction.
+    async fn query_raw(&self, sql: &str, params: &[Value<'_>]) -> crate::Result<ResultSet>;
+
+    /// Run a statement inside the transaction, returning the number of
+    /// affected rows.
+    async fn execute_raw(&self, sql: &str, params: &[Value<'_>]) -> crate::Result<u64>;
+
+    /// Commit the transaction.
+    async fn commit(self: Box<Self>) -> crate::Result<()>;
+
+    /// Roll the transaction back.
+    async fn rollback(self: Box<Self>) -> crate::Result<()>;
+}
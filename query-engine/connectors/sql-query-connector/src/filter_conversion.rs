@@ -1,9 +1,30 @@
 use crate::model_extensions::*;
 use connector_interface::filter::*;
+use once_cell::sync::Lazy;
 use prisma_models::prelude::*;
 use quaint::ast::*;
 use std::convert::TryInto;
 
+/// Strategy for translating relation filters (`some` / `every` / `none`) to SQL,
+/// set via the `PRISMA_RELATION_FILTER_SQL` environment value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RelationFilterStrategy {
+    /// `parent ids IN (SELECT linking ids ...)` over an uncorrelated subquery.
+    /// The historical default.
+    InSubquery,
+
+    /// A correlated subquery probed once per parent row, the equivalent of
+    /// `EXISTS` / `NOT EXISTS`. Optimizes better on MySQL for large child
+    /// tables, where the uncorrelated `IN` subquery is materialized in full.
+    Exists,
+}
+
+static RELATION_FILTER_STRATEGY: Lazy<RelationFilterStrategy> =
+    Lazy::new(|| match std::env::var("PRISMA_RELATION_FILTER_SQL").as_deref() {
+        Ok("exists") => RelationFilterStrategy::Exists,
+        _ => RelationFilterStrategy::InSubquery,
+    });
+
 #[derive(Clone, Copy, Debug)]
 /// A distinction in aliasing to separate the parent table and the joined data
 /// in the statement.
@@ -121,6 +142,7 @@ impl AliasedCondition for Filter {
             Filter::Scalar(filter) => filter.aliased_cond(alias),
             Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
             Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::RelationCount(filter) => filter.aliased_cond(alias),
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -167,7 +189,7 @@ fn scalar_filter_aliased_cond_search(sf: ScalarFilter, alias: Option<Alias>) ->
 
     let comparable: Expression = text_search(columns.as_slice()).into();
 
-    convert_scalar_filter(comparable, sf.condition, sf.mode, &[], false)
+    convert_scalar_filter(comparable, sf.condition, sf.mode, &[], alias, false)
 }
 
 fn scalar_filter_aliased_cond(sf: ScalarFilter, alias: Option<Alias>) -> ConditionTree<'static> {
@@ -175,7 +197,7 @@ fn scalar_filter_aliased_cond(sf: ScalarFilter, alias: Option<Alias>) -> Conditi
         (Some(alias), ScalarProjection::Single(field)) => {
             let comparable: Expression = field.as_column().table(alias.to_string(None)).into();
 
-            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], false)
+            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], Some(alias), false)
         }
         (Some(alias), ScalarProjection::Compound(fields)) => {
             let columns: Vec<Column<'static>> = fields
@@ -184,17 +206,24 @@ fn scalar_filter_aliased_cond(sf: ScalarFilter, alias: Option<Alias>) -> Conditi
                 .map(|field| field.as_column().table(alias.to_string(None)))
                 .collect();
 
-            convert_scalar_filter(Row::from(columns).into(), sf.condition, sf.mode, &fields, false)
+            convert_scalar_filter(
+                Row::from(columns).into(),
+                sf.condition,
+                sf.mode,
+                &fields,
+                Some(alias),
+                false,
+            )
         }
         (None, ScalarProjection::Single(field)) => {
             let comparable: Expression = field.as_column().into();
 
-            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], false)
+            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], None, false)
         }
         (None, ScalarProjection::Compound(fields)) => {
             let columns: Vec<Column<'static>> = fields.clone().into_iter().map(|field| field.as_column()).collect();
 
-            convert_scalar_filter(Row::from(columns).into(), sf.condition, sf.mode, &fields, false)
+            convert_scalar_filter(Row::from(columns).into(), sf.condition, sf.mode, &fields, None, false)
         }
     }
 }
@@ -242,16 +271,137 @@ impl AliasedCondition for RelationFilter {
         };
 
         let condition = self.condition;
-        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
 
-        let comparison = match condition {
-            RelationCondition::AtLeastOneRelatedRecord => Row::from(columns).in_selection(sub_select),
-            RelationCondition::EveryRelatedRecord => Row::from(columns).not_in_selection(sub_select),
-            RelationCondition::NoRelatedRecord => Row::from(columns).not_in_selection(sub_select),
-            RelationCondition::ToOneRelatedRecord => Row::from(columns).in_selection(sub_select),
+        match *RELATION_FILTER_STRATEGY {
+            RelationFilterStrategy::InSubquery => {
+                let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
+
+                let comparison = match condition {
+                    RelationCondition::AtLeastOneRelatedRecord => Row::from(columns).in_selection(sub_select),
+                    RelationCondition::EveryRelatedRecord => Row::from(columns).not_in_selection(sub_select),
+                    RelationCondition::NoRelatedRecord => Row::from(columns).not_in_selection(sub_select),
+                    RelationCondition::ToOneRelatedRecord => Row::from(columns).in_selection(sub_select),
+                };
+
+                comparison.into()
+            }
+            RelationFilterStrategy::Exists => {
+                let sub_select = relation_filter_correlated_sel(self, columns, alias.map(|a| a.inc(AliasMode::Table)));
+                let probe = Expression::from(sub_select);
+
+                // Quaint has no dedicated `EXISTS` node, but a correlated single-value
+                // subquery compared against `NULL` is evaluated the same way by the
+                // planners: probe the related table once per parent row, stopping at
+                // the first match.
+                let comparison = match condition {
+                    RelationCondition::AtLeastOneRelatedRecord => probe.is_not_null(),
+                    RelationCondition::EveryRelatedRecord => probe.is_null(),
+                    RelationCondition::NoRelatedRecord => probe.is_null(),
+                    RelationCondition::ToOneRelatedRecord => probe.is_not_null(),
+                };
+
+                comparison.into()
+            }
+        }
+    }
+}
+
+/// The subselect of a relation filter under the `Exists` strategy. Instead of returning
+/// the linking columns of all related records for an `IN` comparison, the select is
+/// correlated with the outer query through the given columns and probes for the first
+/// matching related record only.
+fn relation_filter_correlated_sel(
+    filter: RelationFilter,
+    outer_columns: Vec<Column<'static>>,
+    alias: Option<Alias>,
+) -> Select<'static> {
+    let alias = alias.unwrap_or_default();
+    let condition = filter.condition;
+
+    let table = filter.field.as_table();
+    let selected_identifier: Vec<Column> = filter
+        .field
+        .identifier_columns()
+        .map(|c| c.table(alias.to_string(None)))
+        .collect();
+
+    let join_columns: Vec<Column> = filter
+        .field
+        .join_columns()
+        .map(|c| c.table(alias.to_string(None)))
+        .collect();
+
+    let related_table = filter.field.related_model().as_table();
+    let related_join_columns: Vec<_> = ModelProjection::from(filter.field.related_field().linking_fields())
+        .as_columns()
+        .map(|col| col.table(alias.to_string(Some(AliasMode::Join))))
+        .collect();
+
+    let nested_conditions = filter
+        .nested_filter
+        .aliased_cond(Some(alias.flip(AliasMode::Join)))
+        .invert_if(condition.invert_of_subselect());
+
+    // `NULL` linking values never equal the outer row, so unlike the `IN` strategy no
+    // additional `IS NOT NULL` guards are needed on the selected identifier.
+    let conditions = nested_conditions.and(Row::from(selected_identifier).equals(Row::from(outer_columns)));
+
+    let join = related_table
+        .alias(alias.to_string(Some(AliasMode::Join)))
+        .on(Row::from(related_join_columns).equals(Row::from(join_columns)));
+
+    Select::from_table(table.alias(alias.to_string(Some(AliasMode::Table))))
+        .value(Value::integer(1).raw())
+        .inner_join(join)
+        .so_that(conditions)
+        .limit(1)
+}
+
+impl AliasedCondition for RelationCountFilter {
+    /// Conversion from a `RelationCountFilter` to a query condition tree. The number of
+    /// related records is computed by a correlated `COUNT` subselect and compared as a
+    /// scalar value. A count over an empty set yields 0, so conditions including zero
+    /// match parents without any related records.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let ids = ModelProjection::from(self.field.model().primary_identifier()).as_columns();
+        let columns: Vec<Column<'static>> = match alias {
+            Some(alias) => ids.map(|c| c.table(alias.to_string(None))).collect(),
+            None => ids.collect(),
         };
 
-        comparison.into()
+        let sub_alias = alias.map(|a| a.inc(AliasMode::Table)).unwrap_or_default();
+
+        let table = self.field.as_table();
+        let selected_identifier: Vec<Column> = self
+            .field
+            .identifier_columns()
+            .map(|c| c.table(sub_alias.to_string(None)))
+            .collect();
+
+        let join_columns: Vec<Column> = self
+            .field
+            .join_columns()
+            .map(|c| c.table(sub_alias.to_string(None)))
+            .collect();
+
+        let related_table = self.field.related_model().as_table();
+        let related_join_columns: Vec<_> = ModelProjection::from(self.field.related_field().linking_fields())
+            .as_columns()
+            .map(|col| col.table(sub_alias.to_string(Some(AliasMode::Join))))
+            .collect();
+
+        let join = related_table
+            .alias(sub_alias.to_string(Some(AliasMode::Join)))
+            .on(Row::from(related_join_columns).equals(Row::from(join_columns)));
+
+        let count_select = Select::from_table(table.alias(sub_alias.to_string(None)))
+            .value(count(asterisk()))
+            .inner_join(join)
+            .so_that(Row::from(selected_identifier).equals(Row::from(columns)));
+
+        let comparable = Expression::from(count_select);
+
+        convert_scalar_filter(comparable, self.condition, QueryMode::Default, &[], alias, false)
     }
 }
 
@@ -393,11 +543,11 @@ where
         }
         (Some(alias), ScalarProjection::Single(field)) => {
             let comparable: Expression = field_transformer(field.as_column().table(alias.to_string(None)));
-            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], true)
+            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], Some(alias), true)
         }
         (None, ScalarProjection::Single(field)) => {
             let comparable: Expression = field_transformer(field.as_column());
-            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], true)
+            convert_scalar_filter(comparable, sf.condition, sf.mode, &[field], None, true)
         }
     }
 }
@@ -407,15 +557,20 @@ fn convert_scalar_filter(
     cond: ScalarCondition,
     mode: QueryMode,
     fields: &[ScalarFieldRef],
+    alias: Option<Alias>,
     is_parent_aggregation: bool,
 ) -> ConditionTree<'static> {
     match cond {
-        ScalarCondition::JsonCompare(json_compare) => {
-            convert_json_filter(comparable, json_compare, mode, fields.first().unwrap().to_owned())
-        }
+        ScalarCondition::JsonCompare(json_compare) => convert_json_filter(
+            comparable,
+            json_compare,
+            mode,
+            fields.first().unwrap().to_owned(),
+            alias,
+        ),
         _ => match mode {
-            QueryMode::Default => default_scalar_filter(comparable, cond, fields),
-            QueryMode::Insensitive => insensitive_scalar_filter(comparable, cond, fields, is_parent_aggregation),
+            QueryMode::Default => default_scalar_filter(comparable, cond, fields, alias),
+            QueryMode::Insensitive => insensitive_scalar_filter(comparable, cond, fields, alias, is_parent_aggregation),
         },
     }
 }
@@ -425,6 +580,7 @@ fn convert_json_filter(
     json_condition: JsonCondition,
     query_mode: QueryMode,
     field: ScalarFieldRef,
+    alias: Option<Alias>,
 ) -> ConditionTree<'static> {
     let json_filter_path = json_condition.path;
     let cond = json_condition.condition;
@@ -524,7 +680,7 @@ fn convert_json_filter(
             .and(filter_json_type(expr_json, value))
             .into(),
         _ => {
-            return convert_scalar_filter(expr_json, *cond, query_mode, &[field], false);
+            return convert_scalar_filter(expr_json, *cond, query_mode, &[field], alias, false);
         }
     };
 
@@ -550,6 +706,7 @@ fn default_scalar_filter(
     comparable: Expression<'static>,
     cond: ScalarCondition,
     fields: &[ScalarFieldRef],
+    alias: Option<Alias>,
 ) -> ConditionTree<'static> {
     let condition = match cond {
         ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
@@ -568,6 +725,14 @@ fn default_scalar_filter(
         ScalarCondition::GreaterThanOrEquals(value) => {
             comparable.greater_than_or_equals(convert_first_value(fields, value))
         }
+        ScalarCondition::EqualsField(field) => comparable.equals(field_ref_expr(&field, alias)),
+        ScalarCondition::NotEqualsField(field) => comparable.not_equals(field_ref_expr(&field, alias)),
+        ScalarCondition::LessThanField(field) => comparable.less_than(field_ref_expr(&field, alias)),
+        ScalarCondition::LessThanOrEqualsField(field) => comparable.less_than_or_equals(field_ref_expr(&field, alias)),
+        ScalarCondition::GreaterThanField(field) => comparable.greater_than(field_ref_expr(&field, alias)),
+        ScalarCondition::GreaterThanOrEqualsField(field) => {
+            comparable.greater_than_or_equals(field_ref_expr(&field, alias))
+        }
         ScalarCondition::In(values) => match values.split_first() {
             Some((PrismaValue::List(_), _)) => {
                 let mut sql_values = Values::with_capacity(values.len());
@@ -618,10 +783,15 @@ fn insensitive_scalar_filter(
     comparable: Expression<'static>,
     cond: ScalarCondition,
     fields: &[ScalarFieldRef],
+    alias: Option<Alias>,
     is_parent_aggregation: bool,
 ) -> ConditionTree<'static> {
-    // Current workaround: We assume we can use ILIKE when we see `mode: insensitive`, because postgres is the only DB that has
-    // insensitive. We need a connector context for filter building that is unexpectedly complicated to integrate.
+    // Invariant: `mode: insensitive` only reaches this code for connectors advertising the
+    // `InsensitiveFilters` capability (the schema builder doesn't render the `mode` argument otherwise,
+    // and the query mode extractor rejects it with a clear error). All of those connectors understand
+    // `ILIKE`, so we can use it directly for string comparisons and fall back to `LOWER()` for the
+    // ordered comparisons below. Should a `LOWER()`-only flavour ever gain the capability, this
+    // function needs a connector context to pick the right operator per flavour.
     let condition = match cond {
         ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
         ScalarCondition::NotEquals(PrismaValue::Null) => comparable.is_not_null(),
@@ -653,6 +823,36 @@ fn insensitive_scalar_filter(
 
             comparable.greater_than_or_equals(lower(convert_first_value(fields, value)))
         }
+        ScalarCondition::EqualsField(field) => {
+            let comparable: Expression = lower_if(comparable, !is_parent_aggregation);
+
+            comparable.equals(lower(field_ref_expr(&field, alias)))
+        }
+        ScalarCondition::NotEqualsField(field) => {
+            let comparable: Expression = lower_if(comparable, !is_parent_aggregation);
+
+            comparable.not_equals(lower(field_ref_expr(&field, alias)))
+        }
+        ScalarCondition::LessThanField(field) => {
+            let comparable: Expression = lower_if(comparable, !is_parent_aggregation);
+
+            comparable.less_than(lower(field_ref_expr(&field, alias)))
+        }
+        ScalarCondition::LessThanOrEqualsField(field) => {
+            let comparable: Expression = lower_if(comparable, !is_parent_aggregation);
+
+            comparable.less_than_or_equals(lower(field_ref_expr(&field, alias)))
+        }
+        ScalarCondition::GreaterThanField(field) => {
+            let comparable: Expression = lower_if(comparable, !is_parent_aggregation);
+
+            comparable.greater_than(lower(field_ref_expr(&field, alias)))
+        }
+        ScalarCondition::GreaterThanOrEqualsField(field) => {
+            let comparable: Expression = lower_if(comparable, !is_parent_aggregation);
+
+            comparable.greater_than_or_equals(lower(field_ref_expr(&field, alias)))
+        }
         ScalarCondition::In(values) => match values.split_first() {
             Some((PrismaValue::List(_), _)) => {
                 let mut sql_values = Values::with_capacity(values.len());
@@ -727,6 +927,15 @@ fn insensitive_scalar_filter(
     ConditionTree::single(condition)
 }
 
+/// A column expression for a referenced field of the same model, qualified with the
+/// same alias as the column it is compared against.
+fn field_ref_expr(field: &ScalarFieldRef, alias: Option<Alias>) -> Expression<'static> {
+    match alias {
+        Some(alias) => field.as_column().table(alias.to_string(None)).into(),
+        None => field.as_column().into(),
+    }
+}
+
 fn lower_if(expr: Expression<'static>, cond: bool) -> Expression<'static> {
     if cond {
         lower(expr).into()
@@ -0,0 +1,101 @@
+use connector_interface::{
+    filter::{Filter, ScalarProjection},
+    QueryArguments,
+};
+use once_cell::sync::Lazy;
+use prisma_models::*;
+use std::collections::BTreeSet;
+
+/// Env var toggling the index advisor. Set to `1` or `true` during development to emit
+/// a structured record on the tracing pipeline (target `index_advisor`) whenever a read
+/// query filters or orders on columns no index covers.
+pub const INDEX_ADVISOR_ENV: &str = "QUERY_INDEX_ADVISOR";
+
+static INDEX_ADVISOR_ENABLED: Lazy<bool> = Lazy::new(|| {
+    std::env::var(INDEX_ADVISOR_ENV)
+        .map(|s| s == "1" || s == "true")
+        .unwrap_or(false)
+});
+
+/// Inspects the filters and orderings of a read query and emits an advisory event when
+/// none of the model's indexes can serve them. Only the leading column of an index
+/// counts as covered - a condition on the second column of a compound index alone
+/// still scans.
+pub(crate) fn advise(query_arguments: &QueryArguments, model: &ModelRef) {
+    if !*INDEX_ADVISOR_ENABLED {
+        return;
+    }
+
+    let mut candidates = BTreeSet::new();
+
+    if let Some(filter) = &query_arguments.filter {
+        collect_filter_fields(filter, &mut candidates);
+    }
+
+    for order_by in &query_arguments.order_by {
+        if let OrderBy::Scalar(scalar) = order_by {
+            if scalar.path.is_empty() {
+                candidates.insert(scalar.field.name.clone());
+            }
+        }
+    }
+
+    let unindexed: Vec<String> = candidates
+        .into_iter()
+        .filter(|field_name| !is_covered(model, field_name))
+        .collect();
+
+    if unindexed.is_empty() {
+        return;
+    }
+
+    tracing::info!(
+        target: "index_advisor",
+        model = %model.name,
+        fields = %unindexed.join(","),
+        suggestion = %format!("@@index([{}])", unindexed.join(", ")),
+    );
+}
+
+/// Collects the names of the scalar fields of the queried model that the filter
+/// conditions on. Relation filters are not followed - their conditions apply to
+/// other models.
+fn collect_filter_fields(filter: &Filter, fields: &mut BTreeSet<String>) {
+    match filter {
+        Filter::And(inner) | Filter::Or(inner) | Filter::Not(inner) => {
+            for filter in inner {
+                collect_filter_fields(filter, fields);
+            }
+        }
+        Filter::Scalar(scalar) => match &scalar.projection {
+            ScalarProjection::Single(field) => {
+                fields.insert(field.name.clone());
+            }
+            ScalarProjection::Compound(compound) => {
+                for field in compound {
+                    fields.insert(field.name.clone());
+                }
+            }
+        },
+        Filter::ScalarList(scalar_list) => {
+            fields.insert(scalar_list.field.name.clone());
+        }
+        _ => (),
+    }
+}
+
+/// Whether the field is the leading column of any index or of the primary key.
+fn is_covered(model: &ModelRef, field_name: &str) -> bool {
+    let leads_index = model
+        .indexes()
+        .iter()
+        .any(|index| index.fields().first().map(|f| f.name == field_name).unwrap_or(false));
+
+    let leads_primary_key = model
+        .primary_identifier()
+        .as_scalar_fields()
+        .and_then(|fields| fields.first().map(|f| f.name == field_name))
+        .unwrap_or(false);
+
+    leads_index || leads_primary_key
+}
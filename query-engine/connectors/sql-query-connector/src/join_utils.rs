@@ -90,6 +90,44 @@ fn compute_aggr_join_one2m(
     }
 }
 
+/// Computes a plain join from the given relation field's model to its related model,
+/// allowing the related record's columns to be selected alongside the parent ones
+/// (used to hydrate to-one relations without an additional roundtrip).
+pub fn compute_to_one_join(rf: &RelationFieldRef, join_alias: &str) -> AliasedJoin {
+    let join_alias = format!("{}_{}", join_alias, &rf.related_model().name);
+
+    let (left_fields, right_fields) = if rf.is_inlined_on_enclosing_model() {
+        (rf.scalar_fields(), rf.referenced_fields())
+    } else {
+        (
+            rf.related_field().referenced_fields(),
+            rf.related_field().scalar_fields(),
+        )
+    };
+
+    let pairs = left_fields.into_iter().zip(right_fields.into_iter());
+    let on_conditions: Vec<Expression> = pairs
+        .map(|(a, b)| {
+            let col_a = a.as_column();
+            let col_b = Column::from((join_alias.clone(), b.db_name().to_owned()));
+
+            col_a.equals(col_b).into()
+        })
+        .collect();
+
+    // + LEFT JOIN B AS <join_alias> ON (A.<fk> = <join_alias>.<id>)
+    let join = rf
+        .related_model()
+        .as_table()
+        .alias(join_alias.clone())
+        .on(ConditionTree::And(on_conditions));
+
+    AliasedJoin {
+        data: join,
+        alias: join_alias,
+    }
+}
+
 fn compute_aggr_join_m2m(
     rf: &RelationFieldRef,
     aggregation: AggregationType,
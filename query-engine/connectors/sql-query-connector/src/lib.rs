@@ -11,8 +11,10 @@
 mod column_metadata;
 mod cursor_condition;
 mod database;
+mod driver_adapter;
 mod error;
 mod filter_conversion;
+mod index_advisor;
 mod join_utils;
 mod model_extensions;
 mod nested_aggregations;
@@ -30,6 +32,7 @@ use query_ext::QueryExt;
 use row::*;
 
 pub use database::*;
+pub use driver_adapter::{AdapterFlavour, DriverAdapter, DriverAdapterTransaction};
 pub use error::SqlError;
 
 type Result<T> = std::result::Result<T, error::SqlError>;
@@ -13,6 +13,9 @@ pub struct OrderByDefinition {
     pub(crate) order_column: Expression<'static>,
     /// Defines ordering for an `ORDER BY` statement.
     pub(crate) order_definition: OrderDefinition<'static>,
+    /// Additional ordering on `<column> IS NULL`, rendered right before
+    /// `order_definition` to pin the placement of NULL values (`nulls: first | last`).
+    pub(crate) nulls_definition: Option<OrderDefinition<'static>>,
     /// Joins necessary to perform the order by
     pub(crate) joins: Vec<AliasedJoin>,
 }
@@ -50,14 +53,38 @@ fn build_order_scalar(
     let (joins, order_column) = compute_joins_scalar(order_by, index, base_model);
     let order: Option<Order> = Some(order_by.sort_order.into_order(needs_reversed_order));
     let order_definition: OrderDefinition = (order_column.clone().into(), order);
+    let nulls_definition = order_by
+        .nulls_order
+        .map(|nulls_order| build_nulls_placement(&order_column, nulls_order, needs_reversed_order));
 
     OrderByDefinition {
         order_column: order_column.into(),
         order_definition,
+        nulls_definition,
         joins,
     }
 }
 
+/// Builds the extra ordering expression pinning NULL placement. `<column> IS NULL`
+/// evaluates to true exactly for the NULL rows, so sorting that flag descending puts
+/// them first and ascending puts them last, regardless of how the database places
+/// NULLs by default.
+fn build_nulls_placement(
+    order_column: &Column<'static>,
+    nulls_order: NullsOrder,
+    needs_reversed_order: bool,
+) -> OrderDefinition<'static> {
+    let direction = match nulls_order {
+        NullsOrder::First => SortOrder::Descending,
+        NullsOrder::Last => SortOrder::Ascending,
+    };
+
+    (
+        order_column.clone().is_null().into(),
+        Some(direction.into_order(needs_reversed_order)),
+    )
+}
+
 fn build_order_relevance(order_by: &OrderByRelevance, needs_reversed_order: bool) -> OrderByDefinition {
     let columns: Vec<Expression> = order_by.fields.iter().map(|sf| sf.as_column().into()).collect();
     let order_column: Expression = text_search_relevance(&columns, order_by.search.clone()).into();
@@ -67,6 +94,7 @@ fn build_order_relevance(order_by: &OrderByRelevance, needs_reversed_order: bool
     OrderByDefinition {
         order_column,
         order_definition,
+        nulls_definition: None,
         joins: vec![],
     }
 }
@@ -85,6 +113,7 @@ fn build_order_aggr_scalar(order_by: &OrderByAggregation, needs_reversed_order:
     OrderByDefinition {
         order_column: order_column.into(),
         order_definition,
+        nulls_definition: None,
         joins: vec![],
     }
 }
@@ -111,6 +140,7 @@ fn build_order_aggr_rel(
     OrderByDefinition {
         order_column: order_column.into(),
         order_definition,
+        nulls_definition: None,
         joins,
     }
 }
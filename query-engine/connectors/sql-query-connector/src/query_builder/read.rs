@@ -50,6 +50,8 @@ impl SelectDefinition for QueryArguments {
         model: &ModelRef,
         aggr_selections: &[RelAggregationSelection],
     ) -> (Select<'static>, Vec<Expression<'static>>) {
+        crate::index_advisor::advise(&self, model);
+
         let order_by_definitions = ordering::build(&self, &model);
         let (table_opt, cursor_condition) = cursor_condition::build(&self, &model, &order_by_definitions);
         let aggregation_joins = nested_aggregations::build(aggr_selections);
@@ -91,9 +93,14 @@ impl SelectDefinition for QueryArguments {
             select_ast
         };
 
-        let select_ast = order_by_definitions
-            .iter()
-            .fold(select_ast, |acc, o| acc.order_by(o.order_definition.clone()));
+        let select_ast = order_by_definitions.iter().fold(select_ast, |acc, o| {
+            let acc = match &o.nulls_definition {
+                Some(nulls_definition) => acc.order_by(nulls_definition.clone()),
+                None => acc,
+            };
+
+            acc.order_by(o.order_definition.clone())
+        });
 
         match limit {
             Some(limit) => (select_ast.limit(limit as usize), aggregation_joins.columns),
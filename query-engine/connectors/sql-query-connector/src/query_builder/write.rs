@@ -177,29 +177,43 @@ pub fn delete_many(model: &ModelRef, ids: &[&SelectionResult]) -> Vec<Query<'sta
     })
 }
 
+/// Orders the given ids deterministically by their values. Concurrent writers touching
+/// overlapping children on the same relation table then lock rows in the same order,
+/// which prevents them from deadlocking each other.
+fn in_locking_order(child_ids: &[SelectionResult]) -> Vec<&SelectionResult> {
+    let mut child_ids: Vec<_> = child_ids.iter().collect();
+    child_ids.sort_by(|a, b| a.values().cmp(b.values()));
+    child_ids
+}
+
 #[tracing::instrument(skip(field, parent_id, child_ids))]
 pub fn create_relation_table_records(
     field: &RelationFieldRef,
     parent_id: &SelectionResult,
     child_ids: &[SelectionResult],
-) -> Query<'static> {
+) -> Vec<Query<'static>> {
     let relation = field.relation();
 
     let parent_columns: Vec<_> = field.related_field().m2m_columns();
     let child_columns: Vec<_> = field.m2m_columns();
-
     let columns: Vec<_> = parent_columns.into_iter().chain(child_columns).collect();
-    let insert = Insert::multi_into(relation.as_table(), columns);
 
-    let insert: MultiRowInsert = child_ids.iter().fold(insert, |insert, child_id| {
-        let mut values: Vec<_> = parent_id.db_values();
+    in_locking_order(child_ids)
+        .chunks(super::PARAMETER_LIMIT)
+        .map(|chunk| {
+            let insert = Insert::multi_into(relation.as_table(), columns.clone());
+
+            let insert: MultiRowInsert = chunk.iter().fold(insert, |insert, child_id| {
+                let mut values: Vec<_> = parent_id.db_values();
 
-        values.extend(child_id.db_values());
-        insert.values(values)
-    });
+                values.extend(child_id.db_values());
+                insert.values(values)
+            });
 
-    // NOTE: There is no comment support for MultiRowInsert
-    insert.build().on_conflict(OnConflict::DoNothing).into()
+            // NOTE: There is no comment support for MultiRowInsert
+            insert.build().on_conflict(OnConflict::DoNothing).into()
+        })
+        .collect()
 }
 
 #[tracing::instrument(skip(parent_field, parent_id, child_ids))]
@@ -207,7 +221,7 @@ pub fn delete_relation_table_records(
     parent_field: &RelationFieldRef,
     parent_id: &SelectionResult,
     child_ids: &[SelectionResult],
-) -> Delete<'static> {
+) -> Vec<Query<'static>> {
     let relation = parent_field.relation();
 
     let mut parent_columns: Vec<_> = parent_field.related_field().m2m_columns();
@@ -220,9 +234,9 @@ pub fn delete_relation_table_records(
         parent_columns.pop().unwrap().equals(parent_id_values)
     };
 
-    let child_id_criteria = super::conditions(&child_columns, child_ids);
-
-    Delete::from_table(relation.as_table())
-        .so_that(parent_id_criteria.and(child_id_criteria))
-        .append_trace(&Span::current())
+    super::chunked_conditions(&child_columns, &in_locking_order(child_ids), |child_id_criteria| {
+        Delete::from_table(relation.as_table())
+            .so_that(parent_id_criteria.clone().and(child_id_criteria))
+            .append_trace(&Span::current())
+    })
 }
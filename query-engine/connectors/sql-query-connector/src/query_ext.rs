@@ -112,23 +112,34 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Process the record filter and either return directly with precomputed values,
-    /// or fetch IDs from the database.
+    /// or fetch IDs from the database. An optional `limit` caps the number of
+    /// returned identifiers.
     #[tracing::instrument(skip(self, model, record_filter))]
     async fn filter_selectors(
         &self,
         model: &ModelRef,
         record_filter: RecordFilter,
+        limit: Option<usize>,
     ) -> crate::Result<Vec<SelectionResult>> {
-        if let Some(selectors) = record_filter.selectors {
+        if let Some(mut selectors) = record_filter.selectors {
+            if let Some(limit) = limit {
+                selectors.truncate(limit);
+            }
+
             Ok(selectors)
         } else {
-            self.filter_ids(model, record_filter.filter).await
+            self.filter_ids(model, record_filter.filter, limit).await
         }
     }
 
     /// Read the all columns as a (primary) identifier.
     #[tracing::instrument(skip(self, model, filter))]
-    async fn filter_ids(&self, model: &ModelRef, filter: Filter) -> crate::Result<Vec<SelectionResult>> {
+    async fn filter_ids(
+        &self,
+        model: &ModelRef,
+        filter: Filter,
+        limit: Option<usize>,
+    ) -> crate::Result<Vec<SelectionResult>> {
         let model_id: ModelProjection = model.primary_identifier().into();
         let id_cols: Vec<Column<'static>> = model_id.as_columns().collect();
 
@@ -137,6 +148,11 @@ pub trait QueryExt: Queryable + Send + Sync {
             .append_trace(&Span::current())
             .so_that(filter.aliased_cond(None));
 
+        let select = match limit {
+            Some(limit) => select.limit(limit),
+            None => select,
+        };
+
         self.select_ids(select, model_id).await
     }
 
@@ -1,6 +1,6 @@
 use crate::{
-    InterpreterError, QueryGraphBuilderError, QueryGraphError, QueryParserError, QueryParserErrorKind,
-    RelationViolation, TransactionError,
+    InterpreterError, NativeTypeViolation, QueryGraphBuilderError, QueryGraphError, QueryParserError,
+    QueryParserErrorKind, RelationViolation, TransactionError,
 };
 use connector::error::ConnectorError;
 use prisma_models::DomainError;
@@ -41,6 +41,12 @@ pub enum CoreError {
 
     #[error("{}", _0)]
     TransactionError(#[from] TransactionError),
+
+    #[error("The query engine is overloaded and shed this request.")]
+    OverloadedError { queue_depth: u64, timeout_millis: u64 },
+
+    #[error("{}", _0)]
+    PolicyViolationError(String),
 }
 
 impl CoreError {
@@ -170,6 +176,60 @@ impl From<CoreError> for user_facing_errors::Error {
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details }).into()
             }
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::NativeTypeViolation(violation)) => {
+                match violation {
+                    NativeTypeViolation::ValueTooLong { model_name, field_name } => {
+                        user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputValueTooLong {
+                            column_name: format!("{}.{}", model_name, field_name),
+                        })
+                        .into()
+                    }
+                    NativeTypeViolation::ValueOutOfRange { details } => {
+                        user_facing_errors::KnownError::new(user_facing_errors::query_engine::ValueOutOfRange {
+                            details,
+                        })
+                        .into()
+                    }
+                    NativeTypeViolation::InvalidEnumValue {
+                        field_value,
+                        model_name,
+                        field_name,
+                    } => user_facing_errors::KnownError::new(user_facing_errors::query_engine::TypeMismatch {
+                        field_value,
+                        model_name,
+                        field_name,
+                    })
+                    .into(),
+                }
+            }
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::FieldNotWritable {
+                field_name,
+                object_name,
+                reason,
+            }) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::FieldNotWritable {
+                field_name,
+                object_name,
+                reason,
+            })
+            .into(),
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::PaginationOutOfRange {
+                argument_name,
+                value,
+                maximum,
+            }) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::PaginationOutOfRange {
+                argument_name,
+                value,
+                maximum,
+            })
+            .into(),
+            CoreError::OverloadedError {
+                queue_depth,
+                timeout_millis,
+            } => user_facing_errors::KnownError::new(user_facing_errors::query_engine::EngineOverloaded {
+                queue_depth,
+                timeout_millis,
+            })
+            .into(),
             CoreError::InterpreterError(InterpreterError::InterpretationError(msg, Some(cause))) => {
                 match cause.as_ref() {
                     InterpreterError::QueryGraphBuilderError(QueryGraphBuilderError::RecordNotFound(cause)) => {
@@ -0,0 +1,93 @@
+use crate::CoreError;
+use once_cell::sync::Lazy;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore},
+    time::{self, Duration},
+};
+
+/// Maximum number of requests admitted for execution at once,
+/// set via the `QUERY_ADMISSION_QUEUE_DEPTH` environment value.
+/// Unset or `0` disables admission control entirely.
+pub static ADMISSION_QUEUE_DEPTH: Lazy<usize> = Lazy::new(|| match std::env::var("QUERY_ADMISSION_QUEUE_DEPTH") {
+    Ok(depth) => depth.parse().unwrap_or(0),
+    Err(_) => 0,
+});
+
+/// Milliseconds a request may wait for admission before it is shed,
+/// set via the `QUERY_ADMISSION_WAIT_TIMEOUT_MS` environment value.
+pub static ADMISSION_WAIT_TIMEOUT_MS: Lazy<u64> =
+    Lazy::new(|| match std::env::var("QUERY_ADMISSION_WAIT_TIMEOUT_MS") {
+        Ok(millis) => millis.parse().unwrap_or(5000),
+        Err(_) => 5000,
+    });
+
+/// Bounded admission gate in front of query execution. When the engine is saturated,
+/// incoming requests wait for a free slot instead of piling up unboundedly; requests
+/// that exceed the configured waiting bound or wait timeout are shed with a dedicated
+/// "engine overloaded" error instead of being buffered in memory.
+pub(crate) struct AdmissionQueue {
+    slots: Arc<Semaphore>,
+    depth: usize,
+    wait_timeout: Duration,
+
+    /// Number of requests currently waiting for admission.
+    queued: AtomicUsize,
+}
+
+impl AdmissionQueue {
+    /// Returns an admission queue if admission control is enabled via `QUERY_ADMISSION_QUEUE_DEPTH`.
+    pub fn from_env() -> Option<Self> {
+        match *ADMISSION_QUEUE_DEPTH {
+            0 => None,
+            depth => Some(Self {
+                slots: Arc::new(Semaphore::new(depth)),
+                depth,
+                wait_timeout: Duration::from_millis(*ADMISSION_WAIT_TIMEOUT_MS),
+                queued: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// Waits for an execution slot. The returned permit must be held for the duration
+    /// of the request. Sheds the request if the waiting bound is already reached, or
+    /// if no slot frees up within the wait timeout.
+    pub async fn admit(&self) -> crate::Result<OwnedSemaphorePermit> {
+        // Bound the number of waiters by the queue depth as well - beyond that the
+        // engine is clearly not keeping up and buffering more requests only wastes memory.
+        if self.queued.load(Ordering::SeqCst) >= self.depth {
+            return Err(self.overloaded());
+        }
+
+        let queued = self.queued.fetch_add(1, Ordering::SeqCst) + 1;
+        trace!(queue_depth = queued, "Request queued for admission.");
+
+        let permit = time::timeout(self.wait_timeout, Arc::clone(&self.slots).acquire_owned()).await;
+        self.queued.fetch_sub(1, Ordering::SeqCst);
+
+        match permit {
+            Ok(Ok(permit)) => Ok(permit),
+            // Acquisition only fails if the semaphore is closed, which never happens here,
+            // so both failure modes shed the request.
+            _ => {
+                trace!(queue_depth = self.queued(), "Request shed.");
+                Err(self.overloaded())
+            }
+        }
+    }
+
+    /// Number of requests currently waiting for admission.
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    fn overloaded(&self) -> CoreError {
+        CoreError::OverloadedError {
+            queue_depth: self.depth as u64,
+            timeout_millis: self.wait_timeout.as_millis() as u64,
+        }
+    }
+}
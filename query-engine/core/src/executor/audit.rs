@@ -0,0 +1,82 @@
+use super::interactive_tx::TxId;
+use crate::{Operation, QueryValue};
+use once_cell::sync::Lazy;
+
+/// Env var toggling the audit subsystem. Set to `1` or `true` to emit one structured
+/// record on the tracing pipeline (target `query_audit`) for every successful mutation.
+pub const AUDIT_LOG_ENV: &str = "QUERY_AUDIT_LOG";
+
+static AUDIT_LOG_ENABLED: Lazy<bool> = Lazy::new(|| {
+    std::env::var(AUDIT_LOG_ENV)
+        .map(|s| s == "1" || s == "true")
+        .unwrap_or(false)
+});
+
+pub(crate) fn enabled() -> bool {
+    *AUDIT_LOG_ENABLED
+}
+
+/// The audit-relevant parts of a mutation, captured before the operation is consumed
+/// by the graph builder and emitted only if the operation succeeds.
+#[derive(Debug)]
+pub(crate) struct AuditEvent {
+    /// The operation name, e.g. `updateOneUser`.
+    operation: String,
+
+    /// The alias the client gave the operation, if any. Clients can use aliases to
+    /// tag mutations for correlation with their own audit trail.
+    request_tag: Option<String>,
+
+    /// The top-level field names of the `data` argument. Names only - values never
+    /// make it into the audit record, they may be sensitive.
+    changed_fields: Vec<String>,
+
+    /// The interactive transaction the mutation ran in, if any. Mutations inside an
+    /// open transaction are recorded when they execute, not when the transaction
+    /// commits - the ID allows correlating them with the final commit or rollback.
+    tx_id: Option<String>,
+}
+
+impl AuditEvent {
+    /// Captures an event for the operation, `None` if auditing is disabled or the
+    /// operation is not a mutation.
+    pub fn capture(operation: &Operation, tx_id: Option<&TxId>) -> Option<Self> {
+        if !enabled() {
+            return None;
+        }
+
+        let selection = match operation {
+            Operation::Write(selection) => selection,
+            Operation::Read(_) => return None,
+        };
+
+        let changed_fields = selection
+            .arguments()
+            .iter()
+            .find(|(name, _)| name == "data")
+            .and_then(|(_, value)| match value {
+                QueryValue::Object(map) => Some(map.keys().cloned().collect()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        Some(AuditEvent {
+            operation: selection.name().to_owned(),
+            request_tag: selection.alias().clone(),
+            changed_fields,
+            tx_id: tx_id.map(|id| id.to_string()),
+        })
+    }
+
+    /// Emits the record on the tracing pipeline. The subscriber supplies the timestamp.
+    pub fn emit(&self, models: &[String]) {
+        info!(
+            target: "query_audit",
+            operation = %self.operation,
+            models = %models.join(","),
+            changed_fields = %self.changed_fields.join(","),
+            request_tag = %self.request_tag.as_deref().unwrap_or(""),
+            tx_id = %self.tx_id.as_deref().unwrap_or(""),
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use super::interactive_tx::TxId;
+use crate::{QueryResult, WriteQuery};
+use once_cell::sync::Lazy;
+use prisma_models::SelectionResult;
+
+/// Env var toggling row-level change events. Set to `1` or `true` to emit one structured
+/// record on the tracing pipeline (target `change_events`) for every write that made it
+/// into the database, enabling cache invalidation or search-index syncing without
+/// database-level CDC. External sinks (message queues, webhooks) attach as tracing
+/// subscribers filtering on the target.
+pub const CHANGE_EVENTS_ENV: &str = "PRISMA_CHANGE_EVENTS";
+
+static CHANGE_EVENTS_ENABLED: Lazy<bool> = Lazy::new(|| {
+    std::env::var(CHANGE_EVENTS_ENV)
+        .map(|s| s == "1" || s == "true")
+        .unwrap_or(false)
+});
+
+pub(crate) fn enabled() -> bool {
+    *CHANGE_EVENTS_ENABLED
+}
+
+/// A single row-level change, captured when a write query executes and emitted once the
+/// surrounding transaction has committed. Writes in rolled-back transactions are
+/// discarded with the transaction. A crash between commit and emission can still drop
+/// events, so consumers requiring completeness need their own reconciliation.
+#[derive(Debug)]
+pub(crate) struct ChangeEvent {
+    /// The model the write targeted.
+    model: String,
+
+    /// The kind of write, e.g. `create` or `updateMany`.
+    operation: &'static str,
+
+    /// Rendered primary identifiers of the affected records. Many-record writes only
+    /// report a count, their events carry no ids.
+    ids: Vec<String>,
+}
+
+impl ChangeEvent {
+    /// Captures an event for the write query, `None` if change events are disabled or
+    /// the write is a raw query, which bypasses the query AST and cannot be attributed
+    /// to a model. The affected ids are attached after execution via `record_result`.
+    pub fn capture(query: &WriteQuery) -> Option<Self> {
+        if !enabled() {
+            return None;
+        }
+
+        let operation = match query {
+            WriteQuery::CreateRecord(_) => "create",
+            WriteQuery::CreateManyRecords(_) => "createMany",
+            WriteQuery::UpdateRecord(_) => "update",
+            WriteQuery::UpdateManyRecords(_) => "updateMany",
+            WriteQuery::DeleteRecord(_) => "delete",
+            WriteQuery::DeleteManyRecords(_) => "deleteMany",
+            WriteQuery::ConnectRecords(_) => "connect",
+            WriteQuery::DisconnectRecords(_) => "disconnect",
+            WriteQuery::ExecuteRaw(_) | WriteQuery::QueryRaw(_) => return None,
+        };
+
+        Some(ChangeEvent {
+            model: query.model().name.clone(),
+            operation,
+            ids: Vec::new(),
+        })
+    }
+
+    /// Records the identifiers the write returned.
+    pub fn record_result(&mut self, result: &QueryResult) {
+        if let QueryResult::Id(Some(id)) = result {
+            self.ids.push(render_id(id));
+        }
+    }
+
+    /// Emits the event on the tracing pipeline, tagged with the interactive transaction
+    /// it was part of, if any. The subscriber supplies the timestamp.
+    pub fn emit(&self, tx_id: Option<&TxId>) {
+        info!(
+            target: "change_events",
+            model = %self.model,
+            operation = %self.operation,
+            ids = %self.ids.join(","),
+            tx_id = %tx_id.map(|id| id.to_string()).unwrap_or_default(),
+        );
+    }
+}
+
+/// Emits all buffered events. Called by the executor once the outcome of the surrounding
+/// transaction is known.
+pub(crate) fn emit_all(events: &[ChangeEvent], tx_id: Option<&TxId>) {
+    for event in events {
+        event.emit(tx_id);
+    }
+}
+
+/// Renders a selection result as a stable id string, compound keys joined with `:`.
+fn render_id(id: &SelectionResult) -> String {
+    id.values().map(|value| value.to_string()).collect::<Vec<_>>().join(":")
+}
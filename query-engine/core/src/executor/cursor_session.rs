@@ -0,0 +1,83 @@
+use super::interactive_tx::{TransactionError, TxId, CACHE_EVICTION_SECS};
+use dashmap::DashMap;
+use std::{fmt::Display, sync::Arc};
+use tokio::{
+    task,
+    time::{self, Duration},
+};
+
+/// Token handed out to clients that want to continue a paginated read inside
+/// the same database snapshot over multiple requests.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct CursorSessionId(String);
+
+impl Default for CursorSessionId {
+    fn default() -> Self {
+        Self(cuid::cuid().unwrap())
+    }
+}
+
+impl<T> From<T> for CursorSessionId
+where
+    T: Into<String>,
+{
+    fn from(s: T) -> Self {
+        Self(s.into())
+    }
+}
+
+impl Display for CursorSessionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Registry mapping cursor session tokens to the interactive transaction that backs
+/// their snapshot. Sessions piggyback on the interactive transaction infrastructure:
+/// expiry and cleanup of the underlying transaction are handled by the
+/// `TransactionCache`, this registry only tracks the token indirection. A session
+/// whose transaction expired resolves fine here and subsequently fails with the
+/// regular transaction lifecycle errors, until the entry itself is evicted.
+#[derive(Default)]
+pub(crate) struct CursorSessionRegistry {
+    sessions: Arc<DashMap<CursorSessionId, TxId>>,
+}
+
+impl CursorSessionRegistry {
+    /// Registers a new session backed by the given transaction, returning the
+    /// session token. The entry is evicted once the backing transaction can no
+    /// longer exist: its maximum lifetime plus the grace period the transaction
+    /// cache keeps closed transactions around for. Sessions leaked by crashed
+    /// clients therefore do not accumulate in the registry.
+    pub fn create(&self, tx_id: TxId, valid_for_millis: u64) -> CursorSessionId {
+        let id = CursorSessionId::default();
+        self.sessions.insert(id.clone(), tx_id);
+
+        let sessions = Arc::clone(&self.sessions);
+        let session_id = id.clone();
+
+        task::spawn(async move {
+            time::sleep(Duration::from_millis(valid_for_millis) + Duration::from_secs(*CACHE_EVICTION_SECS)).await;
+
+            if sessions.remove(&session_id).is_some() {
+                debug!("[{}] Evicted expired cursor session.", session_id);
+            }
+        });
+
+        id
+    }
+
+    /// Resolves a session token to the transaction backing it.
+    pub fn resolve(&self, session_id: &CursorSessionId) -> crate::Result<TxId> {
+        self.sessions
+            .get(session_id)
+            .map(|entry| entry.value().clone())
+            .ok_or_else(|| TransactionError::CursorSessionNotFound.into())
+    }
+
+    /// Removes a session from the registry, returning the transaction it was backed
+    /// by, if any, for the caller to finalize.
+    pub fn remove(&self, session_id: &CursorSessionId) -> Option<TxId> {
+        self.sessions.remove(session_id).map(|(_, tx_id)| tx_id)
+    }
+}
@@ -1,12 +1,21 @@
+use super::change_events::ChangeEvent;
 use crate::CoreError;
 use connector::{Connection, ConnectionLike, Transaction};
 use dashmap::{mapref::one::RefMut, DashMap};
 use once_cell::sync::Lazy;
-use std::{fmt::Display, sync::Arc};
+use serde::Serialize;
+use std::{
+    fmt::Display,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
 use thiserror::Error;
 use tokio::{
+    sync::OwnedSemaphorePermit,
     task::{self, JoinHandle},
-    time::{self, Duration},
+    time::{self, Duration, Instant},
 };
 
 pub static CACHE_EVICTION_SECS: Lazy<u64> = Lazy::new(|| match std::env::var("CLOSED_TX_CLEANUP") {
@@ -14,11 +23,39 @@ pub static CACHE_EVICTION_SECS: Lazy<u64> = Lazy::new(|| match std::env::var("CL
     Err(_) => 300,
 });
 
+/// Maximum milliseconds an interactive transaction may sit without executing an
+/// operation before it is rolled back, set via the `TX_IDLE_TIMEOUT` environment
+/// value. Unset or unparseable disables the idle check, so only the lifetime
+/// limit applies. Leaked transactions from crashed clients stop holding their
+/// connection after this long.
+pub static TX_IDLE_TIMEOUT_MILLIS: Lazy<Option<u64>> = Lazy::new(|| {
+    std::env::var("TX_IDLE_TIMEOUT")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .filter(|millis| *millis > 0)
+});
+
+/// Server-side cap in milliseconds on the lifetime of an interactive
+/// transaction, set via the `TX_MAX_LIFETIME` environment value. The effective
+/// lifetime is the smaller of this cap and the timeout the client requested.
+/// Unset or unparseable leaves the client-requested timeout untouched.
+pub static TX_MAX_LIFETIME_MILLIS: Lazy<Option<u64>> = Lazy::new(|| {
+    std::env::var("TX_MAX_LIFETIME")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .filter(|millis| *millis > 0)
+});
+
 #[derive(Debug, Error, PartialEq)]
 pub enum TransactionError {
     #[error("Unable to start a transaction in the given time.")]
     AcquisitionTimeout,
 
+    #[error(
+        "The dedicated connection limit for interactive transactions ({limit}) has been reached. The transaction could not acquire a connection in the given time."
+    )]
+    ConnectionsExhausted { limit: usize },
+
     #[error("Attempted to start a transaction inside of a transaction.")]
     AlreadyStarted,
 
@@ -27,6 +64,17 @@ pub enum TransactionError {
 
     #[error("Transaction already closed: {reason}.")]
     Closed { reason: String },
+
+    #[error("Transaction expired. It exceeded its maximum lifetime or sat idle for too long and was rolled back.")]
+    Expired,
+
+    #[error("Cursor session not found or expired.")]
+    CursorSessionNotFound,
+
+    #[error(
+        "Distributed transaction {gid} is in doubt: the commit decision was recorded but not all participants confirmed it yet. Recovery will finish the commit."
+    )]
+    InDoubt { gid: String },
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
@@ -77,38 +125,121 @@ impl CachedTx {
     /// Requires this cached TX to be `Open`, else an error will be raised that it is no longer valid.
     /// Consumes self to remove the `CachedTx` indirection to get to the underlying `OpenTx`.
     pub fn into_open(self) -> crate::Result<OpenTx> {
-        if let Self::Open(otx) = self {
-            Ok(otx)
-        } else {
-            let reason = format!("Transaction is no longer valid. Last state: '{}'", self);
-            Err(CoreError::from(TransactionError::Closed { reason }))
+        match self {
+            Self::Open(otx) => Ok(otx),
+            Self::Expired => Err(CoreError::from(TransactionError::Expired)),
+            _ => {
+                let reason = format!("Transaction is no longer valid. Last state: '{}'", self);
+                Err(CoreError::from(TransactionError::Closed { reason }))
+            }
         }
     }
 
     /// Requires this cached TX to be `Open`, else an error will be raised that it is no longer valid.
     pub fn as_open(&mut self) -> crate::Result<&mut OpenTx> {
-        if let Self::Open(ref mut otx) = self {
-            Ok(otx)
-        } else {
-            let reason = format!("Transaction is no longer valid. Last state: '{}'", self);
-            Err(CoreError::from(TransactionError::Closed { reason }))
+        match self {
+            Self::Open(ref mut otx) => Ok(otx),
+            Self::Expired => Err(CoreError::from(TransactionError::Expired)),
+            _ => {
+                let reason = format!("Transaction is no longer valid. Last state: '{}'", self);
+                Err(CoreError::from(TransactionError::Closed { reason }))
+            }
         }
     }
 }
 
+/// Point-in-time snapshot of the transaction manager, served by the
+/// `transactionStatus` admin endpoint so operators can diagnose pool
+/// exhaustion caused by client bugs.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStatus {
+    /// Currently open interactive transactions.
+    pub open_transactions: Vec<OpenTransactionInfo>,
+    /// Transactions committed since the engine started.
+    pub committed: u64,
+    /// Transactions rolled back since the engine started, explicit rollbacks
+    /// and connector-side aborts alike.
+    pub rolled_back: u64,
+    /// Transactions that exceeded their lifetime or idle timeout since the
+    /// engine started.
+    pub expired: u64,
+}
+
+/// A single open interactive transaction in a [`TransactionStatus`] snapshot.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenTransactionInfo {
+    /// The transaction id.
+    pub id: String,
+    /// Milliseconds since the transaction was started.
+    pub age_millis: u64,
+    /// Milliseconds since the transaction last executed an operation.
+    pub idle_millis: u64,
+    /// Number of operations executed on the transaction so far.
+    pub operation_count: u64,
+    /// Client-supplied tag identifying the originating request, if any.
+    pub tag: Option<String>,
+}
+
+/// Counters over closed transactions, shared between the cache and the
+/// expiration timer tasks.
+#[derive(Default)]
+struct TransactionCounters {
+    committed: AtomicU64,
+    rolled_back: AtomicU64,
+    expired: AtomicU64,
+}
+
 #[derive(Default)]
 pub(crate) struct TransactionCache {
     cache: Arc<DashMap<TxId, CachedTx>>,
+    counters: Arc<TransactionCounters>,
 }
 
 impl TransactionCache {
     pub async fn insert(&self, key: TxId, mut value: OpenTx, valid_for_millis: u64) {
         let cache = Arc::clone(&self.cache);
         let cache_key = key.clone();
+        let last_used = Arc::clone(&value.last_used);
+        let counters = Arc::clone(&self.counters);
+
+        // The client-requested timeout is capped by the server-side lifetime limit, if set.
+        let valid_for_millis = match *TX_MAX_LIFETIME_MILLIS {
+            Some(cap) => valid_for_millis.min(cap),
+            None => valid_for_millis,
+        };
 
         let timer_handle = task::spawn(async move {
             debug!("[{}] Valid for {} milliseconds", cache_key, valid_for_millis);
-            time::sleep(Duration::from_millis(valid_for_millis)).await;
+
+            let started = Instant::now();
+            let lifetime = Duration::from_millis(valid_for_millis);
+
+            loop {
+                let remaining = match lifetime.checked_sub(started.elapsed()) {
+                    Some(remaining) if !remaining.is_zero() => remaining,
+                    _ => break, // Lifetime exhausted.
+                };
+
+                match *TX_IDLE_TIMEOUT_MILLIS {
+                    Some(idle_millis) => {
+                        let idle_limit = Duration::from_millis(idle_millis);
+                        let idle = last_used.lock().unwrap().elapsed();
+
+                        if idle >= idle_limit {
+                            debug!("[{}] Idle for {} milliseconds.", cache_key, idle.as_millis());
+                            break;
+                        }
+
+                        // Sleep until the earlier of the next possible idle
+                        // expiry and the end of the lifetime, then re-check.
+                        time::sleep((idle_limit - idle).min(remaining)).await;
+                    }
+                    None => time::sleep(remaining).await,
+                }
+            }
+
             debug!("[{}] Forced rollback triggered.", cache_key);
 
             if let Some(ref mut c_tx) = cache.get_mut(&cache_key) {
@@ -120,6 +251,7 @@ impl TransactionCache {
             }
 
             cache.insert(cache_key.clone(), CachedTx::Expired);
+            counters.expired.fetch_add(1, Ordering::Relaxed);
             schedule_cache_eviction(cache_key, cache, *CACHE_EVICTION_SECS);
         });
 
@@ -135,19 +267,72 @@ impl TransactionCache {
     /// Replaces the cache entry for the tx with the specified `CachedTx`.
     /// After `CACHE_EVICTION_SECS`, the entry is removed completely.
     pub fn finalize_tx(&self, key: TxId, with: CachedTx) {
+        match with {
+            CachedTx::Committed => self.counters.committed.fetch_add(1, Ordering::Relaxed),
+            CachedTx::RolledBack | CachedTx::Aborted => self.counters.rolled_back.fetch_add(1, Ordering::Relaxed),
+            CachedTx::Expired => self.counters.expired.fetch_add(1, Ordering::Relaxed),
+            CachedTx::Open(_) => 0,
+        };
+
         self.cache.insert(key.clone(), with);
         schedule_cache_eviction(key, Arc::clone(&self.cache), *CACHE_EVICTION_SECS)
     }
+
+    /// Takes a point-in-time snapshot of all open transactions and the
+    /// lifecycle counters.
+    pub fn status(&self) -> TransactionStatus {
+        let open_transactions = self
+            .cache
+            .iter()
+            .filter_map(|entry| match entry.value() {
+                CachedTx::Open(otx) => Some(OpenTransactionInfo {
+                    id: entry.key().to_string(),
+                    age_millis: otx.started_at.elapsed().as_millis() as u64,
+                    idle_millis: otx.last_used.lock().unwrap().elapsed().as_millis() as u64,
+                    operation_count: otx.operation_count,
+                    tag: otx.tag.clone(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        TransactionStatus {
+            open_transactions,
+            committed: self.counters.committed.load(Ordering::Relaxed),
+            rolled_back: self.counters.rolled_back.load(Ordering::Relaxed),
+            expired: self.counters.expired.load(Ordering::Relaxed),
+        }
+    }
 }
 
 pub struct OpenTx {
     pub conn: Box<dyn Connection>,
     pub tx: Box<dyn Transaction + 'static>,
     pub expiration_timer: Option<JoinHandle<()>>,
+    /// When the transaction was started.
+    pub started_at: Instant,
+    /// When the transaction last executed an operation, shared with the
+    /// expiration timer for the idle check.
+    pub last_used: Arc<Mutex<Instant>>,
+    /// Number of operations executed on the transaction so far.
+    pub operation_count: u64,
+    /// Client-supplied tag identifying the originating request, if any.
+    pub tag: Option<String>,
+    /// Change events captured by writes inside this transaction, emitted once the
+    /// transaction commits (see `change_events`).
+    pub pending_change_events: Vec<ChangeEvent>,
+    /// Accounts for the connection this transaction holds against the
+    /// dedicated transaction connection limit, if one is configured. The
+    /// permit is released when the `OpenTx` is dropped, i.e. when the
+    /// transaction closes.
+    pub connection_permit: Option<OwnedSemaphorePermit>,
 }
 
 impl OpenTx {
-    pub async fn start(mut conn: Box<dyn Connection>) -> crate::Result<Self> {
+    pub async fn start(
+        mut conn: Box<dyn Connection>,
+        connection_permit: Option<OwnedSemaphorePermit>,
+    ) -> crate::Result<Self> {
         // Forces static lifetime for the transaction, disabling the lifetime checks for `tx`.
         // Why is this okay? We store the connection the tx depends on with its lifetime next to
         // the tx in the struct. Neither the connection nor the tx are moved out of this struct.
@@ -162,11 +347,24 @@ impl OpenTx {
             conn,
             tx,
             expiration_timer: None,
+            started_at: Instant::now(),
+            last_used: Arc::new(Mutex::new(Instant::now())),
+            operation_count: 0,
+            tag: None,
+            pending_change_events: Vec::new(),
+            connection_permit,
         };
 
         Ok(c_tx)
     }
 
+    /// Marks the transaction as used right now, resetting the idle timeout
+    /// and bumping the operation count.
+    pub fn touch(&mut self) {
+        *self.last_used.lock().unwrap() = Instant::now();
+        self.operation_count += 1;
+    }
+
     /// Cancels a running expiration timer, if any.
     pub fn cancel_expiration_timer(&mut self) {
         if let Some(timer) = self.expiration_timer.take() {
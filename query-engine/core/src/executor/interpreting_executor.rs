@@ -1,6 +1,12 @@
 use super::{
+    admission_queue::AdmissionQueue,
+    audit::AuditEvent,
+    change_events::{self, ChangeEvent},
+    cursor_session::{CursorSessionId, CursorSessionRegistry},
     interactive_tx::{CachedTx, TransactionCache, TxId},
     pipeline::QueryPipeline,
+    policy::Policy,
+    result_cache::ResultCache,
     QueryExecutor,
 };
 use crate::{
@@ -10,7 +16,28 @@ use crate::{
 use async_trait::async_trait;
 use connector::{error::ErrorKind, Connection, ConnectionLike, Connector};
 use futures::{future, Future};
-use tokio::time;
+use once_cell::sync::Lazy;
+use std::sync::Arc;
+use tokio::{sync::Semaphore, time};
+
+/// Maximum number of connections a single operation may use to evaluate independent
+/// parts of its query graph concurrently, set via the `QUERY_CONCURRENT_CONNECTIONS`
+/// environment value. Unset, `0` or `1` disables concurrent evaluation.
+static CONCURRENT_CONNECTIONS: Lazy<usize> = Lazy::new(|| match std::env::var("QUERY_CONCURRENT_CONNECTIONS") {
+    Ok(value) => value.parse().unwrap_or(1),
+    Err(_) => 1,
+});
+
+/// Maximum number of connections interactive transactions may hold at the same
+/// time, set via the `TX_CONNECTION_LIMIT` environment value. Unset or
+/// unparseable disables the limit, so transactions compete with regular
+/// operations for the whole pool.
+static TX_CONNECTION_LIMIT: Lazy<Option<usize>> = Lazy::new(|| {
+    std::env::var("TX_CONNECTION_LIMIT")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .filter(|limit| *limit > 0)
+});
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
@@ -19,6 +46,24 @@ pub struct InterpretingExecutor<C> {
 
     tx_cache: TransactionCache,
 
+    /// Cursor sessions mapping pagination snapshot tokens to their backing transaction.
+    cursor_sessions: CursorSessionRegistry,
+
+    /// Optional TTL cache for read query results, `None` if disabled.
+    result_cache: Option<ResultCache>,
+
+    /// Optional bounded admission gate for load shedding, `None` if disabled.
+    admission_queue: Option<AdmissionQueue>,
+
+    /// Optional policy document evaluated for every operation, `None` if disabled.
+    policy: Option<Policy>,
+
+    /// Optional cap on the connections interactive transactions may pin at the
+    /// same time, `None` if disabled. The semaphore queues waiters fairly, so
+    /// transactions start in the order they were requested while the rest of
+    /// the pool stays available for regular operations.
+    tx_connections: Option<Arc<Semaphore>>,
+
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
@@ -32,21 +77,27 @@ where
         InterpretingExecutor {
             connector,
             tx_cache: TransactionCache::default(),
+            cursor_sessions: CursorSessionRegistry::default(),
+            result_cache: ResultCache::from_env(),
+            admission_queue: AdmissionQueue::from_env(),
+            policy: Policy::from_env(),
+            tx_connections: TX_CONNECTION_LIMIT.map(|limit| Arc::new(Semaphore::new(limit))),
             force_transactions,
         }
     }
 
     /// Execute the operation as a self-contained operation, if necessary wrapped in a transaction.
-    #[tracing::instrument(skip(conn, graph, serializer, force_transactions))]
+    #[tracing::instrument(skip(conn, graph, serializer, force_transactions, aux_connections))]
     async fn execute_self_contained(
         mut conn: Box<dyn Connection>,
         graph: QueryGraph,
         serializer: IrSerializer,
         force_transactions: bool,
+        aux_connections: Vec<Box<dyn Connection + Send + Sync>>,
     ) -> crate::Result<ResponseData> {
         if force_transactions || graph.needs_transaction() {
             let mut tx = conn.start_transaction().await?;
-            let result = Self::execute_on(tx.as_connection_like(), graph, serializer).await;
+            let result = Self::execute_on(tx.as_connection_like(), graph, serializer, Vec::new()).await;
 
             if result.is_ok() {
                 tx.commit().await?;
@@ -54,25 +105,64 @@ where
                 tx.rollback().await?;
             }
 
-            result
+            let (data, events) = result?;
+            change_events::emit_all(&events, None);
+
+            Ok(data)
         } else {
-            Self::execute_on(conn.as_connection_like(), graph, serializer).await
+            let (data, events) =
+                Self::execute_on(conn.as_connection_like(), graph, serializer, aux_connections).await?;
+            change_events::emit_all(&events, None);
+
+            Ok(data)
         }
     }
 
     /// Simplest execution on anything that's a ConnectionLike. Caller decides handling of connections and transactions.
-    #[tracing::instrument(skip(conn, graph, serializer))]
+    #[tracing::instrument(skip(conn, graph, serializer, aux_connections))]
     async fn execute_on(
         conn: &mut dyn ConnectionLike,
         graph: QueryGraph,
         serializer: IrSerializer,
-    ) -> crate::Result<ResponseData> {
-        let interpreter = QueryInterpreter::new(conn);
+        aux_connections: Vec<Box<dyn Connection + Send + Sync>>,
+    ) -> crate::Result<(ResponseData, Vec<ChangeEvent>)> {
+        let interpreter = QueryInterpreter::new(conn).with_auxiliary_connections(aux_connections);
         let result = QueryPipeline::new(graph, interpreter, serializer).execute().await;
 
         result
     }
 
+    /// Acquires additional connections for evaluating independent read subgraphs of
+    /// the given graph concurrently, if enabled and worthwhile. Failing to acquire
+    /// extra connections is not an error - evaluation falls back to sequential.
+    async fn acquire_auxiliary_connections(&self, graph: &QueryGraph) -> Vec<Box<dyn Connection + Send + Sync>> {
+        let max_connections = *CONCURRENT_CONNECTIONS;
+
+        // Transactions are bound to a single, exclusive connection, so only side effect
+        // free graphs outside of transactions can use additional connections.
+        if max_connections <= 1 || self.force_transactions || graph.needs_transaction() || !graph.is_read_only() {
+            return Vec::new();
+        }
+
+        let independent_roots = graph.root_nodes().len();
+
+        if independent_roots < 2 {
+            return Vec::new();
+        }
+
+        let wanted = std::cmp::min(max_connections, independent_roots) - 1;
+        let mut connections = Vec::with_capacity(wanted);
+
+        for _ in 0..wanted {
+            match self.connector.get_connection().await {
+                Ok(conn) => connections.push(conn),
+                Err(_) => break,
+            }
+        }
+
+        connections
+    }
+
     async fn finalize_tx<F>(&self, tx_id: TxId, final_state: CachedTx, finalizer: F) -> crate::Result<()>
     where
         F: Fn(&mut OpenTx) -> Box<dyn Future<Output = connector::Result<()>> + Unpin + Send + '_>,
@@ -96,6 +186,12 @@ where
                 final_state
             };
 
+            if matches!(state, CachedTx::Committed) {
+                // The commit went through, the buffered change events are now definite.
+                let events = std::mem::take(&mut otx.pending_change_events);
+                change_events::emit_all(&events, Some(&tx_id));
+            }
+
             otx.cancel_expiration_timer();
             state
         };
@@ -114,22 +210,84 @@ where
     async fn execute(
         &self,
         tx_id: Option<TxId>,
-        operation: Operation,
+        mut operation: Operation,
         query_schema: QuerySchemaRef,
     ) -> crate::Result<ResponseData> {
+        let _admission_permit = match &self.admission_queue {
+            Some(queue) => Some(queue.admit().await?),
+            None => None,
+        };
+
+        if let Some(policy) = &self.policy {
+            policy.apply(&mut operation)?;
+        }
+
+        let cached_read_key = match (&self.result_cache, &tx_id, &operation) {
+            // Reads inside an interactive transaction must observe the transaction state and bypass the cache.
+            (Some(cache), None, Operation::Read(_)) => {
+                let key = ResultCache::key_for(&operation);
+
+                if let Some(hit) = cache.get(&key) {
+                    return Ok(hit);
+                }
+
+                Some(key)
+            }
+            _ => None,
+        };
+
+        let is_write = matches!(operation, Operation::Write(_));
+        let audit_event = AuditEvent::capture(&operation, tx_id.as_ref());
+
         // Parse, validate, and extract query graph from query document.
         let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
 
+        let involved_models = if self.result_cache.is_some() || audit_event.is_some() {
+            query_graph.involved_models()
+        } else {
+            Vec::new()
+        };
+
         // If a Tx id is provided, execute on that one. Else execute normally as a single operation.
-        if let Some(tx_id) = tx_id {
+        let result = if let Some(tx_id) = tx_id {
             let mut c_tx = self.tx_cache.get_or_err(&tx_id)?;
             let otx = c_tx.as_open()?;
-
-            Self::execute_on(otx.tx.as_connection_like(), query_graph, serializer).await
+            otx.touch();
+
+            match Self::execute_on(otx.tx.as_connection_like(), query_graph, serializer, Vec::new()).await {
+                Ok((data, events)) => {
+                    // The changes only become definite if the transaction commits,
+                    // buffer the events on it (see `commit_tx`).
+                    otx.pending_change_events.extend(events);
+                    Ok(data)
+                }
+                Err(err) => Err(err),
+            }
         } else {
+            let aux_connections = self.acquire_auxiliary_connections(&query_graph).await;
             let conn = self.connector.get_connection().await?;
-            Self::execute_self_contained(conn, query_graph, serializer, self.force_transactions).await
+
+            Self::execute_self_contained(conn, query_graph, serializer, self.force_transactions, aux_connections).await
+        };
+
+        if let (Some(cache), Ok(response)) = (&self.result_cache, &result) {
+            if is_write {
+                // The graph knows exactly which models the write touched, drop all cached reads over them.
+                cache.invalidate_models(&involved_models);
+            } else if let Some(key) = cached_read_key {
+                // Responses carrying field-level errors are not cached - the cache only
+                // stores the data portion and would silently drop the errors on a hit.
+                if response.errors.is_empty() {
+                    cache.insert(key, response, involved_models.clone());
+                }
+            }
+        }
+
+        if let (Some(event), Ok(_)) = (&audit_event, &result) {
+            event.emit(&involved_models);
         }
+
+        result
     }
 
     /// Executes a batch of operations.
@@ -148,10 +306,34 @@ where
     async fn execute_all(
         &self,
         tx_id: Option<TxId>,
-        operations: Vec<Operation>,
+        mut operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
+        let _admission_permit = match &self.admission_queue {
+            Some(queue) => Some(queue.admit().await?),
+            None => None,
+        };
+
+        if let Some(policy) = &self.policy {
+            for operation in operations.iter_mut() {
+                policy.apply(operation)?;
+            }
+        }
+
+        if let Some(cache) = &self.result_cache {
+            // Batches don't populate the cache; conservatively drop everything a writing batch might touch.
+            if operations.iter().any(|op| matches!(op, Operation::Write(_))) {
+                cache.clear();
+            }
+        }
+
+        // All `None` if auditing is disabled.
+        let audit_events: Vec<Option<AuditEvent>> = operations
+            .iter()
+            .map(|op| AuditEvent::capture(op, tx_id.as_ref()))
+            .collect();
+
         if let Some(tx_id) = tx_id {
             let queries = operations
                 .into_iter()
@@ -160,15 +342,31 @@ where
 
             let mut c_tx = self.tx_cache.get_or_err(&tx_id)?;
             let otx = c_tx.as_open()?;
+            otx.touch();
+
             let mut results = Vec::with_capacity(queries.len());
+            let mut batch_events = Vec::new();
 
             let tx = otx.as_connection_like();
 
-            for (graph, serializer) in queries {
-                let result = Self::execute_on(tx, graph, serializer).await?;
+            for ((graph, serializer), audit_event) in queries.into_iter().zip(audit_events) {
+                let models = audit_event
+                    .as_ref()
+                    .map(|_| graph.involved_models())
+                    .unwrap_or_default();
+                let (result, events) = Self::execute_on(tx, graph, serializer, Vec::new()).await?;
+
+                batch_events.extend(events);
+
+                if let Some(event) = audit_event {
+                    event.emit(&models);
+                }
+
                 results.push(Ok(result));
             }
 
+            otx.pending_change_events.extend(batch_events);
+
             Ok(results)
         } else if transactional {
             let queries = operations
@@ -179,37 +377,59 @@ where
             let mut conn = self.connector.get_connection().await?;
             let mut tx = conn.start_transaction().await?;
             let mut results = Vec::with_capacity(queries.len());
+            let mut batch_events = Vec::new();
 
-            for (graph, serializer) in queries {
-                let result = Self::execute_on(tx.as_connection_like(), graph, serializer).await;
+            for ((graph, serializer), audit_event) in queries.into_iter().zip(audit_events) {
+                let models = audit_event
+                    .as_ref()
+                    .map(|_| graph.involved_models())
+                    .unwrap_or_default();
+                let result = Self::execute_on(tx.as_connection_like(), graph, serializer, Vec::new()).await;
 
                 if result.is_err() {
                     tx.rollback().await?;
+                } else if let Some(event) = audit_event {
+                    event.emit(&models);
                 }
 
-                results.push(Ok(result?));
+                let (result, events) = result?;
+
+                batch_events.extend(events);
+                results.push(Ok(result));
             }
 
             tx.commit().await?;
+            change_events::emit_all(&batch_events, None);
+
             Ok(results)
         } else {
             let mut futures = Vec::with_capacity(operations.len());
+            let mut audit_meta = Vec::with_capacity(operations.len());
 
-            for op in operations {
+            for (op, audit_event) in operations.into_iter().zip(audit_events) {
                 match QueryGraphBuilder::new(query_schema.clone()).build(op) {
                     Ok((graph, serializer)) => {
                         let conn = self.connector.get_connection().await?;
+                        let models = audit_event
+                            .as_ref()
+                            .map(|_| graph.involved_models())
+                            .unwrap_or_default();
 
+                        audit_meta.push((audit_event, models));
                         futures.push(tokio::spawn(Self::execute_self_contained(
                             conn,
                             graph,
                             serializer,
                             self.force_transactions,
+                            Vec::new(),
                         )));
                     }
 
                     // This looks unnecessary, but is the simplest way to preserve ordering of results for the batch.
-                    Err(err) => futures.push(tokio::spawn(async move { Err(err.into()) })),
+                    Err(err) => {
+                        audit_meta.push((None, Vec::new()));
+                        futures.push(tokio::spawn(async move { Err(err.into()) }));
+                    }
                 }
             }
 
@@ -219,6 +439,12 @@ where
                 .map(|res| res.expect("IO Error in tokio::spawn"))
                 .collect();
 
+            for ((audit_event, models), result) in audit_meta.iter().zip(responses.iter()) {
+                if let (Some(event), Ok(_)) = (audit_event, result) {
+                    event.emit(models);
+                }
+            }
+
             Ok(responses)
         }
     }
@@ -233,10 +459,32 @@ impl<C> TransactionManager for InterpretingExecutor<C>
 where
     C: Connector + Send + Sync,
 {
-    async fn start_tx(&self, max_acquisition_millis: u64, valid_for_millis: u64) -> crate::Result<TxId> {
+    async fn start_tx(
+        &self,
+        max_acquisition_millis: u64,
+        valid_for_millis: u64,
+        tag: Option<String>,
+    ) -> crate::Result<TxId> {
         let id = TxId::default();
         debug!("[{}] Starting...", id);
 
+        let connection_permit = match &self.tx_connections {
+            Some(semaphore) => {
+                let permit = time::timeout(
+                    time::Duration::from_millis(max_acquisition_millis),
+                    Arc::clone(semaphore).acquire_owned(),
+                )
+                .await
+                .map_err(|_| TransactionError::ConnectionsExhausted {
+                    limit: TX_CONNECTION_LIMIT.unwrap_or_default(),
+                })?
+                .expect("Transaction connection semaphore is never closed");
+
+                Some(permit)
+            }
+            None => None,
+        };
+
         let conn = time::timeout(
             time::Duration::from_millis(max_acquisition_millis),
             self.connector.get_connection(),
@@ -244,7 +492,8 @@ where
         .await;
 
         let conn = conn.map_err(|_| TransactionError::AcquisitionTimeout)??;
-        let c_tx = OpenTx::start(conn).await?;
+        let mut c_tx = OpenTx::start(conn, connection_permit).await?;
+        c_tx.tag = tag;
 
         self.tx_cache.insert(id.clone(), c_tx, valid_for_millis).await;
 
@@ -254,6 +503,13 @@ where
 
     async fn commit_tx(&self, tx_id: TxId) -> crate::Result<()> {
         debug!("[{}] Committing.", tx_id);
+
+        if let Some(cache) = &self.result_cache {
+            // Writes performed inside the transaction become visible now. We don't track
+            // which models a transaction touched, so conservatively drop everything.
+            cache.clear();
+        }
+
         self.finalize_tx(tx_id, CachedTx::Committed, |otx| Box::new(otx.tx.commit()))
             .await
     }
@@ -263,4 +519,59 @@ where
         self.finalize_tx(tx_id, CachedTx::RolledBack, |otx| Box::new(otx.tx.rollback()))
             .await
     }
+
+    fn transaction_status(&self) -> crate::TransactionStatus {
+        self.tx_cache.status()
+    }
+
+    async fn start_cursor_session(
+        &self,
+        max_acquisition_millis: u64,
+        valid_for_millis: u64,
+    ) -> crate::Result<CursorSessionId> {
+        let tx_id = self.start_tx(max_acquisition_millis, valid_for_millis, None).await?;
+
+        // All pages of the session must read from the same snapshot. Postgres and
+        // MSSQL accept the isolation change as the first statement of the open
+        // transaction; MySQL (InnoDB) rejects it mid-transaction but already runs
+        // REPEATABLE READ by default, and SQLite reads are serialized by nature.
+        let statement = match self.connector.name().as_str() {
+            "postgres" | "mssql" => Some("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ"),
+            _ => None,
+        };
+
+        if let Some(statement) = statement {
+            // The reference needs to be dropped before the rollback below, or else
+            // the DashMap deadlocks (see `finalize_tx`).
+            let result = {
+                let mut tx = self.tx_cache.get_or_err(&tx_id)?;
+                let otx = tx.as_open()?;
+
+                otx.as_connection_like().execute_raw(statement.into(), vec![]).await
+            };
+
+            if let Err(err) = result {
+                self.rollback_tx(tx_id).await.ok();
+                return Err(err.into());
+            }
+        }
+
+        let session_id = self.cursor_sessions.create(tx_id, valid_for_millis);
+
+        debug!("[{}] Cursor session started.", session_id);
+        Ok(session_id)
+    }
+
+    fn resolve_cursor_session(&self, session_id: &CursorSessionId) -> crate::Result<TxId> {
+        self.cursor_sessions.resolve(session_id)
+    }
+
+    async fn close_cursor_session(&self, session_id: CursorSessionId) -> crate::Result<()> {
+        debug!("[{}] Closing cursor session.", session_id);
+
+        match self.cursor_sessions.remove(&session_id) {
+            Some(tx_id) => self.rollback_tx(tx_id).await,
+            None => Err(TransactionError::CursorSessionNotFound.into()),
+        }
+    }
 }
@@ -5,13 +5,29 @@
 //!
 //! What the executor module DOES NOT DO:
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
+mod admission_queue;
+mod audit;
+pub(crate) mod change_events;
+mod cursor_session;
 mod interactive_tx;
 mod interpreting_executor;
 mod loader;
 mod pipeline;
+mod policy;
+mod result_cache;
+mod routing;
+mod two_phase;
 
+pub use admission_queue::{ADMISSION_QUEUE_DEPTH, ADMISSION_WAIT_TIMEOUT_MS};
+pub use audit::AUDIT_LOG_ENV;
+pub use change_events::CHANGE_EVENTS_ENV;
+pub use cursor_session::*;
 pub use interactive_tx::*;
 pub use loader::*;
+pub use policy::{Policy, POLICY_FILE_ENV};
+pub use result_cache::{RESULT_CACHE_MAX_ENTRIES, RESULT_CACHE_TTL_MS};
+pub use routing::RoutingExecutor;
+pub use two_phase::{TwoPhaseCoordinator, TWO_PHASE_COMMIT_ENV};
 
 use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
 use async_trait::async_trait;
@@ -53,11 +69,37 @@ pub trait TransactionManager {
     /// Expected to throw an error if no transaction could be opened for `max_acquisition_millis` milliseconds.
     /// The new transaction must only live for `valid_for_millis` milliseconds before it automatically rolls back.
     /// This rollback mechanism is an implementation detail of the trait implementer.
-    async fn start_tx(&self, max_acquisition_millis: u64, valid_for_millis: u64) -> crate::Result<TxId>;
+    /// The optional `tag` identifies the originating request in the `transactionStatus` snapshot.
+    async fn start_tx(
+        &self,
+        max_acquisition_millis: u64,
+        valid_for_millis: u64,
+        tag: Option<String>,
+    ) -> crate::Result<TxId>;
+
+    /// Takes a point-in-time snapshot of open transactions and lifecycle
+    /// counters for the `transactionStatus` admin endpoint.
+    fn transaction_status(&self) -> TransactionStatus;
 
     /// Commits a transaction.
     async fn commit_tx(&self, tx_id: TxId) -> crate::Result<()>;
 
     /// Rolls back a transaction.
     async fn rollback_tx(&self, tx_id: TxId) -> crate::Result<()>;
+
+    /// Starts a cursor session: a read-only snapshot for multi-page `findMany` sequences,
+    /// backed by a long-lived transaction so pagination over actively written tables
+    /// doesn't skip or duplicate rows. Lifetime semantics follow `start_tx`.
+    /// Returns the session token clients pass alongside their cursor.
+    async fn start_cursor_session(
+        &self,
+        max_acquisition_millis: u64,
+        valid_for_millis: u64,
+    ) -> crate::Result<CursorSessionId>;
+
+    /// Resolves a cursor session token to the transaction queries must run on.
+    fn resolve_cursor_session(&self, session_id: &CursorSessionId) -> crate::Result<TxId>;
+
+    /// Closes a cursor session, rolling back the backing transaction (sessions never write).
+    async fn close_cursor_session(&self, session_id: CursorSessionId) -> crate::Result<()>;
 }
@@ -1,3 +1,4 @@
+use super::change_events::ChangeEvent;
 use crate::{Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, ResponseData};
 
 #[derive(Debug)]
@@ -16,12 +17,14 @@ impl<'conn> QueryPipeline<'conn> {
         }
     }
 
-    pub async fn execute(mut self) -> crate::Result<ResponseData> {
+    pub async fn execute(mut self) -> crate::Result<(ResponseData, Vec<ChangeEvent>)> {
         let serializer = self.serializer;
         let expr = Expressionista::translate(self.graph)?;
         let result = self.interpreter.interpret(expr, Env::default(), 0).await;
 
         trace!("{}", self.interpreter.log_output());
-        serializer.serialize(result?)
+
+        let change_events = self.interpreter.drain_change_events();
+        Ok((serializer.serialize(result?)?, change_events))
     }
 }
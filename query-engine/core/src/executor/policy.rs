@@ -0,0 +1,129 @@
+use crate::{CoreError, Operation, QueryValue};
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+/// Path to a JSON policy document evaluated for every operation before execution,
+/// set via the `QUERY_POLICY_FILE` environment value. Unset disables policy evaluation.
+///
+/// The document holds a list of rules matched against the operation name, first match wins:
+///
+/// ```json
+/// {
+///     "rules": [
+///         { "operation": "deleteMany*", "deny": true },
+///         { "operation": "*User", "where": { "tenantId": 1 } }
+///     ]
+/// }
+/// ```
+///
+/// A matching rule either denies the operation outright, or injects a mandatory filter
+/// into the operation's `where` argument (combined with `AND`), enforcing row scoping
+/// in the engine where application-level scoping gets bypassed.
+pub const POLICY_FILE_ENV: &str = "QUERY_POLICY_FILE";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Policy {
+    rules: Vec<PolicyRule>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct PolicyRule {
+    /// Operation name pattern. A leading or trailing `*` matches any suffix or prefix,
+    /// `*` alone matches every operation.
+    operation: String,
+
+    /// Denies matching operations entirely.
+    #[serde(default)]
+    deny: bool,
+
+    /// Filter object injected into the `where` argument of matching operations.
+    #[serde(default)]
+    r#where: Option<serde_json::Value>,
+}
+
+impl Policy {
+    /// Loads the policy document if policy evaluation is enabled via `QUERY_POLICY_FILE`.
+    /// A policy file that cannot be read or parsed aborts engine startup: silently
+    /// executing without the intended restrictions is not an option for a policy.
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var(POLICY_FILE_ENV).ok()?;
+
+        let content = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Unable to read policy file '{}': {}", path, err));
+
+        let policy: Policy = serde_json::from_str(&content)
+            .unwrap_or_else(|err| panic!("Unable to parse policy file '{}': {}", path, err));
+
+        Some(policy)
+    }
+
+    /// Evaluates the policy against an operation, denying it or injecting mandatory
+    /// filters as configured. No-op if no rule matches.
+    pub fn apply(&self, operation: &mut Operation) -> crate::Result<()> {
+        let rule = match self.rules.iter().find(|rule| rule.matches(operation.name())) {
+            Some(rule) => rule,
+            None => return Ok(()),
+        };
+
+        if rule.deny {
+            return Err(CoreError::PolicyViolationError(format!(
+                "Operation '{}' is denied by the loaded policy.",
+                operation.name()
+            )));
+        }
+
+        if let Some(filter) = &rule.r#where {
+            let injected = json_to_query_value(filter);
+            let selection = match operation {
+                Operation::Read(selection) => selection,
+                Operation::Write(selection) => selection,
+            };
+
+            let arguments = selection.arguments_mut();
+
+            match arguments.iter_mut().find(|(name, _)| name == "where") {
+                Some((_, existing)) => {
+                    // Combine the existing filter with the injected one so the policy
+                    // can only ever narrow the result set.
+                    let conjunction = QueryValue::List(vec![existing.clone(), injected]);
+                    let mut map = IndexMap::new();
+                    map.insert("AND".to_owned(), conjunction);
+
+                    *existing = QueryValue::Object(map);
+                }
+                None => arguments.push(("where".to_owned(), injected)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PolicyRule {
+    fn matches(&self, operation_name: &str) -> bool {
+        match (self.operation.strip_prefix('*'), self.operation.strip_suffix('*')) {
+            (Some(""), _) => true,
+            (Some(suffix), _) => operation_name.ends_with(suffix),
+            (_, Some(prefix)) => operation_name.starts_with(prefix),
+            _ => operation_name == self.operation,
+        }
+    }
+}
+
+fn json_to_query_value(value: &serde_json::Value) -> QueryValue {
+    match value {
+        serde_json::Value::Null => QueryValue::Null,
+        serde_json::Value::Bool(b) => QueryValue::Boolean(*b),
+        serde_json::Value::Number(n) if n.is_i64() => QueryValue::Int(n.as_i64().unwrap()),
+        serde_json::Value::Number(n) => QueryValue::Float(n.as_f64().unwrap().try_into().unwrap()),
+        serde_json::Value::String(s) => QueryValue::String(s.clone()),
+        serde_json::Value::Array(values) => QueryValue::List(values.iter().map(json_to_query_value).collect()),
+        serde_json::Value::Object(map) => QueryValue::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), json_to_query_value(value)))
+                .collect(),
+        ),
+    }
+}
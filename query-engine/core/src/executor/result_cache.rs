@@ -0,0 +1,120 @@
+use crate::{response_ir::Item, Operation, ResponseData};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use std::time::{Duration, Instant};
+
+/// TTL in milliseconds for the optional engine-level read query cache,
+/// set via the `QUERY_RESULT_CACHE_TTL_MS` environment value.
+/// Unset or `0` disables the cache entirely.
+pub static RESULT_CACHE_TTL_MS: Lazy<u64> = Lazy::new(|| match std::env::var("QUERY_RESULT_CACHE_TTL_MS") {
+    Ok(ttl) => ttl.parse().unwrap_or(0),
+    Err(_) => 0,
+});
+
+/// Maximum number of entries the result cache may hold, set via the
+/// `QUERY_RESULT_CACHE_MAX_ENTRIES` environment value. Unset or unparseable
+/// falls back to the default, so a stream of distinct one-off queries cannot
+/// grow the cache without bound.
+pub static RESULT_CACHE_MAX_ENTRIES: Lazy<usize> =
+    Lazy::new(|| match std::env::var("QUERY_RESULT_CACHE_MAX_ENTRIES") {
+        Ok(max) => max.parse().unwrap_or(1000),
+        Err(_) => 1000,
+    });
+
+/// Optional cache for read query results. Entries are keyed by the normalized
+/// operation (including parameters), expire after a fixed TTL and are dropped
+/// eagerly whenever a mutation touches one of the models the read involved -
+/// the engine knows exactly which models a write touches, so invalidation is
+/// precise where bespoke external caches have to guess.
+pub(crate) struct ResultCache {
+    ttl: Duration,
+    max_entries: usize,
+    entries: DashMap<String, CacheEntry>,
+}
+
+struct CacheEntry {
+    /// Top level serialization key of the cached response.
+    response_key: String,
+    data: Item,
+    /// Models the cached read involved, used for invalidation.
+    models: Vec<String>,
+    expires_at: Instant,
+}
+
+impl ResultCache {
+    /// Returns a cache instance if result caching is enabled via `QUERY_RESULT_CACHE_TTL_MS`.
+    pub fn from_env() -> Option<Self> {
+        match *RESULT_CACHE_TTL_MS {
+            0 => None,
+            ttl => Some(Self {
+                ttl: Duration::from_millis(ttl),
+                max_entries: *RESULT_CACHE_MAX_ENTRIES,
+                entries: DashMap::new(),
+            }),
+        }
+    }
+
+    /// Cache key over the normalized operation. Operations are keyed over their full
+    /// parsed representation, so pure formatting differences in the incoming request
+    /// do not fragment the cache, and distinct operations can never collide the way
+    /// a truncated digest could and serve one query's response to another.
+    pub fn key_for(operation: &Operation) -> String {
+        format!("{:?}", operation)
+    }
+
+    pub fn get(&self, key: &str) -> Option<ResponseData> {
+        let expired = match self.entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => {
+                return Some(ResponseData::new(entry.response_key.clone(), entry.data.clone()));
+            }
+            Some(_) => true,
+            None => false,
+        };
+
+        // The reference needs to be dropped before removal, or else the DashMap deadlocks.
+        if expired {
+            self.entries.remove(key);
+        }
+
+        None
+    }
+
+    pub fn insert(&self, key: String, response: &ResponseData, models: Vec<String>) {
+        // Expired entries are otherwise only removed when their key is read again,
+        // so sweep them once the cache is full before making room.
+        if self.entries.len() >= self.max_entries {
+            let now = Instant::now();
+            self.entries.retain(|_, entry| entry.expires_at > now);
+        }
+
+        // Still full of live entries: evict an arbitrary one to stay bounded.
+        if self.entries.len() >= self.max_entries {
+            let victim = self.entries.iter().next().map(|entry| entry.key().clone());
+
+            // The reference needs to be dropped before removal, or else the DashMap deadlocks.
+            if let Some(victim) = victim {
+                self.entries.remove(&victim);
+            }
+        }
+
+        let entry = CacheEntry {
+            response_key: response.key.clone(),
+            data: response.data.clone(),
+            models,
+            expires_at: Instant::now() + self.ttl,
+        };
+
+        self.entries.insert(key, entry);
+    }
+
+    /// Drops all cached reads involving any of the given models.
+    pub fn invalidate_models(&self, models: &[String]) {
+        self.entries
+            .retain(|_, entry| !entry.models.iter().any(|model| models.contains(model)));
+    }
+
+    /// Drops all cached reads.
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+}
@@ -0,0 +1,347 @@
+//! Routing of operations to the datasource their model lives on.
+//!
+//! With more than one datasource block in the schema, the engine loads one executor
+//! per datasource. The first datasource is the default; models carrying the
+//! `@@datasource` attribute live on one of the others. The routing executor fronts
+//! all of them behind the regular [`QueryExecutor`] interface and dispatches each
+//! operation by resolving the schema field it addresses to its model.
+
+use super::{
+    change_events::{self, ChangeEvent},
+    pipeline::QueryPipeline,
+    CursorSessionId, QueryExecutor, TransactionManager, TransactionStatus, TwoPhaseCoordinator, TxId,
+    TWO_PHASE_COMMIT_ENV,
+};
+use crate::{
+    query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef, CoreError, QueryGraphBuilder,
+    QueryInterpreter,
+};
+use async_trait::async_trait;
+use connector::{Connection, ConnectionLike, Connector, Transaction};
+use std::collections::HashMap;
+
+/// A query executor fronting one executor per datasource, routing operations by the
+/// `@@datasource` attribute of the model they address.
+pub struct RoutingExecutor {
+    /// Executor for the default (first) datasource. Operations that cannot be
+    /// attributed to a routed model - raw queries, models without `@@datasource` -
+    /// run here, as do all transactions.
+    primary: Box<dyn QueryExecutor + Send + Sync>,
+
+    /// Executors for the remaining datasources, by datasource name.
+    secondary: HashMap<String, Box<dyn QueryExecutor + Send + Sync>>,
+}
+
+impl RoutingExecutor {
+    pub fn new(
+        primary: Box<dyn QueryExecutor + Send + Sync>,
+        secondary: HashMap<String, Box<dyn QueryExecutor + Send + Sync>>,
+    ) -> Self {
+        Self { primary, secondary }
+    }
+
+    /// The name of the datasource the operation routes to, `None` for the default.
+    /// Resolved through the schema field the operation addresses, whose model carries
+    /// the `@@datasource` routing information.
+    fn route(&self, operation: &Operation, query_schema: &QuerySchemaRef) -> Option<String> {
+        let field = match operation {
+            Operation::Read(selection) => query_schema.find_query_field(selection.name()),
+            Operation::Write(selection) => query_schema.find_mutation_field(selection.name()),
+        };
+
+        field
+            .and_then(|field| {
+                field
+                    .model
+                    .as_ref()
+                    .and_then(|model| model.datasource().map(String::from))
+            })
+            .filter(|name| self.secondary.contains_key(name))
+    }
+
+    /// The executor for the given route.
+    fn executor_for(&self, route: Option<&str>) -> &(dyn QueryExecutor + Send + Sync) {
+        route
+            .and_then(|name| self.secondary.get(name))
+            .map(|executor| executor.as_ref())
+            .unwrap_or_else(|| self.primary.as_ref())
+    }
+
+    /// Canonical participant order for two-phase commits: the default datasource
+    /// first, then the routed ones sorted by name. The journal records participants
+    /// by index, so the order has to be stable across batches and process restarts
+    /// for recovery to address the right database.
+    fn participant_routes(&self) -> Vec<Option<String>> {
+        let mut names: Vec<_> = self.secondary.keys().cloned().collect();
+        names.sort();
+
+        let mut routes = vec![None];
+        routes.extend(names.into_iter().map(Some));
+        routes
+    }
+
+    /// Finishes distributed transactions an earlier process left prepared on the
+    /// participants. Called once at startup when two-phase commit is enabled -
+    /// prepared transactions hold their locks until this runs.
+    pub async fn recover_two_phase(&self) -> crate::Result<()> {
+        let mut journal_conn = self.primary.primary_connector().get_connection().await?;
+
+        let mut connections = Vec::new();
+        for route in self.participant_routes() {
+            let conn = self
+                .executor_for(route.as_deref())
+                .primary_connector()
+                .get_connection()
+                .await?;
+
+            connections.push(conn);
+        }
+
+        let mut participants: Vec<&mut dyn ConnectionLike> =
+            connections.iter_mut().map(|conn| conn.as_connection_like()).collect();
+
+        TwoPhaseCoordinator::recover(journal_conn.as_connection_like(), &mut participants).await
+    }
+
+    /// Executes a transactional batch spanning multiple datasources through the
+    /// experimental two-phase commit coordinator. One transaction is opened per
+    /// datasource - including ones the batch does not touch, to keep participant
+    /// indices canonical for recovery - each operation runs on the transaction of
+    /// its datasource, and the coordinator drives the commit.
+    async fn execute_two_phase(
+        &self,
+        operations: Vec<Operation>,
+        routes: Vec<Option<String>>,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
+        let participant_routes = self.participant_routes();
+
+        let mut connections = Vec::with_capacity(participant_routes.len());
+        for route in &participant_routes {
+            let conn = self
+                .executor_for(route.as_deref())
+                .primary_connector()
+                .get_connection()
+                .await?;
+
+            connections.push(conn);
+        }
+
+        // Forces static lifetimes for the transactions, like `OpenTx` does: the
+        // connections they borrow from live right next to them in this function
+        // and both are dropped together.
+        let mut transactions: Vec<Box<dyn Transaction + 'static>> = Vec::with_capacity(connections.len());
+        for conn in connections.iter_mut() {
+            let tx: Box<dyn Transaction + '_> = conn.start_transaction().await?;
+            let tx: Box<dyn Transaction + 'static> = unsafe { std::mem::transmute(tx) };
+            transactions.push(tx);
+        }
+
+        let mut results = Vec::with_capacity(operations.len());
+        let mut batch_events = Vec::new();
+
+        for (operation, route) in operations.into_iter().zip(routes) {
+            let index = participant_routes
+                .iter()
+                .position(|participant| *participant == route)
+                .expect("participant routes cover all resolved routes");
+
+            let result =
+                Self::execute_on_participant(transactions[index].as_connection_like(), operation, &query_schema).await;
+
+            match result {
+                Ok((data, events)) => {
+                    batch_events.extend(events);
+                    results.push(Ok(data));
+                }
+                Err(err) => {
+                    for tx in transactions.iter_mut() {
+                        tx.rollback().await.ok();
+                    }
+
+                    return Err(err);
+                }
+            }
+        }
+
+        // The journal lives on the default datasource, on a connection of its own,
+        // so the commit point is recorded outside the participant transactions.
+        let mut journal_conn = self.primary.primary_connector().get_connection().await?;
+        let coordinator = TwoPhaseCoordinator::new();
+
+        TwoPhaseCoordinator::ensure_journal(journal_conn.as_connection_like()).await?;
+        coordinator
+            .commit(journal_conn.as_connection_like(), &mut transactions)
+            .await?;
+
+        change_events::emit_all(&batch_events, None);
+        Ok(results)
+    }
+
+    async fn execute_on_participant(
+        conn: &mut dyn ConnectionLike,
+        operation: Operation,
+        query_schema: &QuerySchemaRef,
+    ) -> crate::Result<(ResponseData, Vec<ChangeEvent>)> {
+        let (graph, serializer) = QueryGraphBuilder::new(query_schema.clone()).build(operation)?;
+        let interpreter = QueryInterpreter::new(conn);
+
+        QueryPipeline::new(graph, interpreter, serializer).execute().await
+    }
+}
+
+impl std::fmt::Debug for RoutingExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RoutingExecutor")
+            .field("secondary", &self.secondary.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl QueryExecutor for RoutingExecutor {
+    async fn execute(
+        &self,
+        tx_id: Option<TxId>,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData> {
+        let route = self.route(&operation, &query_schema);
+
+        // Interactive transactions are pinned to the default datasource. Running a
+        // routed operation against the primary executor instead would silently hit
+        // the wrong database, so it is rejected like cross-datasource batches are.
+        if tx_id.is_some() {
+            if route.is_some() {
+                return Err(CoreError::UnsupportedFeatureError(
+                    "An interactive transaction cannot contain operations on models routed to another datasource."
+                        .into(),
+                ));
+            }
+
+            return self.primary.execute(tx_id, operation, query_schema).await;
+        }
+
+        self.executor_for(route.as_deref())
+            .execute(tx_id, operation, query_schema)
+            .await
+    }
+
+    async fn execute_all(
+        &self,
+        tx_id: Option<TxId>,
+        operations: Vec<Operation>,
+        transactional: bool,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
+        let routes: Vec<_> = operations
+            .iter()
+            .map(|operation| self.route(operation, &query_schema))
+            .collect();
+
+        if tx_id.is_some() {
+            // Same rule as in `execute`: routed operations cannot run inside an
+            // interactive transaction on the default datasource.
+            if routes.iter().any(|route| route.is_some()) {
+                return Err(CoreError::UnsupportedFeatureError(
+                    "An interactive transaction cannot contain operations on models routed to another datasource."
+                        .into(),
+                ));
+            }
+
+            return self
+                .primary
+                .execute_all(tx_id, operations, transactional, query_schema)
+                .await;
+        }
+
+        // Batches staying on one datasource are delegated wholesale so the inner
+        // executor keeps its batching semantics (including transactional batches).
+        if let Some((first, rest)) = routes.split_first() {
+            if rest.iter().all(|route| route == first) {
+                return self
+                    .executor_for(first.as_deref())
+                    .execute_all(tx_id, operations, transactional, query_schema)
+                    .await;
+            }
+        }
+
+        // Transactional batches spanning datasources have no shared transaction to
+        // run in; they either go through the experimental two-phase commit
+        // coordinator or are rejected.
+        if transactional {
+            if TwoPhaseCoordinator::enabled() {
+                return self.execute_two_phase(operations, routes, query_schema).await;
+            }
+
+            return Err(CoreError::UnsupportedFeatureError(format!(
+                "A transactional batch cannot span multiple datasources. Set {}=1 to enable the experimental two-phase commit coordination.",
+                TWO_PHASE_COMMIT_ENV
+            )));
+        }
+
+        let mut results = Vec::with_capacity(operations.len());
+
+        for (operation, route) in operations.into_iter().zip(routes) {
+            let result = self
+                .executor_for(route.as_deref())
+                .execute(None, operation, query_schema.clone())
+                .await;
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    fn primary_connector(&self) -> &(dyn Connector + Send + Sync) {
+        self.primary.primary_connector()
+    }
+}
+
+/// Transactions and cursor sessions always live on the default datasource: an
+/// interactive transaction holds a single connection, and routed models cannot be
+/// part of it.
+#[async_trait]
+impl TransactionManager for RoutingExecutor {
+    async fn start_tx(
+        &self,
+        max_acquisition_millis: u64,
+        valid_for_millis: u64,
+        tag: Option<String>,
+    ) -> crate::Result<TxId> {
+        self.primary
+            .start_tx(max_acquisition_millis, valid_for_millis, tag)
+            .await
+    }
+
+    fn transaction_status(&self) -> TransactionStatus {
+        self.primary.transaction_status()
+    }
+
+    async fn commit_tx(&self, tx_id: TxId) -> crate::Result<()> {
+        self.primary.commit_tx(tx_id).await
+    }
+
+    async fn rollback_tx(&self, tx_id: TxId) -> crate::Result<()> {
+        self.primary.rollback_tx(tx_id).await
+    }
+
+    async fn start_cursor_session(
+        &self,
+        max_acquisition_millis: u64,
+        valid_for_millis: u64,
+    ) -> crate::Result<CursorSessionId> {
+        self.primary
+            .start_cursor_session(max_acquisition_millis, valid_for_millis)
+            .await
+    }
+
+    fn resolve_cursor_session(&self, session_id: &CursorSessionId) -> crate::Result<TxId> {
+        self.primary.resolve_cursor_session(session_id)
+    }
+
+    async fn close_cursor_session(&self, session_id: CursorSessionId) -> crate::Result<()> {
+        self.primary.close_cursor_session(session_id).await
+    }
+}
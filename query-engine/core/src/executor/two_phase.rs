@@ -0,0 +1,294 @@
+//! Experimental two-phase commit coordination across multiple datasources.
+//!
+//! The coordinator drives the classic presumed-abort protocol over a set of open
+//! transactions on different databases: every participant is asked to `PREPARE
+//! TRANSACTION`, the decision to commit is persisted in a journal table on the
+//! coordinating datasource, and only then are the prepared transactions committed.
+//! Participants therefore have to speak the `PREPARE TRANSACTION` / `COMMIT PREPARED`
+//! dialect (PostgreSQL and compatibles); MySQL XA uses a different statement shape and
+//! is not supported yet.
+//!
+//! The journal is the source of truth for recovery: a distributed transaction without
+//! a `prepared` journal entry is rolled back, one with it is committed. A process that
+//! crashes mid-protocol leaves prepared transactions behind on the participants, which
+//! hold their locks until [`TwoPhaseCoordinator::recover`] finishes them.
+
+use crate::{CoreError, TransactionError};
+use connector::{ConnectionLike, Transaction};
+use once_cell::sync::Lazy;
+
+/// Env var toggling the experimental two-phase commit coordination. Set to `1` or
+/// `true` to allow multi-datasource write operations to commit through the
+/// coordinator instead of being rejected.
+pub const TWO_PHASE_COMMIT_ENV: &str = "PRISMA_EXPERIMENTAL_TWO_PHASE_COMMIT";
+
+static TWO_PHASE_COMMIT_ENABLED: Lazy<bool> = Lazy::new(|| {
+    std::env::var(TWO_PHASE_COMMIT_ENV)
+        .map(|s| s == "1" || s == "true")
+        .unwrap_or(false)
+});
+
+/// Name of the coordination table on the coordinating datasource. One row per
+/// in-flight distributed transaction, deleted once the outcome is settled everywhere.
+const JOURNAL_TABLE: &str = "_prisma_two_phase_journal";
+
+/// Journal states, written in protocol order. `prepared` is the commit point: recovery
+/// commits everything at or past it and rolls back everything before it.
+const STATE_PREPARING: &str = "preparing";
+const STATE_PREPARED: &str = "prepared";
+
+/// Coordinates one distributed transaction across several participant transactions.
+pub struct TwoPhaseCoordinator {
+    /// Globally unique id of the distributed transaction. Participant transactions are
+    /// prepared under `<gid>-<index>`.
+    gid: String,
+}
+
+impl TwoPhaseCoordinator {
+    pub fn new() -> Self {
+        Self {
+            gid: cuid::cuid().unwrap(),
+        }
+    }
+
+    pub fn enabled() -> bool {
+        *TWO_PHASE_COMMIT_ENABLED
+    }
+
+    pub fn gid(&self) -> &str {
+        &self.gid
+    }
+
+    /// Ensures the journal table exists on the coordinating datasource. Called once
+    /// before the first distributed commit.
+    pub async fn ensure_journal(journal_conn: &mut dyn ConnectionLike) -> crate::Result<()> {
+        journal_conn
+            .execute_raw(
+                format!(
+                    "CREATE TABLE IF NOT EXISTS {} (gid TEXT PRIMARY KEY, participants INTEGER NOT NULL, state TEXT NOT NULL)",
+                    JOURNAL_TABLE
+                ),
+                vec![],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Commits the distributed transaction made up of the given open participant
+    /// transactions, journaling on `journal_conn`. Any failure before the commit point
+    /// rolls everything back; a failure after it leaves the journal entry in place so
+    /// that `recover` can finish the commit, and surfaces as an in-doubt error.
+    pub async fn commit<'a>(
+        &self,
+        journal_conn: &mut dyn ConnectionLike,
+        participants: &mut [Box<dyn Transaction + 'a>],
+    ) -> crate::Result<()> {
+        // Phase 1: prepare all participants. The journal entry is written first so a
+        // crash during this phase is recognizably an abort.
+        self.journal(journal_conn, participants.len(), STATE_PREPARING).await?;
+
+        let mut prepared = 0;
+        let mut failure: Option<CoreError> = None;
+
+        for index in 0..participants.len() {
+            let statement = format!("PREPARE TRANSACTION '{}'", self.participant_gid(index));
+
+            match participants[index]
+                .as_connection_like()
+                .execute_raw(statement, vec![])
+                .await
+            {
+                Ok(_) => prepared += 1,
+                Err(err) => {
+                    failure = Some(err.into());
+                    break;
+                }
+            }
+        }
+
+        if let Some(err) = failure {
+            self.abort(journal_conn, participants, prepared).await;
+            return Err(err);
+        }
+
+        // Commit point: once the journal records `prepared`, the transaction commits,
+        // no matter what happens to this process. If the journal write itself fails the
+        // decision was never made, so everything still rolls back.
+        if let Err(err) = self.journal(journal_conn, participants.len(), STATE_PREPARED).await {
+            self.abort(journal_conn, participants, prepared).await;
+            return Err(err);
+        }
+
+        // Phase 2: commit the prepared transactions. Failures here cannot abort
+        // anymore - the participant stays prepared and recovery retries the commit.
+        let mut in_doubt = false;
+
+        for index in 0..participants.len() {
+            let statement = format!("COMMIT PREPARED '{}'", self.participant_gid(index));
+
+            if let Err(err) = participants[index]
+                .as_connection_like()
+                .execute_raw(statement, vec![])
+                .await
+            {
+                warn!(
+                    "Two-phase commit: participant {} of {} failed to commit, leaving it for recovery: {}",
+                    index, self.gid, err
+                );
+                in_doubt = true;
+            }
+        }
+
+        if in_doubt {
+            return Err(TransactionError::InDoubt { gid: self.gid.clone() }.into());
+        }
+
+        // Everything is settled, the journal entry is no longer needed.
+        self.forget(journal_conn).await;
+        Ok(())
+    }
+
+    /// Finishes distributed transactions an earlier process left behind. `participants`
+    /// are connections to the participant datasources in the same order used when
+    /// committing. Journaled-as-prepared transactions are committed, everything else is
+    /// rolled back; settled entries are removed from the journal.
+    pub async fn recover(
+        journal_conn: &mut dyn ConnectionLike,
+        participants: &mut [&mut dyn ConnectionLike],
+    ) -> crate::Result<()> {
+        Self::ensure_journal(journal_conn).await?;
+
+        let rows = journal_conn
+            .query_raw(
+                format!("SELECT gid, participants, state FROM {}", JOURNAL_TABLE),
+                vec![],
+            )
+            .await?;
+
+        let rows = match rows.as_array() {
+            Some(rows) => rows.clone(),
+            None => return Ok(()),
+        };
+
+        for row in rows {
+            let (gid, count, state) = match (
+                row.get("gid").and_then(|v| v.as_str()),
+                row.get("participants").and_then(|v| v.as_i64()),
+                row.get("state").and_then(|v| v.as_str()),
+            ) {
+                (Some(gid), Some(count), Some(state)) => (gid.to_owned(), count as usize, state),
+                _ => continue,
+            };
+
+            let finish = if state == STATE_PREPARED { "COMMIT" } else { "ROLLBACK" };
+
+            for (index, conn) in participants.iter_mut().enumerate().take(count) {
+                let statement = format!("{} PREPARED '{}-{}'", finish, gid, index);
+
+                // The participant may never have prepared (crash mid phase 1) or have
+                // finished already (crash mid phase 2) - in both cases the statement
+                // fails because the prepared transaction does not exist, which is fine.
+                if let Err(err) = conn.execute_raw(statement, vec![]).await {
+                    debug!("Two-phase recovery of {} on participant {}: {}", gid, index, err);
+                }
+            }
+
+            if count <= participants.len() {
+                journal_conn
+                    .execute_raw(format!("DELETE FROM {} WHERE gid = '{}'", JOURNAL_TABLE, gid), vec![])
+                    .await?;
+            } else {
+                warn!(
+                    "Two-phase recovery: journal entry {} lists {} participants but only {} connections were supplied, keeping the entry.",
+                    gid,
+                    count,
+                    participants.len()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn participant_gid(&self, index: usize) -> String {
+        format!("{}-{}", self.gid, index)
+    }
+
+    /// Upserts the journal entry. Values are inlined because placeholder syntax differs
+    /// between connectors - the gid is an engine-generated cuid and the state a fixed
+    /// token, so no quoting issues arise.
+    async fn journal(
+        &self,
+        journal_conn: &mut dyn ConnectionLike,
+        participants: usize,
+        state: &str,
+    ) -> crate::Result<()> {
+        journal_conn
+            .execute_raw(
+                format!("DELETE FROM {} WHERE gid = '{}'", JOURNAL_TABLE, self.gid),
+                vec![],
+            )
+            .await?;
+
+        journal_conn
+            .execute_raw(
+                format!(
+                    "INSERT INTO {} (gid, participants, state) VALUES ('{}', {}, '{}')",
+                    JOURNAL_TABLE, self.gid, participants, state
+                ),
+                vec![],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rolls back after a phase 1 failure: already prepared participants via `ROLLBACK
+    /// PREPARED`, the rest through a plain rollback. Best effort - the journal entry
+    /// never reached `prepared`, so recovery also treats leftovers as aborted.
+    async fn abort<'a>(
+        &self,
+        journal_conn: &mut dyn ConnectionLike,
+        participants: &mut [Box<dyn Transaction + 'a>],
+        prepared: usize,
+    ) {
+        for index in 0..participants.len() {
+            let result = if index < prepared {
+                let statement = format!("ROLLBACK PREPARED '{}'", self.participant_gid(index));
+                participants[index]
+                    .as_connection_like()
+                    .execute_raw(statement, vec![])
+                    .await
+                    .map(drop)
+            } else {
+                participants[index].rollback().await
+            };
+
+            if let Err(err) = result {
+                warn!(
+                    "Two-phase commit: failed to roll back participant {} of {}: {}",
+                    index, self.gid, err
+                );
+            }
+        }
+
+        self.forget(journal_conn).await;
+    }
+
+    /// Removes the journal entry, best effort. A leftover entry in a pre-`prepared`
+    /// state only costs recovery a few no-op rollbacks.
+    async fn forget(&self, journal_conn: &mut dyn ConnectionLike) {
+        let statement = format!("DELETE FROM {} WHERE gid = '{}'", JOURNAL_TABLE, self.gid);
+
+        if let Err(err) = journal_conn.execute_raw(statement, vec![]).await {
+            warn!("Two-phase commit: failed to clear journal entry {}: {}", self.gid, err);
+        }
+    }
+}
+
+impl Default for TwoPhaseCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -25,6 +25,26 @@ pub enum InterpreterError {
     Generic(String),
 }
 
+impl InterpreterError {
+    /// Attaches the logical operation path at which the error occurred (e.g.
+    /// `["updateOneUser", "createOnePost"]`) to the user-facing error metadata,
+    /// so P-coded connector errors carry their location in the query graph.
+    pub(crate) fn with_operation_path(self, path: &[String]) -> Self {
+        match self {
+            Self::ConnectorError(mut err) => {
+                if let Some(known) = err.user_facing_error.as_mut() {
+                    if let serde_json::Value::Object(meta) = &mut known.meta {
+                        meta.insert("path".to_owned(), serde_json::Value::from(path.to_vec()));
+                    }
+                }
+
+                Self::ConnectorError(err)
+            }
+            other => other,
+        }
+    }
+}
+
 impl fmt::Display for InterpreterError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -6,12 +6,24 @@ pub enum Expression {
         seq: Vec<Expression>,
     },
 
+    /// Like `Sequence`, but the expressions are known to be free of data dependencies
+    /// and side effects, allowing the interpreter to evaluate them concurrently if it
+    /// has connections to spare (see `QueryInterpreter::with_auxiliary_connections`).
+    Concurrent {
+        seq: Vec<Expression>,
+    },
+
     Func {
         func: Box<dyn FnOnce(Env) -> InterpretationResult<Expression> + Send + Sync + 'static>,
     },
 
     Query {
         query: Box<Query>,
+
+        /// The logical operation path from the root operation down to this query,
+        /// e.g. `["updateOneUser", "createOnePost"]`. Attached to connector errors
+        /// so they carry the location in the query graph at which they occurred.
+        path: Vec<String>,
     },
 
     Let {
@@ -18,50 +18,67 @@ struct IfNodeAcc {
 impl Expressionista {
     #[tracing::instrument(skip(graph))]
     pub fn translate(mut graph: QueryGraph) -> InterpretationResult<Expression> {
+        // Root subgraphs have no data dependencies between each other (dependencies are
+        // always expressed through bindings of an enclosing scope). Graphs without side
+        // effects may therefore evaluate them concurrently (see `Expression::Concurrent`).
+        let independent_roots = graph.is_read_only();
+
         graph
             .root_nodes()
             .into_iter()
-            .map(|root_node| Self::build_expression(&mut graph, &root_node, vec![]))
+            .map(|root_node| Self::build_expression(&mut graph, &root_node, vec![], vec![]))
             .collect::<InterpretationResult<Vec<Expression>>>()
-            .map(|res| Expression::Sequence { seq: res })
+            .map(|seq| {
+                if independent_roots && seq.len() > 1 {
+                    Expression::Concurrent { seq }
+                } else {
+                    Expression::Sequence { seq }
+                }
+            })
     }
 
-    #[tracing::instrument(skip(graph, node, parent_edges))]
+    #[tracing::instrument(skip(graph, node, parent_edges, path))]
     fn build_expression(
         graph: &mut QueryGraph,
         node: &NodeRef,
         parent_edges: Vec<EdgeRef>,
+        path: Vec<String>,
     ) -> InterpretationResult<Expression> {
         match graph
             .node_content(node)
             .unwrap_or_else(|| panic!("Node content {} was empty", node.id()))
         {
-            Node::Query(_) => Self::build_query_expression(graph, node, parent_edges),
-            Node::Flow(_) => Self::build_flow_expression(graph, node, parent_edges),
-            Node::Computation(_) => Self::build_computation_expression(graph, node, parent_edges),
-            Node::Empty => Self::build_empty_expression(graph, node, parent_edges),
+            Node::Query(_) => Self::build_query_expression(graph, node, parent_edges, path),
+            Node::Flow(_) => Self::build_flow_expression(graph, node, parent_edges, path),
+            Node::Computation(_) => Self::build_computation_expression(graph, node, parent_edges, path),
+            Node::Empty => Self::build_empty_expression(graph, node, parent_edges, path),
         }
     }
 
-    #[tracing::instrument(skip(graph, node, parent_edges))]
+    #[tracing::instrument(skip(graph, node, parent_edges, path))]
     fn build_query_expression(
         graph: &mut QueryGraph,
         node: &NodeRef,
         parent_edges: Vec<EdgeRef>,
+        mut path: Vec<String>,
     ) -> InterpretationResult<Expression> {
         graph.mark_visited(&node);
 
+        if let Some(Node::Query(query)) = graph.node_content(node) {
+            path.push(query.path_segment());
+        }
+
         // Child edges are ordered, evaluation order is low to high in the graph, unless other rules override.
         let direct_children = graph.direct_child_pairs(&node);
 
-        let mut child_expressions = Self::process_children(graph, direct_children)?;
+        let mut child_expressions = Self::process_children(graph, direct_children, &path)?;
 
         let is_result = graph.is_result_node(&node);
         let node_id = node.id();
         let node = graph.pluck_node(&node);
         let into_expr = Box::new(|node: Node| {
             let query: Box<Query> = Box::new(node.try_into()?);
-            Ok(Expression::Query { query })
+            Ok(Expression::Query { query, path })
         });
 
         let expr = Self::transform_node(graph, parent_edges, node, into_expr)?;
@@ -89,10 +106,11 @@ impl Expressionista {
         }
     }
 
-    #[tracing::instrument(skip(graph, child_pairs))]
+    #[tracing::instrument(skip(graph, child_pairs, path))]
     fn process_children(
         graph: &mut QueryGraph,
         mut child_pairs: Vec<(EdgeRef, NodeRef)>,
+        path: &[String],
     ) -> InterpretationResult<Vec<Expression>> {
         // Find the positions of all result returning graph nodes.
         let mut result_positions: Vec<usize> = child_pairs
@@ -122,42 +140,44 @@ impl Expressionista {
             .into_iter()
             .map(|(_, node)| {
                 let edges = graph.incoming_edges(&node);
-                Self::build_expression(graph, &node, edges)
+                Self::build_expression(graph, &node, edges, path.to_vec())
             })
             .collect::<InterpretationResult<Vec<Expression>>>()?;
 
         // Fold result scopes into one expression.
         if !result_subgraphs.is_empty() {
-            let result_exp = Self::fold_result_scopes(graph, result_subgraphs)?;
+            let result_exp = Self::fold_result_scopes(graph, result_subgraphs, path)?;
             expressions.push(result_exp);
         }
 
         Ok(expressions)
     }
 
-    #[tracing::instrument(skip(graph, node, parent_edges))]
+    #[tracing::instrument(skip(graph, node, parent_edges, path))]
     fn build_empty_expression(
         graph: &mut QueryGraph,
         node: &NodeRef,
         parent_edges: Vec<EdgeRef>,
+        path: Vec<String>,
     ) -> InterpretationResult<Expression> {
         graph.mark_visited(node);
 
         let child_pairs = graph.direct_child_pairs(node);
         let exprs: Vec<Expression> = child_pairs
             .into_iter()
-            .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node)))
+            .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node), path.clone()))
             .collect::<InterpretationResult<_>>()?;
 
         let into_expr = Box::new(move |_node: Node| Ok(Expression::Sequence { seq: exprs }));
         Self::transform_node(graph, parent_edges, Node::Empty, into_expr)
     }
 
-    #[tracing::instrument(skip(graph, node, parent_edges))]
+    #[tracing::instrument(skip(graph, node, parent_edges, path))]
     fn build_computation_expression(
         graph: &mut QueryGraph,
         node: &NodeRef,
         parent_edges: Vec<EdgeRef>,
+        path: Vec<String>,
     ) -> InterpretationResult<Expression> {
         graph.mark_visited(node);
 
@@ -166,7 +186,7 @@ impl Expressionista {
 
         let exprs: Vec<Expression> = child_pairs
             .into_iter()
-            .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node)))
+            .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node), path.clone()))
             .collect::<InterpretationResult<_>>()?;
 
         let node = graph.pluck_node(node);
@@ -206,26 +226,28 @@ impl Expressionista {
         }
     }
 
-    #[tracing::instrument(skip(graph, node, parent_edges))]
+    #[tracing::instrument(skip(graph, node, parent_edges, path))]
     fn build_flow_expression(
         graph: &mut QueryGraph,
         node: &NodeRef,
         parent_edges: Vec<EdgeRef>,
+        path: Vec<String>,
     ) -> InterpretationResult<Expression> {
         graph.mark_visited(node);
 
         match graph.node_content(node).unwrap() {
-            Node::Flow(Flow::If(_)) => Self::translate_if_node(graph, node, parent_edges),
-            Node::Flow(Flow::Return(_)) => Self::translate_return_node(graph, node, parent_edges),
+            Node::Flow(Flow::If(_)) => Self::translate_if_node(graph, node, parent_edges, path),
+            Node::Flow(Flow::Return(_)) => Self::translate_return_node(graph, node, parent_edges, path),
             _ => unreachable!(),
         }
     }
 
-    #[tracing::instrument(skip(graph, node, parent_edges))]
+    #[tracing::instrument(skip(graph, node, parent_edges, path))]
     fn translate_if_node(
         graph: &mut QueryGraph,
         node: &NodeRef,
         parent_edges: Vec<EdgeRef>,
+        path: Vec<String>,
     ) -> InterpretationResult<Expression> {
         let child_pairs = graph.direct_child_pairs(node);
 
@@ -246,14 +268,14 @@ impl Expressionista {
             .expect("Expected if-node to always have a then edge to another node.");
 
         // Build expressions for both arms.
-        let then_expr = Self::build_expression(graph, &then_pair.1, graph.incoming_edges(&then_pair.1))?;
+        let then_expr = Self::build_expression(graph, &then_pair.1, graph.incoming_edges(&then_pair.1), path.clone())?;
         let else_expr = if_node_info
             ._else
             .into_iter()
-            .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node)))
+            .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node), path.clone()))
             .collect::<InterpretationResult<Vec<_>>>()?;
 
-        let child_expressions = Self::process_children(graph, if_node_info.other)?;
+        let child_expressions = Self::process_children(graph, if_node_info.other, &path)?;
 
         let node_id = node.id();
         let node = graph.pluck_node(node);
@@ -288,14 +310,15 @@ impl Expressionista {
         Self::transform_node(graph, parent_edges, node, into_expr)
     }
 
-    #[tracing::instrument(skip(graph, node, parent_edges))]
+    #[tracing::instrument(skip(graph, node, parent_edges, path))]
     fn translate_return_node(
         graph: &mut QueryGraph,
         node: &NodeRef,
         parent_edges: Vec<EdgeRef>,
+        path: Vec<String>,
     ) -> InterpretationResult<Expression> {
         let direct_children = graph.direct_child_pairs(&node);
-        let child_expressions = Self::process_children(graph, direct_children)?;
+        let child_expressions = Self::process_children(graph, direct_children, &path)?;
 
         let into_expr = Box::new(move |node: Node| {
             let flow: Flow = node.try_into()?;
@@ -411,10 +434,11 @@ impl Expressionista {
             .collect()
     }
 
-    #[tracing::instrument(skip(graph, result_subgraphs))]
+    #[tracing::instrument(skip(graph, result_subgraphs, path))]
     fn fold_result_scopes(
         graph: &mut QueryGraph,
         result_subgraphs: Vec<(EdgeRef, NodeRef)>,
+        path: &[String],
     ) -> InterpretationResult<Expression> {
         // if the subgraphs all point to the same result node, we fold them in sequence
         // if not, we can separate them with a getfirstnonempty
@@ -423,7 +447,7 @@ impl Expressionista {
             .map(|(_, node)| {
                 let name = node.id();
                 let edges = graph.incoming_edges(&node);
-                let expr = Self::build_expression(graph, &node, edges)?;
+                let expr = Self::build_expression(graph, &node, edges, path.to_vec())?;
 
                 Ok(Binding { name, expr })
             })
@@ -10,7 +10,13 @@ pub fn format_expression(expr: &Expression, indent: usize) -> String {
             .collect::<Vec<String>>()
             .join("\n"),
 
-        Expression::Query { query } => match &**query {
+        Expression::Concurrent { seq } => seq
+            .iter()
+            .map(|expr| add_indent(indent, format_expression(expr, indent + 2)))
+            .collect::<Vec<String>>()
+            .join("\n"),
+
+        Expression::Query { query, path: _ } => match &**query {
             Query::Read(rq) => add_indent(indent, format!("{}", rq)),
             Query::Write(wq) => add_indent(indent, format!("{}", wq)),
         },
@@ -3,12 +3,13 @@ use std::fmt;
 use super::{
     expression::*,
     query_interpreters::{read, write},
+    record_loader::RecordLoader,
     InterpretationResult, InterpreterError,
 };
-use crate::{Query, QueryResult};
-use connector::ConnectionLike;
+use crate::{executor::change_events::ChangeEvent, Query, QueryResult};
+use connector::{Connection, ConnectionLike};
 use crossbeam_queue::SegQueue;
-use futures::future::BoxFuture;
+use futures::{future::BoxFuture, FutureExt};
 use im::HashMap;
 use prisma_models::prelude::*;
 
@@ -140,6 +141,18 @@ impl Env {
 
 pub struct QueryInterpreter<'conn> {
     pub(crate) conn: &'conn mut dyn ConnectionLike,
+
+    /// Additional connections for evaluating independent expressions concurrently.
+    /// Empty unless provided by the executor (see `Expression::Concurrent`).
+    aux_connections: Vec<Box<dyn Connection + Send + Sync>>,
+
+    /// Request-scoped cache deduplicating repeated unique record lookups.
+    record_loader: RecordLoader,
+
+    /// Change events captured from successful writes, drained by the pipeline and
+    /// emitted by the executor once the transaction outcome is known.
+    change_events: SegQueue<ChangeEvent>,
+
     log: SegQueue<String>,
 }
 
@@ -161,7 +174,21 @@ impl<'conn> QueryInterpreter<'conn> {
             log.push("\n".to_string());
         }
 
-        Self { conn, log }
+        Self {
+            conn,
+            aux_connections: Vec::new(),
+            record_loader: RecordLoader::default(),
+            change_events: SegQueue::new(),
+            log,
+        }
+    }
+
+    /// Hands the interpreter additional connections for evaluating independent
+    /// expressions concurrently. Without any, concurrent expressions are evaluated
+    /// sequentially on the main connection.
+    pub fn with_auxiliary_connections(mut self, connections: Vec<Box<dyn Connection + Send + Sync>>) -> Self {
+        self.aux_connections = connections;
+        self
     }
 
     #[tracing::instrument(skip(self, exp, env, level))]
@@ -195,6 +222,73 @@ impl<'conn> QueryInterpreter<'conn> {
                 })
             }
 
+            Expression::Concurrent { seq } if seq.is_empty() => Box::pin(async { Ok(ExpressionResult::Empty) }),
+
+            // Without connections to spare, concurrent expressions are evaluated
+            // like a plain sequence.
+            Expression::Concurrent { seq } if self.aux_connections.is_empty() => {
+                self.interpret(Expression::Sequence { seq }, env, level)
+            }
+
+            Expression::Concurrent { seq } => {
+                Box::pin(async move {
+                    self.log_line(level, || {
+                        format!("CONCURRENT ({} connections)", self.aux_connections.len() + 1)
+                    });
+
+                    let conn = &mut self.conn;
+                    let aux_connections = &mut self.aux_connections;
+                    let width = aux_connections.len() + 1;
+
+                    let mut results = Vec::with_capacity(seq.len());
+                    let mut expressions = seq.into_iter();
+
+                    // Evaluate in waves of one expression per connection: the first expression
+                    // of each wave runs on the main connection, the remaining ones on one
+                    // auxiliary connection each.
+                    loop {
+                        let wave: Vec<Expression> = expressions.by_ref().take(width).collect();
+
+                        if wave.is_empty() {
+                            break;
+                        }
+
+                        let mut futures: Vec<BoxFuture<'_, InterpretationResult<ExpressionResult>>> =
+                            Vec::with_capacity(wave.len());
+
+                        let mut wave = wave.into_iter();
+                        let first = wave.next().unwrap();
+                        let main_conn: &mut dyn ConnectionLike = &mut **conn;
+
+                        let env_clone = env.clone();
+                        futures.push(
+                            async move {
+                                let mut interpreter = QueryInterpreter::new(main_conn);
+                                interpreter.interpret(first, env_clone, level + 1).await
+                            }
+                            .boxed(),
+                        );
+
+                        for (expr, aux_conn) in wave.zip(aux_connections.iter_mut()) {
+                            let env_clone = env.clone();
+
+                            futures.push(
+                                async move {
+                                    let mut interpreter = QueryInterpreter::new(aux_conn.as_connection_like());
+                                    interpreter.interpret(expr, env_clone, level + 1).await
+                                }
+                                .boxed(),
+                            );
+                        }
+
+                        results.extend(futures::future::try_join_all(futures).await?);
+                    }
+
+                    // Last result gets returned
+                    Ok(results.pop().unwrap())
+                })
+            }
+
             Expression::Let {
                 bindings,
                 mut expressions,
@@ -221,18 +315,48 @@ impl<'conn> QueryInterpreter<'conn> {
                 })
             }
 
-            Expression::Query { query } => Box::pin(async move {
+            Expression::Query { query, path } => Box::pin(async move {
                 match *query {
                     Query::Read(read) => {
                         self.log_line(level, || format!("READ {}", read));
-                        Ok(read::execute(self.conn, read, None)
+
+                        let cache_key = RecordLoader::key_for(&read);
+
+                        if let Some(key) = &cache_key {
+                            if let Some(result) = self.record_loader.get(key, read.name()) {
+                                self.log_line(level + 1, || "cached");
+                                return Ok(ExpressionResult::Query(result));
+                            }
+                        }
+
+                        let result = read::execute(self.conn, read, None)
                             .await
-                            .map(ExpressionResult::Query)?)
+                            .map_err(|err| err.with_operation_path(&path))?;
+
+                        if let Some(key) = cache_key {
+                            self.record_loader.insert(key, result.clone());
+                        }
+
+                        Ok(ExpressionResult::Query(result))
                     }
 
                     Query::Write(write) => {
                         self.log_line(level, || format!("WRITE {}", write));
-                        Ok(write::execute(self.conn, write).await.map(ExpressionResult::Query)?)
+
+                        let change_event = ChangeEvent::capture(&write);
+                        let result = write::execute(self.conn, write)
+                            .await
+                            .map_err(|err| err.with_operation_path(&path))?;
+
+                        if let Some(mut event) = change_event {
+                            event.record_result(&result);
+                            self.change_events.push(event);
+                        }
+
+                        // Cached lookups may be stale after any mutation.
+                        self.record_loader.clear();
+
+                        Ok(ExpressionResult::Query(result))
                     }
                 }
             }),
@@ -275,6 +399,17 @@ impl<'conn> QueryInterpreter<'conn> {
         }
     }
 
+    /// Takes the change events captured so far out of the interpreter.
+    pub fn drain_change_events(&self) -> Vec<ChangeEvent> {
+        let mut events = Vec::with_capacity(self.change_events.len());
+
+        while let Ok(event) = self.change_events.pop() {
+            events.push(event);
+        }
+
+        events
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn log_output(&self) -> String {
         let mut output = String::with_capacity(self.log.len() * 30);
@@ -3,6 +3,7 @@ mod expression;
 mod expressionista;
 mod formatters;
 mod interpreter;
+mod record_loader;
 
 pub(self) mod query_interpreters;
 
@@ -2,11 +2,39 @@ use super::{inmemory_record_processor::InMemoryRecordProcessor, read};
 use crate::{interpreter::InterpretationResult, query_ast::*};
 use connector::{
     self, filter::Filter, ConnectionLike, QueryArguments, RelAggregationRow, RelAggregationSelection, ScalarCompare,
+    ToOneRelationSelection,
 };
 use prisma_models::{FieldSelection, ManyRecords, Record, RelationFieldRef, SelectionResult};
 use prisma_value::PrismaValue;
 use std::collections::HashMap;
 
+/// Whether a nested query can be hydrated together with its parent records in a single
+/// database query (see `ReadOperations::get_many_records_joined`). Only plain to-one
+/// reads qualify - arguments, aggregations or deeper nesting require the dedicated
+/// roundtrip.
+pub fn is_to_one_join_candidate(rq: &RelatedRecordsQuery) -> bool {
+    !rq.parent_field.is_list()
+        && rq.parent_results.is_none()
+        && rq.args.do_nothing()
+        && rq.aggregation_selections.is_empty()
+        && rq.nested.is_empty()
+}
+
+/// Collects the selections of all nested queries that qualify for single-query
+/// hydration alongside their parent records.
+pub fn to_one_join_selections(nested: &[ReadQuery]) -> Vec<ToOneRelationSelection> {
+    nested
+        .iter()
+        .filter_map(|query| match query {
+            ReadQuery::RelatedRecordsQuery(rq) if is_to_one_join_candidate(rq) => Some(ToOneRelationSelection {
+                field: rq.parent_field.clone(),
+                selected_fields: rq.selected_fields.clone(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
 #[tracing::instrument(skip(tx, query, parent_result, processor))]
 pub async fn m2m(
     tx: &mut dyn ConnectionLike,
@@ -28,6 +28,53 @@ fn read_one(tx: &mut dyn ConnectionLike, query: RecordQuery) -> BoxFuture<'_, In
     let fut = async move {
         let model = query.model;
         let filter = query.filter.expect("Expected filter to be set for ReadOne query.");
+
+        // To-one relations are hydrated in the same database query where the connector
+        // supports it, saving one roundtrip per relation.
+        let joins = nested_read::to_one_join_selections(&query.nested);
+
+        if !joins.is_empty() {
+            let args = QueryArguments::from((model.clone(), filter.clone()));
+            let result = tx
+                .get_many_records_joined(
+                    &model,
+                    args,
+                    &query.selected_fields,
+                    &query.aggregation_selections,
+                    &joins,
+                )
+                .await?;
+
+            if let Some((scalars, children)) = result {
+                if scalars.records.is_empty() {
+                    return Ok(QueryResult::RecordSelection(Box::new(RecordSelection {
+                        name: query.name,
+                        fields: query.selection_order,
+                        scalars: ManyRecords::default(),
+                        nested: vec![],
+                        query_arguments: QueryArguments::new(model.clone()),
+                        model,
+                        aggregation_rows: None,
+                    })));
+                }
+
+                let (scalars, aggregation_rows) =
+                    extract_aggregation_rows_from_scalars(scalars, query.aggregation_selections);
+                let nested = process_nested_joined(tx, query.nested, &scalars, children).await?;
+
+                return Ok(RecordSelection {
+                    name: query.name,
+                    fields: query.selection_order,
+                    scalars,
+                    nested,
+                    query_arguments: QueryArguments::new(model.clone()),
+                    model,
+                    aggregation_rows,
+                }
+                .into());
+            }
+        }
+
         let scalars = tx
             .get_single_record(&model, &filter, &query.selected_fields, &query.aggregation_selections)
             .await?;
@@ -77,6 +124,42 @@ fn read_many(
     mut query: ManyRecordsQuery,
 ) -> BoxFuture<'_, InterpretationResult<QueryResult>> {
     let fut = async move {
+        // To-one relations are hydrated in the same database query where the connector
+        // supports it, saving one roundtrip per relation. In-memory processing may drop
+        // or reorder the parent records, so the fast path is skipped in that case.
+        if !query.args.requires_inmemory_processing() {
+            let joins = nested_read::to_one_join_selections(&query.nested);
+
+            if !joins.is_empty() {
+                let result = tx
+                    .get_many_records_joined(
+                        &query.model,
+                        query.args.clone(),
+                        &query.selected_fields,
+                        &query.aggregation_selections,
+                        &joins,
+                    )
+                    .await?;
+
+                if let Some((scalars, children)) = result {
+                    let (scalars, aggregation_rows) =
+                        extract_aggregation_rows_from_scalars(scalars, query.aggregation_selections);
+                    let nested = process_nested_joined(tx, query.nested, &scalars, children).await?;
+
+                    return Ok(RecordSelection {
+                        name: query.name,
+                        fields: query.selection_order,
+                        scalars,
+                        nested,
+                        query_arguments: query.args,
+                        model: query.model,
+                        aggregation_rows,
+                    }
+                    .into());
+                }
+            }
+        }
+
         let (scalars, aggregation_rows) = if query.args.requires_inmemory_processing() {
             let processor = InMemoryRecordProcessor::new_from_query_args(&mut query.args);
             let scalars = tx
@@ -208,6 +291,45 @@ fn process_nested<'conn>(
     fut.boxed()
 }
 
+/// Like `process_nested`, except that qualifying to-one relations have already been
+/// hydrated by the connector (see `get_many_records_joined`). Their record selections
+/// are assembled from the prefetched child records instead of causing another query.
+async fn process_nested_joined(
+    tx: &mut dyn ConnectionLike,
+    nested: Vec<ReadQuery>,
+    parent_result: &ManyRecords,
+    children: Vec<ManyRecords>,
+) -> InterpretationResult<Vec<QueryResult>> {
+    let mut children = children.into_iter();
+    let mut results = Vec::with_capacity(nested.len());
+
+    for query in nested {
+        match query {
+            ReadQuery::RelatedRecordsQuery(rq) if nested_read::is_to_one_join_candidate(&rq) => {
+                let scalars = children
+                    .next()
+                    .expect("Expected one prefetched record set per hydrated to-one relation.");
+
+                results.push(
+                    RecordSelection {
+                        name: rq.name,
+                        fields: rq.selection_order,
+                        scalars,
+                        nested: vec![],
+                        query_arguments: rq.args,
+                        model: rq.parent_field.related_model(),
+                        aggregation_rows: None,
+                    }
+                    .into(),
+                );
+            }
+            query => results.push(execute(tx, query, Some(parent_result)).await?),
+        }
+    }
+
+    Ok(results)
+}
+
 /// Removes the relation aggregation data from the database result and collect it into some RelAggregationRow
 /// Explanation: Relation aggregations on a findMany are selected from an output object type. eg:
 /// findManyX { _count { rel_1, rel 2 } }
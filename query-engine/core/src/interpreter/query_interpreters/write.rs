@@ -54,7 +54,7 @@ async fn create_many(tx: &mut dyn ConnectionLike, q: CreateManyRecords) -> Inter
 }
 
 async fn update_one(tx: &mut dyn ConnectionLike, q: UpdateRecord) -> InterpretationResult<QueryResult> {
-    let mut res = tx.update_records(&q.model, q.record_filter, q.args).await?;
+    let mut res = tx.update_records(&q.model, q.record_filter, q.args, None).await?;
 
     Ok(QueryResult::Id(res.pop()))
 }
@@ -69,19 +69,19 @@ async fn delete_one(tx: &mut dyn ConnectionLike, q: DeleteRecord) -> Interpretat
         )),
     }?;
 
-    let res = tx.delete_records(&q.model, filter).await?;
+    let res = tx.delete_records(&q.model, filter, None).await?;
 
     Ok(QueryResult::Count(res))
 }
 
 async fn update_many(tx: &mut dyn ConnectionLike, q: UpdateManyRecords) -> InterpretationResult<QueryResult> {
-    let res = tx.update_records(&q.model, q.record_filter, q.args).await?;
+    let res = tx.update_records(&q.model, q.record_filter, q.args, q.limit).await?;
 
     Ok(QueryResult::Count(res.len()))
 }
 
 async fn delete_many(tx: &mut dyn ConnectionLike, q: DeleteManyRecords) -> InterpretationResult<QueryResult> {
-    let res = tx.delete_records(&q.model, q.record_filter).await?;
+    let res = tx.delete_records(&q.model, q.record_filter, q.limit).await?;
 
     Ok(QueryResult::Count(res))
 }
@@ -0,0 +1,63 @@
+use crate::{QueryResult, ReadQuery, RecordQuery};
+use std::collections::HashMap;
+
+/// Request-scoped cache for unique record lookups. Batched `findUnique` requests with
+/// identical shapes are compacted before they reach the interpreter, but one request can
+/// still look up the same record repeatedly, e.g. when a deeply nested graph references
+/// the same parent more than once. The loader deduplicates those lookups for the duration
+/// of a single interpretation and is dropped with the interpreter.
+#[derive(Debug, Default)]
+pub(crate) struct RecordLoader {
+    entries: HashMap<String, QueryResult>,
+}
+
+impl RecordLoader {
+    /// Cache key for the given read query, or `None` if the query is not cacheable.
+    /// Only plain unique record lookups qualify - queries with nested selections or
+    /// relation aggregations are left to the regular execution path. The key is the
+    /// full rendered lookup shape rather than a digest of it, so two different
+    /// lookups can never collide and dedupe into one result.
+    pub fn key_for(query: &ReadQuery) -> Option<String> {
+        match query {
+            ReadQuery::RecordQuery(RecordQuery {
+                model,
+                filter: Some(filter),
+                selected_fields,
+                nested,
+                selection_order,
+                aggregation_selections,
+                ..
+            }) if nested.is_empty() && aggregation_selections.is_empty() => Some(format!(
+                "{}|{:?}|{:?}|{:?}",
+                model.name, filter, selected_fields, selection_order
+            )),
+
+            _ => None,
+        }
+    }
+
+    /// Returns a copy of the cached result for the given key, renamed to the name of
+    /// the requesting query. The name is the only part of the result that may differ
+    /// between two lookups with the same key.
+    pub fn get(&self, key: &str, query_name: &str) -> Option<QueryResult> {
+        self.entries.get(key).map(|result| {
+            let mut result = result.clone();
+
+            if let QueryResult::RecordSelection(ref mut selection) = result {
+                selection.name = query_name.to_owned();
+            }
+
+            result
+        })
+    }
+
+    pub fn insert(&mut self, key: String, result: QueryResult) {
+        self.entries.insert(key, result);
+    }
+
+    /// Drops all cached lookups. Invoked after every write, as any mutation may change
+    /// what a previously cached lookup would return.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
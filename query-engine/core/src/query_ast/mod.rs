@@ -28,6 +28,16 @@ impl Query {
             Self::Write(wq) => wq.model(),
         }
     }
+
+    /// A short label for this query, used as a segment of the operation path attached
+    /// to errors. Read queries use the (relation) field name they were built from,
+    /// write queries mirror the client-facing operation names.
+    pub fn path_segment(&self) -> String {
+        match self {
+            Self::Read(rq) => rq.name().to_owned(),
+            Self::Write(wq) => wq.path_segment(),
+        }
+    }
 }
 
 impl FilteredQuery for Query {
@@ -40,6 +40,24 @@ impl ReadQuery {
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
         }
     }
+
+    /// All models this query reads from, including the models of nested relation selections.
+    pub fn involved_models(&self) -> Vec<ModelRef> {
+        let mut models = vec![self.model()];
+
+        let nested = match self {
+            ReadQuery::RecordQuery(x) => x.nested.as_slice(),
+            ReadQuery::ManyRecordsQuery(x) => x.nested.as_slice(),
+            ReadQuery::RelatedRecordsQuery(x) => x.nested.as_slice(),
+            ReadQuery::AggregateRecordsQuery(_) => &[],
+        };
+
+        for nested_query in nested {
+            models.extend(nested_query.involved_models());
+        }
+
+        models
+    }
 }
 
 impl FilteredQuery for ReadQuery {
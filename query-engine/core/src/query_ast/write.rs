@@ -37,6 +37,25 @@ impl WriteQuery {
         args.update_datetimes(model);
     }
 
+    /// Fills in the `@updatedAt` fields the user did not provide explicitly. Creates always get a
+    /// value, updates only when they already change something. Invoked once for every write node
+    /// when the query graph is finalized.
+    pub fn inject_datetimes(&mut self) {
+        match self {
+            Self::CreateRecord(cr) => cr.args.add_datetimes(&cr.model),
+            Self::CreateManyRecords(cmr) => {
+                let model = cmr.model.clone();
+
+                for args in cmr.args.iter_mut() {
+                    args.add_datetimes(&model);
+                }
+            }
+            Self::UpdateRecord(ur) => ur.args.update_datetimes(ur.model.clone()),
+            Self::UpdateManyRecords(ur) => ur.args.update_datetimes(ur.model.clone()),
+            _ => (),
+        }
+    }
+
     #[tracing::instrument(skip(self, field_selection))]
     pub fn returns(&self, field_selection: &FieldSelection) -> bool {
         let returns_id = &self.model().primary_identifier() == field_selection;
@@ -58,6 +77,22 @@ impl WriteQuery {
         }
     }
 
+    /// See `Query::path_segment`.
+    pub fn path_segment(&self) -> String {
+        match self {
+            Self::CreateRecord(q) => format!("createOne{}", q.model.name),
+            Self::CreateManyRecords(q) => format!("createMany{}", q.model.name),
+            Self::UpdateRecord(q) => format!("updateOne{}", q.model.name),
+            Self::DeleteRecord(q) => format!("deleteOne{}", q.model.name),
+            Self::UpdateManyRecords(q) => format!("updateMany{}", q.model.name),
+            Self::DeleteManyRecords(q) => format!("deleteMany{}", q.model.name),
+            Self::ConnectRecords(_) => "connect".to_owned(),
+            Self::DisconnectRecords(_) => "disconnect".to_owned(),
+            Self::ExecuteRaw(_) => "executeRaw".to_owned(),
+            Self::QueryRaw(_) => "queryRaw".to_owned(),
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn model(&self) -> ModelRef {
         match self {
@@ -154,6 +189,8 @@ pub struct UpdateManyRecords {
     pub model: ModelRef,
     pub record_filter: RecordFilter,
     pub args: WriteArgs,
+    /// Restricts the update to the first `n` matching records.
+    pub limit: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -166,6 +203,8 @@ pub struct DeleteRecord {
 pub struct DeleteManyRecords {
     pub model: ModelRef,
     pub record_filter: RecordFilter,
+    /// Restricts the delete to the first `n` matching records.
+    pub limit: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -111,6 +111,10 @@ impl Selection {
         self.arguments.pop()
     }
 
+    pub fn arguments_mut(&mut self) -> &mut Vec<(String, QueryValue)> {
+        &mut self.arguments
+    }
+
     pub fn nested_selections(&self) -> &[Self] {
         &self.nested_selections
     }
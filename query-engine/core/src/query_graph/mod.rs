@@ -222,6 +222,7 @@ impl QueryGraph {
             self.ensure_return_nodes_have_parent_dependency()?;
             self.insert_reloads()?;
             self.normalize_if_nodes()?;
+            self.inject_datetimes();
             self.finalized = true;
         }
 
@@ -319,6 +320,34 @@ impl QueryGraph {
     }
 
     /// Mark the query graph to need a transaction.
+    /// Names of all models queries in this graph touch.
+    /// Allows caching layers to scope cached reads by, and to invalidate them from writes over, this set.
+    pub fn involved_models(&self) -> Vec<String> {
+        let mut models: Vec<String> = self
+            .graph
+            .node_indices()
+            .filter_map(|ix| {
+                let node = NodeRef { node_ix: ix };
+
+                match self.node_content(&node) {
+                    Some(Node::Query(Query::Read(rq))) => Some(
+                        rq.involved_models()
+                            .into_iter()
+                            .map(|model| model.name.clone())
+                            .collect(),
+                    ),
+                    Some(Node::Query(Query::Write(wq))) => Some(vec![wq.model().name.clone()]),
+                    _ => None,
+                }
+            })
+            .flatten()
+            .collect();
+
+        models.sort();
+        models.dedup();
+        models
+    }
+
     pub fn flag_transactional(&mut self) {
         self.needs_transaction = true;
     }
@@ -328,11 +357,26 @@ impl QueryGraph {
         self.needs_transaction
     }
 
+    /// Whether this graph contains read queries only. Read-only graphs have no side
+    /// effects, allowing independent subgraphs to be evaluated concurrently.
+    pub fn is_read_only(&self) -> bool {
+        self.graph.node_indices().all(|node_ix| {
+            let node = NodeRef { node_ix };
+
+            !matches!(self.node_content(&node), Some(Node::Query(Query::Write(_))))
+        })
+    }
+
     /// Returns a reference to the content of `node`, if the content is still present.
     pub fn node_content(&self, node: &NodeRef) -> Option<&Node> {
         self.graph.node_weight(node.node_ix).unwrap().borrow()
     }
 
+    /// Returns a mutable reference to the content of `node`, if the content is still present.
+    pub fn node_content_mut(&mut self, node: &NodeRef) -> Option<&mut Node> {
+        self.graph.node_weight_mut(node.node_ix).unwrap().borrow_mut()
+    }
+
     /// Returns a reference to the content of `edge`, if the content is still present.
     pub fn edge_content(&self, edge: &EdgeRef) -> Option<&QueryGraphDependency> {
         self.graph.edge_weight(edge.edge_ix).unwrap().borrow()
@@ -615,6 +659,20 @@ impl QueryGraph {
     ///         sibling   │                                ─ ─ ─ ─ ─ ─ ┘
     ///      └ ─ ─ ─ ─ ─ ─
     /// ```
+    /// Injects engine-generated datetime values (`@updatedAt`) into the write arguments of every
+    /// write query node. Doing this in one place covers all write paths uniformly, including
+    /// `createMany` rows and the relation updates the graph builder synthesizes itself.
+    #[tracing::instrument(skip(self))]
+    fn inject_datetimes(&mut self) {
+        for node_ix in self.graph.node_indices() {
+            let node = NodeRef { node_ix };
+
+            if let Some(Node::Query(Query::Write(wq))) = self.node_content_mut(&node) {
+                wq.inject_datetimes();
+            }
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     fn normalize_if_nodes(&mut self) -> QueryGraphResult<()> {
         for node_ix in self.graph.node_indices() {
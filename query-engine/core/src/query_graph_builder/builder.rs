@@ -89,6 +89,8 @@ impl QueryGraphBuilder {
         let parsed_field = field_pair.parsed_field;
         let connector_ctx = self.query_schema.context();
 
+        extractors::validate_pagination(&parsed_field, connector_ctx)?;
+
         let mut graph = match (&query_info.tag, query_info.model.clone()) {
             (QueryTag::FindUnique, Some(m)) => read::find_unique(parsed_field, m).map(Into::into),
             (QueryTag::FindFirst, Some(m)) => read::find_first(parsed_field, m).map(Into::into),
@@ -39,9 +39,46 @@ pub enum QueryGraphBuilderError {
 
     RecordNotFound(String),
 
+    /// A field in a write input either does not exist on the target model or can
+    /// never be written to, e.g. because it is computed by the database.
+    FieldNotWritable {
+        field_name: String,
+        object_name: String,
+        reason: String,
+    },
+
+    /// A write value violates the native type declared for its column. Caught in core
+    /// so that every connector surfaces the same error instead of its own database error.
+    NativeTypeViolation(NativeTypeViolation),
+
+    /// A `take` or `skip` value exceeds what the connector supports. Caught in core so
+    /// that oversized values fail loudly instead of being truncated on the way to the
+    /// database.
+    PaginationOutOfRange {
+        argument_name: String,
+        value: i64,
+        maximum: i64,
+    },
+
     QueryGraphError(QueryGraphError),
 }
 
+#[derive(Debug)]
+pub enum NativeTypeViolation {
+    /// The value is longer than the declared maximum length of the column type.
+    ValueTooLong { model_name: String, field_name: String },
+
+    /// The value does not fit the declared precision and scale of the column type.
+    ValueOutOfRange { details: String },
+
+    /// The value is not a member of the enum backing the field.
+    InvalidEnumValue {
+        field_value: String,
+        model_name: String,
+        field_name: String,
+    },
+}
+
 #[derive(Debug)]
 pub struct RelationViolation {
     pub(crate) relation_name: String,
@@ -292,9 +292,12 @@ fn parse_query_mode(input: ParsedInputValue) -> QueryGraphBuilderResult<QueryMod
         _ => unreachable!(),
     };
 
-    Ok(match s.as_str() {
-        "default" => QueryMode::Default,
-        "insensitive" => QueryMode::Insensitive,
-        _ => unreachable!(),
-    })
+    match s.as_str() {
+        "default" => Ok(QueryMode::Default),
+        "insensitive" => Ok(QueryMode::Insensitive),
+        mode => Err(QueryGraphBuilderError::InputError(format!(
+            "Invalid query mode `{}`. `insensitive` is only available for connectors that support case-insensitive filtering (e.g. via the `citext` type or `ILIKE` on PostgreSQL).",
+            mode
+        ))),
+    }
 }
@@ -1,7 +1,10 @@
 use super::extract_filter;
-use crate::{constants::filters, ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
-use connector::{Filter, RelationCompare};
-use prisma_models::RelationFieldRef;
+use crate::{
+    constants::{aggregations, filters},
+    ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
+};
+use connector::{Filter, RelationCompare, ScalarCondition};
+use prisma_models::{PrismaValue, RelationFieldRef};
 use std::convert::TryInto;
 
 #[tracing::instrument(name = "parse_relation_field", skip(filter_key, field, input))]
@@ -14,6 +17,14 @@ pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue
         (filters::NONE, Some(value)) => Ok(field.no_related(extract_filter(value, &field.related_model())?)),
         (filters::EVERY, Some(value)) => Ok(field.every_related(extract_filter(value, &field.related_model())?)),
 
+        // Relation aggregate filters
+        (aggregations::UNDERSCORE_COUNT, Some(value)) => {
+            let conditions = parse_count_conditions(value, false)?;
+            let filters = conditions.into_iter().map(|cond| field.related_count(cond)).collect();
+
+            Ok(Filter::and(filters))
+        }
+
         // One-relation filters
         (filters::IS, Some(value)) => Ok(field.to_one_related(extract_filter(value, &field.related_model())?)),
         (filters::IS, None) => Ok(field.one_relation_is_null()),
@@ -26,3 +37,52 @@ pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue
         ))),
     }
 }
+
+/// Parses the conditions of a `_count` relation filter into scalar conditions on the
+/// related record count.
+fn parse_count_conditions(value_map: ParsedInputMap, reverse: bool) -> QueryGraphBuilderResult<Vec<ScalarCondition>> {
+    let conditions = value_map
+        .into_iter()
+        .map(|(k, v)| match k.as_str() {
+            filters::NOT_LOWERCASE => match v {
+                // Support for shorthand `{ _count: { not: 5 } }`.
+                ParsedInputValue::Single(value) => Ok(vec![ScalarCondition::NotEquals(value).invert(reverse)]),
+                _ => parse_count_conditions(v.try_into()?, !reverse),
+            },
+            filters::EQUALS => {
+                let value: PrismaValue = v.try_into()?;
+                Ok(vec![ScalarCondition::Equals(value).invert(reverse)])
+            }
+            filters::IN => {
+                let values: Vec<PrismaValue> = v.try_into()?;
+                Ok(vec![ScalarCondition::In(values).invert(reverse)])
+            }
+            filters::NOT_IN => {
+                let values: Vec<PrismaValue> = v.try_into()?;
+                Ok(vec![ScalarCondition::NotIn(values).invert(reverse)])
+            }
+            filters::LOWER_THAN => {
+                let value: PrismaValue = v.try_into()?;
+                Ok(vec![ScalarCondition::LessThan(value).invert(reverse)])
+            }
+            filters::LOWER_THAN_OR_EQUAL => {
+                let value: PrismaValue = v.try_into()?;
+                Ok(vec![ScalarCondition::LessThanOrEquals(value).invert(reverse)])
+            }
+            filters::GREATER_THAN => {
+                let value: PrismaValue = v.try_into()?;
+                Ok(vec![ScalarCondition::GreaterThan(value).invert(reverse)])
+            }
+            filters::GREATER_THAN_OR_EQUAL => {
+                let value: PrismaValue = v.try_into()?;
+                Ok(vec![ScalarCondition::GreaterThanOrEquals(value).invert(reverse)])
+            }
+            _ => Err(QueryGraphBuilderError::InputError(format!(
+                "{} is not a valid relation count filter operation",
+                k
+            ))),
+        })
+        .collect::<QueryGraphBuilderResult<Vec<Vec<_>>>>()?;
+
+    Ok(conditions.into_iter().flatten().collect())
+}
@@ -305,25 +305,55 @@ fn parse_internal_scalar(
             Ok(vec![filter])
         }
 
-        filters::EQUALS if reverse => Ok(vec![field.not_equals(as_prisma_value(input)?)]),
+        filters::EQUALS if reverse => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.not_equals_field(other),
+            None => field.not_equals(as_prisma_value(input)?),
+        }]),
         filters::CONTAINS if reverse => Ok(vec![field.not_contains(as_prisma_value(input)?)]),
         filters::STARTS_WITH if reverse => Ok(vec![field.not_starts_with(as_prisma_value(input)?)]),
         filters::ENDS_WITH if reverse => Ok(vec![field.not_ends_with(as_prisma_value(input)?)]),
 
-        filters::EQUALS => Ok(vec![field.equals(as_prisma_value(input)?)]),
+        filters::EQUALS => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.equals_field(other),
+            None => field.equals(as_prisma_value(input)?),
+        }]),
         filters::CONTAINS => Ok(vec![field.contains(as_prisma_value(input)?)]),
         filters::STARTS_WITH => Ok(vec![field.starts_with(as_prisma_value(input)?)]),
         filters::ENDS_WITH => Ok(vec![field.ends_with(as_prisma_value(input)?)]),
 
-        filters::LOWER_THAN if reverse => Ok(vec![field.greater_than_or_equals(as_prisma_value(input)?)]),
-        filters::GREATER_THAN if reverse => Ok(vec![field.less_than_or_equals(as_prisma_value(input)?)]),
-        filters::LOWER_THAN_OR_EQUAL if reverse => Ok(vec![field.greater_than(as_prisma_value(input)?)]),
-        filters::GREATER_THAN_OR_EQUAL if reverse => Ok(vec![field.less_than(as_prisma_value(input)?)]),
-
-        filters::LOWER_THAN => Ok(vec![field.less_than(as_prisma_value(input)?)]),
-        filters::GREATER_THAN => Ok(vec![field.greater_than(as_prisma_value(input)?)]),
-        filters::LOWER_THAN_OR_EQUAL => Ok(vec![field.less_than_or_equals(as_prisma_value(input)?)]),
-        filters::GREATER_THAN_OR_EQUAL => Ok(vec![field.greater_than_or_equals(as_prisma_value(input)?)]),
+        filters::LOWER_THAN if reverse => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.greater_than_or_equals_field(other),
+            None => field.greater_than_or_equals(as_prisma_value(input)?),
+        }]),
+        filters::GREATER_THAN if reverse => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.less_than_or_equals_field(other),
+            None => field.less_than_or_equals(as_prisma_value(input)?),
+        }]),
+        filters::LOWER_THAN_OR_EQUAL if reverse => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.greater_than_field(other),
+            None => field.greater_than(as_prisma_value(input)?),
+        }]),
+        filters::GREATER_THAN_OR_EQUAL if reverse => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.less_than_field(other),
+            None => field.less_than(as_prisma_value(input)?),
+        }]),
+
+        filters::LOWER_THAN => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.less_than_field(other),
+            None => field.less_than(as_prisma_value(input)?),
+        }]),
+        filters::GREATER_THAN => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.greater_than_field(other),
+            None => field.greater_than(as_prisma_value(input)?),
+        }]),
+        filters::LOWER_THAN_OR_EQUAL => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.less_than_or_equals_field(other),
+            None => field.less_than_or_equals(as_prisma_value(input)?),
+        }]),
+        filters::GREATER_THAN_OR_EQUAL => Ok(vec![match as_field_ref(&input, field)? {
+            Some(other) => field.greater_than_or_equals_field(other),
+            None => field.greater_than_or_equals(as_prisma_value(input)?),
+        }]),
 
         filters::SEARCH if reverse => Ok(vec![field.not_search(as_prisma_value(input)?)]),
         filters::SEARCH => Ok(vec![field.search(as_prisma_value(input)?)]),
@@ -354,6 +384,42 @@ fn as_prisma_value(input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaVal
     Ok(input.try_into()?)
 }
 
+/// Resolves a `{ _ref: "otherField" }` input (`fieldReference` preview feature) to the
+/// referenced scalar field on the same model. Plain values return `None` and are parsed
+/// as usual by the caller.
+fn as_field_ref(input: &ParsedInputValue, field: &ScalarFieldRef) -> QueryGraphBuilderResult<Option<ScalarFieldRef>> {
+    let map = match input {
+        ParsedInputValue::Map(map) => map,
+        _ => return Ok(None),
+    };
+
+    let name = match map.get(filters::UNDERSCORE_REF) {
+        Some(ParsedInputValue::Single(PrismaValue::String(name))) => name,
+        _ => return Ok(None),
+    };
+
+    let model = field
+        .container
+        .as_model()
+        .expect("Field reference filters are only available on model fields.");
+
+    let other = model.fields().find_from_scalar(name).map_err(|_| {
+        QueryGraphBuilderError::InputError(format!(
+            "The field `{}` referenced in `_ref` does not exist as a scalar field on model `{}`.",
+            name, model.name
+        ))
+    })?;
+
+    if other.type_identifier != field.type_identifier || other.is_list() != field.is_list() {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "The fields `{}` and `{}` on model `{}` do not have matching types and cannot be compared.",
+            field.name, other.name, model.name
+        )));
+    }
+
+    Ok(Some(other))
+}
+
 fn as_prisma_value_list(input: ParsedInputValue) -> QueryGraphBuilderResult<Vec<PrismaValue>> {
     Ok(input.try_into()?)
 }
@@ -2,12 +2,64 @@ use super::*;
 use crate::{
     constants::{aggregations, args, ordering},
     query_document::{ParsedArgument, ParsedInputMap},
+    schema::ConnectorContext,
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
 use connector::QueryArguments;
+use datamodel_connector::ConnectorCapability;
+use once_cell::sync::Lazy;
 use prisma_models::prelude::*;
 use std::convert::TryInto;
 
+/// The maximum `skip` and absolute `take` value for connectors without the
+/// `LargeOffsets` capability.
+const MAX_PAGINATION_32_BIT: i64 = i32::MAX as i64;
+
+/// Validates every `take` and `skip` argument in the field tree against the limits of
+/// the connector before any query is built. Without the `LargeOffsets` capability,
+/// values beyond the 32-bit integer range would be truncated or rejected further down,
+/// so they are caught here with a typed error.
+#[tracing::instrument(skip(field, connector_ctx))]
+pub fn validate_pagination(field: &ParsedField, connector_ctx: &ConnectorContext) -> QueryGraphBuilderResult<()> {
+    let maximum = if connector_ctx.capabilities.contains(&ConnectorCapability::LargeOffsets) {
+        i64::MAX
+    } else {
+        MAX_PAGINATION_32_BIT
+    };
+
+    validate_pagination_arguments(field, maximum)
+}
+
+fn validate_pagination_arguments(field: &ParsedField, maximum: i64) -> QueryGraphBuilderResult<()> {
+    for arg in &field.arguments {
+        let value = match (arg.name.as_str(), &arg.value) {
+            (args::TAKE | args::SKIP, ParsedInputValue::Single(PrismaValue::Int(value))) => *value,
+            _ => continue,
+        };
+
+        // A negative take is valid and pages backwards from the cursor. A negative skip
+        // is rejected in `extract_skip`.
+        let out_of_range = match arg.name.as_str() {
+            args::TAKE => value > maximum || value < -maximum,
+            _ => value > maximum,
+        };
+
+        if out_of_range {
+            return Err(QueryGraphBuilderError::PaginationOutOfRange {
+                argument_name: arg.name.clone(),
+                value,
+                maximum,
+            });
+        }
+    }
+
+    for pair in field.nested_fields.iter().flat_map(|object| &object.fields) {
+        validate_pagination_arguments(&pair.parsed_field, maximum)?;
+    }
+
+    Ok(())
+}
+
 /// Expects the caller to know that it is structurally guaranteed that query arguments can be extracted,
 /// e.g. that the query schema guarantees that required fields are present.
 /// Errors occur if conversions fail.
@@ -130,7 +182,7 @@ fn process_order_object(
                     process_order_object(&rf.related_model(), object, path, None)
                 }
                 Field::Scalar(sf) => {
-                    let sort_order = extract_sort_order(field_value)?;
+                    let (sort_order, nulls_order) = extract_scalar_sort(field_value)?;
 
                     if let Some(sort_aggr) = parent_sort_aggregation {
                         Ok(Some(OrderBy::aggregation(
@@ -140,7 +192,7 @@ fn process_order_object(
                             sort_aggr,
                         )))
                     } else {
-                        Ok(Some(OrderBy::scalar(sf.clone(), path, sort_order)))
+                        Ok(Some(OrderBy::scalar(sf.clone(), path, sort_order, nulls_order)))
                     }
                 }
                 Field::Composite(_) => Ok(None), // [Composites] todo
@@ -187,6 +239,31 @@ fn extract_sort_aggregation(field_name: &str) -> QueryGraphBuilderResult<SortAgg
     }
 }
 
+/// Extracts the sort order of a scalar field, which is either a plain `SortOrder`
+/// enum value or a `{ sort: ..., nulls: ... }` object.
+fn extract_scalar_sort(field_value: ParsedInputValue) -> QueryGraphBuilderResult<(SortOrder, Option<NullsOrder>)> {
+    match field_value {
+        ParsedInputValue::Map(mut map) => {
+            let sort_order = extract_sort_order(map.remove(ordering::SORT).unwrap())?;
+            let nulls_order = map.remove(ordering::NULLS).map(extract_nulls_order).transpose()?;
+
+            Ok((sort_order, nulls_order))
+        }
+        value => Ok((extract_sort_order(value)?, None)),
+    }
+}
+
+fn extract_nulls_order(field_value: ParsedInputValue) -> QueryGraphBuilderResult<NullsOrder> {
+    let value: PrismaValue = field_value.try_into()?;
+    let nulls_order = match value.into_string().unwrap().as_str() {
+        ordering::FIRST => NullsOrder::First,
+        ordering::LAST => NullsOrder::Last,
+        _ => unreachable!(),
+    };
+
+    Ok(nulls_order)
+}
+
 fn extract_sort_order(field_value: ParsedInputValue) -> QueryGraphBuilderResult<SortOrder> {
     let value: PrismaValue = field_value.try_into()?;
     let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
@@ -273,22 +350,48 @@ fn extract_compound_cursor_field(
     Ok(pairs)
 }
 
+/// Disables the primary key tiebreaker that is appended to unstable orderings when
+/// paginating. The tiebreaker changes the generated queries, which can defeat index
+/// usage on large tables - this escape hatch restores the previous behaviour.
+static DISABLE_ORDER_STABILIZATION: Lazy<bool> =
+    Lazy::new(|| std::env::var("PRISMA_DISABLE_ORDER_STABILIZATION").is_ok());
+
 /// Runs final transformations on the QueryArguments.
 fn finalize_arguments(mut args: QueryArguments, model: &ModelRef) -> QueryArguments {
     // Check if the query requires an implicit ordering added to the arguments.
     // An implicit ordering is convenient for deterministic results for take and skip, for cursor it's _required_
     // as a cursor needs a direction to page. We simply take the primary identifier as a default order-by.
+    //
+    // The same applies when an ordering is present but does not pin down a total order
+    // (e.g. ordering by a non-unique column only): records with equal values have no
+    // defined relative position, so pages can repeat or skip records. The primary
+    // identifier is appended as a tiebreaker in that case as well.
+    let uses_pagination =
+        args.skip.as_ref().map(|skip| *skip > 0).unwrap_or(false) || args.cursor.is_some() || args.take.is_some();
     let add_implicit_ordering =
-        (args.skip.as_ref().map(|skip| *skip > 0).unwrap_or(false) || args.cursor.is_some() || args.take.is_some())
-            && args.order_by.is_empty();
+        uses_pagination && (args.order_by.is_empty() || (!args.is_stable_ordering() && !*DISABLE_ORDER_STABILIZATION));
 
     if add_implicit_ordering {
         let primary_identifier = model.primary_identifier();
-        let order_bys = primary_identifier.into_iter().map(|f| match f {
-            // IDs can _only_ contain scalar selections.
-            SelectedField::Scalar(sf) => sf.into(),
-            _ => unreachable!(),
-        });
+        let order_bys: Vec<_> = primary_identifier
+            .into_iter()
+            .filter_map(|f| match f {
+                // IDs can _only_ contain scalar selections.
+                SelectedField::Scalar(sf) => {
+                    let already_ordered_by = args
+                        .order_by
+                        .iter()
+                        .any(|o| matches!(o, OrderBy::Scalar(scalar) if scalar.path.is_empty() && scalar.field == sf));
+
+                    if already_ordered_by {
+                        None
+                    } else {
+                        Some(sf.into())
+                    }
+                }
+                _ => unreachable!(),
+            })
+            .collect();
 
         args.order_by.extend(order_bys);
     }
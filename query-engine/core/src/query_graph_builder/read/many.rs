@@ -17,6 +17,7 @@ pub fn find_many(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult
 
     let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
     let selected_fields = utils::merge_cursor_fields(selected_fields, &args.cursor);
+    let selected_fields = utils::merge_order_by_fields(selected_fields, &args);
 
     Ok(ReadQuery::ManyRecordsQuery(ManyRecordsQuery {
         name,
@@ -21,6 +21,7 @@ pub fn find_related(
 
     let selected_fields = utils::merge_relation_selections(selected_fields, Some(parent_field.clone()), &nested);
     let selected_fields = utils::merge_cursor_fields(selected_fields, &args.cursor);
+    let selected_fields = utils::merge_order_by_fields(selected_fields, &args);
 
     Ok(ReadQuery::RelatedRecordsQuery(RelatedRecordsQuery {
         name,
@@ -1,6 +1,6 @@
 use super::*;
 use crate::{constants::aggregations::*, FieldPair, ReadQuery};
-use connector::RelAggregationSelection;
+use connector::{QueryArguments, RelAggregationSelection};
 use prisma_models::prelude::*;
 use std::sync::Arc;
 
@@ -103,6 +103,26 @@ pub fn merge_cursor_fields(selected_fields: FieldSelection, cursor: &Option<Sele
     }
 }
 
+/// Ensures that the scalar fields used for ordering on the model itself are part of
+/// the selection. Necessary for all in-memory record processing (distinct, unstable
+/// cursors, queries split due to parameter limits), which orders the fetched records
+/// without the database - the ordering values must be present on the records, as the
+/// projection only fetches the requested columns otherwise.
+pub fn merge_order_by_fields(selected_fields: FieldSelection, args: &QueryArguments) -> FieldSelection {
+    let order_by_selections = args
+        .order_by
+        .iter()
+        .filter_map(|order_by| match order_by {
+            OrderBy::Scalar(by_scalar) if by_scalar.path.is_empty() => {
+                Some(FieldSelection::from(by_scalar.field.clone()))
+            }
+            _ => None,
+        })
+        .collect();
+
+    selected_fields.merge(FieldSelection::union(order_by_selections))
+}
+
 pub fn collect_relation_aggr_selections(from: &[FieldPair], model: &ModelRef) -> Vec<RelAggregationSelection> {
     from.iter()
         .flat_map(|pair| match pair.parsed_field.name.as_str() {
@@ -81,10 +81,8 @@ pub fn create_many_records(
         .into_iter()
         .map(|data_value| {
             let data_map = data_value.try_into()?;
-            let mut args = WriteArgsParser::from(&model, data_map)?.args;
 
-            args.add_datetimes(&model);
-            Ok(args)
+            Ok(WriteArgsParser::from(&model, data_map)?.args)
         })
         .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
 
@@ -106,9 +104,7 @@ pub fn create_record_node(
     data_map: ParsedInputMap,
 ) -> QueryGraphBuilderResult<NodeRef> {
     let create_args = WriteArgsParser::from(&model, data_map)?;
-    let mut args = create_args.args;
-
-    args.add_datetimes(&model);
+    let args = create_args.args;
 
     let cr = CreateRecord { model, args };
     let create_node = graph.create_node(Query::Write(WriteQuery::CreateRecord(cr)));
@@ -71,12 +71,15 @@ pub fn delete_many_records(
         None => Filter::empty(),
     };
 
+    let limit = parse_limit(field.arguments.lookup(args::LIMIT))?;
+
     let model_id = model.primary_identifier();
     let read_query = utils::read_ids_infallible(model.clone(), model_id, filter.clone());
     let record_filter = filter.into();
     let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
         model: model.clone(),
         record_filter,
+        limit,
     });
 
     let read_query_node = graph.create_node(read_query);
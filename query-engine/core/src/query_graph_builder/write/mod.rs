@@ -2,6 +2,7 @@ mod connect;
 mod create;
 mod delete;
 mod disconnect;
+mod native_types;
 mod nested;
 mod raw;
 mod update;
@@ -11,6 +12,8 @@ mod write_args_parser;
 pub mod utils;
 
 use super::*;
+use crate::ParsedArgument;
+use std::convert::TryInto;
 
 // Expose top level write operation builder functions.
 pub use create::{create_many_records, create_record};
@@ -18,3 +21,19 @@ pub use delete::{delete_many_records, delete_record};
 pub use raw::{execute_raw, query_raw};
 pub use update::{update_many_records, update_record};
 pub use upsert::upsert_record;
+
+/// Parses the optional "limit" argument shared by the `updateMany` and `deleteMany` operations.
+pub(crate) fn parse_limit(arg: Option<ParsedArgument>) -> QueryGraphBuilderResult<Option<usize>> {
+    let limit: Option<i64> = match arg {
+        Some(limit_arg) => limit_arg.value.try_into()?,
+        None => None,
+    };
+
+    match limit {
+        Some(limit) if limit < 0 => Err(QueryGraphBuilderError::InputError(format!(
+            "Provided limit ({}) must not be negative.",
+            limit
+        ))),
+        limit => Ok(limit.map(|limit| limit as usize)),
+    }
+}
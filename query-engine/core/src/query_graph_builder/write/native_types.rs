@@ -0,0 +1,167 @@
+use super::*;
+use bigdecimal::BigDecimal;
+use prisma_models::{PrismaValue, ScalarFieldRef};
+
+/// Native types whose first argument is the maximum length of the stored value.
+const LENGTH_TYPES: &[&str] = &[
+    "Char",
+    "NChar",
+    "VarChar",
+    "NVarChar",
+    "Binary",
+    "VarBinary",
+    "Bit",
+    "VarBit",
+];
+
+/// Native types declaring a fixed precision and scale.
+const DECIMAL_TYPES: &[&str] = &["Decimal", "Numeric"];
+
+/// Integer native types with a value range that differs from the scalar type they map to,
+/// with their inclusive bounds. Only types whose name is unambiguous across connectors are
+/// listed - `TinyInt`, for example, is signed on MySQL but unsigned on SQL Server.
+const INTEGER_RANGE_TYPES: &[(&str, i64, i64)] = &[
+    ("UnsignedTinyInt", 0, 255),
+    ("UnsignedSmallInt", 0, 65535),
+    ("UnsignedMediumInt", 0, 16777215),
+    ("UnsignedInt", 0, 4294967295),
+    // The upper bound of `BIGINT UNSIGNED` does not fit into an i64, so only the lower
+    // bound can be validated here.
+    ("UnsignedBigInt", 0, i64::MAX),
+    ("MediumInt", -8388608, 8388607),
+];
+
+/// Validates a write value against the native type declared for its column, so that
+/// values that can never be stored fail with the same error on every connector instead
+/// of a connector-specific database error. Only violations that are decidable from the
+/// native type parameters are checked: value length, decimal precision, integer range
+/// and enum membership. Anything else is left to the database.
+pub(super) fn validate_value(
+    model_name: &str,
+    sf: &ScalarFieldRef,
+    value: &PrismaValue,
+) -> QueryGraphBuilderResult<()> {
+    match value {
+        PrismaValue::List(values) => values
+            .iter()
+            .try_for_each(|value| validate_value(model_name, sf, value)),
+        PrismaValue::String(s) => validate_length(model_name, sf, s.chars().count()),
+        PrismaValue::Bytes(b) => validate_length(model_name, sf, b.len()),
+        PrismaValue::Float(decimal) => validate_precision(model_name, sf, decimal),
+        PrismaValue::Int(int) | PrismaValue::BigInt(int) => validate_integer_range(model_name, sf, *int),
+        PrismaValue::Enum(variant) => validate_enum_membership(model_name, sf, variant),
+        _ => Ok(()),
+    }
+}
+
+fn validate_length(model_name: &str, sf: &ScalarFieldRef, actual: usize) -> QueryGraphBuilderResult<()> {
+    let max_length = sf.native_type.as_ref().and_then(|nt| {
+        if LENGTH_TYPES.contains(&nt.name.as_str()) {
+            // Non-numeric length arguments (e.g. `VarChar(Max)`) impose no limit.
+            nt.args.first().and_then(|arg| arg.parse::<usize>().ok())
+        } else {
+            None
+        }
+    });
+
+    match max_length {
+        Some(max_length) if actual > max_length => Err(QueryGraphBuilderError::NativeTypeViolation(
+            NativeTypeViolation::ValueTooLong {
+                model_name: model_name.to_owned(),
+                field_name: sf.name.clone(),
+            },
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_integer_range(model_name: &str, sf: &ScalarFieldRef, value: i64) -> QueryGraphBuilderResult<()> {
+    let range = sf.native_type.as_ref().and_then(|nt| {
+        INTEGER_RANGE_TYPES
+            .iter()
+            .find(|(name, _, _)| name == &nt.name.as_str())
+            .map(|(_, min, max)| (*min, *max))
+    });
+
+    match range {
+        Some((min, max)) if value < min || value > max => {
+            let nt = sf.native_type.as_ref().unwrap();
+
+            Err(QueryGraphBuilderError::NativeTypeViolation(
+                NativeTypeViolation::ValueOutOfRange {
+                    details: format!(
+                        "The value {} for the field `{}.{}` does not fit into the range of its type `{}`.",
+                        value,
+                        model_name,
+                        sf.name,
+                        nt.render(),
+                    ),
+                },
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_precision(model_name: &str, sf: &ScalarFieldRef, decimal: &BigDecimal) -> QueryGraphBuilderResult<()> {
+    let params = sf.native_type.as_ref().and_then(|nt| {
+        if DECIMAL_TYPES.contains(&nt.name.as_str()) {
+            match nt.args.as_slice() {
+                [precision, scale] => Some((precision.parse::<i64>().ok()?, scale.parse::<i64>().ok()?)),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    });
+
+    if let Some((precision, scale)) = params {
+        // The digits in front of the decimal point must fit into `precision - scale`.
+        // The scale itself is not validated - databases round excess fractional digits
+        // rather than rejecting the value.
+        let (int, exponent) = decimal.as_bigint_and_exponent();
+        let digits = int.to_string();
+        let digits = digits.trim_start_matches('-');
+
+        let integer_digits = if digits == "0" {
+            0
+        } else {
+            digits.len() as i64 - exponent
+        };
+
+        if integer_digits > precision - scale {
+            let nt = sf.native_type.as_ref().unwrap();
+
+            return Err(QueryGraphBuilderError::NativeTypeViolation(
+                NativeTypeViolation::ValueOutOfRange {
+                    details: format!(
+                        "The value for the field `{}.{}` has {} digits in front of the decimal point, but its type `{}` fits at most {}.",
+                        model_name,
+                        sf.name,
+                        integer_digits,
+                        nt.render(),
+                        precision - scale
+                    ),
+                },
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_enum_membership(model_name: &str, sf: &ScalarFieldRef, variant: &str) -> QueryGraphBuilderResult<()> {
+    if let Some(ref internal_enum) = sf.internal_enum {
+        if !internal_enum.values.iter().any(|value| value.name == variant) {
+            return Err(QueryGraphBuilderError::NativeTypeViolation(
+                NativeTypeViolation::InvalidEnumValue {
+                    field_value: variant.to_owned(),
+                    model_name: model_name.to_owned(),
+                    field_name: sf.name.clone(),
+                },
+            ));
+        }
+    }
+
+    Ok(())
+}
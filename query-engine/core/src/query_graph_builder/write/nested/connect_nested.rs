@@ -356,6 +356,21 @@ fn handle_one_to_one(
     let relation_inlined_parent = parent_relation_field.relation_is_inlined_in_parent();
     let relation_inlined_child = !relation_inlined_parent;
 
+    // If the linking fields overlap the parent's primary identifier (e.g. the relation scalar is
+    // part of a compound `@@id`), the connect changes the identifier of the parent record. Running
+    // it as a separate update after the parent operation would leave downstream nodes - most
+    // importantly the final re-read - filtering by the stale, pre-connect identifier values.
+    // In that case we inject the child link into the parent update itself, so the connect is a
+    // single UPDATE whose returned identifier already carries the new values.
+    // This is only safe if no existing-child check runs after the parent (see doc comment), which
+    // is the case iff the child side is not required.
+    let parent_model_identifier = parent_relation_field.model().primary_identifier();
+    let links_overlap_identifier = parent_linking_fields
+        .prisma_names()
+        .any(|name| parent_model_identifier.contains(&name));
+    let inject_into_parent_update =
+        relation_inlined_parent && !parent_is_create && !child_side_required && links_overlap_identifier;
+
     // Build-time check
     if parent_side_required && child_side_required {
         // Both sides are required, which means that we know that there has to be already a parent connected to the child (as it exists).
@@ -387,12 +402,13 @@ fn handle_one_to_one(
         QueryGraphDependency::ProjectedDataDependency(
             child_linking_fields.clone(),
             Box::new(move |mut read_new_child_node, mut child_links| {
-                // This takes care of cases where the relation is inlined, CREATE ONLY. See doc comment for explanation.
-                if relation_inlined_parent && parent_is_create {
+                // This takes care of cases where the relation is inlined and the parent is either a create,
+                // or an update whose identifier the connect changes. See doc comment for explanation.
+                if (relation_inlined_parent && parent_is_create) || inject_into_parent_update {
                     let child_link = match child_links.pop() {
                         Some(link) => Ok(link),
                         None => Err(QueryGraphBuilderError::RecordNotFound(format!(
-                            "No '{}' record (needed to inline connect on create for '{}' record) was found for a nested connect on one-to-one relation '{}'.",
+                            "No '{}' record (needed to inline connect for '{}' record) was found for a nested connect on one-to-one relation '{}'.",
                             child_model_name, parent_model_name, relation_name
                         ))),
                     }?;
@@ -475,7 +491,7 @@ fn handle_one_to_one(
                  Ok(update_children_node)
              })),
          )?;
-    } else if relation_inlined_parent && !parent_is_create {
+    } else if relation_inlined_parent && !parent_is_create && !inject_into_parent_update {
         // Relation is inlined on the parent and a non-create.
         // Create an update node for parent record to set the connection to the child.
         let parent_model = parent_relation_field.model();
@@ -131,8 +131,14 @@ pub fn nested_update_many(
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter)?;
 
-        let update_many_node =
-            update::update_many_record_node(graph, connector_ctx, Filter::empty(), Arc::clone(child_model), data_map)?;
+        let update_many_node = update::update_many_record_node(
+            graph,
+            connector_ctx,
+            Filter::empty(),
+            Arc::clone(child_model),
+            data_map,
+            None,
+        )?;
 
         graph.create_edge(
             &find_child_records_node,
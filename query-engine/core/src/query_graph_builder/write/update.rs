@@ -3,7 +3,7 @@ use crate::{constants::args, query_graph_builder::write::write_args_parser::*, C
 use crate::{
     query_ast::*,
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, ParsedField, ParsedInputMap,
+    ArgumentListLookup, FilteredQuery, ParsedField, ParsedInputMap,
 };
 use connector::{Filter, IntoFilter};
 use prisma_models::ModelRef;
@@ -25,9 +25,20 @@ pub fn update_record(
     let data_argument = field.arguments.lookup(args::DATA).unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
 
+    // "returnOldValues"
+    let return_old_values: bool = match field.arguments.lookup(args::RETURN_OLD_VALUES) {
+        Some(arg) => arg.value.try_into()?,
+        None => false,
+    };
+
     let update_node = update_record_node(graph, connector_ctx, filter.clone(), Arc::clone(&model), data_map)?;
 
-    let read_query = read::find_unique(field, model.clone())?;
+    let mut read_query = read::find_unique(field, model.clone())?;
+
+    if return_old_values {
+        read_query.add_filter(filter.clone());
+    }
+
     let read_node = graph.create_node(Query::Read(read_query));
 
     if connector_ctx.referential_integrity.is_prisma() {
@@ -56,27 +67,53 @@ pub fn update_record(
     }
 
     graph.add_result_node(&read_node);
-    graph.create_edge(
-        &update_node,
-        &read_node,
-        QueryGraphDependency::ProjectedDataDependency(
-            model.primary_identifier(),
-            Box::new(move |mut read_node, mut parent_ids| {
-                let parent_id = match parent_ids.pop() {
-                    Some(pid) => Ok(pid),
-                    None => Err(QueryGraphBuilderError::RecordNotFound(
-                        "Record to update not found.".to_string(),
-                    )),
-                }?;
-
-                if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = read_node {
-                    rq.add_filter(parent_id.filter());
-                };
-
-                Ok(read_node)
-            }),
-        ),
-    )?;
+
+    if return_old_values {
+        // Read the record _before_ it is updated, so the result reflects the pre-update state.
+        // The graph is transactional (see `update_record_node`), making the read-then-update atomic.
+        graph.create_edge(
+            &read_node,
+            &update_node,
+            QueryGraphDependency::ProjectedDataDependency(
+                model.primary_identifier(),
+                Box::new(move |mut update_node, parent_ids| {
+                    if parent_ids.is_empty() {
+                        return Err(QueryGraphBuilderError::RecordNotFound(
+                            "Record to update not found.".to_string(),
+                        ));
+                    }
+
+                    if let Node::Query(Query::Write(WriteQuery::UpdateRecord(ref mut ur))) = update_node {
+                        ur.record_filter = parent_ids.into();
+                    }
+
+                    Ok(update_node)
+                }),
+            ),
+        )?;
+    } else {
+        graph.create_edge(
+            &update_node,
+            &read_node,
+            QueryGraphDependency::ProjectedDataDependency(
+                model.primary_identifier(),
+                Box::new(move |mut read_node, mut parent_ids| {
+                    let parent_id = match parent_ids.pop() {
+                        Some(pid) => Ok(pid),
+                        None => Err(QueryGraphBuilderError::RecordNotFound(
+                            "Record to update not found.".to_string(),
+                        )),
+                    }?;
+
+                    if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = read_node {
+                        rq.add_filter(parent_id.filter());
+                    };
+
+                    Ok(read_node)
+                }),
+            ),
+        )?;
+    }
 
     Ok(())
 }
@@ -101,15 +138,19 @@ pub fn update_many_records(
     let data_argument = field.arguments.lookup(args::DATA).unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
 
+    // "limit"
+    let limit = parse_limit(field.arguments.lookup(args::LIMIT))?;
+
     if connector_ctx.referential_integrity.uses_foreign_keys() {
-        update_many_record_node(graph, connector_ctx, filter, model, data_map)?;
+        update_many_record_node(graph, connector_ctx, filter, model, data_map, limit)?;
     } else {
         let pre_read_node = graph.create_node(utils::read_ids_infallible(
             model.clone(),
             model.primary_identifier(),
             filter,
         ));
-        let update_many_node = update_many_record_node(graph, connector_ctx, Filter::empty(), model.clone(), data_map)?;
+        let update_many_node =
+            update_many_record_node(graph, connector_ctx, Filter::empty(), model.clone(), data_map, limit)?;
 
         utils::insert_emulated_on_update(graph, connector_ctx, &model, &pre_read_node, &update_many_node)?;
 
@@ -147,9 +188,7 @@ where
     graph.flag_transactional();
 
     let update_args = WriteArgsParser::from(&model, data_map)?;
-    let mut args = update_args.args;
-
-    args.update_datetimes(Arc::clone(&model));
+    let args = update_args.args;
 
     let filter: Filter = filter.into();
     let update_parent = Query::Write(WriteQuery::UpdateRecord(UpdateRecord {
@@ -174,6 +213,7 @@ pub fn update_many_record_node<T>(
     filter: T,
     model: ModelRef,
     data_map: ParsedInputMap,
+    limit: Option<usize>,
 ) -> QueryGraphBuilderResult<NodeRef>
 where
     T: Into<Filter>,
@@ -183,14 +223,13 @@ where
     let filter = filter.into();
     let record_filter = filter.into();
     let update_args = WriteArgsParser::from(&model, data_map)?;
-    let mut args = update_args.args;
-
-    args.update_datetimes(Arc::clone(&model));
+    let args = update_args.args;
 
     let update_many = UpdateManyRecords {
         model,
         record_filter,
         args,
+        limit,
     };
 
     let update_many_node = graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(update_many)));
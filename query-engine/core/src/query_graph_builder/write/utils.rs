@@ -6,7 +6,7 @@ use crate::{
 use connector::{DatasourceFieldName, Filter, RecordFilter, WriteArgs};
 use datamodel::ReferentialAction;
 use prisma_models::{FieldSelection, ModelRef, PrismaValue, RelationFieldRef, SelectionResult};
-use std::sync::Arc;
+use std::{collections::VecDeque, sync::Arc};
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
 /// Simply unpacks `ParsedInputValue::List`.
@@ -151,6 +151,7 @@ where
         model,
         record_filter,
         args,
+        limit: None,
     };
 
     graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(ur)))
@@ -320,26 +321,119 @@ pub fn insert_emulated_on_delete(
     }
 
     // If the connector uses the `ReferentialIntegrity::Prisma` mode, then the emulation will kick in.
-    let internal_model = model_to_delete.internal_data_model();
-    let relation_fields = internal_model.fields_pointing_to_model(model_to_delete, false);
+    //
+    // The full cascade graph is computed up front, level by level: every level holds the models whose rows
+    // go away (or lose their foreign keys) because of the level above. All cascading relations of one level
+    // that live on the same dependent model feed a single batched `DeleteManyRecords` instead of one delete
+    // per relation. Relation cycles are detected while walking and rejected, and the number of levels is
+    // capped by [`MAX_CASCADE_DEPTH`].
+    let mut pending = VecDeque::new();
+
+    pending.push_back(PendingCascadeLevel {
+        model: model_to_delete.clone(),
+        parent_node: *parent_node,
+        child_node: *child_node,
+        traversed_relations: Vec::new(),
+    });
 
-    for rf in relation_fields {
-        match rf.relation().on_delete() {
-            ReferentialAction::NoAction => continue, // Explicitly do nothing.
-            ReferentialAction::Restrict => emulate_restrict(graph, &rf, parent_node, child_node)?,
-            ReferentialAction::SetNull => {
-                emulate_on_delete_set_null(graph, connector_ctx, &rf, parent_node, child_node)?
+    while let Some(level) = pending.pop_front() {
+        let internal_model = level.model.internal_data_model();
+        let relation_fields = internal_model.fields_pointing_to_model(&level.model, false);
+
+        // Cascading relations of this level, grouped by the dependent model they live on.
+        let mut cascades: Vec<(ModelRef, Vec<RelationFieldRef>)> = Vec::new();
+
+        for rf in relation_fields {
+            match rf.relation().on_delete() {
+                ReferentialAction::NoAction => continue, // Explicitly do nothing.
+                ReferentialAction::Restrict => emulate_restrict(graph, &rf, &level.parent_node, &level.child_node)?,
+                ReferentialAction::SetNull => {
+                    if let Some((read_node, set_null_node)) =
+                        emulate_on_delete_set_null(graph, &rf, &level.parent_node, &level.child_node)?
+                    {
+                        pending.push_back(PendingCascadeLevel {
+                            model: rf.model(),
+                            parent_node: read_node,
+                            child_node: set_null_node,
+                            traversed_relations: level.traverse(&rf)?,
+                        });
+                    }
+                }
+                ReferentialAction::Cascade => {
+                    let dependent_model = rf.model();
+
+                    match cascades
+                        .iter_mut()
+                        .find(|(model, _)| model.name == dependent_model.name)
+                    {
+                        Some((_, relation_fields)) => relation_fields.push(rf),
+                        None => cascades.push((dependent_model, vec![rf])),
+                    }
+                }
+                x => panic!("Unsupported referential action emulation: {}", x),
             }
-            ReferentialAction::Cascade => {
-                emulate_on_delete_cascade(graph, &rf, connector_ctx, model_to_delete, parent_node, child_node)?
+        }
+
+        for (dependent_model, relation_fields) in cascades {
+            let (read_nodes, delete_node) =
+                emulate_on_delete_cascade(graph, &relation_fields, &level.parent_node, &level.child_node)?;
+
+            for (rf, read_node) in relation_fields.iter().zip(read_nodes) {
+                pending.push_back(PendingCascadeLevel {
+                    model: dependent_model.clone(),
+                    parent_node: read_node,
+                    child_node: delete_node,
+                    traversed_relations: level.traverse(rf)?,
+                });
             }
-            x => panic!("Unsupported referential action emulation: {}", x),
         }
     }
 
     Ok(())
 }
 
+/// Upper bound on the number of levels the emulated cascade walk inserts for a single delete.
+/// Non-cyclic schemas stay far below this, it merely bounds the plan as a last line of defense.
+const MAX_CASCADE_DEPTH: usize = 25;
+
+/// One level of the emulated cascade walk: the model whose rows are being removed or updated, the
+/// node producing their ids, the node that must wait for the emulation of this level, and the
+/// relations traversed to get here (used for cycle detection and the depth limit).
+struct PendingCascadeLevel {
+    model: ModelRef,
+    parent_node: NodeRef,
+    child_node: NodeRef,
+    traversed_relations: Vec<String>,
+}
+
+impl PendingCascadeLevel {
+    /// Extends the traversed relation path with `rf`, erroring on relation cycles and on plans
+    /// exceeding [`MAX_CASCADE_DEPTH`]. Previously, cyclic cascades recursed until the stack overflowed.
+    fn traverse(&self, rf: &RelationFieldRef) -> QueryGraphBuilderResult<Vec<String>> {
+        let relation_name = rf.relation().name.clone();
+
+        if self.traversed_relations.contains(&relation_name) {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Emulated referential actions do not support relation cycles. The relation path {} leads back onto relation `{}`. Please break the cycle, for example by setting `onDelete: NoAction` on one of the relations.",
+                self.traversed_relations.join(" -> "),
+                relation_name
+            )));
+        }
+
+        if self.traversed_relations.len() >= MAX_CASCADE_DEPTH {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Emulated referential actions exceed the maximum supported cascade depth of {}.",
+                MAX_CASCADE_DEPTH
+            )));
+        }
+
+        let mut traversed_relations = self.traversed_relations.clone();
+        traversed_relations.push(relation_name);
+
+        Ok(traversed_relations)
+    }
+}
+
 /// Inserts restrict emulations into the graph between `parent_node` and `child_node`.
 /// `relation_field` is the relation field pointing to the model to be deleted/updated.
 ///
@@ -401,8 +495,9 @@ pub fn emulate_restrict(
 }
 
 /// Inserts cascade emulations into the graph between `parent_node` and `child_node`.
-/// `relation_field` is the relation field pointing to the model to be deleted.
-/// Recurses into the deletion emulation to ensure that subsequent deletions are handled correctly as well.
+/// `relation_fields` are all cascading relation fields of one dependent model pointing to the model
+/// to be deleted. Their connected records are collected into a single batched delete. Subsequent
+/// levels are planned by [`insert_emulated_on_delete`] from the returned read nodes and delete node.
 ///
 /// ```text
 ///    ┌ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─
@@ -439,49 +534,55 @@ pub fn emulate_restrict(
 /// ```
 pub fn emulate_on_delete_cascade(
     graph: &mut QueryGraph,
-    relation_field: &RelationFieldRef, // This is the field _on the other model_ for cascade.
-    connector_ctx: &ConnectorContext,
-    _model: &ModelRef,
+    relation_fields: &[RelationFieldRef], // These are the fields _on the other model_ for cascade.
     parent_node: &NodeRef,
     child_node: &NodeRef,
-) -> QueryGraphBuilderResult<()> {
-    let dependent_model = relation_field.model();
-    let parent_relation_field = relation_field.related_field();
-    let child_model_identifier = relation_field.related_model().primary_identifier();
-
-    // Records that need to be deleted for the cascade.
-    let dependent_records_node =
-        insert_find_children_by_parent_node(graph, parent_node, &parent_relation_field, Filter::empty())?;
+) -> QueryGraphBuilderResult<(Vec<NodeRef>, NodeRef)> {
+    let dependent_model = relation_fields
+        .first()
+        .expect("emulate_on_delete_cascade requires at least one relation field")
+        .model();
 
     let delete_query = WriteQuery::DeleteManyRecords(DeleteManyRecords {
-        model: dependent_model.clone(),
+        model: dependent_model,
         record_filter: RecordFilter::empty(),
+        limit: None,
     });
 
     let delete_dependents_node = graph.create_node(Query::Write(delete_query));
-
-    insert_emulated_on_delete(
-        graph,
-        connector_ctx,
-        &dependent_model,
-        &dependent_records_node,
-        &delete_dependents_node,
-    )?;
-
-    graph.create_edge(
-        &dependent_records_node,
-        &delete_dependents_node,
-        QueryGraphDependency::ProjectedDataDependency(
-            child_model_identifier.clone(),
-            Box::new(move |mut delete_dependents_node, dependent_ids| {
-                if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) = delete_dependents_node {
-                    dmr.record_filter = dependent_ids.into();
-                }
-
-                Ok(delete_dependents_node)
-            }),
-        ),
-    )?;
+    let mut dependent_records_nodes = Vec::with_capacity(relation_fields.len());
+
+    for relation_field in relation_fields {
+        let parent_relation_field = relation_field.related_field();
+        let child_model_identifier = relation_field.related_model().primary_identifier();
+
+        // Records that need to be deleted for the cascade.
+        let dependent_records_node =
+            insert_find_children_by_parent_node(graph, parent_node, &parent_relation_field, Filter::empty())?;
+
+        graph.create_edge(
+            &dependent_records_node,
+            &delete_dependents_node,
+            QueryGraphDependency::ProjectedDataDependency(
+                child_model_identifier,
+                Box::new(move |mut delete_dependents_node, dependent_ids| {
+                    if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) =
+                        delete_dependents_node
+                    {
+                        // Append rather than overwrite, several relations may feed the same batched delete.
+                        match dmr.record_filter.selectors {
+                            Some(ref mut selectors) => selectors.extend(dependent_ids),
+                            None => dmr.record_filter = dependent_ids.into(),
+                        }
+                    }
+
+                    Ok(delete_dependents_node)
+                }),
+            ),
+        )?;
+
+        dependent_records_nodes.push(dependent_records_node);
+    }
 
     graph.create_edge(
         &delete_dependents_node,
@@ -489,12 +590,13 @@ pub fn emulate_on_delete_cascade(
         QueryGraphDependency::ExecutionOrder,
     )?;
 
-    Ok(())
+    Ok((dependent_records_nodes, delete_dependents_node))
 }
 
 /// Inserts set null emulations into the graph between `parent_node` and `child_node`.
 /// `relation_field` is the relation field pointing to the model to be deleted.
-/// Recurses into the deletion emulation to ensure that subsequent deletions are handled correctly as well.
+/// Returns the inserted read and set null nodes so [`insert_emulated_on_delete`] can plan the
+/// emulation of subsequent levels, or `None` if the relation holds no nullable foreign keys.
 ///
 /// ```text
 ///    ┌ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─
@@ -532,11 +634,10 @@ pub fn emulate_on_delete_cascade(
 /// ```
 pub fn emulate_on_delete_set_null(
     graph: &mut QueryGraph,
-    connector_ctx: &ConnectorContext,
     relation_field: &RelationFieldRef,
     parent_node: &NodeRef,
     child_node: &NodeRef,
-) -> QueryGraphBuilderResult<()> {
+) -> QueryGraphBuilderResult<Option<(NodeRef, NodeRef)>> {
     let dependent_model = relation_field.model();
     let parent_relation_field = relation_field.related_field();
     let child_model_identifier = relation_field.related_model().primary_identifier().clone();
@@ -554,7 +655,7 @@ pub fn emulate_on_delete_set_null(
         .collect();
 
     if child_update_args.is_empty() {
-        return Ok(());
+        return Ok(None);
     }
 
     // Records that need to be updated for the cascade.
@@ -565,6 +666,7 @@ pub fn emulate_on_delete_set_null(
         model: dependent_model.clone(),
         record_filter: RecordFilter::empty(),
         args: child_update_args.into(),
+        limit: None,
     });
 
     let set_null_dependents_node = graph.create_node(Query::Write(set_null_query));
@@ -591,15 +693,7 @@ pub fn emulate_on_delete_set_null(
         QueryGraphDependency::ExecutionOrder,
     )?;
 
-    insert_emulated_on_delete(
-        graph,
-        connector_ctx,
-        &dependent_model,
-        &dependent_records_node,
-        &set_null_dependents_node,
-    )?;
-
-    Ok(())
+    Ok(Some((dependent_records_node, set_null_dependents_node)))
 }
 
 /// Inserts set null emulations into the graph between `parent_node` and `child_node`.
@@ -685,6 +779,7 @@ pub fn emulate_on_update_set_null(
         model: dependent_model.clone(),
         record_filter: RecordFilter::empty(),
         args: child_update_args.into(),
+        limit: None,
     });
 
     let set_null_dependents_node = graph.create_node(Query::Write(set_null_query));
@@ -979,6 +1074,7 @@ pub fn emulate_on_update_cascade(
         model: dependent_model.clone(),
         record_filter: RecordFilter::empty(),
         args: child_update_args.into(),
+        limit: None,
     });
 
     let update_dependents_node = graph.create_node(Query::Write(update_query));
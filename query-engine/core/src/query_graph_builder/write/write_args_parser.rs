@@ -21,18 +21,42 @@ impl WriteArgsParser {
         data_map.into_iter().try_fold(
             WriteArgsParser::default(),
             |mut args, (k, v): (String, ParsedInputValue)| {
-                let field = model.fields().find_from_all(&k).unwrap();
+                let field = match model.fields().find_from_all(&k) {
+                    Ok(field) => field,
+                    Err(_) => {
+                        return Err(QueryGraphBuilderError::FieldNotWritable {
+                            field_name: k,
+                            object_name: model.name.clone(),
+                            reason: "The field does not exist on the model.".to_owned(),
+                        })
+                    }
+                };
+
+                if let Field::Scalar(sf) = field {
+                    if sf.is_computed() {
+                        return Err(QueryGraphBuilderError::FieldNotWritable {
+                            field_name: k,
+                            object_name: model.name.clone(),
+                            reason: "The field is computed by the database and read-only.".to_owned(),
+                        });
+                    }
+                }
 
                 match field {
                     Field::Scalar(sf) if sf.is_list() => match v {
                         ParsedInputValue::List(_) => {
                             let set_value: PrismaValue = v.try_into()?;
 
+                            native_types::validate_value(&model.name, sf, &set_value)?;
                             args.args.insert(sf, set_value);
                         }
                         ParsedInputValue::Map(map) => {
                             let expr = extract_scalar_list_ops(map)?;
 
+                            if let WriteExpression::Value(value) | WriteExpression::Add(value) = &expr {
+                                native_types::validate_value(&model.name, sf, value)?;
+                            }
+
                             args.args.insert(sf, expr)
                         }
                         _ => unreachable!(),
@@ -68,6 +92,10 @@ impl WriteArgsParser {
                             _ => unreachable!(),
                         };
 
+                        if let WriteExpression::Value(ref value) = expr {
+                            native_types::validate_value(&model.name, sf, value)?;
+                        }
+
                         args.args.insert(sf, expr)
                     }
 
@@ -35,14 +35,18 @@ type UncheckedItemsWithParents = IndexMap<Option<SelectionResult>, Vec<Item>>;
 /// // todo more here
 ///
 /// Returns a map of pairs of (parent ID, response)
-#[tracing::instrument(skip(result, field, is_list))]
+#[tracing::instrument(skip(result, field, is_list, path, errors))]
 pub fn serialize_internal(
     result: QueryResult,
     field: &OutputFieldRef,
     is_list: bool,
+    path: &[String],
+    errors: &mut Vec<FieldError>,
 ) -> crate::Result<CheckedItemsWithParents> {
     match result {
-        QueryResult::RecordSelection(rs) => serialize_record_selection(*rs, field, &field.field_type, is_list),
+        QueryResult::RecordSelection(rs) => {
+            serialize_record_selection(*rs, field, &field.field_type, is_list, path, errors)
+        }
         QueryResult::RecordAggregations(ras) => serialize_aggregations(field, ras),
         QueryResult::Count(c) => {
             // Todo needs a real implementation or needs to move to RecordAggregation
@@ -216,19 +220,21 @@ fn coerce_non_numeric(value: PrismaValue, output: &OutputType) -> PrismaValue {
     }
 }
 
-#[tracing::instrument(skip(record_selection, field, typ, is_list))]
+#[tracing::instrument(skip(record_selection, field, typ, is_list, path, errors))]
 fn serialize_record_selection(
     record_selection: RecordSelection,
     field: &OutputFieldRef,
     typ: &OutputTypeRef, // We additionally pass the type to allow recursing into nested type definitions of a field.
     is_list: bool,
+    path: &[String],
+    errors: &mut Vec<FieldError>,
 ) -> crate::Result<CheckedItemsWithParents> {
     let name = record_selection.name.clone();
 
     match typ.borrow() {
-        OutputType::List(inner) => serialize_record_selection(record_selection, field, inner, true),
+        OutputType::List(inner) => serialize_record_selection(record_selection, field, inner, true, path, errors),
         OutputType::Object(obj) => {
-            let result = serialize_objects(record_selection, obj.into_arc())?;
+            let result = serialize_objects(record_selection, obj.into_arc(), path, errors)?;
             let is_optional = field.is_nullable;
 
             // Items will be ref'ed on the top level to allow cheap clones in nested scenarios.
@@ -239,9 +245,11 @@ fn serialize_record_selection(
                         .into_iter()
                         .map(|(parent, items)| {
                             if !opt {
-                                // Check that all items are non-null
+                                // An item coerced to null means one of its required nested fields could
+                                // not be resolved (the error is recorded at its origin). List items are
+                                // non-nullable, so the null propagates to the list itself.
                                 if items.iter().any(|item| matches!(item, Item::Value(PrismaValue::Null))) {
-                                    return Err(CoreError::null_serialization_error(&name));
+                                    return Ok((parent, Item::Ref(ItemRef::new(Item::Value(PrismaValue::Null)))));
                                 }
                             }
 
@@ -264,8 +272,15 @@ fn serialize_record_selection(
                                 Ok((parent, Item::Ref(ItemRef::new(first))))
                             } else if items.is_empty() && opt {
                                 Ok((parent, Item::Ref(ItemRef::new(Item::Value(PrismaValue::Null)))))
-                            } else if items.is_empty() && opt {
-                                Err(CoreError::null_serialization_error(&name))
+                            } else if items.is_empty() {
+                                // Required field without a record: record a field-level error and
+                                // coerce to null instead of failing the whole response.
+                                errors.push(FieldError::new(
+                                    path.to_vec(),
+                                    CoreError::null_serialization_error(&name),
+                                ));
+
+                                Ok((parent, Item::Ref(ItemRef::new(Item::Value(PrismaValue::Null)))))
                             } else {
                                 Ok((parent, Item::Ref(ItemRef::new(items.pop().unwrap()))))
                             }
@@ -282,17 +297,20 @@ fn serialize_record_selection(
 /// Serializes the given result into objects of given type.
 /// Doesn't validate the shape of the result set ("unchecked" result).
 /// Returns a vector of serialized objects (as Item::Map), grouped into a map by parent, if present.
-#[tracing::instrument(skip(result, typ))]
+#[tracing::instrument(skip(result, typ, path, errors))]
 fn serialize_objects(
     mut result: RecordSelection,
     typ: ObjectTypeStrongRef,
+    path: &[String],
+    errors: &mut Vec<FieldError>,
 ) -> crate::Result<UncheckedItemsWithParents> {
     // The way our query execution works, we only need to look at nested + lists if we hit an object.
     // Move nested out of result for separate processing.
     let nested = std::mem::take(&mut result.nested);
 
     // { <nested field name> -> { parent ID -> items } }
-    let mut nested_mapping: HashMap<String, CheckedItemsWithParents> = process_nested_results(nested, &typ)?;
+    let mut nested_mapping: HashMap<String, CheckedItemsWithParents> =
+        process_nested_results(nested, &typ, path, errors)?;
 
     // We need the Arcs to solve the issue where we have multiple parents claiming the same data (we want to move the data out of the nested structure
     // to prevent expensive copying during serialization).
@@ -329,7 +347,7 @@ fn serialize_objects(
         }
 
         // Write nested results
-        write_nested_items(&record_id, &mut nested_mapping, &mut object, &typ)?;
+        let poisoned = write_nested_items(&record_id, &mut nested_mapping, &mut object, &typ, path, errors)?;
 
         let aggr_row = result.aggregation_rows.as_ref().map(|rows| rows.get(r_index).unwrap());
         if let Some(aggr_row) = aggr_row {
@@ -359,7 +377,13 @@ fn serialize_objects(
 
         // TODO: Find out how to easily determine when a result is null.
         // If the object is null or completely empty, coerce into null instead.
-        let result = Item::Map(map);
+        let result = if poisoned {
+            // Null propagation: a required nested field of this record could not be resolved
+            // (the error is recorded at its origin), so the whole record collapses to null.
+            Item::Value(PrismaValue::Null)
+        } else {
+            Item::Map(map)
+        };
         // let result = if result.is_null_or_empty() {
         //     Item::Value(PrismaValue::Null)
         // } else {
@@ -373,28 +397,55 @@ fn serialize_objects(
 }
 
 /// Unwraps are safe due to query validation.
-#[tracing::instrument(skip(record_id, items_with_parent, into, enclosing_type))]
+///
+/// Returns whether the enclosing record is "poisoned", i.e. one of its required nested
+/// fields resolved to null and the record itself has to collapse to null (GraphQL null
+/// propagation). Errors are recorded where the null originates, not where it propagates.
+#[tracing::instrument(skip(record_id, items_with_parent, into, enclosing_type, path, errors))]
 fn write_nested_items(
     record_id: &Option<SelectionResult>,
     items_with_parent: &mut HashMap<String, CheckedItemsWithParents>,
     into: &mut HashMap<String, Item>,
     enclosing_type: &ObjectTypeStrongRef,
-) -> crate::Result<()> {
+    path: &[String],
+    errors: &mut Vec<FieldError>,
+) -> crate::Result<bool> {
+    let mut poisoned = false;
+
     for (field_name, inner) in items_with_parent.iter_mut() {
         let val = inner.get(record_id);
+        let field = enclosing_type.find_field(field_name).unwrap();
 
         // The value must be a reference (or None - handle default), everything else is an error in the serialization logic.
         match val {
             Some(Item::Ref(ref r)) => {
+                // A null value for a required field means a nested error already coerced it,
+                // which propagates to this record.
+                if !field.is_nullable && matches!(r.as_ref(), Item::Value(PrismaValue::Null)) {
+                    poisoned = true;
+                }
+
                 into.insert(field_name.to_owned(), Item::Ref(ItemRef::clone(r)));
             }
 
             None => {
-                let field = enclosing_type.find_field(field_name).unwrap();
                 let default = match field.field_type.borrow() {
                     OutputType::List(_) => Item::list(Vec::new()),
                     _ if field.is_nullable => Item::Value(PrismaValue::Null),
-                    _ => return Err(CoreError::null_serialization_error(field_name)),
+                    _ => {
+                        // Required relation without a matching record: record a field-level
+                        // error and coerce to null instead of failing the whole response.
+                        let mut error_path = path.to_vec();
+                        error_path.push(field_name.clone());
+
+                        errors.push(FieldError::new(
+                            error_path,
+                            CoreError::null_serialization_error(field_name),
+                        ));
+
+                        poisoned = true;
+                        Item::Value(PrismaValue::Null)
+                    }
                 };
 
                 into.insert(field_name.to_owned(), Item::Ref(ItemRef::new(default)));
@@ -403,14 +454,16 @@ fn write_nested_items(
         };
     }
 
-    Ok(())
+    Ok(poisoned)
 }
 
 /// Processes nested results into a more ergonomic structure of { <nested field name> -> { parent ID -> item (list, map, ...) } }.
-#[tracing::instrument(skip(nested, enclosing_type))]
+#[tracing::instrument(skip(nested, enclosing_type, path, errors))]
 fn process_nested_results(
     nested: Vec<QueryResult>,
     enclosing_type: &ObjectTypeStrongRef,
+    path: &[String],
+    errors: &mut Vec<FieldError>,
 ) -> crate::Result<HashMap<String, CheckedItemsWithParents>> {
     // For each nested selected field we need to map the parents to their items.
     let mut nested_mapping = HashMap::with_capacity(nested.len());
@@ -422,7 +475,11 @@ fn process_nested_results(
         if let QueryResult::RecordSelection(ref rs) = nested_result {
             let name = rs.name.clone();
             let field = enclosing_type.find_field(&name).unwrap();
-            let result = serialize_internal(nested_result, &field, false)?;
+
+            let mut nested_path = path.to_vec();
+            nested_path.push(name.clone());
+
+            let result = serialize_internal(nested_result, &field, false, &nested_path, errors)?;
 
             nested_mapping.insert(name, result);
         }
@@ -23,7 +23,9 @@ impl IrSerializer {
             }
 
             ExpressionResult::Query(r) => {
-                let serialized = serialize_internal(r, &self.output_field, false)?;
+                let path = vec![self.key.clone()];
+                let mut errors = Vec::new();
+                let serialized = serialize_internal(r, &self.output_field, false, &path, &mut errors)?;
 
                 // On the top level, each result boils down to a exactly a single serialized result.
                 // All checks for lists and optionals have already been performed during the recursion,
@@ -48,7 +50,7 @@ impl IrSerializer {
                     item
                 };
 
-                Ok(ResponseData::new(self.key.clone(), result))
+                Ok(ResponseData::with_errors(self.key.clone(), result, errors))
             }
 
             ExpressionResult::Empty => panic!("Internal error: Attempted to serialize empty result."),
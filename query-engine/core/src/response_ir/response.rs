@@ -1,4 +1,5 @@
 use super::*;
+use crate::CoreError;
 
 #[derive(Debug)]
 pub struct ResponseData {
@@ -7,10 +8,42 @@ pub struct ResponseData {
 
     /// The actual response data.
     pub data: Item,
+
+    /// Field-level errors collected during serialization (see [`FieldError`]).
+    /// The data at the affected paths has been coerced to null.
+    pub errors: Vec<FieldError>,
 }
 
 impl ResponseData {
     pub fn new(key: String, data: Item) -> Self {
-        Self { key, data }
+        Self {
+            key,
+            data,
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn with_errors(key: String, data: Item, errors: Vec<FieldError>) -> Self {
+        Self { key, data, errors }
+    }
+}
+
+/// An error attached to a specific response field, per the GraphQL spec. Instead of
+/// discarding the whole response, the failed field is coerced to null, nulls are
+/// propagated up to the nearest nullable enclosing field, and the error is recorded
+/// here together with its path.
+///
+/// The path contains the field names from the response root down to the field that
+/// failed. List indices are omitted: serialized items can be shared between multiple
+/// parent records, so a single item has no unambiguous index at serialization time.
+#[derive(Debug)]
+pub struct FieldError {
+    pub path: Vec<String>,
+    pub error: CoreError,
+}
+
+impl FieldError {
+    pub fn new(path: Vec<String>, error: CoreError) -> Self {
+        Self { path, error }
     }
 }
@@ -1,14 +1,24 @@
 use super::*;
+use crate::schema_builder::DeferredInputFields;
 use fmt::Debug;
 use once_cell::sync::OnceCell;
 use prisma_models::dml;
-use std::{boxed::Box, fmt, sync::Arc};
+use std::{boxed::Box, fmt, sync::Arc, sync::Mutex};
 
-#[derive(PartialEq)]
 pub struct InputObjectType {
     pub identifier: Identifier,
     pub constraints: InputObjectTypeConstraints,
     pub fields: OnceCell<Vec<InputFieldRef>>,
+
+    /// Deferred field initializer, for input objects whose fields are only
+    /// computed on first access instead of eagerly during schema building.
+    deferred_fields: Mutex<Option<DeferredInputFields>>,
+}
+
+impl PartialEq for InputObjectType {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier && self.constraints == other.constraints
+    }
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -31,7 +41,20 @@ impl Debug for InputObjectType {
 }
 
 impl InputObjectType {
+    pub fn new(identifier: Identifier) -> Self {
+        InputObjectType {
+            identifier,
+            constraints: InputObjectTypeConstraints::default(),
+            fields: OnceCell::new(),
+            deferred_fields: Mutex::new(None),
+        }
+    }
+
     pub fn get_fields(&self) -> &Vec<InputFieldRef> {
+        if self.fields.get().is_none() {
+            self.resolve_deferred_fields();
+        }
+
         self.fields.get().unwrap()
     }
 
@@ -41,6 +64,32 @@ impl InputObjectType {
             .expect("InputObjectType::set_fields");
     }
 
+    /// Attaches a deferred initializer, making this a lazy input object type.
+    /// The fields are computed on the first `get_fields` call.
+    pub(crate) fn set_deferred_fields(&self, deferred: DeferredInputFields) {
+        let previous = self.deferred_fields.lock().unwrap().replace(deferred);
+
+        assert!(
+            previous.is_none() && self.fields.get().is_none(),
+            "Invariant violation: Deferred input object fields set twice."
+        );
+    }
+
+    /// Runs the deferred field initializer, if present.
+    /// The lock is held across the field computation so that concurrent accessors
+    /// observe the fields as set once the initializer has been taken.
+    fn resolve_deferred_fields(&self) {
+        let mut deferred_fields = self.deferred_fields.lock().unwrap();
+
+        if self.fields.get().is_some() {
+            return;
+        }
+
+        if let Some(deferred) = deferred_fields.take() {
+            self.set_fields(deferred.resolve());
+        }
+    }
+
     /// True if fields are empty, false otherwise.
     pub fn is_empty(&self) -> bool {
         self.get_fields().is_empty()
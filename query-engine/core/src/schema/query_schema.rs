@@ -1,4 +1,5 @@
 use super::*;
+use crate::schema_builder::LazyBuilder;
 use datamodel::common::preview_features::PreviewFeature;
 use datamodel_connector::{ConnectorCapability, ReferentialIntegrity};
 use fmt::Debug;
@@ -15,7 +16,8 @@ use std::{borrow::Borrow, fmt};
 /// of weak references to prevent memory leaks. To simplify the overall management of Arcs and weaks, the
 /// query schema is subject to a number of invariants.
 /// The most important one is that the only strong references (Arc) to a single object types
-/// is only ever held by the top-level QuerySchema struct, never by the trees, which only ever hold weak refs.
+/// is only ever held by the top-level QuerySchema struct (directly or through the retained
+/// builder context), never by the trees, which only ever hold weak refs.
 ///
 /// Using a QuerySchema should never involve dealing with the strong references.
 #[derive(Debug)]
@@ -32,11 +34,13 @@ pub struct QuerySchema {
     /// Information about the connector this schema was build for.
     pub context: ConnectorContext,
 
-    /// Internal. Stores all strong Arc refs to the input object types.
-    _input_object_types: Vec<InputObjectTypeStrongRef>,
-
-    /// Internal. Stores all strong Arc refs to the output object types.
+    /// Internal. Stores the strong Arc refs to the root object types. All other object
+    /// type strong refs live in the type cache of the retained builder context.
     _output_object_types: Vec<ObjectTypeStrongRef>,
+
+    /// Internal. The retained builder context, keeping all cached object types alive and
+    /// resolving input object types whose field computation was deferred.
+    _lazy_builder: LazyBuilder,
 }
 
 /// Connector meta information, to be used in query execution if necessary.
@@ -68,21 +72,21 @@ impl ConnectorContext {
 
 impl QuerySchema {
     #[allow(clippy::too_many_arguments)]
-    pub fn new(
+    pub(crate) fn new(
         query: OutputTypeRef,
         mutation: OutputTypeRef,
-        _input_object_types: Vec<InputObjectTypeStrongRef>,
         _output_object_types: Vec<ObjectTypeStrongRef>,
         internal_data_model: InternalDataModelRef,
         capabilities: Vec<ConnectorCapability>,
         features: Vec<PreviewFeature>,
         referential_integrity: ReferentialIntegrity,
+        _lazy_builder: LazyBuilder,
     ) -> Self {
         QuerySchema {
             query,
             mutation,
-            _input_object_types,
             _output_object_types,
+            _lazy_builder,
             internal_data_model,
             context: ConnectorContext::new(capabilities, features, referential_integrity),
         }
@@ -48,16 +48,6 @@ impl<T: Debug> TypeRefCache<T> {
     }
 }
 
-/// Consumes the cache and returns all contents as vector of the cached values.
-impl<T> Into<Vec<Arc<T>>> for TypeRefCache<T> {
-    fn into(self) -> Vec<Arc<T>> {
-        let mut vec: Vec<Arc<T>> = vec![];
-
-        vec.extend(self.cache.into_iter().map(|(_, t)| t));
-        vec
-    }
-}
-
 /// Builds a cache over T from a vector of tuples of shape (String, Arc<T>).
 impl<T> From<Vec<(Identifier, Arc<T>)>> for TypeRefCache<T> {
     fn from(tuples: Vec<(Identifier, Arc<T>)>) -> TypeRefCache<T> {
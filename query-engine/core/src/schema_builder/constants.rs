@@ -26,6 +26,12 @@ pub mod args {
 
     // createMany-specific args
     pub const SKIP_DUPLICATES: &str = "skipDuplicates";
+
+    // updateMany/deleteMany-specific args
+    pub const LIMIT: &str = "limit";
+
+    // updateOne-specific args
+    pub const RETURN_OLD_VALUES: &str = "returnOldValues";
 }
 
 pub mod operations {
@@ -88,6 +94,9 @@ pub mod filters {
     pub const HAS_EVERY: &str = "hasEvery";
     pub const IS_EMPTY: &str = "isEmpty";
 
+    // field reference filters (`fieldReference` preview feature)
+    pub const UNDERSCORE_REF: &str = "_ref";
+
     // m2m filters
     pub const EVERY: &str = "every";
     pub const SOME: &str = "some";
@@ -127,6 +136,13 @@ pub mod ordering {
     pub const ASC: &str = "asc";
     pub const DESC: &str = "desc";
 
+    // Explicit NULL placement (`{ sort: ..., nulls: ... }` objects)
+    pub const SORT_ORDER_INPUT: &str = "SortOrderInput";
+    pub const NULLS_ORDER: &str = "NullsOrder";
+    pub const NULLS: &str = "nulls";
+    pub const FIRST: &str = "first";
+    pub const LAST: &str = "last";
+
     // Full-text-search specifics
     pub const UNDERSCORE_RELEVANCE: &str = "_relevance";
     pub const SEARCH: &str = "search";
@@ -39,12 +39,17 @@ pub(crate) fn delete_one_arguments(ctx: &mut BuilderContext, model: &ModelRef) -
     where_unique_argument(ctx, model).map(|arg| vec![arg])
 }
 
-/// Builds "where" (unique) and "data" arguments intended for the update field.
+/// Builds "where" (unique), "data" and "returnOldValues" arguments intended for the update field.
 pub(crate) fn update_one_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Vec<InputField>> {
     where_unique_argument(ctx, model).map(|unique_arg| {
         let update_types = update_one_objects::update_one_input_types(ctx, model, None);
+        let return_old_values_arg = input_field(args::RETURN_OLD_VALUES, InputType::boolean(), None).optional();
 
-        vec![input_field(args::DATA, update_types, None), unique_arg]
+        vec![
+            input_field(args::DATA, update_types, None),
+            unique_arg,
+            return_old_values_arg,
+        ]
     })
 }
 
@@ -85,14 +90,17 @@ pub(crate) fn update_many_arguments(ctx: &mut BuilderContext, model: &ModelRef)
     let update_many_types = update_many_objects::update_many_input_types(ctx, model, None);
     let where_arg = where_argument(ctx, model);
 
-    vec![input_field(args::DATA, update_many_types, None), where_arg]
+    let limit_arg = input_field(args::LIMIT, InputType::int(), None).optional();
+
+    vec![input_field(args::DATA, update_many_types, None), where_arg, limit_arg]
 }
 
-/// Builds "where" argument intended for the delete many field.
+/// Builds "where" and "limit" arguments intended for the delete many field.
 pub(crate) fn delete_many_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
     let where_arg = where_argument(ctx, model);
+    let limit_arg = input_field(args::LIMIT, InputType::int(), None).optional();
 
-    vec![where_arg]
+    vec![where_arg, limit_arg]
 }
 
 /// Builds "many records where" arguments based on the given model and field.
@@ -20,6 +20,17 @@ pub(crate) fn get_field_filter_types(
         }
         ModelField::Composite(_) => vec![], // [Composites] todo
         ModelField::Scalar(sf) if field.is_list() => vec![InputType::object(scalar_list_filter_type(ctx, sf))],
+        ModelField::Scalar(sf) if sf.is_encrypted => {
+            let mut types = vec![InputType::object(encrypted_scalar_filter_type(ctx, sf))];
+
+            types.push(map_scalar_input_type_for_field(ctx, sf)); // Scalar equality shorthand
+
+            if !sf.is_required() {
+                types.push(InputType::null()); // Scalar null-equality shorthand
+            }
+
+            types
+        }
         ModelField::Scalar(sf) => {
             let mut types = vec![InputType::object(full_scalar_filter_type(
                 ctx,
@@ -77,10 +88,18 @@ fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Inpu
     ctx.cache_input_type(ident, object.clone());
 
     let fields = if rf.is_list() {
+        let count_filter_type = full_scalar_filter_type(ctx, &TypeIdentifier::Int, false, false, true, false);
+
         vec![
             input_field(filters::EVERY, InputType::object(related_input_type.clone()), None).optional(),
             input_field(filters::SOME, InputType::object(related_input_type.clone()), None).optional(),
             input_field(filters::NONE, InputType::object(related_input_type), None).optional(),
+            input_field(
+                aggregations::UNDERSCORE_COUNT,
+                InputType::object(count_filter_type),
+                None,
+            )
+            .optional(),
         ]
     } else {
         vec![
@@ -113,7 +132,7 @@ fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Inp
 
     let mapped_nonlist_type = map_scalar_input_type(ctx, &sf.type_identifier, false);
     let mapped_list_type = InputType::list(mapped_nonlist_type.clone());
-    let mut fields: Vec<_> = equality_filters(mapped_list_type.clone(), !sf.is_required()).collect();
+    let mut fields: Vec<_> = equality_filters(ctx, mapped_list_type.clone(), !sf.is_required()).collect();
 
     fields.push(
         input_field(filters::HAS, mapped_nonlist_type, None)
@@ -129,6 +148,40 @@ fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Inp
     Arc::downgrade(&object)
 }
 
+/// Filter type for `@encrypted` fields. Ciphertext only supports deterministic equality,
+/// so all filters except equality and inclusion are omitted.
+#[tracing::instrument(skip(ctx, sf))]
+fn encrypted_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> InputObjectTypeWeakRef {
+    let nullable = !sf.is_required();
+    let ident = Identifier::new(
+        format!(
+            "Encrypted{}",
+            scalar_filter_name(&sf.type_identifier, false, nullable, false, false)
+        ),
+        PRISMA_NAMESPACE,
+    );
+
+    return_cached_input!(ctx, &ident);
+
+    let object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, object.clone());
+
+    let mapped_scalar_type = map_scalar_input_type(ctx, &sf.type_identifier, false);
+    let mut fields: Vec<_> = equality_filters(ctx, mapped_scalar_type.clone(), nullable)
+        .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
+        .collect();
+
+    // Shorthand `not equals` only, no nested filter object.
+    fields.push(
+        input_field(filters::NOT_LOWERCASE, vec![mapped_scalar_type], None)
+            .optional()
+            .nullable_if(nullable),
+    );
+
+    object.set_fields(fields);
+    Arc::downgrade(&object)
+}
+
 #[tracing::instrument(skip(ctx, typ, list, nullable, nested, include_aggregates))]
 fn full_scalar_filter_type(
     ctx: &mut BuilderContext,
@@ -151,9 +204,9 @@ fn full_scalar_filter_type(
     let mapped_scalar_type = map_scalar_input_type(ctx, typ, list);
 
     let mut fields: Vec<_> = match typ {
-        TypeIdentifier::String | TypeIdentifier::UUID => equality_filters(mapped_scalar_type.clone(), nullable)
+        TypeIdentifier::String | TypeIdentifier::UUID => equality_filters(ctx, mapped_scalar_type.clone(), nullable)
             .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
-            .chain(alphanumeric_filters(mapped_scalar_type.clone()))
+            .chain(alphanumeric_filters(ctx, mapped_scalar_type.clone()))
             .chain(string_filters(ctx, mapped_scalar_type.clone()))
             .chain(query_mode_field(ctx, nested))
             .collect(),
@@ -162,16 +215,16 @@ fn full_scalar_filter_type(
         | TypeIdentifier::BigInt
         | TypeIdentifier::Float
         | TypeIdentifier::DateTime
-        | TypeIdentifier::Decimal => equality_filters(mapped_scalar_type.clone(), nullable)
+        | TypeIdentifier::Decimal => equality_filters(ctx, mapped_scalar_type.clone(), nullable)
             .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
-            .chain(alphanumeric_filters(mapped_scalar_type.clone()))
+            .chain(alphanumeric_filters(ctx, mapped_scalar_type.clone()))
             .collect(),
 
         TypeIdentifier::Json => {
             let mut filters: Vec<InputField> =
                 json_equality_filters(ctx, mapped_scalar_type.clone(), nullable).collect();
 
-            if ctx.has_feature(&PreviewFeature::FilterJson)
+            if ctx.has_feature(PreviewFeature::FilterJson)
                 && ctx.capabilities.supports_any(&[
                     ConnectorCapability::JsonFilteringJsonPath,
                     ConnectorCapability::JsonFilteringArrayPath,
@@ -183,7 +236,7 @@ fn full_scalar_filter_type(
                     .capabilities
                     .contains(ConnectorCapability::JsonFilteringAlphanumeric)
                 {
-                    filters.extend(alphanumeric_filters(mapped_scalar_type.clone()))
+                    filters.extend(alphanumeric_filters(ctx, mapped_scalar_type.clone()))
                 }
             }
 
@@ -191,10 +244,10 @@ fn full_scalar_filter_type(
         }
 
         TypeIdentifier::Boolean | TypeIdentifier::Xml => {
-            equality_filters(mapped_scalar_type.clone(), nullable).collect()
+            equality_filters(ctx, mapped_scalar_type.clone(), nullable).collect()
         }
 
-        TypeIdentifier::Bytes | TypeIdentifier::Enum(_) => equality_filters(mapped_scalar_type.clone(), nullable)
+        TypeIdentifier::Bytes | TypeIdentifier::Enum(_) => equality_filters(ctx, mapped_scalar_type.clone(), nullable)
             .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
             .collect(),
 
@@ -261,9 +314,15 @@ fn full_scalar_filter_type(
     Arc::downgrade(&object)
 }
 
-fn equality_filters(mapped_type: InputType, nullable: bool) -> impl Iterator<Item = InputField> {
+fn equality_filters(
+    ctx: &mut BuilderContext,
+    mapped_type: InputType,
+    nullable: bool,
+) -> impl Iterator<Item = InputField> {
+    let types = with_field_ref_input(ctx, mapped_type);
+
     std::iter::once(
-        input_field(filters::EQUALS, mapped_type, None)
+        input_field(filters::EQUALS, types, None)
             .optional()
             .nullable_if(nullable),
     )
@@ -309,16 +368,42 @@ fn inclusion_filters(mapped_type: InputType, nullable: bool) -> impl Iterator<It
     .into_iter()
 }
 
-fn alphanumeric_filters(mapped_type: InputType) -> impl Iterator<Item = InputField> {
+fn alphanumeric_filters(ctx: &mut BuilderContext, mapped_type: InputType) -> impl Iterator<Item = InputField> {
+    let types = with_field_ref_input(ctx, mapped_type);
+
     vec![
-        input_field(filters::LOWER_THAN, mapped_type.clone(), None).optional(),
-        input_field(filters::LOWER_THAN_OR_EQUAL, mapped_type.clone(), None).optional(),
-        input_field(filters::GREATER_THAN, mapped_type.clone(), None).optional(),
-        input_field(filters::GREATER_THAN_OR_EQUAL, mapped_type, None).optional(),
+        input_field(filters::LOWER_THAN, types.clone(), None).optional(),
+        input_field(filters::LOWER_THAN_OR_EQUAL, types.clone(), None).optional(),
+        input_field(filters::GREATER_THAN, types.clone(), None).optional(),
+        input_field(filters::GREATER_THAN_OR_EQUAL, types, None).optional(),
     ]
     .into_iter()
 }
 
+/// Adds the `{ _ref: ... }` alternative to a filter input type when the `fieldReference`
+/// preview feature is enabled, allowing comparisons against another scalar field of the
+/// same model instead of an inline value.
+fn with_field_ref_input(ctx: &mut BuilderContext, mapped_type: InputType) -> Vec<InputType> {
+    let mut types = vec![mapped_type];
+
+    if ctx.has_feature(PreviewFeature::FieldReference) {
+        types.push(InputType::object(field_ref_input(ctx)));
+    }
+
+    types
+}
+
+fn field_ref_input(ctx: &mut BuilderContext) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new("FieldRefInput", PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, object.clone());
+
+    object.set_fields(vec![input_field(filters::UNDERSCORE_REF, InputType::string(), None)]);
+    Arc::downgrade(&object)
+}
+
 fn string_filters(ctx: &mut BuilderContext, mapped_type: InputType) -> impl Iterator<Item = InputField> {
     let mut string_filters = vec![
         input_field(filters::CONTAINS, mapped_type.clone(), None).optional(),
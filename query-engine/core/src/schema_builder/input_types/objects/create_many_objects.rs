@@ -43,7 +43,7 @@ pub(crate) fn create_many_object_type(
         .scalar()
         .into_iter()
         .filter(|sf| {
-            if linking_fields.contains(sf) {
+            if linking_fields.contains(sf) || sf.is_computed() {
                 false
             } else if sf.is_autoincrement {
                 ctx.capabilities
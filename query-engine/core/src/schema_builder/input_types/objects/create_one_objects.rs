@@ -8,6 +8,12 @@ pub(crate) fn create_one_input_types(
     parent_field: Option<&RelationFieldRef>,
 ) -> Vec<InputType> {
     let checked_input = InputType::object(checked_create_input_type(ctx, model, parent_field));
+
+    // Unchecked inputs roughly double the input type count and can be omitted entirely.
+    if ctx.omit_unchecked_inputs {
+        return vec![checked_input];
+    }
+
     let unchecked_input = InputType::object(unchecked_create_input_type(ctx, model, parent_field));
 
     // If the inputs are equal, only use one.
@@ -183,7 +189,7 @@ fn unchecked_create_input_type(
         .fields()
         .scalar()
         .into_iter()
-        .filter(|sf| !linking_fields.contains(sf))
+        .filter(|sf| !linking_fields.contains(sf) && !sf.is_computed() && !sf.is_readonly)
         .collect();
 
     let mut fields = input_fields::scalar_input_fields(
@@ -1,5 +1,6 @@
 use super::*;
 use constants::{aggregations, ordering};
+use datamodel_connector::ConnectorCapability;
 use itertools::Itertools;
 use output_types::aggregation;
 
@@ -55,7 +56,16 @@ pub(crate) fn order_by_object_type(
                 Some(input_field(rf.name.clone(), InputType::object(related_object_type), None).optional())
             }
             ModelField::Scalar(sf) => {
-                Some(input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
+                let mut field_types = vec![InputType::Enum(enum_type.clone())];
+
+                // Optional fields can pin the placement of NULL values with a
+                // `{ sort: ..., nulls: ... }` object instead of a plain sort order.
+                if !sf.is_required() && !sf.is_list() && ctx.has_capability(ConnectorCapability::OrderByNullsFirstLast)
+                {
+                    field_types.push(InputType::object(sort_order_input_object_type(ctx, &enum_type)));
+                }
+
+                Some(input_field(sf.name.clone(), field_types, None).optional())
             }
             _ => None,
         })
@@ -131,6 +141,28 @@ pub(crate) fn order_by_object_type(
     Arc::downgrade(&input_object)
 }
 
+/// Builds the `SortOrderInput` object type (`{ sort: SortOrder, nulls: NullsOrder }`).
+fn sort_order_input_object_type(ctx: &mut BuilderContext, ordering_enum: &Arc<EnumType>) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(ordering::SORT_ORDER_INPUT.to_owned(), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let nulls_enum_type = Arc::new(string_enum_type(
+        ordering::NULLS_ORDER,
+        vec![ordering::FIRST.to_owned(), ordering::LAST.to_owned()],
+    ));
+
+    let fields = vec![
+        input_field(ordering::SORT, InputType::Enum(ordering_enum.clone()), None),
+        input_field(ordering::NULLS, InputType::Enum(nulls_enum_type), None).optional(),
+    ];
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
 fn order_by_field_aggregate(
     name: &str,
     suffix: &str,
@@ -173,19 +205,17 @@ fn order_by_object_type_aggregate(
 
     return_cached_input!(ctx, &ident);
 
-    let mut input_object = init_input_object_type(ident.clone());
+    let mut input_object = init_input_object_type(ident);
     input_object.require_exactly_one_field();
 
-    let input_object = Arc::new(input_object);
-    ctx.cache_input_type(ident, input_object.clone());
-
     let fields = scalar_fields
         .iter()
         .map(|sf| input_field(sf.name.clone(), InputType::Enum(ordering_enum.clone()), None).optional())
         .collect();
 
-    input_object.set_fields(fields);
-    Arc::downgrade(&input_object)
+    // Models with the same aggregatable fields produce identical aggregate
+    // ordering objects - share a single instance for those.
+    ctx.intern_input_object_type(input_object, fields)
 }
 
 fn order_by_object_type_rel_aggregate(
@@ -197,12 +227,9 @@ fn order_by_object_type_rel_aggregate(
 
     return_cached_input!(ctx, &ident);
 
-    let mut input_object = init_input_object_type(ident.clone());
+    let mut input_object = init_input_object_type(ident);
     input_object.require_exactly_one_field();
 
-    let input_object = Arc::new(input_object);
-    ctx.cache_input_type(ident, input_object.clone());
-
     let fields = vec![input_field(
         aggregations::UNDERSCORE_COUNT,
         InputType::Enum(ordering_enum.clone()),
@@ -210,9 +237,9 @@ fn order_by_object_type_rel_aggregate(
     )
     .optional()];
 
-    input_object.set_fields(fields);
-
-    Arc::downgrade(&input_object)
+    // This object is structurally identical for every model (`_count: SortOrder`),
+    // so all models share a single instance.
+    ctx.intern_input_object_type(input_object, fields)
 }
 
 fn order_by_field_text_search(
@@ -8,6 +8,11 @@ pub(crate) fn update_many_input_types(
     parent_field: Option<&RelationFieldRef>,
 ) -> Vec<InputType> {
     let checked_input = InputType::object(checked_update_many_input_type(ctx, model));
+
+    if ctx.omit_unchecked_inputs {
+        return vec![checked_input];
+    }
+
     let unchecked_input = InputType::object(unchecked_update_many_input_type(ctx, model, parent_field));
 
     // If the inputs are equal, only use one.
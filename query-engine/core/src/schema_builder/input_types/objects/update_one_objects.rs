@@ -10,6 +10,11 @@ pub(crate) fn update_one_input_types(
     parent_field: Option<&RelationFieldRef>,
 ) -> Vec<InputType> {
     let checked_input = InputType::object(checked_update_one_input_type(ctx, model, parent_field));
+
+    if ctx.omit_unchecked_inputs {
+        return vec![checked_input];
+    }
+
     let unchecked_input = InputType::object(unchecked_update_one_input_type(ctx, model, parent_field));
 
     // If the inputs are equal, only use one.
@@ -122,7 +127,7 @@ pub(super) fn scalar_input_fields_for_unchecked_update(
         .fields()
         .scalar()
         .into_iter()
-        .filter(|sf| !linking_fields.contains(sf))
+        .filter(|sf| !linking_fields.contains(sf) && !sf.is_computed() && !sf.is_readonly)
         .filter(|sf| {
             if let Some(ref id_fields) = &id_fields {
                 // Exclude @@id or @id fields if not updatable
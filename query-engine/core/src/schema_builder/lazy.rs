@@ -0,0 +1,120 @@
+//! Deferred construction of input object types.
+//!
+//! The nested create / update input objects (all the `CreateNestedManyWithout<x>Input`
+//! permutations) dominate both build time and memory of the query schema on large data
+//! models, while most of them are never referenced by incoming queries. Instead of
+//! draining the nested input queues eagerly during schema building, each queued input
+//! object is tagged with a [`DeferredInputFields`] initializer. The fields are computed
+//! on first access (see `InputObjectType::get_fields`), using the builder context that
+//! the query schema keeps alive for exactly this purpose.
+//!
+//! Resolving deferred fields may recursively enqueue new nested inputs, which are
+//! deferred again, so a query only ever pays for the part of the input type graph it
+//! actually traverses.
+use super::*;
+use std::{
+    fmt,
+    sync::{Mutex, Weak},
+};
+
+/// Handle to the builder context retained by the query schema to resolve deferred
+/// input object types after building. The context is only populated once the initial
+/// (eager) build has finished.
+pub struct LazyBuilder(pub(crate) Arc<Mutex<Option<BuilderContext>>>);
+
+impl LazyBuilder {
+    pub(crate) fn new() -> Self {
+        Self(Arc::new(Mutex::new(None)))
+    }
+
+    /// A weak handle for deferred initializers. Weak, because the initializers are
+    /// (indirectly) owned by the context via the type cache, and a strong reference
+    /// would leak the entire schema.
+    pub(crate) fn handle(&self) -> Weak<Mutex<Option<BuilderContext>>> {
+        Arc::downgrade(&self.0)
+    }
+
+    /// Stores the builder context after the eager build phase, enabling deferred resolution.
+    pub(crate) fn install(&self, ctx: BuilderContext) {
+        *self.0.lock().unwrap() = Some(ctx);
+    }
+}
+
+impl fmt::Debug for LazyBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyBuilder").finish()
+    }
+}
+
+/// Recipe to compute the fields of a nested input object on demand.
+pub struct DeferredInputFields {
+    context: Weak<Mutex<Option<BuilderContext>>>,
+    kind: DeferredInputKind,
+    relation_field: RelationFieldRef,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum DeferredInputKind {
+    NestedCreate,
+    NestedUpdate,
+}
+
+impl fmt::Debug for DeferredInputFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeferredInputFields").field("kind", &self.kind).finish()
+    }
+}
+
+impl DeferredInputFields {
+    /// Computes the fields of the deferred input object.
+    /// Panics if the query schema (and with it the retained builder context) is gone,
+    /// which violates the schema invariant that weak refs are always valid.
+    pub(crate) fn resolve(self) -> Vec<InputField> {
+        let context = self
+            .context
+            .upgrade()
+            .expect("Expected deferred input fields to not outlive the query schema.");
+
+        let mut guard = context.lock().unwrap();
+        let ctx = guard
+            .as_mut()
+            .expect("Expected deferred input fields to only be resolved after schema building.");
+
+        let fields = match self.kind {
+            DeferredInputKind::NestedCreate => {
+                output_types::mutation_type::nested_create_input_fields(ctx, &self.relation_field)
+            }
+            DeferredInputKind::NestedUpdate => {
+                output_types::mutation_type::nested_update_input_fields(ctx, &self.relation_field)
+            }
+        };
+
+        // Resolution may have enqueued further nested inputs - defer those as well.
+        defer_queued_inputs(ctx);
+
+        fields
+    }
+}
+
+/// Drains the nested input queues and attaches a deferred initializer to each queued
+/// input object instead of computing its fields.
+pub(crate) fn defer_queued_inputs(ctx: &mut BuilderContext) {
+    let create_inputs = std::mem::take(&mut ctx.nested_create_inputs_queue);
+    let update_inputs = std::mem::take(&mut ctx.nested_update_inputs_queue);
+
+    for (input_object, rf) in create_inputs {
+        input_object.set_deferred_fields(DeferredInputFields {
+            context: ctx.lazy_handle.clone(),
+            kind: DeferredInputKind::NestedCreate,
+            relation_field: rf,
+        });
+    }
+
+    for (input_object, rf) in update_inputs {
+        input_object.set_deferred_fields(DeferredInputFields {
+            context: ctx.lazy_handle.clone(),
+            kind: DeferredInputKind::NestedUpdate,
+            relation_field: rf,
+        });
+    }
+}
@@ -27,13 +27,20 @@
 //!
 //! Additionally, the cache also acts as the component to prevent memory leaks from circular dependencies
 //! in the query schema later on, as described on the QuerySchema type.
-//! The cache can be consumed to produce a list of strong references to the individual input and output
-//! object types, which are then moved to the query schema to keep weak references alive (see TypeRefCache for additional infos).
+//! The cache holds the strong references to the individual input and output object types, and is kept
+//! alive by the query schema (as part of the retained builder context) to keep weak references valid
+//! (see TypeRefCache for additional infos).
+//!
+//! Nested create / update input object types are not built eagerly: they are tagged with a deferred
+//! initializer and only get their fields computed on first access (see the `lazy` module). On large
+//! data models the vast majority of these types is never referenced by incoming queries, so deferring
+//! them cuts both schema build time and memory considerably.
 
 #[macro_use]
 mod cache;
 pub mod constants;
 mod input_types;
+pub(crate) mod lazy;
 mod output_types;
 mod utils;
 
@@ -42,8 +49,10 @@ use cache::TypeRefCache;
 use datamodel::common::preview_features::PreviewFeature;
 use datamodel_connector::{ConnectorCapabilities, ConnectorCapability, ReferentialIntegrity};
 use prisma_models::{Field as ModelField, Index, InternalDataModelRef, ModelRef, RelationFieldRef, TypeIdentifier};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
 
+pub use lazy::{DeferredInputFields, LazyBuilder};
 pub use utils::*;
 
 // [DTODO] Remove
@@ -58,15 +67,50 @@ pub enum BuildMode {
     Modern,
 }
 
+/// Typed set of the preview features enabled for a schema build.
+///
+/// All feature-gated type construction has to consult this set (through
+/// [`BuilderContext::has_feature`]) instead of ad-hoc slice lookups, so that
+/// experimental parts of the schema are reliably omitted from the query schema
+/// (and thus the DMMF) when the corresponding flag is off.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EnabledFeatures(Vec<PreviewFeature>);
+
+impl EnabledFeatures {
+    pub fn contains(&self, feature: PreviewFeature) -> bool {
+        self.0.contains(&feature)
+    }
+}
+
+impl From<&[PreviewFeature]> for EnabledFeatures {
+    fn from(features: &[PreviewFeature]) -> Self {
+        let mut features = features.to_vec();
+
+        features.sort_unstable_by_key(|feature| *feature as u32);
+        features.dedup();
+
+        Self(features)
+    }
+}
+
 pub(crate) struct BuilderContext {
     mode: BuildMode,
     internal_data_model: InternalDataModelRef,
     enable_raw_queries: bool,
+    omit_unchecked_inputs: bool,
     cache: TypeCache,
     capabilities: ConnectorCapabilities,
-    preview_features: Vec<PreviewFeature>,
+    features: EnabledFeatures,
     nested_create_inputs_queue: NestedInputsQueue,
     nested_update_inputs_queue: NestedInputsQueue,
+
+    /// Structural signatures of interned input object types, mapped to the identifier
+    /// of the canonical instance (see `intern_input_object_type`).
+    interned_input_types: HashMap<String, Identifier>,
+
+    /// Weak handle to the slot this context is stored in after building,
+    /// captured by deferred input field initializers (see the `lazy` module).
+    lazy_handle: Weak<Mutex<Option<BuilderContext>>>,
 }
 
 impl BuilderContext {
@@ -74,23 +118,28 @@ impl BuilderContext {
         mode: BuildMode,
         internal_data_model: InternalDataModelRef,
         enable_raw_queries: bool,
+        omit_unchecked_inputs: bool,
         capabilities: ConnectorCapabilities,
-        preview_features: Vec<PreviewFeature>,
+        features: EnabledFeatures,
+        lazy_handle: Weak<Mutex<Option<BuilderContext>>>,
     ) -> Self {
         Self {
             mode,
             internal_data_model,
             enable_raw_queries,
+            omit_unchecked_inputs,
             cache: TypeCache::new(),
             capabilities,
-            preview_features,
+            features,
             nested_create_inputs_queue: Vec::new(),
             nested_update_inputs_queue: Vec::new(),
+            interned_input_types: HashMap::new(),
+            lazy_handle,
         }
     }
 
-    pub fn has_feature(&self, feature: &PreviewFeature) -> bool {
-        self.preview_features.contains(feature)
+    pub fn has_feature(&self, feature: PreviewFeature) -> bool {
+        self.features.contains(feature)
     }
 
     pub fn has_capability(&self, capability: ConnectorCapability) -> bool {
@@ -125,8 +174,53 @@ impl BuilderContext {
         self.cache.output_types.insert(ident, typ);
     }
 
+    /// Caches an input object type with structural interning: if a structurally identical
+    /// type has been interned before, the existing instance is shared and the given
+    /// identifier merely aliased to it, instead of duplicating the type per model.
+    ///
+    /// The canonical instance keeps the identifier of the first structurally identical
+    /// type that was interned. Models are processed in data model order, so the resulting
+    /// names are stable for a given schema.
+    ///
+    /// Only usable for input object types whose fields do not recursively reference the
+    /// type itself, as the fields have to be known up front for the structural comparison.
+    pub fn intern_input_object_type(
+        &mut self,
+        input_object: InputObjectType,
+        fields: Vec<InputField>,
+    ) -> InputObjectTypeWeakRef {
+        let signature = structural_signature(&input_object.constraints, &fields);
+
+        if let Some(canonical_ident) = self.interned_input_types.get(&signature).cloned() {
+            let canonical = self
+                .cache
+                .input_types
+                .get(&canonical_ident)
+                .expect("Expected interned input object types to be cached.");
+
+            // Alias this identifier to the canonical instance, so cache lookups
+            // for either name resolve to the same object.
+            if self.cache.input_types.get(&input_object.identifier).is_none() {
+                self.cache
+                    .input_types
+                    .insert(input_object.identifier.clone(), canonical.into_arc());
+            }
+
+            return canonical;
+        }
+
+        let ident = input_object.identifier.clone();
+        let input_object = Arc::new(input_object);
+
+        input_object.set_fields(fields);
+        self.cache_input_type(ident.clone(), input_object.clone());
+        self.interned_input_types.insert(signature, ident);
+
+        Arc::downgrade(&input_object)
+    }
+
     pub fn can_full_text_search(&self) -> bool {
-        self.has_feature(&PreviewFeature::FullTextSearch)
+        self.has_feature(PreviewFeature::FullTextSearch)
             && (self.has_capability(ConnectorCapability::FullTextSearchWithoutIndex)
                 || self.has_capability(ConnectorCapability::FullTextSearchWithIndex))
     }
@@ -145,62 +239,79 @@ impl TypeCache {
             output_types: TypeRefCache::new(),
         }
     }
-
-    /// Consumes the cache and collects all types to merge them into the vectors required to
-    /// finalize the query schema building.
-    /// Unwraps are safe because the cache is required to be the only strong Arc ref holder,
-    /// which makes the Arc counter 1, all other refs contained in the schema are weak refs.
-    pub fn collect_types(self) -> (Vec<InputObjectTypeStrongRef>, Vec<ObjectTypeStrongRef>) {
-        let input_objects = self.input_types.into();
-        let output_objects = self.output_types.into();
-
-        (input_objects, output_objects)
-    }
 }
 
 #[tracing::instrument(
     name = "build_query_schema",
-    skip(internal_data_model, enable_raw_queries, capabilities)
+    skip(internal_data_model, enable_raw_queries, omit_unchecked_inputs, capabilities)
 )]
 pub fn build(
     internal_data_model: InternalDataModelRef,
     mode: BuildMode,
     enable_raw_queries: bool,
+    omit_unchecked_inputs: bool,
     capabilities: ConnectorCapabilities,
     preview_features: Vec<PreviewFeature>,
     referential_integrity: ReferentialIntegrity,
 ) -> QuerySchema {
+    let lazy_builder = LazyBuilder::new();
     let mut ctx = BuilderContext::new(
         mode,
         internal_data_model,
         enable_raw_queries,
+        omit_unchecked_inputs,
         capabilities,
-        preview_features.clone(),
+        EnabledFeatures::from(preview_features.as_slice()),
+        lazy_builder.handle(),
     );
 
     output_types::output_objects::initialize_model_object_type_cache(&mut ctx);
 
     let (query_type, query_object_ref) = output_types::query_type::build(&mut ctx);
     let (mutation_type, mutation_object_ref) = output_types::mutation_type::build(&mut ctx);
-    let (input_objects, mut output_objects) = ctx.cache.collect_types();
 
-    // The mutation and query object types need to be part of the strong refs.
-    output_objects.push(query_object_ref);
-    output_objects.push(mutation_object_ref);
+    // Nested create / update inputs are not built eagerly - they only get their
+    // fields computed on first access (see the `lazy` module).
+    lazy::defer_queued_inputs(&mut ctx);
+
+    // The mutation and query object types need strong refs, all other object types
+    // are kept alive by the type cache of the retained builder context.
+    let output_objects = vec![query_object_ref, mutation_object_ref];
 
     let query_type = Arc::new(query_type);
     let mutation_type = Arc::new(mutation_type);
 
+    let internal_data_model = ctx.internal_data_model.clone();
+    let capabilities = ctx.capabilities.capabilities.clone();
+
+    // The builder context is retained by the schema to resolve deferred input types.
+    lazy_builder.install(ctx);
+
     QuerySchema::new(
         query_type,
         mutation_type,
-        input_objects,
         output_objects,
-        ctx.internal_data_model,
-        ctx.capabilities.capabilities,
+        internal_data_model,
+        capabilities,
         preview_features,
         referential_integrity,
+        lazy_builder,
     )
 }
 
 type NestedInputsQueue = Vec<(Arc<InputObjectType>, RelationFieldRef)>;
+
+/// Structural signature of an input object type, used for interning.
+/// Two types with the same signature are considered structurally identical
+/// (object type references compare by identifier through their Debug rendering).
+fn structural_signature(constraints: &InputObjectTypeConstraints, fields: &[InputField]) -> String {
+    use std::fmt::Write;
+
+    let mut signature = format!("{:?}", constraints);
+
+    for field in fields {
+        write!(signature, "|{:?}", field).unwrap();
+    }
+
+    signature
+}
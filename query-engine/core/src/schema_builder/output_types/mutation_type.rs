@@ -33,8 +33,6 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
         .flatten()
         .collect();
 
-    create_nested_inputs(ctx);
-
     if ctx.enable_raw_queries && ctx.capabilities.contains(ConnectorCapability::QueryRaw) {
         fields.push(create_execute_raw_field());
         fields.push(create_query_raw_field());
@@ -46,56 +44,46 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
     (OutputType::Object(Arc::downgrade(&strong_ref)), strong_ref)
 }
 
-// implementation note: these need to be in the same function, because these vecs interact: the create inputs will enqueue update inputs, and vice versa.
-#[tracing::instrument(skip(ctx))]
-fn create_nested_inputs(ctx: &mut BuilderContext) {
-    let mut nested_create_inputs_queue = std::mem::take(&mut ctx.nested_create_inputs_queue);
-    let mut nested_update_inputs_queue = std::mem::take(&mut ctx.nested_update_inputs_queue);
-
-    while !(nested_create_inputs_queue.is_empty() && nested_update_inputs_queue.is_empty()) {
-        // Create inputs.
-        for (input_object, rf) in nested_create_inputs_queue.drain(..) {
-            let mut fields = vec![];
+/// Computes the fields of a nested create input object (`<x>CreateNested<One|Many>Without<y>Input`).
+/// Only invoked on first access of the input object, through its deferred initializer.
+pub(crate) fn nested_create_input_fields(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Vec<InputField> {
+    let mut fields = vec![];
 
-            if rf.related_model().supports_create_operation {
-                fields.push(input_fields::nested_create_one_input_field(ctx, &rf));
-
-                append_opt(&mut fields, input_fields::nested_connect_or_create_field(ctx, &rf));
-                append_opt(&mut fields, input_fields::nested_create_many_input_field(ctx, &rf));
-            }
+    if rf.related_model().supports_create_operation {
+        fields.push(input_fields::nested_create_one_input_field(ctx, rf));
 
-            fields.push(input_fields::nested_connect_input_field(ctx, &rf));
-            input_object.set_fields(fields);
-        }
+        append_opt(&mut fields, input_fields::nested_connect_or_create_field(ctx, rf));
+        append_opt(&mut fields, input_fields::nested_create_many_input_field(ctx, rf));
+    }
 
-        // Update inputs.
-        for (input_object, rf) in nested_update_inputs_queue.drain(..) {
-            let mut fields = vec![];
+    fields.push(input_fields::nested_connect_input_field(ctx, rf));
+    fields
+}
 
-            if rf.related_model().supports_create_operation {
-                fields.push(input_fields::nested_create_one_input_field(ctx, &rf));
+/// Computes the fields of a nested update input object (`<x>UpdateNested<One|Many>Without<y>Input`).
+/// Only invoked on first access of the input object, through its deferred initializer.
+pub(crate) fn nested_update_input_fields(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Vec<InputField> {
+    let mut fields = vec![];
 
-                append_opt(&mut fields, input_fields::nested_connect_or_create_field(ctx, &rf));
-                append_opt(&mut fields, input_fields::nested_upsert_field(ctx, &rf));
-                append_opt(&mut fields, input_fields::nested_create_many_input_field(ctx, &rf));
-            }
+    if rf.related_model().supports_create_operation {
+        fields.push(input_fields::nested_create_one_input_field(ctx, rf));
 
-            append_opt(&mut fields, input_fields::nested_set_input_field(ctx, &rf));
-            append_opt(&mut fields, input_fields::nested_disconnect_input_field(ctx, &rf));
-            append_opt(&mut fields, input_fields::nested_delete_input_field(ctx, &rf));
+        append_opt(&mut fields, input_fields::nested_connect_or_create_field(ctx, rf));
+        append_opt(&mut fields, input_fields::nested_upsert_field(ctx, rf));
+        append_opt(&mut fields, input_fields::nested_create_many_input_field(ctx, rf));
+    }
 
-            fields.push(input_fields::nested_connect_input_field(ctx, &rf));
-            fields.push(input_fields::nested_update_input_field(ctx, &rf));
+    append_opt(&mut fields, input_fields::nested_set_input_field(ctx, rf));
+    append_opt(&mut fields, input_fields::nested_disconnect_input_field(ctx, rf));
+    append_opt(&mut fields, input_fields::nested_delete_input_field(ctx, rf));
 
-            append_opt(&mut fields, input_fields::nested_update_many_field(ctx, &rf));
-            append_opt(&mut fields, input_fields::nested_delete_many_field(ctx, &rf));
+    fields.push(input_fields::nested_connect_input_field(ctx, rf));
+    fields.push(input_fields::nested_update_input_field(ctx, rf));
 
-            input_object.set_fields(fields);
-        }
+    append_opt(&mut fields, input_fields::nested_update_many_field(ctx, rf));
+    append_opt(&mut fields, input_fields::nested_delete_many_field(ctx, rf));
 
-        std::mem::swap(&mut nested_create_inputs_queue, &mut ctx.nested_create_inputs_queue);
-        std::mem::swap(&mut nested_update_inputs_queue, &mut ctx.nested_update_inputs_queue);
-    }
+    fields
 }
 
 fn create_execute_raw_field() -> OutputField {
@@ -1,6 +1,5 @@
 use super::*;
 use crate::EnumType;
-use once_cell::sync::OnceCell;
 use prisma_models::pk::PrimaryKey;
 use prisma_models::{dml, ModelRef};
 use std::sync::Arc;
@@ -23,11 +22,7 @@ pub fn input_object_type(ident: Identifier, fields: Vec<InputField>) -> InputObj
 
 /// Input object type initializer for cases where only the name is known, and fields are computed later.
 pub fn init_input_object_type(ident: Identifier) -> InputObjectType {
-    InputObjectType {
-        identifier: ident,
-        constraints: InputObjectTypeConstraints::default(),
-        fields: OnceCell::new(),
-    }
+    InputObjectType::new(ident)
 }
 
 /// Enum type convenience wrapper function.
@@ -12,6 +12,9 @@ pub struct ScalarFieldBuilder {
     pub is_auto_generated_int_id: bool,
     pub is_autoincrement: bool,
     pub is_updated_at: bool,
+    pub is_encrypted: bool,
+    pub is_readonly: bool,
+    pub computed_sql: Option<String>,
     pub internal_enum: Option<InternalEnum>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
@@ -21,6 +24,14 @@ pub struct ScalarFieldBuilder {
 
 impl ScalarFieldBuilder {
     pub fn build(self, container: ParentContainer) -> ScalarFieldRef {
+        // Computed and `@readonly` fields are read-only from the start, not only after
+        // relation field analysis.
+        let read_only = if self.is_readonly || self.computed_sql.is_some() {
+            OnceCell::from(true)
+        } else {
+            OnceCell::new()
+        };
+
         let scalar = ScalarField {
             name: self.name,
             type_identifier: self.type_identifier,
@@ -28,6 +39,9 @@ impl ScalarFieldBuilder {
             is_auto_generated_int_id: self.is_auto_generated_int_id,
             is_autoincrement: self.is_autoincrement,
             is_updated_at: self.is_updated_at,
+            is_encrypted: self.is_encrypted,
+            is_readonly: self.is_readonly,
+            computed_sql: self.computed_sql,
             internal_enum: self.internal_enum,
             arity: self.arity,
             db_name: self.db_name,
@@ -35,7 +49,7 @@ impl ScalarFieldBuilder {
             native_type: self.native_type,
             container,
             is_unique: self.is_unique,
-            read_only: OnceCell::new(),
+            read_only,
         };
 
         Arc::new(scalar)
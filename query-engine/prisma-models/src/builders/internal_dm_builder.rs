@@ -150,6 +150,9 @@ fn model_field_builders(
                         is_auto_generated_int_id: model.field_is_auto_generated_int_id(&sf.name),
                         is_autoincrement: sf.is_auto_increment(),
                         is_updated_at: sf.is_updated_at,
+                        is_encrypted: sf.is_encrypted,
+                        is_readonly: sf.is_readonly,
+                        computed_sql: sf.computed_sql.clone(),
                         internal_enum: sf.internal_enum(datamodel),
                         arity: sf.arity,
                         db_name: sf.database_name.clone(),
@@ -187,7 +190,10 @@ fn composite_field_builders(composite: &dml::CompositeType) -> Vec<FieldBuilder>
                         is_auto_generated_int_id: false,
                         is_autoincrement: false,
                         is_updated_at: false, // Todo: This info isn't available here.
-                        internal_enum: None,  // Todo: No enums on composites?
+                        is_encrypted: false,  // Composites can't be encrypted at the moment.
+                        is_readonly: false,
+                        computed_sql: None,
+                        internal_enum: None, // Todo: No enums on composites?
                         arity: field.arity,
                         db_name: field.database_name.clone(),
                         default_value: None, // Todo: No defaults?
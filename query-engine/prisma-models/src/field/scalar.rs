@@ -17,6 +17,9 @@ pub struct ScalarField {
     pub is_auto_generated_int_id: bool,
     pub is_autoincrement: bool,
     pub is_updated_at: bool,
+    pub is_encrypted: bool,
+    pub is_readonly: bool,
+    pub computed_sql: Option<String>,
     pub internal_enum: Option<InternalEnum>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
@@ -74,6 +77,12 @@ impl ScalarField {
     pub fn is_numeric(&self) -> bool {
         self.type_identifier.is_numeric()
     }
+
+    /// Is this field computed by a SQL expression (`@computedSql`)?
+    /// Computed fields are read-only and never part of write operations.
+    pub fn is_computed(&self) -> bool {
+        self.computed_sql.is_some()
+    }
 }
 
 impl Debug for ScalarField {
@@ -86,6 +95,9 @@ impl Debug for ScalarField {
             .field("is_autoincrement", &self.is_autoincrement)
             .field("internal_enum", &self.internal_enum)
             .field("is_updated_at", &self.is_updated_at)
+            .field("is_encrypted", &self.is_encrypted)
+            .field("is_readonly", &self.is_readonly)
+            .field("computed_sql", &self.computed_sql)
             .field("arity", &self.arity)
             .field("db_name", &self.db_name)
             .field("default_value", &self.default_value)
@@ -74,6 +74,12 @@ impl Model {
         self.manifestation.as_ref().map(|m| m.as_ref())
     }
 
+    /// The name of the datasource this model is routed to (`@@datasource`), if any.
+    /// `None` means the model lives on the default datasource.
+    pub fn datasource(&self) -> Option<&str> {
+        self.dml_model.datasource.as_deref()
+    }
+
     pub fn internal_data_model(&self) -> InternalDataModelRef {
         self.internal_data_model
             .upgrade()
@@ -51,6 +51,9 @@ pub struct OrderByScalar {
     pub field: ScalarFieldRef,
     pub path: Vec<RelationFieldRef>,
     pub sort_order: SortOrder,
+    /// Explicit placement of NULL values. `None` leaves the database default,
+    /// which differs per database.
+    pub nulls_order: Option<NullsOrder>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -95,11 +98,17 @@ pub struct OrderByRelevance {
 }
 
 impl OrderBy {
-    pub fn scalar(field: ScalarFieldRef, path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
+    pub fn scalar(
+        field: ScalarFieldRef,
+        path: Vec<RelationFieldRef>,
+        sort_order: SortOrder,
+        nulls_order: Option<NullsOrder>,
+    ) -> Self {
         Self::Scalar(OrderByScalar {
             field,
             path,
             sort_order,
+            nulls_order,
         })
     }
 
@@ -132,6 +141,12 @@ pub enum SortOrder {
     Descending,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
 impl SortOrder {
     pub fn into_order(self, reverse: bool) -> Order {
         match (self, reverse) {
@@ -167,6 +182,7 @@ impl From<ScalarFieldRef> for OrderBy {
             field,
             path: vec![],
             sort_order: SortOrder::Ascending,
+            nulls_order: None,
         })
     }
 }
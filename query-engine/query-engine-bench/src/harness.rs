@@ -0,0 +1,50 @@
+use std::time::{Duration, Instant};
+
+/// Per-iteration wall times of one benchmark, sorted ascending.
+pub struct BenchResult {
+    name: &'static str,
+    timings: Vec<Duration>,
+}
+
+/// Runs the closure `iterations` times, recording the wall time of each run.
+pub fn bench(name: &'static str, iterations: usize, mut run: impl FnMut()) -> BenchResult {
+    let mut timings = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let started_at = Instant::now();
+        run();
+        timings.push(started_at.elapsed());
+    }
+
+    timings.sort();
+
+    BenchResult { name, timings }
+}
+
+impl BenchResult {
+    /// Prints one line with the timing distribution of the benchmark.
+    pub fn report(&self) {
+        println!(
+            "{:<16} {:>6} iters   min {:>12}   median {:>12}   p95 {:>12}   max {:>12}",
+            self.name,
+            self.timings.len(),
+            format_duration(self.percentile(0.0)),
+            format_duration(self.percentile(50.0)),
+            format_duration(self.percentile(95.0)),
+            format_duration(self.percentile(100.0)),
+        );
+    }
+
+    fn percentile(&self, percentile: f64) -> Duration {
+        let index = ((self.timings.len() - 1) as f64 * percentile / 100.0).round() as usize;
+        self.timings[index]
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_millis(1) {
+        format!("{:.1}µs", duration.as_secs_f64() * 1_000_000.0)
+    } else {
+        format!("{:.1}ms", duration.as_secs_f64() * 1_000.0)
+    }
+}
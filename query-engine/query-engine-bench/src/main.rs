@@ -0,0 +1,178 @@
+//! A benchmark harness for the query pipeline with representative workloads:
+//! schema build time, DMMF generation, simple and nested reads, bulk writes
+//! and filter-heavy queries.
+//!
+//! The harness is self-contained: it times wall clocks with [`harness::bench`]
+//! instead of pulling in a benchmark framework, creates and seeds its tables
+//! through `executeRaw`, and runs against an in-memory SQLite database by
+//! default. Point `--database-url` (or `BENCH_DATABASE_URL`) at a dockerized
+//! Postgres or MySQL to benchmark a server database:
+//!
+//! ```text
+//! cargo run --release -p query-engine-bench -- --database-url postgresql://...
+//! ```
+
+mod harness;
+mod workloads;
+
+use anyhow::anyhow;
+use prisma_models::InternalDataModelBuilder;
+use query_core::{executor, schema_builder, BuildMode, QueryExecutor, QuerySchema};
+use request_handlers::{dmmf, GraphQlBody, GraphQlHandler, PrismaResponse};
+use std::sync::Arc;
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+struct BenchOpt {
+    /// Number of timed iterations per benchmark.
+    #[structopt(long, default_value = "50")]
+    iterations: usize,
+
+    /// Only run benchmarks whose name contains this string.
+    #[structopt(long)]
+    filter: Option<String>,
+
+    /// Database URL to benchmark against.
+    #[structopt(long, env = "BENCH_DATABASE_URL", default_value = "file:bench?mode=memory")]
+    database_url: String,
+}
+
+fn main() -> anyhow::Result<()> {
+    let opts = BenchOpt::from_args();
+
+    let provider = workloads::Provider::from_url(&opts.database_url)?;
+    let datamodel_str = workloads::datamodel(provider, &opts.database_url);
+
+    let enabled = |name: &str| match opts.filter.as_deref() {
+        Some(filter) => name.contains(filter),
+        None => true,
+    };
+
+    // Offline benchmarks: no database involved.
+    if enabled("schema_build") {
+        harness::bench("schema_build", opts.iterations, || {
+            build_schema(&datamodel_str).unwrap();
+        })
+        .report();
+    }
+
+    if enabled("dmmf") {
+        let (datamodel, query_schema) = build_schema(&datamodel_str)?;
+
+        harness::bench("dmmf", opts.iterations, || {
+            dmmf::render_dmmf(&datamodel, Arc::clone(&query_schema));
+        })
+        .report();
+    }
+
+    // Online benchmarks: connect, seed, then time each workload.
+    let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build()?;
+    let (query_schema, executor) = runtime.block_on(connect(&datamodel_str))?;
+    let handler = GraphQlHandler::new(&*executor, &query_schema);
+
+    for statement in workloads::setup_statements(provider) {
+        let query = format!(
+            "mutation {{ executeRaw(query: {}, parameters: \"[]\") }}",
+            serde_json::to_string(&statement)?
+        );
+        let response = runtime.block_on(handler.handle(body(&query), None));
+
+        if let Some(error) = first_error(&response) {
+            return Err(anyhow!("Setting up the benchmark tables failed: {}", error));
+        }
+    }
+
+    for workload in workloads::workloads() {
+        if !enabled(workload.name) {
+            continue;
+        }
+
+        // One untimed run to warm up and to skip workloads the provider does
+        // not support (e.g. createMany on SQLite).
+        let response = runtime.block_on(handler.handle(body(&workload.query), None));
+
+        if let Some(error) = first_error(&response) {
+            println!("{:<16} skipped: {}", workload.name, error);
+            continue;
+        }
+
+        harness::bench(workload.name, opts.iterations, || {
+            runtime.block_on(handler.handle(body(&workload.query), None));
+        })
+        .report();
+    }
+
+    Ok(())
+}
+
+/// Parses the datamodel and builds the query schema, as the engine does on
+/// startup.
+fn build_schema(datamodel_str: &str) -> anyhow::Result<(datamodel::Datamodel, Arc<QuerySchema>)> {
+    let config = datamodel::parse_configuration(datamodel_str)
+        .map_err(|errors| anyhow!(errors.to_pretty_string("schema.prisma", datamodel_str)))?
+        .subject;
+
+    let datamodel = datamodel::parse_datamodel(datamodel_str)
+        .map_err(|errors| anyhow!(errors.to_pretty_string("schema.prisma", datamodel_str)))?
+        .subject;
+
+    let data_source = config.datasources.first().ok_or_else(|| anyhow!("No datasource"))?;
+    let internal_data_model = InternalDataModelBuilder::from(&datamodel).build("".into());
+
+    let query_schema = Arc::new(schema_builder::build(
+        internal_data_model,
+        BuildMode::Modern,
+        true,
+        false,
+        data_source.capabilities(),
+        config.preview_features().iter().collect(),
+        data_source.referential_integrity(),
+    ));
+
+    Ok((datamodel, query_schema))
+}
+
+/// Connects to the database and builds the executor, mirroring engine startup.
+async fn connect(datamodel_str: &str) -> anyhow::Result<(Arc<QuerySchema>, Box<dyn QueryExecutor + Send + Sync>)> {
+    let config = datamodel::parse_configuration(datamodel_str)
+        .map_err(|errors| anyhow!(errors.to_pretty_string("schema.prisma", datamodel_str)))?
+        .subject;
+
+    let datamodel = datamodel::parse_datamodel(datamodel_str)
+        .map_err(|errors| anyhow!(errors.to_pretty_string("schema.prisma", datamodel_str)))?
+        .subject;
+
+    let data_source = config.datasources.first().ok_or_else(|| anyhow!("No datasource"))?;
+    let preview_features: Vec<_> = config.preview_features().iter().collect();
+    let url = data_source
+        .load_url(|key| std::env::var(key).ok())
+        .map_err(|errors| anyhow!(errors.to_pretty_string("schema.prisma", datamodel_str)))?;
+
+    let (db_name, executor) = executor::load(data_source, &preview_features, &url).await?;
+    executor.primary_connector().get_connection().await?;
+
+    let internal_data_model = InternalDataModelBuilder::from(&datamodel).build(db_name);
+
+    let query_schema = Arc::new(schema_builder::build(
+        internal_data_model,
+        BuildMode::Modern,
+        true,
+        false,
+        data_source.capabilities(),
+        preview_features,
+        data_source.referential_integrity(),
+    ));
+
+    Ok((query_schema, executor))
+}
+
+fn body(query: &str) -> GraphQlBody {
+    GraphQlBody::Single(query.into())
+}
+
+fn first_error(response: &PrismaResponse) -> Option<String> {
+    match response {
+        PrismaResponse::Single(response) => response.errors().next().map(|err| err.message().to_owned()),
+        PrismaResponse::Multi(responses) => responses.errors().next().map(|err| err.message().to_owned()),
+    }
+}
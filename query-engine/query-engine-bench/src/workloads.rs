@@ -0,0 +1,195 @@
+//! The benchmarked schema, the SQL to set it up, and the query workloads.
+//!
+//! The schema is a deliberately ordinary two-model blog: the point of the
+//! suite is tracking regressions in the query pipeline, not exercising every
+//! feature. Workloads that need a capability the provider lacks (createMany
+//! on SQLite) are skipped by the sanity run in `main`.
+
+use anyhow::anyhow;
+
+/// Number of seeded users; posts reference them by id.
+pub const USERS: usize = 100;
+
+/// Number of seeded posts, spread evenly over the users.
+pub const POSTS: usize = 500;
+
+/// The database the benchmarks run against, derived from the URL scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Provider {
+    Sqlite,
+    Postgres,
+    Mysql,
+}
+
+impl Provider {
+    pub fn from_url(url: &str) -> anyhow::Result<Self> {
+        if url.starts_with("file:") || url.starts_with("sqlite:") {
+            Ok(Self::Sqlite)
+        } else if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+            Ok(Self::Postgres)
+        } else if url.starts_with("mysql://") {
+            Ok(Self::Mysql)
+        } else {
+            Err(anyhow!("Unsupported database URL for benchmarks: {}", url))
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Sqlite => "sqlite",
+            Self::Postgres => "postgresql",
+            Self::Mysql => "mysql",
+        }
+    }
+
+    fn quote(self, identifier: &str) -> String {
+        match self {
+            Self::Sqlite | Self::Postgres => format!("\"{}\"", identifier),
+            Self::Mysql => format!("`{}`", identifier),
+        }
+    }
+}
+
+/// Renders the benchmarked datamodel for the given database.
+pub fn datamodel(provider: Provider, url: &str) -> String {
+    format!(
+        r#"
+datasource db {{
+  provider = "{provider}"
+  url      = "{url}"
+}}
+
+model User {{
+  id    Int     @id @default(autoincrement())
+  email String  @unique
+  name  String?
+  posts Post[]
+}}
+
+model Post {{
+  id        Int     @id @default(autoincrement())
+  title     String
+  published Boolean @default(false)
+  authorId  Int
+  author    User    @relation(fields: [authorId], references: [id])
+}}
+"#,
+        provider = provider.name(),
+        url = url,
+    )
+}
+
+/// The statements that (re-)create and seed the benchmarked tables. They are
+/// executed through `executeRaw`, so no migration engine is needed.
+pub fn setup_statements(provider: Provider) -> Vec<String> {
+    let user = provider.quote("User");
+    let post = provider.quote("Post");
+    let id = provider.quote("id");
+    let email = provider.quote("email");
+    let name = provider.quote("name");
+    let title = provider.quote("title");
+    let published = provider.quote("published");
+    let author_id = provider.quote("authorId");
+
+    let id_column = match provider {
+        Provider::Sqlite => "INTEGER PRIMARY KEY AUTOINCREMENT",
+        Provider::Postgres => "SERIAL PRIMARY KEY",
+        Provider::Mysql => "INTEGER AUTO_INCREMENT PRIMARY KEY",
+    };
+
+    let text = match provider {
+        Provider::Sqlite | Provider::Postgres => "TEXT",
+        Provider::Mysql => "VARCHAR(191)",
+    };
+
+    let mut statements = vec![
+        format!("DROP TABLE IF EXISTS {}", post),
+        format!("DROP TABLE IF EXISTS {}", user),
+        format!(
+            "CREATE TABLE {user} ({id} {id_column}, {email} {text} NOT NULL UNIQUE, {name} {text})",
+            user = user,
+            id = id,
+            id_column = id_column,
+            email = email,
+            name = name,
+            text = text,
+        ),
+        format!(
+            "CREATE TABLE {post} ({id} {id_column}, {title} {text} NOT NULL, {published} BOOLEAN NOT NULL DEFAULT false, {author_id} INTEGER NOT NULL, FOREIGN KEY ({author_id}) REFERENCES {user}({id}))",
+            post = post,
+            id = id,
+            id_column = id_column,
+            title = title,
+            text = text,
+            published = published,
+            author_id = author_id,
+            user = user,
+        ),
+    ];
+
+    let users = (1..=USERS)
+        .map(|i| format!("('user{i}@example.com', 'User {i}')", i = i))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    statements.push(format!("INSERT INTO {} ({}, {}) VALUES {}", user, email, name, users));
+
+    let posts = (1..=POSTS)
+        .map(|i| format!("('Post {}', {}, {})", i, i % 2 == 0, i % USERS + 1))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    statements.push(format!(
+        "INSERT INTO {} ({}, {}, {}) VALUES {}",
+        post, title, published, author_id, posts
+    ));
+
+    statements
+}
+
+/// One named GraphQL operation to benchmark.
+pub struct Workload {
+    pub name: &'static str,
+    pub query: String,
+}
+
+/// The benchmarked operations, covering simple and nested reads, filter-heavy
+/// queries and bulk writes.
+pub fn workloads() -> Vec<Workload> {
+    let create_many_data = (1..=POSTS)
+        .map(|i| format!(r#"{{ title: "Bulk {}", authorId: {} }}"#, i, i % USERS + 1))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    vec![
+        Workload {
+            name: "simple_reads",
+            query: "query { findManyUser(take: 100) { id email name } }".into(),
+        },
+        Workload {
+            name: "nested_reads",
+            query: "query { findManyUser(take: 50) { id email posts { id title published } } }".into(),
+        },
+        Workload {
+            name: "filter_heavy",
+            query: r#"query {
+                findManyUser(
+                    where: {
+                        OR: [{ email: { contains: "1" } }, { name: { startsWith: "User 2" } }]
+                        posts: { some: { published: { equals: true } } }
+                    }
+                    orderBy: { email: asc }
+                    take: 50
+                ) { id email }
+            }"#
+            .into(),
+        },
+        Workload {
+            name: "create_many",
+            query: format!(
+                "mutation {{ createManyPost(data: [{}]) {{ count }} }}",
+                create_many_data
+            ),
+        },
+    ]
+}
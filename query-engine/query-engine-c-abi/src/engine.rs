@@ -0,0 +1,93 @@
+use crate::error::ApiError;
+use prisma_models::InternalDataModelBuilder;
+use query_core::{executor, schema_builder, BuildMode, QueryExecutor, QuerySchema, QuerySchemaRenderer};
+use request_handlers::{GraphQLSchemaRenderer, GraphQlBody, GraphQlHandler, PrismaResponse};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// A connected query engine, driving its own tokio runtime. Unlike the
+/// Node-API engine there is no builder state: the engine connects on
+/// construction and is destroyed to disconnect.
+pub struct Engine {
+    runtime: Runtime,
+    query_schema: Arc<QuerySchema>,
+    executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+}
+
+impl Engine {
+    /// Parses the given datamodel, connects to the database and builds the
+    /// query schema. Datasource URLs are resolved from the process
+    /// environment.
+    pub fn start(datamodel_str: &str) -> Result<Self, ApiError> {
+        let config = datamodel::parse_configuration(datamodel_str)
+            .and_then(|mut config| {
+                config
+                    .subject
+                    .resolve_datasource_urls_from_env(&[], |key| std::env::var(key).ok())?;
+
+                Ok(config)
+            })
+            .map_err(|errors| ApiError::conversion(errors, datamodel_str))?;
+
+        config
+            .subject
+            .validate_that_one_datasource_is_provided()
+            .map_err(|errors| ApiError::conversion(errors, datamodel_str))?;
+
+        let ast = datamodel::parse_datamodel(datamodel_str)
+            .map_err(|errors| ApiError::conversion(errors, datamodel_str))?
+            .subject;
+
+        let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build()?;
+
+        let (query_schema, executor) = runtime.block_on(async {
+            let data_source = config
+                .subject
+                .datasources
+                .first()
+                .ok_or_else(|| ApiError::configuration("No valid data source found"))?;
+
+            let preview_features: Vec<_> = config.subject.preview_features().iter().collect();
+            let url = data_source
+                .load_url(|key| std::env::var(key).ok())
+                .map_err(|err| ApiError::conversion(err, datamodel_str))?;
+
+            let (db_name, executor) = executor::load(data_source, &preview_features, &url).await?;
+            let connector = executor.primary_connector();
+            connector.get_connection().await?;
+
+            let internal_data_model = InternalDataModelBuilder::from(&ast).build(db_name);
+
+            let query_schema = schema_builder::build(
+                internal_data_model,
+                BuildMode::Modern,
+                true,  // enable raw queries
+                false, // omit unchecked inputs
+                data_source.capabilities(),
+                preview_features,
+                data_source.referential_integrity(),
+            );
+
+            Ok::<_, ApiError>((Arc::new(query_schema), executor))
+        })?;
+
+        Ok(Engine {
+            runtime,
+            query_schema,
+            executor,
+        })
+    }
+
+    /// Renders the query schema as GraphQL SDL text.
+    pub fn sdl(&self) -> String {
+        GraphQLSchemaRenderer::render(self.query_schema.clone())
+    }
+
+    /// Sends a query to the core and returns the serialized response.
+    pub fn query(&self, body: GraphQlBody) -> PrismaResponse {
+        self.runtime.block_on(async {
+            let handler = GraphQlHandler::new(&*self.executor, &self.query_schema);
+            handler.handle(body, None).await
+        })
+    }
+}
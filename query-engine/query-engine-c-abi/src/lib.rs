@@ -0,0 +1,185 @@
+//! A C-compatible FFI surface for the query engine, so that non-Node runtimes
+//! can embed the engine as a shared library instead of spawning the binary
+//! and speaking GraphQL over stdio/HTTP.
+//!
+//! The surface is deliberately small and string-based:
+//!
+//! - [`prisma_engine_start`] parses the schema, connects and returns an
+//!   opaque engine handle.
+//! - [`prisma_engine_query`] takes a GraphQL JSON body and returns the JSON
+//!   response.
+//! - [`prisma_engine_sdl`] renders the query schema as GraphQL SDL text.
+//! - [`prisma_engine_stop`] disconnects and frees the handle.
+//! - [`prisma_engine_free_string`] frees any string returned by the engine.
+//!
+//! All functions report failures as a JSON-encoded user-facing error written
+//! to the `error_out` parameter, following the same error format as the
+//! Node-API engine.
+
+mod engine;
+mod error;
+mod logger;
+
+pub use logger::LogCallback;
+
+use engine::Engine;
+use error::ApiError;
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    ptr,
+};
+
+unsafe fn write_error(error_out: *mut *mut c_char, error: ApiError) {
+    if error_out.is_null() {
+        return;
+    }
+
+    let user_facing = user_facing_errors::Error::from(error);
+    let message = serde_json::to_string(&user_facing).unwrap();
+
+    *error_out = into_c_string(message);
+}
+
+fn into_c_string(s: String) -> *mut c_char {
+    // The engine responses never contain NUL bytes; if one sneaks in through
+    // an error message, replace it rather than crash across the FFI boundary.
+    match CString::new(s) {
+        Ok(s) => s.into_raw(),
+        Err(err) => {
+            let bytes = err
+                .into_vec()
+                .into_iter()
+                .map(|b| if b == 0 { b' ' } else { b })
+                .collect::<Vec<_>>();
+            CString::new(bytes).unwrap().into_raw()
+        }
+    }
+}
+
+unsafe fn read_c_string<'a>(s: *const c_char) -> Result<&'a str, ApiError> {
+    if s.is_null() {
+        return Err(ApiError::configuration("Unexpected null pointer"));
+    }
+
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| ApiError::configuration("Invalid UTF-8 in string argument"))
+}
+
+/// Starts a query engine for the given datamodel and connects to the
+/// database. Returns an opaque handle, or null on failure, in which case
+/// `error_out` is set to a JSON-encoded error.
+///
+/// # Safety
+///
+/// `datamodel` and `log_level` must be valid NUL-terminated strings. The
+/// `log_callback` must stay callable for the lifetime of the process.
+#[no_mangle]
+pub unsafe extern "C" fn prisma_engine_start(
+    datamodel: *const c_char,
+    log_level: *const c_char,
+    log_callback: Option<LogCallback>,
+    error_out: *mut *mut c_char,
+) -> *mut Engine {
+    let datamodel = match read_c_string(datamodel) {
+        Ok(s) => s,
+        Err(err) => {
+            write_error(error_out, err);
+            return ptr::null_mut();
+        }
+    };
+
+    if let Some(callback) = log_callback {
+        let level = read_c_string(log_level).unwrap_or("error");
+        logger::install(level, callback);
+    }
+
+    match Engine::start(datamodel) {
+        Ok(engine) => Box::into_raw(Box::new(engine)),
+        Err(err) => {
+            write_error(error_out, err);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Sends a GraphQL JSON body to the engine and returns the JSON response as
+/// a string to be freed with [`prisma_engine_free_string`]. Returns null on
+/// failure and sets `error_out`.
+///
+/// # Safety
+///
+/// `engine` must be a handle returned by [`prisma_engine_start`] that has not
+/// been passed to [`prisma_engine_stop`]. `body` must be a valid
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn prisma_engine_query(
+    engine: *const Engine,
+    body: *const c_char,
+    error_out: *mut *mut c_char,
+) -> *mut c_char {
+    let result = (|| {
+        let engine = engine
+            .as_ref()
+            .ok_or_else(|| ApiError::configuration("Unexpected null engine handle"))?;
+
+        let body = serde_json::from_str(read_c_string(body)?)?;
+        let response = engine.query(body);
+
+        Ok(serde_json::to_string(&response)?)
+    })();
+
+    match result {
+        Ok(response) => into_c_string(response),
+        Err(err) => {
+            write_error(error_out, err);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Renders the query schema as GraphQL SDL text to be freed with
+/// [`prisma_engine_free_string`], for documentation pipelines and diffing the
+/// API surface between versions. Returns null on failure and sets `error_out`.
+///
+/// # Safety
+///
+/// `engine` must be a handle returned by [`prisma_engine_start`] that has not
+/// been passed to [`prisma_engine_stop`].
+#[no_mangle]
+pub unsafe extern "C" fn prisma_engine_sdl(engine: *const Engine, error_out: *mut *mut c_char) -> *mut c_char {
+    match engine.as_ref() {
+        Some(engine) => into_c_string(engine.sdl()),
+        None => {
+            write_error(error_out, ApiError::configuration("Unexpected null engine handle"));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Disconnects the engine and frees the handle.
+///
+/// # Safety
+///
+/// `engine` must be a handle returned by [`prisma_engine_start`], and must
+/// not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn prisma_engine_stop(engine: *mut Engine) {
+    if !engine.is_null() {
+        drop(Box::from_raw(engine));
+    }
+}
+
+/// Frees a string returned by the engine.
+///
+/// # Safety
+///
+/// `s` must be a string returned by this library, and must not be used
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn prisma_engine_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
@@ -0,0 +1,41 @@
+use std::{ffi::CString, io, os::raw::c_char};
+use tracing_subscriber::EnvFilter;
+
+/// The function pointer the embedder hands over to receive log lines. Each
+/// call carries one JSON-encoded log event as a NUL-terminated string, only
+/// valid for the duration of the call.
+pub type LogCallback = extern "C" fn(*const c_char);
+
+/// Installs the global logger, forwarding every event as a JSON line to the
+/// given callback. Later engines share the logger of the first one; the
+/// callback must therefore stay valid for the lifetime of the process.
+pub(crate) fn install(level: &str, callback: LogCallback) {
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_env_filter(EnvFilter::new(level))
+        .with_writer(move || CallbackWriter { callback })
+        .finish();
+
+    // Errors only when a logger is already installed.
+    let _ = tracing::subscriber::set_global_default(subscriber);
+}
+
+struct CallbackWriter {
+    callback: LogCallback,
+}
+
+impl io::Write for CallbackWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let line = buf.strip_suffix(b"\n").unwrap_or(buf);
+
+        if let Ok(message) = CString::new(line) {
+            (self.callback)(message.as_ptr());
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -21,6 +21,9 @@ use tracing_opentelemetry::OpenTelemetrySpanExt;
 #[derive(Clone)]
 pub struct QueryEngine {
     inner: Arc<RwLock<Inner>>,
+
+    /// Maximum size in bytes for serialized query responses, `None` meaning unlimited.
+    max_response_size: Option<usize>,
 }
 
 /// The state of the engine.
@@ -58,13 +61,16 @@ pub struct ConnectedEngine {
     env: HashMap<String, String>,
 }
 
-/// Returned from the `serverInfo` method in javascript.
+/// Returned from the `getServerInfo` method in javascript. Before the engine is
+/// connected, only the build information and preview features are known.
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerInfo {
     commit: String,
     version: String,
     primary_connector: Option<String>,
+    preview_features: Vec<String>,
+    capabilities: Vec<String>,
 }
 
 impl ConnectedEngine {
@@ -96,6 +102,15 @@ pub struct ConstructorOptions {
     config_dir: PathBuf,
     #[serde(default)]
     ignore_env_var_errors: bool,
+    #[serde(default = "default_max_response_size")]
+    max_response_size: Option<usize>,
+}
+
+/// Default response size cap for the library build. Large enough for any reasonable result
+/// set, while staying well below the V8 string size limit the serialized response has to
+/// fit into when crossing the N-API bridge. Passing an explicit `null` disables the cap.
+fn default_max_response_size() -> Option<usize> {
+    Some(256 * 1024 * 1024)
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -119,6 +134,7 @@ impl QueryEngine {
             telemetry,
             config_dir,
             ignore_env_var_errors,
+            max_response_size,
         } = opts;
 
         let env = stringify_env_values(env)?; // we cannot trust anything JS sends us from process.env
@@ -165,9 +181,15 @@ impl QueryEngine {
 
         Ok(Self {
             inner: Arc::new(RwLock::new(Inner::Builder(builder))),
+            max_response_size,
         })
     }
 
+    /// Maximum size in bytes for serialized query responses, `None` meaning unlimited.
+    pub fn max_response_size(&self) -> Option<usize> {
+        self.max_response_size
+    }
+
     /// Connect to the database, allow queries to be run.
     pub async fn connect(&self) -> crate::Result<()> {
         let mut inner = self.inner.write().await;
@@ -203,7 +225,8 @@ impl QueryEngine {
                         let query_schema = schema_builder::build(
                             internal_data_model,
                             BuildMode::Modern,
-                            true, // enable raw queries
+                            true,  // enable raw queries
+                            false, // omit unchecked inputs
                             data_source.capabilities(),
                             preview_features,
                             data_source.referential_integrity(),
@@ -291,7 +314,11 @@ impl QueryEngine {
 
                         span.set_parent(cx);
 
-                        match engine.executor().start_tx(input.max_wait, input.timeout).await {
+                        match engine
+                            .executor()
+                            .start_tx(input.max_wait, input.timeout, input.tag)
+                            .await
+                        {
                             Ok(tx_id) => Ok(json!({ "id": tx_id.to_string() }).to_string()),
                             Err(err) => Ok(map_known_error(err)?),
                         }
@@ -348,6 +375,37 @@ impl QueryEngine {
         }
     }
 
+    /// Info about the running engine: build information plus, when connected, the
+    /// active connector and its capability flags.
+    pub async fn server_info(&self) -> crate::Result<ServerInfo> {
+        match *self.inner.read().await {
+            Inner::Connected(ref engine) => {
+                let context = engine.query_schema().context();
+
+                Ok(ServerInfo {
+                    commit: env!("GIT_HASH").into(),
+                    version: env!("CARGO_PKG_VERSION").into(),
+                    primary_connector: Some(engine.executor().primary_connector().name()),
+                    preview_features: context.features.iter().map(ToString::to_string).collect(),
+                    capabilities: context.capabilities.iter().map(ToString::to_string).collect(),
+                })
+            }
+            Inner::Builder(ref builder) => Ok(ServerInfo {
+                commit: env!("GIT_HASH").into(),
+                version: env!("CARGO_PKG_VERSION").into(),
+                primary_connector: None,
+                preview_features: builder
+                    .config
+                    .subject
+                    .preview_features()
+                    .iter()
+                    .map(|feature| feature.to_string())
+                    .collect(),
+                capabilities: Vec::new(),
+            }),
+        }
+    }
+
     /// Loads the query schema. Only available when connected.
     pub async fn sdl_schema(&self) -> crate::Result<String> {
         match *self.inner.read().await {
@@ -13,6 +13,7 @@ pub fn init(mut exports: JsObject, env: Env) -> napi::Result<()> {
             Property::new(&env, "connect")?.with_method(engine::connect),
             Property::new(&env, "disconnect")?.with_method(engine::disconnect),
             Property::new(&env, "query")?.with_method(engine::query),
+            Property::new(&env, "getServerInfo")?.with_method(engine::server_info),
             Property::new(&env, "sdlSchema")?.with_method(engine::sdl_schema),
             Property::new(&env, "startTransaction")?.with_method(engine::start_transaction),
             Property::new(&env, "commitTransaction")?.with_method(engine::commit_transaction),
@@ -77,10 +77,15 @@ pub fn query(ctx: CallContext) -> napi::Result<JsObject> {
         _ => None,
     };
 
+    let max_response_size = engine.max_response_size();
+
     ctx.env.execute_tokio_future(
         async move { Ok(engine.query(body, trace, tx_id).await?) },
-        |env, response| {
-            let res = serde_json::to_string(&response).unwrap();
+        move |env, response| {
+            let res = match max_response_size {
+                Some(limit) => response.to_json_string_capped(limit),
+                None => serde_json::to_string(&response).unwrap(),
+            };
 
             env.adjust_external_memory(res.len() as i64)?;
             env.create_string_from_std(res)
@@ -88,6 +93,18 @@ pub fn query(ctx: CallContext) -> napi::Result<JsObject> {
     )
 }
 
+#[js_function(0)]
+pub fn server_info(ctx: CallContext) -> napi::Result<JsObject> {
+    let this: JsObject = ctx.this_unchecked();
+    let engine: &QueryEngine = ctx.env.unwrap(&this)?;
+    let engine: QueryEngine = engine.clone();
+
+    ctx.env
+        .execute_tokio_future(async move { Ok(engine.server_info().await?) }, |env, info| {
+            env.to_js_value(&info)
+        })
+}
+
 #[js_function(0)]
 pub fn sdl_schema(ctx: CallContext) -> napi::Result<JsObject> {
     let this: JsObject = ctx.this_unchecked();
@@ -26,9 +26,26 @@ pub fn version(ctx: CallContext) -> napi::Result<JsUnknown> {
     ctx.env.to_js_value(&version)
 }
 
-#[js_function(1)]
+#[js_function(2)]
 pub fn dmmf(ctx: CallContext) -> napi::Result<JsString> {
     let datamodel_string = ctx.get::<JsString>(0)?.into_utf8()?.into_owned()?;
+
+    // Optional second argument: the DMMF encoding version, defaulting to 1.
+    let version = match ctx.get::<JsUnknown>(1) {
+        Ok(value) => match value.get_type()? {
+            napi::ValueType::Undefined | napi::ValueType::Null => 1,
+            _ => ctx.env.from_js_value::<u32, _>(value)?,
+        },
+        Err(_) => 1,
+    };
+
+    if version != 1 && version != 2 {
+        return Err(napi::Error::from_reason(format!(
+            "Unknown DMMF version: {}. Supported versions: 1, 2.",
+            version
+        )));
+    }
+
     let datamodel = datamodel::parse_datamodel(&datamodel_string)
         .map_err(|errors| ApiError::conversion(errors, &datamodel_string))?;
     let config = datamodel::parse_configuration(&datamodel_string)
@@ -46,13 +63,16 @@ pub fn dmmf(ctx: CallContext) -> napi::Result<JsString> {
         internal_data_model,
         BuildMode::Modern,
         true,
+        false,
         capabilities,
         config.subject.preview_features().iter().collect(),
         referential_integrity,
     ));
 
-    let dmmf = dmmf::render_dmmf(&datamodel.subject, query_schema);
-    let dmmf_string = serde_json::to_string(&dmmf).unwrap();
+    let dmmf_string = match version {
+        2 => serde_json::to_string(&dmmf::render_dmmf_compact(&datamodel.subject, query_schema)).unwrap(),
+        _ => serde_json::to_string(&dmmf::render_dmmf(&datamodel.subject, query_schema)).unwrap(),
+    };
 
     ctx.env.adjust_external_memory(dmmf_string.len() as i64)?;
     ctx.env.create_string_from_std(dmmf_string)
@@ -1,6 +1,7 @@
 use crate::{
     context::PrismaContext,
     opt::{CliOpt, PrismaOpt, Subcommand},
+    server::capture::CapturedOperation,
     PrismaResult,
 };
 use datamodel::ValidatedConfiguration;
@@ -8,7 +9,7 @@ use datamodel::{Configuration, Datamodel};
 use datamodel_connector::ConnectorCapabilities;
 use prisma_models::InternalDataModelBuilder;
 use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode};
-use request_handlers::{dmmf, GraphQlHandler};
+use request_handlers::{dmmf, GraphQlHandler, PrismaResponse};
 use std::{env, sync::Arc};
 
 pub struct ExecuteRequest {
@@ -17,15 +18,26 @@ pub struct ExecuteRequest {
     datamodel: Datamodel,
     config: Configuration,
     enable_raw_queries: bool,
+    omit_unchecked_inputs: bool,
 }
 
 pub struct DmmfRequest {
     datamodel: Datamodel,
     build_mode: BuildMode,
     enable_raw_queries: bool,
+    omit_unchecked_inputs: bool,
     config: Configuration,
 }
 
+pub struct ReplayRequest {
+    legacy: bool,
+    path: String,
+    datamodel: Datamodel,
+    config: Configuration,
+    enable_raw_queries: bool,
+    omit_unchecked_inputs: bool,
+}
+
 pub struct GetConfigRequest {
     config: ValidatedConfiguration,
     ignore_env_var_errors: bool,
@@ -35,6 +47,7 @@ pub enum CliCommand {
     Dmmf(DmmfRequest),
     GetConfig(GetConfigRequest),
     ExecuteRequest(ExecuteRequest),
+    Replay(ReplayRequest),
 }
 
 impl CliCommand {
@@ -59,6 +72,7 @@ impl CliCommand {
                         datamodel: opts.datamodel()?,
                         build_mode,
                         enable_raw_queries: opts.enable_raw_queries,
+                        omit_unchecked_inputs: opts.omit_unchecked_inputs,
                         config: opts.configuration(true)?.subject,
                     })))
                 }
@@ -69,6 +83,15 @@ impl CliCommand {
                 CliOpt::ExecuteRequest(input) => Ok(Some(CliCommand::ExecuteRequest(ExecuteRequest {
                     query: input.query.clone(),
                     enable_raw_queries: opts.enable_raw_queries,
+                    omit_unchecked_inputs: opts.omit_unchecked_inputs,
+                    legacy: input.legacy,
+                    datamodel: opts.datamodel()?,
+                    config: opts.configuration(false)?.subject,
+                }))),
+                CliOpt::Replay(input) => Ok(Some(CliCommand::Replay(ReplayRequest {
+                    path: input.path.clone(),
+                    enable_raw_queries: opts.enable_raw_queries,
+                    omit_unchecked_inputs: opts.omit_unchecked_inputs,
                     legacy: input.legacy,
                     datamodel: opts.datamodel()?,
                     config: opts.configuration(false)?.subject,
@@ -82,6 +105,7 @@ impl CliCommand {
             CliCommand::Dmmf(request) => Self::dmmf(request).await,
             CliCommand::GetConfig(input) => Self::get_config(input),
             CliCommand::ExecuteRequest(request) => Self::execute_request(request).await,
+            CliCommand::Replay(request) => Self::replay(request).await,
         }
     }
 
@@ -98,6 +122,7 @@ impl CliCommand {
             internal_data_model,
             request.build_mode,
             request.enable_raw_queries,
+            request.omit_unchecked_inputs,
             capabilities,
             request.config.preview_features().iter().collect(),
             referential_integrity,
@@ -137,6 +162,7 @@ impl CliCommand {
         let cx = PrismaContext::builder(request.config, request.datamodel)
             .legacy(request.legacy)
             .enable_raw_queries(request.enable_raw_queries)
+            .omit_unchecked_inputs(request.omit_unchecked_inputs)
             .build()
             .await?;
 
@@ -151,4 +177,71 @@ impl CliCommand {
 
         Ok(())
     }
+
+    /// Re-executes the operations of a capture file sequentially against the
+    /// configured database, printing per-operation and total timings next to
+    /// the originally recorded ones.
+    async fn replay(request: ReplayRequest) -> PrismaResult<()> {
+        use std::io::BufRead;
+
+        request.config.validate_that_one_datasource_is_provided()?;
+
+        let cx = PrismaContext::builder(request.config, request.datamodel)
+            .legacy(request.legacy)
+            .enable_raw_queries(request.enable_raw_queries)
+            .omit_unchecked_inputs(request.omit_unchecked_inputs)
+            .build()
+            .await?;
+
+        let cx = Arc::new(cx);
+        let handler = GraphQlHandler::new(&*cx.executor, cx.query_schema());
+
+        let file = std::fs::File::open(&request.path)?;
+        let mut executed: u64 = 0;
+        let mut failed: u64 = 0;
+        let started_at = std::time::Instant::now();
+
+        for (index, line) in std::io::BufReader::new(file).lines().enumerate() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let operation: CapturedOperation = serde_json::from_str(&line)?;
+            let body = serde_json::from_value(operation.body)?;
+
+            let operation_started_at = std::time::Instant::now();
+            let response = handler.handle(body, None).await;
+            let elapsed = operation_started_at.elapsed();
+
+            let errors = match &response {
+                PrismaResponse::Single(response) => response.errors().count(),
+                PrismaResponse::Multi(responses) => responses.errors().count(),
+            };
+
+            executed += 1;
+
+            if errors > 0 {
+                failed += 1;
+            }
+
+            println!(
+                "{}: {}ms (recorded: {}ms, errors: {})",
+                index + 1,
+                elapsed.as_millis(),
+                operation.duration_ms,
+                errors,
+            );
+        }
+
+        println!(
+            "Replayed {} operations in {}ms, {} with errors.",
+            executed,
+            started_at.elapsed().as_millis(),
+            failed,
+        );
+
+        Ok(())
+    }
 }
@@ -2,7 +2,7 @@ use crate::{PrismaError, PrismaResult};
 use datamodel::{Configuration, Datamodel};
 use prisma_models::InternalDataModelBuilder;
 use query_core::{executor, schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor};
-use std::{env, fmt, sync::Arc};
+use std::{collections::HashMap, env, fmt, sync::Arc};
 
 /// Prisma request context containing all immutable state of the process.
 /// There is usually only one context initialized per process.
@@ -24,6 +24,7 @@ impl fmt::Debug for PrismaContext {
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    omit_unchecked_inputs: bool,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -39,15 +40,33 @@ impl ContextBuilder {
         self
     }
 
+    pub fn omit_unchecked_inputs(mut self, val: bool) -> Self {
+        self.omit_unchecked_inputs = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.legacy,
+            self.enable_raw_queries,
+            self.omit_unchecked_inputs,
+        )
+        .await
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
-        // We only support one data source at the moment, so take the first one (default not exposed yet).
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        legacy: bool,
+        enable_raw_queries: bool,
+        omit_unchecked_inputs: bool,
+    ) -> PrismaResult<Self> {
+        // The first datasource is the default one; models opt into the others with `@@datasource`.
         let data_source = config
             .datasources
             .first()
@@ -59,6 +78,32 @@ impl PrismaContext {
         let preview_features: Vec<_> = config.preview_features().iter().collect();
         let (db_name, executor) = executor::load(&data_source, &preview_features, &url).await?;
 
+        // With multiple datasources, load an executor for each additional one and front
+        // them with a routing executor that dispatches operations by model.
+        let executor: Box<dyn QueryExecutor + Send + Sync + 'static> = if config.datasources.len() > 1 {
+            let mut secondary = HashMap::new();
+
+            for source in config.datasources.iter().skip(1) {
+                let url = source.load_url(|key| env::var(key).ok())?;
+                let (_, executor) = executor::load(source, &preview_features, &url).await?;
+
+                executor.primary_connector().get_connection().await?;
+                secondary.insert(source.name.clone(), executor);
+            }
+
+            let routing = executor::RoutingExecutor::new(executor, secondary);
+
+            // Finish any distributed transactions a crashed process left prepared;
+            // they hold locks on the participants until recovered.
+            if executor::TwoPhaseCoordinator::enabled() {
+                routing.recover_two_phase().await?;
+            }
+
+            Box::new(routing)
+        } else {
+            executor
+        };
+
         // Build internal data model
         let internal_data_model = InternalDataModelBuilder::from(&dm).build(db_name);
 
@@ -68,6 +113,7 @@ impl PrismaContext {
             internal_data_model,
             build_mode,
             enable_raw_queries,
+            omit_unchecked_inputs,
             data_source.capabilities(),
             preview_features,
             data_source.referential_integrity(),
@@ -81,6 +127,11 @@ impl PrismaContext {
 
         context.verify_connection().await?;
 
+        #[cfg(feature = "sql")]
+        if crate::schema_check::enabled() {
+            crate::schema_check::validate(&context.dm, data_source, &url).await?;
+        }
+
         Ok(context)
     }
 
@@ -93,6 +144,7 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            omit_unchecked_inputs: false,
             datamodel,
             config,
         }
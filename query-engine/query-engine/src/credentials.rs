@@ -0,0 +1,75 @@
+//! Short-lived database credentials from an external provider command, for
+//! IAM-style authentication (AWS RDS IAM auth, GCP Cloud SQL IAM tokens)
+//! instead of static passwords in the connection URL.
+
+use crate::{PrismaError, PrismaResult};
+use std::process::Command;
+
+/// Obtains database tokens by running a user-provided command. The command
+/// is expected to print the token to its standard output; surrounding
+/// whitespace is trimmed.
+pub struct CredentialProvider {
+    command: String,
+}
+
+impl CredentialProvider {
+    pub fn new(command: String) -> Self {
+        CredentialProvider { command }
+    }
+
+    /// Run the provider command and return the token it printed.
+    pub fn fetch_token(&self) -> PrismaResult<String> {
+        let output = if cfg!(windows) {
+            Command::new("cmd").args(["/C", &self.command]).output()
+        } else {
+            Command::new("sh").args(["-c", &self.command]).output()
+        }
+        .map_err(|err| {
+            PrismaError::ConfigurationError(format!("Failed to run the credential provider command: {}", err))
+        })?;
+
+        if !output.status.success() {
+            return Err(PrismaError::ConfigurationError(format!(
+                "The credential provider command exited with {}.",
+                output.status
+            )));
+        }
+
+        let token = String::from_utf8(output.stdout)
+            .map_err(|_| {
+                PrismaError::ConfigurationError(
+                    "The credential provider command returned a token that is not valid UTF-8.".into(),
+                )
+            })?
+            .trim()
+            .to_owned();
+
+        if token.is_empty() {
+            return Err(PrismaError::ConfigurationError(
+                "The credential provider command returned an empty token.".into(),
+            ));
+        }
+
+        Ok(token)
+    }
+}
+
+/// Replace the password in the connection URL with the provided token. The
+/// token is percent-encoded by the URL parser, so tokens containing `&` or
+/// `=` (as AWS RDS IAM tokens do) are safe.
+pub fn inject_token(url: &str, token: &str) -> PrismaResult<String> {
+    let mut parsed = url::Url::parse(url)?;
+
+    if !matches!(parsed.scheme(), "postgres" | "postgresql" | "mysql") {
+        return Err(PrismaError::ConfigurationError(format!(
+            "Token-based authentication is only supported for PostgreSQL and MySQL, not for `{}` URLs.",
+            parsed.scheme()
+        )));
+    }
+
+    parsed.set_password(Some(token)).map_err(|_| {
+        PrismaError::ConfigurationError("Could not set the token as the password of the connection URL.".into())
+    })?;
+
+    Ok(parsed.to_string())
+}
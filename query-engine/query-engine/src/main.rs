@@ -12,9 +12,12 @@ use structopt::StructOpt;
 
 mod cli;
 mod context;
+mod credentials;
 mod error;
 mod logger;
 mod opt;
+#[cfg(feature = "sql")]
+mod schema_check;
 mod server;
 
 #[cfg(test)]
@@ -21,6 +21,15 @@ pub struct ExecuteRequestInput {
     pub legacy: bool,
 }
 
+#[derive(Debug, Clone, StructOpt)]
+pub struct ReplayInput {
+    /// Path to a capture file recorded with `--capture-path`
+    pub path: String,
+    /// Run in the legacy GraphQL mode
+    #[structopt(long)]
+    pub legacy: bool,
+}
+
 #[derive(Debug, Clone, StructOpt)]
 #[structopt(rename_all = "camelCase")]
 pub struct GetConfigInput {
@@ -36,6 +45,8 @@ pub enum CliOpt {
     GetConfig(GetConfigInput),
     /// Executes one request and then terminates.
     ExecuteRequest(ExecuteRequestInput),
+    /// Re-executes the operations of a capture file and then terminates.
+    Replay(ReplayInput),
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -67,6 +78,13 @@ pub struct PrismaOpt {
     #[structopt(long, env = "OVERWRITE_DATASOURCES", parse(try_from_str = parse_base64_string))]
     pub overwrite_datasources: Option<String>,
 
+    /// A command that prints a short-lived database token to stdout, used as
+    /// the password for the datasource connection instead of a static
+    /// password in the URL (AWS RDS IAM auth, GCP Cloud SQL IAM tokens). The
+    /// command is run every time the configuration is loaded.
+    #[structopt(long, env = "CREDENTIAL_PROVIDER")]
+    pub credential_provider: Option<String>,
+
     /// Switches query schema generation to Prisma 1 compatible mode.
     #[structopt(long, short)]
     pub legacy: bool,
@@ -75,6 +93,16 @@ pub struct PrismaOpt {
     #[structopt(long, short = "r")]
     pub enable_raw_queries: bool,
 
+    /// Omits the unchecked input types from the generated schema and DMMF,
+    /// reducing their size for large schemas
+    #[structopt(long)]
+    pub omit_unchecked_inputs: bool,
+
+    /// Maximum size in bytes for serialized query responses. Responses exceeding
+    /// the cap are replaced by an error advising pagination. Unlimited by default.
+    #[structopt(long)]
+    pub max_response_size: Option<usize>,
+
     /// Enables the GraphQL playground
     #[structopt(long, short = "g")]
     pub enable_playground: bool,
@@ -91,6 +119,17 @@ pub struct PrismaOpt {
     #[structopt(long = "log-format", env = "RUST_LOG_FORMAT")]
     pub log_format: Option<String>,
 
+    /// Path to a persisted operations manifest. If set, the engine only executes
+    /// operations whose hash appears in the manifest and rejects ad-hoc queries.
+    #[structopt(long, env = "PERSISTED_OPERATIONS_MANIFEST")]
+    pub persisted_operations_manifest: Option<String>,
+
+    /// Path to a capture file. If set, the engine appends every incoming operation,
+    /// with redacted variables and its timing, as one NDJSON line. The file can be
+    /// re-executed with the `replay` subcommand.
+    #[structopt(long, env = "CAPTURE_PATH")]
+    pub capture_path: Option<String>,
+
     /// Enable OpenTelemetry streaming from requests.
     #[structopt(long)]
     pub open_telemetry: bool,
@@ -103,12 +142,23 @@ pub struct PrismaOpt {
     pub subcommand: Option<Subcommand>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 struct SourceOverride {
     name: String,
     url: String,
 }
 
+// The url carries credentials, so the `Debug` impl redacts it before it can
+// end up in a log line.
+impl std::fmt::Debug for SourceOverride {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SourceOverride")
+            .field("name", &self.name)
+            .field("url", &user_facing_errors::redaction::RedactedUrl::new(&self.url))
+            .finish()
+    }
+}
+
 impl PrismaOpt {
     fn datamodel_str(&self) -> PrismaResult<&str> {
         let res = self
@@ -156,7 +206,26 @@ impl PrismaOpt {
                 Ok(config)
             })
         };
-        config_result.map_err(|errors| PrismaError::ConversionError(errors, datamodel_str.to_string()))
+        let mut config =
+            config_result.map_err(|errors| PrismaError::ConversionError(errors, datamodel_str.to_string()))?;
+
+        if !ignore_env_errors {
+            if let Some(command) = &self.credential_provider {
+                let token = crate::credentials::CredentialProvider::new(command.clone()).fetch_token()?;
+
+                for datasource in &mut config.subject.datasources {
+                    let url = datasource.url.value.as_deref().ok_or_else(|| {
+                        PrismaError::ConfigurationError(
+                            "The datasource has no resolved URL to authenticate against.".into(),
+                        )
+                    })?;
+
+                    datasource.url.value = Some(crate::credentials::inject_token(url, &token)?);
+                }
+            }
+        }
+
+        Ok(config)
     }
 
     /// Extract the log format from on the RUST_LOG_FORMAT env var.
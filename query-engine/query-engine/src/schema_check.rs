@@ -0,0 +1,153 @@
+//! Opt-in startup validation of the datamodel against the live database schema.
+
+use crate::{PrismaError, PrismaResult};
+use datamodel::{
+    dml::{FieldType, ScalarType},
+    Datamodel, Datasource,
+};
+use sql_schema_describer::{ColumnTypeFamily, SqlSchema, SqlSchemaDescriberBackend};
+
+/// Env var toggling the startup schema check. Set to `1` or `true` to describe the
+/// live database on boot and fail with one aggregated report if tables or columns
+/// the datamodel maps to are missing or have incompatible types.
+pub const SCHEMA_CHECK_ENV: &str = "PRISMA_STARTUP_SCHEMA_CHECK";
+
+pub fn enabled() -> bool {
+    std::env::var(SCHEMA_CHECK_ENV)
+        .map(|s| s == "1" || s == "true")
+        .unwrap_or(false)
+}
+
+/// Describes the live database and verifies that every table and column the datamodel
+/// maps to exists with a compatible type. All findings are aggregated into a single
+/// error so one boot surfaces every problem at once instead of failing query by query.
+pub async fn validate(datamodel: &Datamodel, data_source: &Datasource, url: &str) -> PrismaResult<()> {
+    let schema = match describe(data_source, url).await? {
+        Some(schema) => schema,
+        None => return Ok(()),
+    };
+
+    let findings = compare(datamodel, &schema);
+
+    if findings.is_empty() {
+        Ok(())
+    } else {
+        Err(PrismaError::ConfigurationError(format!(
+            "The startup schema check found {} problem(s) between the Prisma schema and the live database:\n{}",
+            findings.len(),
+            findings.join("\n"),
+        )))
+    }
+}
+
+/// Describes the live database, `None` for providers without a SQL describer.
+async fn describe(data_source: &Datasource, url: &str) -> PrismaResult<Option<SqlSchema>> {
+    use sql_schema_describer::{mssql, mysql, postgres, sqlite};
+
+    let conn = quaint::single::Quaint::new(url).await.map_err(|err| {
+        PrismaError::ConfigurationError(format!(
+            "The startup schema check could not connect to the database: {}",
+            err
+        ))
+    })?;
+    let schema_name = conn.connection_info().schema_name().to_owned();
+
+    let result = match data_source.active_provider.as_str() {
+        "postgresql" | "postgres" => {
+            postgres::SqlSchemaDescriber::new(&conn, Default::default())
+                .describe(&schema_name)
+                .await
+        }
+        "cockroachdb" => {
+            postgres::SqlSchemaDescriber::new(&conn, postgres::Circumstances::Cockroach.into())
+                .describe(&schema_name)
+                .await
+        }
+        "mysql" => mysql::SqlSchemaDescriber::new(&conn).describe(&schema_name).await,
+        "sqlite" => sqlite::SqlSchemaDescriber::new(&conn).describe(&schema_name).await,
+        "sqlserver" => mssql::SqlSchemaDescriber::new(&conn).describe(&schema_name).await,
+        other => {
+            warn!("The startup schema check is not supported on the {} provider.", other);
+            return Ok(None);
+        }
+    };
+
+    result.map(Some).map_err(|err| {
+        PrismaError::ConfigurationError(format!(
+            "The startup schema check could not describe the database: {:?}",
+            err
+        ))
+    })
+}
+
+fn compare(datamodel: &Datamodel, schema: &SqlSchema) -> Vec<String> {
+    let mut findings = Vec::new();
+
+    for model in datamodel.models().filter(|model| !model.is_ignored) {
+        let table_name = model.database_name.as_deref().unwrap_or(&model.name);
+
+        let table = match schema.table(table_name) {
+            Ok(table) => table,
+            Err(_) => {
+                findings.push(format!(
+                    "- The table `{}` mapped by model `{}` does not exist.",
+                    table_name, model.name
+                ));
+                continue;
+            }
+        };
+
+        for field in model.scalar_fields().filter(|field| !field.is_ignored) {
+            let column_name = field.db_name();
+
+            let column = match table.columns.iter().find(|column| column.name == column_name) {
+                Some(column) => column,
+                None => {
+                    findings.push(format!(
+                        "- The column `{}.{}` mapped by field `{}.{}` does not exist.",
+                        table_name, column_name, model.name, field.name
+                    ));
+                    continue;
+                }
+            };
+
+            if !families_compatible(&field.field_type, &column.tpe.family) {
+                findings.push(format!(
+                    "- The column `{}.{}` has the database type `{}` ({:?}), which cannot hold the field `{}.{}`.",
+                    table_name, column_name, column.tpe.full_data_type, column.tpe.family, model.name, field.name
+                ));
+            }
+        }
+    }
+
+    findings
+}
+
+/// Very permissive type compatibility: only combinations that can never hold the
+/// field's values are reported, everything remotely plausible passes. The exact
+/// column type for a field is connector specific and validated by the migration
+/// engine, the check here only has to catch schema drift.
+fn families_compatible(field_type: &FieldType, family: &ColumnTypeFamily) -> bool {
+    use ColumnTypeFamily::*;
+
+    // Columns the describer could not classify cannot be judged either way.
+    if matches!(family, Unsupported(_)) {
+        return true;
+    }
+
+    match field_type {
+        FieldType::Enum(_) => matches!(family, Enum(_) | String),
+        FieldType::Scalar(scalar_type, _, _) => match scalar_type {
+            ScalarType::Int | ScalarType::BigInt => matches!(family, Int | BigInt),
+            ScalarType::Float | ScalarType::Decimal => matches!(family, Float | Decimal),
+            ScalarType::Boolean => matches!(family, Boolean | Int),
+            ScalarType::String => matches!(family, String | Uuid),
+            ScalarType::DateTime => matches!(family, DateTime),
+            ScalarType::Json => matches!(family, Json | String),
+            ScalarType::Bytes => matches!(family, Binary),
+        },
+        // Relation fields have no column of their own, unsupported and composite
+        // type fields cannot be checked.
+        _ => true,
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{error::PrismaError, PrismaResult};
+use request_handlers::GraphQlBody;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// One recorded operation, written as a single NDJSON line to the capture file
+/// and replayed by the `replay` CLI subcommand.
+///
+/// Variable values are replaced by `"<redacted>"` before writing, so captures
+/// can be shared without leaking user data. Inline arguments in the query
+/// document itself are kept verbatim — without them the operation could not be
+/// replayed — so captures of queries with inlined values still need the same
+/// care as query logs.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CapturedOperation {
+    /// Milliseconds since the Unix epoch at which the operation arrived.
+    pub timestamp_ms: u64,
+
+    /// Time the engine took to produce the response, in milliseconds.
+    pub duration_ms: u64,
+
+    /// The GraphQL body of the operation, with variable values redacted.
+    pub body: serde_json::Value,
+}
+
+/// Appends incoming operations to the capture file, one NDJSON line each.
+#[derive(Debug)]
+pub(crate) struct OperationCapture {
+    file: Mutex<File>,
+}
+
+impl OperationCapture {
+    /// Opens the capture file for appending, creating it if necessary.
+    pub fn open(path: &str) -> PrismaResult<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|err| PrismaError::ConfigurationError(format!("Unable to open capture file: {}", err)))?;
+
+        Ok(Self { file: Mutex::new(file) })
+    }
+
+    /// Records one operation and its timing. Capture failures are logged and
+    /// otherwise ignored: recording must never fail the request itself.
+    pub fn record(&self, body: &GraphQlBody, duration: Duration) {
+        let mut body = match serde_json::to_value(body) {
+            Ok(body) => body,
+            Err(err) => {
+                warn!("Could not serialize operation for capture: {}", err);
+                return;
+            }
+        };
+
+        redact_variables(&mut body);
+
+        let operation = CapturedOperation {
+            timestamp_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|since| since.as_millis() as u64)
+                .unwrap_or(0),
+            duration_ms: duration.as_millis() as u64,
+            body,
+        };
+
+        let line = serde_json::to_string(&operation).expect("a captured operation always serializes");
+        let mut file = self.file.lock().unwrap();
+
+        if let Err(err) = writeln!(file, "{}", line) {
+            warn!("Could not write to capture file: {}", err);
+        }
+    }
+}
+
+/// Replaces every variable value in a serialized `GraphQlBody` with a
+/// placeholder, recursing into the queries of a batch.
+fn redact_variables(body: &mut serde_json::Value) {
+    let map = match body {
+        serde_json::Value::Object(map) => map,
+        _ => return,
+    };
+
+    if let Some(serde_json::Value::Object(variables)) = map.get_mut("variables") {
+        for value in variables.values_mut() {
+            *value = serde_json::Value::String("<redacted>".into());
+        }
+    }
+
+    if let Some(serde_json::Value::Array(batch)) = map.get_mut("batch") {
+        for query in batch {
+            redact_variables(query);
+        }
+    }
+}
@@ -1,12 +1,17 @@
 #![deny(missing_docs)]
 
+pub(crate) mod capture;
+
 mod elapsed_middleware;
+mod persisted_operations;
 
 use crate::{context::PrismaContext, opt::PrismaOpt, PrismaResult};
+use capture::OperationCapture;
 use datamodel::common::preview_features::PreviewFeature;
 use elapsed_middleware::ElapsedMiddleware;
 use opentelemetry::{global, Context};
-use query_core::{schema::QuerySchemaRenderer, TxId};
+use persisted_operations::PersistedOperations;
+use query_core::{schema::QuerySchemaRenderer, CursorSessionId, TxId};
 use request_handlers::{dmmf, GraphQLSchemaRenderer, GraphQlBody, GraphQlHandler, TxInput};
 use serde_json::json;
 use std::{collections::HashMap, sync::Arc};
@@ -25,15 +30,28 @@ pub(crate) struct State {
     cx: Arc<PrismaContext>,
     enable_playground: bool,
     enable_debug_mode: bool,
+    persisted_operations: Option<Arc<PersistedOperations>>,
+    capture: Option<Arc<OperationCapture>>,
+    max_response_size: Option<usize>,
 }
 
 impl State {
     /// Create a new instance of `State`.
-    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool) -> Self {
+    fn new(
+        cx: PrismaContext,
+        enable_playground: bool,
+        enable_debug_mode: bool,
+        persisted_operations: Option<PersistedOperations>,
+        capture: Option<OperationCapture>,
+        max_response_size: Option<usize>,
+    ) -> Self {
         Self {
             cx: Arc::new(cx),
             enable_playground,
             enable_debug_mode,
+            persisted_operations: persisted_operations.map(Arc::new),
+            capture: capture.map(Arc::new),
+            max_response_size,
         }
     }
 }
@@ -44,6 +62,9 @@ impl Clone for State {
             cx: self.cx.clone(),
             enable_playground: self.enable_playground,
             enable_debug_mode: self.enable_debug_mode,
+            persisted_operations: self.persisted_operations.clone(),
+            capture: self.capture.clone(),
+            max_response_size: self.max_response_size,
         }
     }
 }
@@ -62,10 +83,26 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     let cx = PrismaContext::builder(config, datamodel)
         .legacy(opts.legacy)
         .enable_raw_queries(opts.enable_raw_queries)
+        .omit_unchecked_inputs(opts.omit_unchecked_inputs)
         .build()
         .await?;
 
-    let mut app = tide::with_state(State::new(cx, opts.enable_playground, opts.enable_debug_mode));
+    let persisted_operations = opts
+        .persisted_operations_manifest
+        .as_deref()
+        .map(PersistedOperations::load)
+        .transpose()?;
+
+    let capture = opts.capture_path.as_deref().map(OperationCapture::open).transpose()?;
+
+    let mut app = tide::with_state(State::new(
+        cx,
+        opts.enable_playground,
+        opts.enable_debug_mode,
+        persisted_operations,
+        capture,
+        opts.max_response_size,
+    ));
     app.with(ElapsedMiddleware::new());
 
     if opts.enable_playground {
@@ -78,12 +115,19 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
     app.at("/status").get(|_| async move { Ok(json!({"status": "ok"})) });
+    app.at("/persisted_operations").get(persisted_operations_handler);
 
     if enable_itx {
         // Transaction routes.
         app.at("/transaction/start").post(transaction_start_handler);
         app.at("/transaction/:id/commit").post(transaction_commit_handler);
         app.at("/transaction/:id/rollback").post(transaction_rollback_handler);
+        app.at("/transaction/status").get(transaction_status_handler);
+
+        // Cursor session routes. Sessions are backed by interactive transactions,
+        // so they are only available when those are enabled.
+        app.at("/cursor_session/start").post(cursor_session_start_handler);
+        app.at("/cursor_session/:id/close").post(cursor_session_close_handler);
     }
 
     // Start the Tide server and log the server details.
@@ -117,15 +161,59 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         .map(|values| values.last().to_string())
         .map(TxId::from);
 
+    let cursor_session_id = req
+        .header("X-cursor-session-id")
+        .map(|values| values.last().to_string())
+        .map(CursorSessionId::from);
+
     let work = async move {
         let body: GraphQlBody = req.body_json().await?;
+
+        // In persisted operations mode, only queries from the loaded manifest are executed.
+        if let Some(persisted) = &req.state().persisted_operations {
+            if !body.queries().into_iter().all(|query| persisted.contains(query)) {
+                let mut res = Response::new(StatusCode::Forbidden);
+                res.set_body(Body::from_json(
+                    &json!({ "error": "Operation is not part of the persisted operations manifest." }),
+                )?);
+
+                return Ok(res);
+            }
+        }
+
         let cx = req.state().cx.clone();
+        let capture = req.state().capture.clone();
+
+        // A cursor session token reads inside the transaction backing its
+        // snapshot; an explicit transaction id takes precedence.
+        let tx_id = match (tx_id, cursor_session_id) {
+            (None, Some(session_id)) => match cx.executor.resolve_cursor_session(&session_id) {
+                Ok(tx_id) => Some(tx_id),
+                Err(err) => return err_to_http_resp(err),
+            },
+            (tx_id, _) => tx_id,
+        };
+
+        // Recording needs the body again after the handler consumed it.
+        let captured_body = capture.as_ref().map(|_| body.clone());
+        let started_at = std::time::Instant::now();
 
         let handler = GraphQlHandler::new(&*cx.executor, cx.query_schema());
         let result = handler.handle(body, tx_id).await;
 
+        if let (Some(capture), Some(body)) = (capture, captured_body) {
+            capture.record(&body, started_at.elapsed());
+        }
+
         let mut res = Response::new(StatusCode::Ok);
-        res.set_body(Body::from_json(&result)?);
+
+        match req.state().max_response_size {
+            Some(limit) => {
+                res.set_body(result.to_json_string_capped(limit));
+                res.set_content_type(tide::http::mime::JSON);
+            }
+            None => res.set_body(Body::from_json(&result)?),
+        }
 
         Ok(res)
     };
@@ -159,20 +247,60 @@ async fn sdl_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
 
 /// Renders the Data Model Meta Format.
 /// Only callable if prisma was initialized using a v2 data model.
+/// The encoding version can be negotiated with the `version` query parameter,
+/// defaulting to version 1. Version 2 is the compact encoding.
 async fn dmmf_handler(req: Request<State>) -> tide::Result {
-    let result = dmmf::render_dmmf(req.state().cx.datamodel(), Arc::clone(req.state().cx.query_schema()));
+    #[derive(serde::Deserialize, Default)]
+    struct DmmfParams {
+        version: Option<u32>,
+    }
+
+    let version = req.query::<DmmfParams>().unwrap_or_default().version.unwrap_or(1);
     let mut res = Response::new(StatusCode::Ok);
 
-    res.set_body(Body::from_json(&result)?);
+    match version {
+        1 => {
+            let result = dmmf::render_dmmf(req.state().cx.datamodel(), Arc::clone(req.state().cx.query_schema()));
+            res.set_body(Body::from_json(&result)?);
+        }
+        2 => {
+            let result =
+                dmmf::render_dmmf_compact(req.state().cx.datamodel(), Arc::clone(req.state().cx.query_schema()));
+            res.set_body(Body::from_json(&result)?);
+        }
+        other => {
+            let mut res = Response::new(StatusCode::BadRequest);
+            res.set_body(format!("Unknown DMMF version: {}. Supported versions: 1, 2.", other));
+            return Ok(res);
+        }
+    }
+
     Ok(res)
 }
 
-/// Simple status endpoint
+/// Management endpoint for persisted operations mode, reporting rejected unknown-hash attempts.
+async fn persisted_operations_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
+    match &req.state().persisted_operations {
+        Some(persisted) => Ok(json!({
+            "enabled": true,
+            "unknown_hash_attempts": persisted.unknown_attempts(),
+            "recent_unknown_hashes": persisted.recent_unknown_hashes(),
+        })),
+        None => Ok(json!({ "enabled": false })),
+    }
+}
+
+/// Engine self-description: version, commit, connector and its capabilities,
+/// allowing client tooling to check expectations up front.
 async fn server_info_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
+    let context = req.state().cx.query_schema().context();
+
     Ok(json!({
         "commit": env!("GIT_HASH"),
         "version": env!("CARGO_PKG_VERSION"),
         "primary_connector": req.state().cx.primary_connector(),
+        "preview_features": context.features,
+        "capabilities": context.capabilities.iter().map(ToString::to_string).collect::<Vec<_>>(),
     }))
 }
 
@@ -180,7 +308,12 @@ async fn transaction_start_handler(mut req: Request<State>) -> tide::Result<impl
     let input: TxInput = req.body_json().await?;
     let state = req.state();
 
-    match state.cx.executor.start_tx(input.max_wait, input.timeout).await {
+    match state
+        .cx
+        .executor
+        .start_tx(input.max_wait, input.timeout, input.tag)
+        .await
+    {
         Ok(tx_id) => Ok(json!({ "id": tx_id.to_string() }).into()),
         Err(err) => err_to_http_resp(err),
     }
@@ -196,6 +329,36 @@ async fn transaction_commit_handler(req: Request<State>) -> tide::Result<impl In
     }
 }
 
+async fn cursor_session_start_handler(mut req: Request<State>) -> tide::Result<impl Into<Response>> {
+    let input: TxInput = req.body_json().await?;
+    let state = req.state();
+
+    match state
+        .cx
+        .executor
+        .start_cursor_session(input.max_wait, input.timeout)
+        .await
+    {
+        Ok(session_id) => Ok(json!({ "id": session_id.to_string() }).into()),
+        Err(err) => err_to_http_resp(err),
+    }
+}
+
+async fn cursor_session_close_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
+    let session_id = CursorSessionId::from(req.param("id")?);
+    let state = req.state();
+
+    match state.cx.executor.close_cursor_session(session_id).await {
+        Ok(_) => Ok(json!({}).into()),
+        Err(err) => err_to_http_resp(err),
+    }
+}
+
+async fn transaction_status_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
+    let status = req.state().cx.executor.transaction_status();
+    Ok(Body::from_json(&status)?)
+}
+
 async fn transaction_rollback_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
     let tx_id = TxId::from(req.param("id")?);
     let state = req.state();
@@ -243,9 +406,12 @@ fn err_to_http_resp(err: query_core::CoreError) -> tide::Result<Response> {
     let status = match err {
         query_core::CoreError::TransactionError(ref err) => match err {
             query_core::TransactionError::AcquisitionTimeout => 504,
+            query_core::TransactionError::ConnectionsExhausted { limit: _ } => 503,
             query_core::TransactionError::AlreadyStarted => todo!(),
             query_core::TransactionError::NotFound => 404,
             query_core::TransactionError::Closed { reason: _ } => 422,
+            query_core::TransactionError::Expired => 422,
+            query_core::TransactionError::CursorSessionNotFound => 404,
         },
 
         // All other errors are treated as 500s, most of these paths should never be hit, only connector errors may occur.
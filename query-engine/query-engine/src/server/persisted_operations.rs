@@ -0,0 +1,88 @@
+use crate::{error::PrismaError, PrismaResult};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Mutex,
+};
+
+/// Number of unknown operation hashes kept for reporting.
+const UNKNOWN_HASH_LOG_SIZE: usize = 100;
+
+/// Persisted operations manifest: a JSON object mapping the lowercase hex SHA-256
+/// hash of an operation to its query text, as produced by client tooling:
+///
+/// ```json
+/// { "operations": { "<sha256 of query>": "query { ... }" } }
+/// ```
+///
+/// When loaded, the engine only executes operations whose hash appears in the
+/// manifest and rejects ad-hoc queries.
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    operations: HashMap<String, String>,
+}
+
+/// Allow-list of operations the engine may execute, plus a record of rejected
+/// unknown-hash attempts for the management endpoint.
+#[derive(Debug)]
+pub(crate) struct PersistedOperations {
+    hashes: HashSet<String>,
+
+    unknown_attempts: AtomicU64,
+    recent_unknown_hashes: Mutex<Vec<String>>,
+}
+
+impl PersistedOperations {
+    /// Loads the manifest from the given path.
+    pub fn load(path: &str) -> PrismaResult<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| PrismaError::ConfigurationError(format!("Unable to read operations manifest: {}", err)))?;
+
+        let manifest: Manifest = serde_json::from_str(&content)
+            .map_err(|err| PrismaError::ConfigurationError(format!("Unable to parse operations manifest: {}", err)))?;
+
+        Ok(Self {
+            hashes: manifest.operations.into_keys().collect(),
+            unknown_attempts: AtomicU64::new(0),
+            recent_unknown_hashes: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// The hash of a query as client tooling computes it: lowercase hex SHA-256 over
+    /// the exact query text.
+    pub fn hash(query: &str) -> String {
+        format!("{:x}", Sha256::digest(query.as_bytes()))
+    }
+
+    /// Checks whether the query is part of the manifest. Unknown queries are recorded
+    /// for the management endpoint.
+    pub fn contains(&self, query: &str) -> bool {
+        let hash = Self::hash(query);
+
+        if self.hashes.contains(&hash) {
+            return true;
+        }
+
+        self.unknown_attempts.fetch_add(1, Ordering::SeqCst);
+
+        let mut recent = self.recent_unknown_hashes.lock().unwrap();
+        if recent.len() == UNKNOWN_HASH_LOG_SIZE {
+            recent.remove(0);
+        }
+        recent.push(hash);
+
+        false
+    }
+
+    /// Total number of rejected unknown-hash attempts since startup.
+    pub fn unknown_attempts(&self) -> u64 {
+        self.unknown_attempts.load(Ordering::SeqCst)
+    }
+
+    /// The most recently rejected unknown hashes, oldest first.
+    pub fn recent_unknown_hashes(&self) -> Vec<String> {
+        self.recent_unknown_hashes.lock().unwrap().clone()
+    }
+}
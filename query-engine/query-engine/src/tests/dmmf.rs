@@ -24,6 +24,7 @@ pub fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml:
         internal_ref,
         BuildMode::Modern,
         false,
+        false,
         capabilities,
         config.subject.preview_features().iter().collect(),
         referential_integrity,
@@ -54,6 +55,57 @@ fn must_not_fail_on_missing_env_vars_in_a_datasource() {
     assert!(!inputs.is_empty());
 }
 
+#[test]
+#[serial]
+fn preview_feature_gated_types_must_not_leak_into_dmmf() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost/test"
+        }
+
+        model Blog {
+            blogId String @id
+            title  String
+        }
+    "#;
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = request_handlers::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    for input_type in dmmf.schema.input_object_types.values().flatten() {
+        assert_ne!(input_type.name, "FieldRefInput");
+        assert!(!input_type.name.ends_with("OrderByRelevanceInput"));
+    }
+}
+
+#[test]
+#[serial]
+fn preview_feature_gated_types_are_exposed_when_the_flag_is_on() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost/test"
+        }
+
+        generator client {
+            provider        = "prisma-client-js"
+            previewFeatures = ["fieldReference"]
+        }
+
+        model Blog {
+            blogId String @id
+            title  String
+        }
+    "#;
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = request_handlers::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let mut input_types = dmmf.schema.input_object_types.values().flatten();
+    assert!(input_types.any(|input_type| input_type.name == "FieldRefInput"));
+}
+
 #[test]
 #[serial]
 fn must_not_fail_if_no_datasource_is_defined() {
@@ -107,7 +159,12 @@ fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
         legacy: false,
         log_format: None,
         log_queries: true,
+        omit_unchecked_inputs: false,
+        max_response_size: None,
         overwrite_datasources: None,
+        credential_provider: None,
+        persisted_operations_manifest: None,
+        capture_path: None,
         port: 123,
         unix_path: None,
         subcommand: Some(Subcommand::Cli(CliOpt::Dmmf)),
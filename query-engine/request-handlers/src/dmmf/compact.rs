@@ -0,0 +1,218 @@
+use super::schema::{
+    DmmfDeprecation, DmmfEnum, DmmfInputField, DmmfInputType, DmmfInputTypeConstraints, DmmfOutputType,
+    DmmfTypeReference, TypeLocation,
+};
+use super::{render_dmmf, DmmfOperationMappings};
+use indexmap::IndexMap;
+use query_core::schema::QuerySchemaRef;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Version 2 of the DMMF encoding. Compared to version 1, every type
+/// reference is interned into a single `typeReferences` table and referenced
+/// by index, and fields with default values (`false` booleans, empty lists,
+/// absent constraints) are omitted entirely. For large schemas this shrinks
+/// the document considerably, since the same type references are repeated on
+/// thousands of fields in version 1.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactDataModelMetaFormat {
+    /// The version of the encoding, always 2.
+    pub version: u32,
+    #[serde(rename = "datamodel")]
+    pub data_model: serde_json::Value,
+    /// All distinct type references. Fields refer to entries by index.
+    pub type_references: Vec<DmmfTypeReference>,
+    pub schema: CompactDmmfSchema,
+    pub mappings: DmmfOperationMappings,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactDmmfSchema {
+    pub input_object_types: IndexMap<String, Vec<CompactInputType>>,
+    pub output_object_types: IndexMap<String, Vec<CompactOutputType>>,
+    pub enum_types: IndexMap<String, Vec<DmmfEnum>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactInputType {
+    pub name: String,
+    #[serde(skip_serializing_if = "constraints_are_empty")]
+    pub constraints: DmmfInputTypeConstraints,
+    pub fields: Vec<CompactInputField>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactInputField {
+    pub name: String,
+    #[serde(skip_serializing_if = "is_false")]
+    pub is_required: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub is_nullable: bool,
+    /// Indices into the type reference table.
+    pub input_types: Vec<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<DmmfDeprecation>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactOutputType {
+    pub name: String,
+    pub fields: Vec<CompactOutputField>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactOutputField {
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<CompactInputField>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub is_nullable: bool,
+    /// Index into the type reference table.
+    pub output_type: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<DmmfDeprecation>,
+}
+
+pub fn render_dmmf_compact(dml: &datamodel::Datamodel, query_schema: QuerySchemaRef) -> CompactDataModelMetaFormat {
+    let dmmf = render_dmmf(dml, query_schema);
+    let mut interner = TypeRefInterner::default();
+
+    let input_object_types = dmmf
+        .schema
+        .input_object_types
+        .into_iter()
+        .map(|(namespace, types)| {
+            let types = types
+                .into_iter()
+                .map(|tpe| compact_input_type(tpe, &mut interner))
+                .collect();
+
+            (namespace, types)
+        })
+        .collect();
+
+    let output_object_types = dmmf
+        .schema
+        .output_object_types
+        .into_iter()
+        .map(|(namespace, types)| {
+            let types = types
+                .into_iter()
+                .map(|tpe| compact_output_type(tpe, &mut interner))
+                .collect();
+
+            (namespace, types)
+        })
+        .collect();
+
+    CompactDataModelMetaFormat {
+        version: 2,
+        data_model: dmmf.data_model,
+        type_references: interner.references,
+        schema: CompactDmmfSchema {
+            input_object_types,
+            output_object_types,
+            enum_types: dmmf.schema.enum_types,
+        },
+        mappings: dmmf.mappings,
+    }
+}
+
+fn compact_input_type(tpe: DmmfInputType, interner: &mut TypeRefInterner) -> CompactInputType {
+    CompactInputType {
+        name: tpe.name,
+        constraints: tpe.constraints,
+        fields: tpe
+            .fields
+            .into_iter()
+            .map(|field| compact_input_field(field, interner))
+            .collect(),
+    }
+}
+
+fn compact_input_field(field: DmmfInputField, interner: &mut TypeRefInterner) -> CompactInputField {
+    CompactInputField {
+        name: field.name,
+        is_required: field.is_required,
+        is_nullable: field.is_nullable,
+        input_types: field
+            .input_types
+            .into_iter()
+            .map(|reference| interner.intern(reference))
+            .collect(),
+        deprecation: field.deprecation,
+    }
+}
+
+fn compact_output_type(tpe: DmmfOutputType, interner: &mut TypeRefInterner) -> CompactOutputType {
+    CompactOutputType {
+        name: tpe.name,
+        fields: tpe
+            .fields
+            .into_iter()
+            .map(|field| CompactOutputField {
+                name: field.name,
+                args: field
+                    .args
+                    .into_iter()
+                    .map(|arg| compact_input_field(arg, interner))
+                    .collect(),
+                is_nullable: field.is_nullable,
+                output_type: interner.intern(field.output_type),
+                deprecation: field.deprecation,
+            })
+            .collect(),
+    }
+}
+
+#[derive(Default)]
+struct TypeRefInterner {
+    references: Vec<DmmfTypeReference>,
+    lookup: HashMap<(String, Option<String>, u8, bool), usize>,
+}
+
+impl TypeRefInterner {
+    fn intern(&mut self, reference: DmmfTypeReference) -> usize {
+        let key = (
+            reference.typ.clone(),
+            reference.namespace.clone(),
+            location_tag(reference.location),
+            reference.is_list,
+        );
+
+        if let Some(index) = self.lookup.get(&key) {
+            return *index;
+        }
+
+        let index = self.references.len();
+
+        self.references.push(reference);
+        self.lookup.insert(key, index);
+
+        index
+    }
+}
+
+fn location_tag(location: TypeLocation) -> u8 {
+    match location {
+        TypeLocation::Scalar => 0,
+        TypeLocation::InputObjectTypes => 1,
+        TypeLocation::OutputObjectTypes => 2,
+        TypeLocation::EnumTypes => 3,
+    }
+}
+
+fn constraints_are_empty(constraints: &DmmfInputTypeConstraints) -> bool {
+    constraints.max_num_fields.is_none() && constraints.min_num_fields.is_none()
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_false(value: &bool) -> bool {
+    !*value
+}
@@ -1,5 +1,9 @@
 pub mod schema;
 
+mod compact;
+
+pub use compact::{render_dmmf_compact, CompactDataModelMetaFormat};
+
 use query_core::schema::{QuerySchemaRef, QuerySchemaRenderer};
 use schema::*;
 use serde::{ser::SerializeMap, Serialize, Serializer};
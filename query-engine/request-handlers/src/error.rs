@@ -19,6 +19,12 @@ pub enum HandlerError {
         feature_name: &'static str,
         message: String,
     },
+
+    #[error(
+        "The response size exceeded the configured maximum of {} bytes. Paginate the query with `take`/`skip` or a `cursor` to reduce the response size.",
+        limit
+    )]
+    ResponseSizeLimitExceeded { limit: usize },
 }
 
 impl HandlerError {
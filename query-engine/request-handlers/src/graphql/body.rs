@@ -3,7 +3,7 @@ use query_core::{BatchDocument, Operation, QueryDocument};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::GraphQLProtocolAdapter;
+use super::{GraphQLProtocolAdapter, SerializationOptions};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", untagged)]
@@ -18,6 +18,9 @@ pub struct SingleQuery {
     query: String,
     operation_name: Option<String>,
     variables: HashMap<String, String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    extensions: Option<RequestExtensions>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -25,11 +28,26 @@ pub struct SingleQuery {
 pub struct MultiQuery {
     batch: Vec<SingleQuery>,
     transaction: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    extensions: Option<RequestExtensions>,
+}
+
+/// Optional, protocol-level extensions a client can attach to a request.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestExtensions {
+    #[serde(default)]
+    pub serialization: SerializationOptions,
 }
 
 impl MultiQuery {
     pub fn new(batch: Vec<SingleQuery>, transaction: bool) -> Self {
-        Self { batch, transaction }
+        Self {
+            batch,
+            transaction,
+            extensions: None,
+        }
     }
 }
 
@@ -39,6 +57,7 @@ impl From<String> for SingleQuery {
             query,
             operation_name: None,
             variables: HashMap::new(),
+            extensions: None,
         }
     }
 }
@@ -50,8 +69,29 @@ impl From<&str> for SingleQuery {
 }
 
 impl GraphQlBody {
+    /// The raw query strings contained in the body.
+    pub fn queries(&self) -> Vec<&str> {
+        match self {
+            GraphQlBody::Single(body) => vec![body.query.as_str()],
+            GraphQlBody::Multi(bodies) => bodies.batch.iter().map(|body| body.query.as_str()).collect(),
+        }
+    }
+
+    /// The serialization options requested via the body's extensions, falling
+    /// back to the defaults if none were sent.
+    pub fn serialization_options(&self) -> SerializationOptions {
+        let extensions = match self {
+            GraphQlBody::Single(body) => body.extensions.as_ref(),
+            GraphQlBody::Multi(bodies) => bodies.extensions.as_ref(),
+        };
+
+        extensions.map(|ext| ext.serialization).unwrap_or_default()
+    }
+
     /// Convert a `GraphQlBody` into a `QueryDocument`.
-    pub(crate) fn into_doc(self) -> crate::Result<QueryDocument> {
+    /// Converts the body into a query document. Public so the fuzz targets
+    /// can exercise the full deserialization-to-document path.
+    pub fn into_doc(self) -> crate::Result<QueryDocument> {
         match self {
             GraphQlBody::Single(body) => {
                 let gql_doc = gql::parse_query(&body.query)?;
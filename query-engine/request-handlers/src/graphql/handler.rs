@@ -1,4 +1,4 @@
-use super::{GQLBatchResponse, GQLResponse, GraphQlBody};
+use super::{introspection, GQLBatchResponse, GQLResponse, GraphQlBody};
 use crate::PrismaResponse;
 use futures::FutureExt;
 use indexmap::IndexMap;
@@ -27,14 +27,28 @@ impl<'a> GraphQlHandler<'a> {
     pub async fn handle(&self, body: GraphQlBody, tx_id: Option<TxId>) -> PrismaResponse {
         tracing::debug!("Incoming GraphQL query: {:?}", body);
 
-        match body.into_doc() {
+        let serialization_options = body.serialization_options();
+
+        let mut response = match body.into_doc() {
+            Ok(QueryDocument::Single(query)) if introspection::is_introspection_operation(&query) => {
+                PrismaResponse::Single(introspection::handle(self.query_schema, query))
+            }
             Ok(QueryDocument::Single(query)) => self.handle_single(query, tx_id).await,
             Ok(QueryDocument::Multi(batch)) => match batch.compact() {
                 BatchDocument::Multi(batch, transactional) => self.handle_batch(batch, transactional, tx_id).await,
                 BatchDocument::Compact(compacted) => self.handle_compacted(compacted, tx_id).await,
             },
             Err(err) => PrismaResponse::Single(err.into()),
+        };
+
+        if !serialization_options.is_default() {
+            match &mut response {
+                PrismaResponse::Single(response) => response.apply_serialization_options(&serialization_options),
+                PrismaResponse::Multi(responses) => responses.apply_serialization_options(&serialization_options),
+            }
         }
+
+        response
     }
 
     async fn handle_single(&self, query: Operation, tx_id: Option<TxId>) -> PrismaResponse {
@@ -0,0 +1,312 @@
+use super::{schema_renderer::pick_input_type, GQLResponse};
+use query_core::{schema::*, Item, Operation, QueryValue, Selection};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+/// Resolves GraphQL introspection operations (`__schema` and `__type`) against the query schema,
+/// allowing standard GraphQL clients and codegen tools to introspect the engine directly.
+///
+/// The response always contains the complete introspection data for the requested type(s),
+/// irrespective of the sub-selection: the canonical introspection query issued by tooling selects
+/// essentially everything, and pruning the result has no practical benefit for this endpoint.
+/// The introspection meta types (`__Schema`, `__Type`, ...) are not part of the rendered type list.
+pub(super) fn handle(query_schema: &QuerySchemaRef, operation: Operation) -> GQLResponse {
+    let selection = match operation {
+        Operation::Read(selection) => selection,
+        Operation::Write(_) => unreachable!("Introspection operations are always reads."),
+    };
+
+    let key = selection
+        .alias()
+        .clone()
+        .unwrap_or_else(|| selection.name().to_string());
+
+    let data = match selection.name() {
+        "__schema" => render_schema(query_schema),
+        "__type" => render_type(query_schema, &selection),
+        name => unreachable!("Unknown introspection operation: {}", name),
+    };
+
+    let mut response = GQLResponse::with_capacity(1);
+    response.insert_data(key, Item::Json(data));
+    response
+}
+
+/// Determines whether the operation must be resolved via introspection instead of query execution.
+pub(super) fn is_introspection_operation(operation: &Operation) -> bool {
+    match operation {
+        Operation::Read(selection) => matches!(selection.name(), "__schema" | "__type"),
+        Operation::Write(_) => false,
+    }
+}
+
+/// Renders the `__schema` introspection object.
+fn render_schema(query_schema: &QuerySchemaRef) -> Value {
+    let mut renderer = IntrospectionRenderer::default();
+
+    let query_name = renderer.reference_output_type(&query_schema.query)["name"].clone();
+    let mutation_name = renderer.reference_output_type(&query_schema.mutation)["name"].clone();
+
+    renderer.render_pending();
+
+    json!({
+        "queryType": { "name": query_name },
+        "mutationType": { "name": mutation_name },
+        "subscriptionType": Value::Null,
+        "types": renderer.types,
+        "directives": [],
+    })
+}
+
+/// Renders the `__type` introspection object for the type name given in the `name` argument,
+/// or JSON null if no such type exists. Renders the full schema to find the type by name -
+/// introspection is not a hot path, so simplicity wins over an indexed lookup here.
+fn render_type(query_schema: &QuerySchemaRef, selection: &Selection) -> Value {
+    let name = selection.arguments().iter().find_map(|(arg, value)| match value {
+        QueryValue::String(name) if arg == "name" => Some(name.as_str()),
+        _ => None,
+    });
+
+    let name = match name {
+        Some(name) => name,
+        None => return Value::Null,
+    };
+
+    let mut renderer = IntrospectionRenderer::default();
+
+    renderer.reference_output_type(&query_schema.query);
+    renderer.reference_output_type(&query_schema.mutation);
+    renderer.render_pending();
+
+    renderer
+        .types
+        .into_iter()
+        .find(|typ| typ["name"] == name)
+        .unwrap_or(Value::Null)
+}
+
+/// Object types scheduled for rendering. Objects can reference each other cyclically, so they
+/// are queued on first reference and rendered iteratively instead of recursively.
+enum PendingType {
+    Output(ObjectTypeWeakRef),
+    Input(InputObjectTypeWeakRef),
+}
+
+/// Walks the query schema, accumulating the full introspection type list. Enums and scalars are
+/// rendered eagerly on first reference, object types are queued (see [`PendingType`]).
+#[derive(Default)]
+struct IntrospectionRenderer {
+    /// Fully rendered types, in traversal order.
+    types: Vec<Value>,
+
+    /// Names of types already rendered or queued, preventing double rendering.
+    seen: HashSet<String>,
+
+    /// Object types referenced but not yet rendered.
+    pending: Vec<PendingType>,
+}
+
+impl IntrospectionRenderer {
+    fn render_pending(&mut self) {
+        while let Some(pending) = self.pending.pop() {
+            match pending {
+                PendingType::Output(obj) => self.render_output_object(obj),
+                PendingType::Input(obj) => self.render_input_object(obj),
+            }
+        }
+    }
+
+    fn render_output_object(&mut self, obj: ObjectTypeWeakRef) {
+        let obj = obj.into_arc();
+        let fields: Vec<_> = obj.get_fields().iter().map(|f| self.render_output_field(f)).collect();
+
+        self.types.push(json!({
+            "kind": "OBJECT",
+            "name": obj.identifier.name(),
+            "description": Value::Null,
+            "fields": fields,
+            "inputFields": Value::Null,
+            "interfaces": [],
+            "enumValues": Value::Null,
+            "possibleTypes": Value::Null,
+        }));
+    }
+
+    fn render_input_object(&mut self, obj: InputObjectTypeWeakRef) {
+        let obj = obj.into_arc();
+        let input_fields: Vec<_> = obj.get_fields().iter().map(|f| self.render_input_value(f)).collect();
+
+        self.types.push(json!({
+            "kind": "INPUT_OBJECT",
+            "name": obj.identifier.name(),
+            "description": Value::Null,
+            "fields": Value::Null,
+            "inputFields": input_fields,
+            "interfaces": Value::Null,
+            "enumValues": Value::Null,
+            "possibleTypes": Value::Null,
+        }));
+    }
+
+    fn render_output_field(&mut self, field: &OutputFieldRef) -> Value {
+        let args: Vec<_> = field.arguments.iter().map(|arg| self.render_input_value(arg)).collect();
+        let type_ref = self.reference_output_type(&field.field_type);
+        let type_ref = if field.is_nullable {
+            type_ref
+        } else {
+            non_null(type_ref)
+        };
+
+        json!({
+            "name": field.name,
+            "description": Value::Null,
+            "args": args,
+            "type": type_ref,
+            "isDeprecated": field.deprecation.is_some(),
+            "deprecationReason": field.deprecation.as_ref().map(|d| d.reason.clone()),
+        })
+    }
+
+    fn render_input_value(&mut self, field: &InputFieldRef) -> Value {
+        let type_ref = self.reference_input_type(pick_input_type(&field.field_types));
+        let type_ref = if field.is_required {
+            non_null(type_ref)
+        } else {
+            type_ref
+        };
+
+        json!({
+            "name": field.name,
+            "description": Value::Null,
+            "type": type_ref,
+            "defaultValue": Value::Null,
+        })
+    }
+
+    /// Renders a type reference (the `__Type` ofType chain, sans non-null wrapping) for an
+    /// output type, scheduling or rendering the referenced named type along the way.
+    fn reference_output_type(&mut self, typ: &OutputType) -> Value {
+        match typ {
+            OutputType::Object(obj) => {
+                let name = obj.into_arc().identifier.name().to_string();
+
+                if self.seen.insert(name.clone()) {
+                    self.pending.push(PendingType::Output(obj.clone()));
+                }
+
+                named_type_ref("OBJECT", name)
+            }
+            OutputType::Enum(et) => self.render_enum(et),
+            OutputType::List(inner) => {
+                let inner = self.reference_output_type(inner);
+                json!({ "kind": "LIST", "name": Value::Null, "ofType": inner })
+            }
+            OutputType::Scalar(scalar) => self.render_scalar(scalar),
+        }
+    }
+
+    /// Renders a type reference for an input type, scheduling or rendering the referenced
+    /// named type along the way.
+    fn reference_input_type(&mut self, typ: &InputType) -> Value {
+        match typ {
+            InputType::Object(obj) => {
+                let name = obj.into_arc().identifier.name().to_string();
+
+                if self.seen.insert(name.clone()) {
+                    self.pending.push(PendingType::Input(obj.clone()));
+                }
+
+                named_type_ref("INPUT_OBJECT", name)
+            }
+            InputType::Enum(et) => self.render_enum(et),
+            InputType::List(inner) => {
+                let inner = self.reference_input_type(inner);
+                json!({ "kind": "LIST", "name": Value::Null, "ofType": inner })
+            }
+            InputType::Scalar(scalar) => self.render_scalar(scalar),
+        }
+    }
+
+    fn render_enum(&mut self, enum_type: &EnumType) -> Value {
+        let name = enum_type.name().to_string();
+
+        if self.seen.insert(name.clone()) {
+            let values = match enum_type {
+                EnumType::String(s) => s.values().to_owned(),
+                EnumType::Database(dbt) => dbt.external_values(),
+                EnumType::FieldRef(f) => f.values(),
+            };
+
+            let enum_values: Vec<_> = values
+                .into_iter()
+                .map(|value| {
+                    json!({
+                        "name": value,
+                        "description": Value::Null,
+                        "isDeprecated": false,
+                        "deprecationReason": Value::Null,
+                    })
+                })
+                .collect();
+
+            self.types.push(json!({
+                "kind": "ENUM",
+                "name": name,
+                "description": Value::Null,
+                "fields": Value::Null,
+                "inputFields": Value::Null,
+                "interfaces": Value::Null,
+                "enumValues": enum_values,
+                "possibleTypes": Value::Null,
+            }));
+        }
+
+        named_type_ref("ENUM", name)
+    }
+
+    fn render_scalar(&mut self, scalar: &ScalarType) -> Value {
+        if let ScalarType::Enum(et) = scalar {
+            return self.render_enum(et);
+        }
+
+        let name = match scalar {
+            ScalarType::String => "String",
+            ScalarType::Int => "Int",
+            ScalarType::BigInt => "BigInt",
+            ScalarType::Boolean => "Boolean",
+            ScalarType::Float => "Float",
+            ScalarType::Decimal => "Decimal",
+            ScalarType::DateTime => "DateTime",
+            ScalarType::Json => "Json",
+            ScalarType::UUID => "UUID",
+            ScalarType::JsonList => "Json",
+            ScalarType::Xml => "Xml",
+            ScalarType::Bytes => "Bytes",
+            ScalarType::Enum(_) => unreachable!("Enum scalar types are rendered separately above."),
+            ScalarType::Null => unreachable!("Null types should not be rendered for introspection."),
+        };
+
+        if self.seen.insert(name.to_string()) {
+            self.types.push(json!({
+                "kind": "SCALAR",
+                "name": name,
+                "description": Value::Null,
+                "fields": Value::Null,
+                "inputFields": Value::Null,
+                "interfaces": Value::Null,
+                "enumValues": Value::Null,
+                "possibleTypes": Value::Null,
+            }));
+        }
+
+        named_type_ref("SCALAR", name)
+    }
+}
+
+fn named_type_ref(kind: &str, name: impl Into<String>) -> Value {
+    json!({ "kind": kind, "name": name.into(), "ofType": Value::Null })
+}
+
+fn non_null(of_type: Value) -> Value {
+    json!({ "kind": "NON_NULL", "name": Value::Null, "ofType": of_type })
+}
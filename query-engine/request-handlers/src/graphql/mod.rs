@@ -1,11 +1,14 @@
 mod body;
 mod handler;
+mod introspection;
 mod protocol_adapter;
 mod response;
 mod schema_renderer;
+mod serialization;
 
 pub use body::*;
 pub use handler::*;
 pub use protocol_adapter::*;
 pub use response::*;
 pub use schema_renderer::*;
+pub use serialization::*;
@@ -1,7 +1,8 @@
+use super::SerializationOptions;
 use crate::HandlerError;
 use indexmap::IndexMap;
 use query_core::{
-    response_ir::{Item, Map, ResponseData},
+    response_ir::{FieldError, Item, Map, ResponseData},
     CoreError,
 };
 
@@ -28,6 +29,11 @@ pub struct GQLBatchResponse {
 pub struct GQLError {
     error: String,
     user_facing_error: user_facing_errors::Error,
+
+    /// Response path of the field the error occurred at, per the GraphQL spec.
+    /// Only present for field-level errors of partial responses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<Vec<String>>,
 }
 
 impl GQLError {
@@ -63,6 +69,16 @@ impl GQLResponse {
     pub fn errors(&self) -> impl Iterator<Item = &GQLError> {
         self.errors.iter()
     }
+
+    /// Rewrites the response data according to the given serialization options.
+    pub fn apply_serialization_options(&mut self, options: &SerializationOptions) {
+        let data = std::mem::take(&mut self.data);
+
+        self.data = data
+            .into_iter()
+            .map(|(key, item)| (key, options.apply_to_item(item)))
+            .collect();
+    }
 }
 
 impl From<HandlerError> for GQLResponse {
@@ -100,15 +116,25 @@ impl From<user_facing_errors::Error> for GQLError {
         GQLError {
             error: err.message().to_owned(),
             user_facing_error: err,
+            path: None,
         }
     }
 }
 
+impl From<FieldError> for GQLError {
+    fn from(err: FieldError) -> GQLError {
+        let mut gql_error = GQLError::from(err.error);
+        gql_error.path = Some(err.path);
+        gql_error
+    }
+}
+
 impl From<CoreError> for GQLError {
     fn from(err: CoreError) -> GQLError {
         GQLError {
             error: format!("{}", err),
             user_facing_error: err.into(),
+            path: None,
         }
     }
 }
@@ -118,6 +144,11 @@ impl From<ResponseData> for GQLResponse {
         let mut gql_response = GQLResponse::with_capacity(1);
 
         gql_response.insert_data(response.key, response.data);
+
+        for error in response.errors {
+            gql_response.insert_error(GQLError::from(error));
+        }
+
         gql_response
     }
 }
@@ -147,6 +178,13 @@ impl GQLBatchResponse {
     pub fn errors(&self) -> impl Iterator<Item = &GQLError> {
         self.errors.iter()
     }
+
+    /// Rewrites all contained response data according to the given serialization options.
+    pub fn apply_serialization_options(&mut self, options: &SerializationOptions) {
+        for response in self.batch_result.iter_mut() {
+            response.apply_serialization_options(options);
+        }
+    }
 }
 
 impl From<user_facing_errors::Error> for GQLBatchResponse {
@@ -72,7 +72,7 @@ impl GqlFieldRenderer {
 ///
 /// Important: This doesn't really affect the functionality of the QE,
 ///            it's only serving the playground used for ad-hoc debugging.
-fn pick_input_type(candidates: &[InputType]) -> &InputType {
+pub(crate) fn pick_input_type(candidates: &[InputType]) -> &InputType {
     candidates
         .iter()
         .fold1(|prev, next| match (prev, next) {
@@ -4,6 +4,7 @@ mod object_renderer;
 mod type_renderer;
 
 use enum_renderer::*;
+pub(crate) use field_renderer::pick_input_type;
 use field_renderer::*;
 use object_renderer::*;
 use query_core::schema::*;
@@ -0,0 +1,79 @@
+use prisma_models::PrismaValue;
+use query_core::response_ir::Item;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Response serialization options a client can set per request, allowing different
+/// client languages to pick a lossless representation for values that do not map
+/// cleanly onto JSON (large integers, binary data).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SerializationOptions {
+    /// Render `BigInt` values as JSON strings (default) to avoid overflowing the
+    /// 53-bit safe integer range, or as plain JSON numbers when set to `false`.
+    pub big_int_as_string: bool,
+
+    /// Encoding used for `Bytes` values in the response.
+    pub bytes_encoding: BytesEncoding,
+}
+
+impl Default for SerializationOptions {
+    fn default() -> Self {
+        Self {
+            big_int_as_string: true,
+            bytes_encoding: BytesEncoding::Base64,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BytesEncoding {
+    Base64,
+    Hex,
+}
+
+impl SerializationOptions {
+    /// `true` if applying the options is a no-op over the default serialization.
+    pub fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Rewrites all leaf values of a response item according to the options.
+    pub(crate) fn apply_to_item(&self, item: Item) -> Item {
+        match item {
+            Item::Map(map) => Item::Map(map.into_iter().map(|(k, v)| (k, self.apply_to_item(v))).collect()),
+            Item::List(list) => Item::list(list.into_iter().map(|item| self.apply_to_item(item)).collect()),
+            Item::Value(value) => self.apply_to_value(value),
+            Item::Json(json) => Item::Json(json),
+            Item::Ref(r) => {
+                let item = Arc::try_unwrap(r).unwrap_or_else(|r| (*r).clone());
+                self.apply_to_item(item)
+            }
+        }
+    }
+
+    fn apply_to_value(&self, value: PrismaValue) -> Item {
+        match value {
+            PrismaValue::BigInt(int) if !self.big_int_as_string => Item::Json(serde_json::Value::from(int)),
+            PrismaValue::Bytes(bytes) if self.bytes_encoding == BytesEncoding::Hex => {
+                Item::Value(PrismaValue::String(encode_bytes_hex(&bytes)))
+            }
+            PrismaValue::List(values) => {
+                Item::list(values.into_iter().map(|value| self.apply_to_value(value)).collect())
+            }
+            value => Item::Value(value),
+        }
+    }
+}
+
+fn encode_bytes_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut acc, byte| {
+            write!(acc, "{:02x}", byte).unwrap();
+            acc
+        })
+}
@@ -26,3 +26,61 @@ pub enum PrismaResponse {
     Single(GQLResponse),
     Multi(GQLBatchResponse),
 }
+
+impl PrismaResponse {
+    /// Serializes the response to a JSON string, enforcing the given maximum size in bytes.
+    /// If the serialized response would exceed the cap, the data is discarded and an error
+    /// response advising pagination is returned instead, so oversized results cannot take
+    /// down size-limited transports (e.g. the N-API bridge).
+    ///
+    /// Serialization is aborted as soon as the cap is hit, so an oversized response never
+    /// materializes in memory.
+    pub fn to_json_string_capped(&self, max_response_size: usize) -> String {
+        let mut writer = CappedWriter::new(max_response_size);
+
+        match serde_json::to_writer(&mut writer, self) {
+            Ok(()) => String::from_utf8(writer.buf).expect("Serialized JSON is always valid UTF-8."),
+            Err(_) => {
+                let error = HandlerError::ResponseSizeLimitExceeded {
+                    limit: max_response_size,
+                };
+                let response = PrismaResponse::Single(error.into());
+
+                serde_json::to_string(&response).expect("Error responses are always serializable.")
+            }
+        }
+    }
+}
+
+/// A writer refusing to grow beyond a fixed number of bytes.
+struct CappedWriter {
+    buf: Vec<u8>,
+    max_size: usize,
+}
+
+impl CappedWriter {
+    fn new(max_size: usize) -> Self {
+        Self {
+            buf: Vec::new(),
+            max_size,
+        }
+    }
+}
+
+impl std::io::Write for CappedWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        if self.buf.len() + data.len() > self.max_size {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Response size limit exceeded.",
+            ));
+        }
+
+        self.buf.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ pub fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml:
         internal_ref,
         BuildMode::Modern,
         false,
+        false,
         capabilities,
         config.subject.preview_features().iter().collect(),
         referential_integrity,
@@ -7,4 +7,9 @@ pub struct TxInput {
 
     /// Time in milliseconds after which the transaction rolls back automatically.
     pub timeout: u64,
+
+    /// Optional tag identifying the originating request, shown in the
+    /// `transactionStatus` snapshot.
+    #[serde(default)]
+    pub tag: Option<String>,
 }